@@ -7,8 +7,10 @@ use linked_hash_map::LinkedHashMap;
 use move_binary_format::{
     access::ModuleAccess,
     binary_views::BinaryIndexedView,
+    compatibility::Compatibility,
     errors::VMResult,
     file_format::{AbilitySet, CompiledModule, LocalIndex, SignatureToken, StructHandleIndex},
+    normalized,
 };
 use move_core_types::{
     account_address::AccountAddress,
@@ -41,8 +43,9 @@ use sui_types::{
     error::{ExecutionErrorKind, SuiError},
     event::{Event, TransferType},
     messages::{CallArg, EntryArgumentErrorKind, InputObjectKind, ObjectArg},
+    move_package::{MovePackage, UpgradeCap},
     object::{self, Data, MoveObject, Object, Owner, ID_END_INDEX},
-    storage::{DeleteKind, ObjectChange, ParentSync, Storage, WriteKind},
+    storage::{ChildObjectResolver, DeleteKind, ObjectChange, ParentSync, Storage, WriteKind},
 };
 use sui_verifier::{
     entry_points_verifier::{is_tx_context, RESOLVED_ASCII_STR, RESOLVED_UTF8_STR},
@@ -71,7 +74,7 @@ pub fn new_session<
     'v,
     'r,
     E: Debug,
-    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage,
+    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage + ChildObjectResolver,
 >(
     vm: &'v MoveVM,
     state_view: &'r S,
@@ -94,7 +97,11 @@ pub fn new_session<
 #[allow(clippy::too_many_arguments)]
 pub fn execute<
     E: Debug,
-    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage + ParentSync,
+    S: ResourceResolver<Error = E>
+        + ModuleResolver<Error = E>
+        + Storage
+        + ParentSync
+        + ChildObjectResolver,
 >(
     vm: &MoveVM,
     state_view: &mut S,
@@ -105,10 +112,16 @@ pub fn execute<
     gas_status: &mut GasStatus,
     ctx: &mut TxContext,
 ) -> Result<(), ExecutionError> {
-    let objects = args
+    let package_id = ObjectID::from(*module_id.address());
+    let lineage = state_view
+        .read_object(&package_id)
+        .and_then(|package| package.data.try_as_package())
+        .map(|package| package.lineage().to_vec())
+        .unwrap_or_default();
+    let objects: BTreeMap<_, _> = args
         .iter()
         .filter_map(|arg| match arg {
-            CallArg::Pure(_) => None,
+            CallArg::Pure(_) | CallArg::Result(_) => None,
             CallArg::Object(ObjectArg::ImmOrOwnedObject((id, _, _)))
             | CallArg::Object(ObjectArg::SharedObject { id, .. }) => {
                 Some(vec![(*id, state_view.read_object(id)?)])
@@ -140,7 +153,9 @@ pub fn execute<
         by_value_objects,
         mutable_ref_objects,
         has_ctx_arg,
-    } = resolve_and_type_check(&objects, &module, function, &type_args, args, is_genesis)?;
+    } = resolve_and_type_check(
+        &objects, &module, &lineage, function, &type_args, args, is_genesis,
+    )?;
 
     if has_ctx_arg {
         args.push(ctx.to_vec());
@@ -149,6 +164,7 @@ pub fn execute<
         vm,
         state_view,
         &module_id,
+        &lineage,
         function,
         type_args,
         args,
@@ -161,16 +177,80 @@ pub fn execute<
     )
 }
 
+/// Resolves the types defined by the previous versions of a package to the corresponding types
+/// of the package. Upgrades preserve struct layouts, so objects keep the types they were created
+/// with, and their types are only resolved when they are checked against the types of the called
+/// version of the package.
+#[derive(Clone, Copy)]
+struct PackageLineage<'a> {
+    package_id: ObjectID,
+    previous: &'a [ObjectID],
+}
+
+impl<'a> PackageLineage<'a> {
+    fn new(module_id: &ModuleId, previous: &'a [ObjectID]) -> Self {
+        Self {
+            package_id: ObjectID::from(*module_id.address()),
+            previous,
+        }
+    }
+
+    fn resolve_struct_tag(&self, tag: &StructTag) -> StructTag {
+        let mut tag = tag.clone();
+        self.resolve_struct_tag_in_place(&mut tag);
+        tag
+    }
+
+    fn resolve_type_tag(&self, tag: &TypeTag) -> TypeTag {
+        let mut tag = tag.clone();
+        self.resolve_type_tag_in_place(&mut tag);
+        tag
+    }
+
+    fn resolve_module_id(&self, module_id: ModuleId) -> ModuleId {
+        if self
+            .previous
+            .contains(&ObjectID::from(*module_id.address()))
+        {
+            ModuleId::new(self.package_id.into(), module_id.name().to_owned())
+        } else {
+            module_id
+        }
+    }
+
+    fn resolve_struct_tag_in_place(&self, tag: &mut StructTag) {
+        if self.previous.contains(&ObjectID::from(tag.address)) {
+            tag.address = self.package_id.into();
+        }
+        for type_param in &mut tag.type_params {
+            self.resolve_type_tag_in_place(type_param);
+        }
+    }
+
+    fn resolve_type_tag_in_place(&self, tag: &mut TypeTag) {
+        match tag {
+            TypeTag::Struct(tag) => self.resolve_struct_tag_in_place(tag),
+            TypeTag::Vector(inner) => self.resolve_type_tag_in_place(inner),
+            _ => (),
+        }
+    }
+}
+
 /// This function calls into Move VM to execute a Move function
 /// call.
 #[allow(clippy::too_many_arguments)]
 fn execute_internal<
     E: Debug,
-    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage + ParentSync,
+    S: ResourceResolver<Error = E>
+        + ModuleResolver<Error = E>
+        + Storage
+        + ParentSync
+        + ChildObjectResolver,
 >(
     vm: &MoveVM,
     state_view: &mut S,
     module_id: &ModuleId,
+    lineage: &[ObjectID],
     function: &Identifier,
     type_args: Vec<TypeTag>,
     args: Vec<Vec<u8>>,
@@ -240,7 +320,7 @@ fn execute_internal<
         mutable_ref_objects.is_empty(),
         "All mutable references should have been marked as updated"
     );
-    let by_value_object_map = object_data
+    let mut by_value_object_map: BTreeMap<_, _> = object_data
         .into_iter()
         .filter(|(id, _obj)| by_value_objects.contains(id))
         .collect();
@@ -248,7 +328,16 @@ fn execute_internal<
         writes,
         deletions,
         user_events,
+        loaded_child_objects,
     } = object_runtime.finish()?;
+    // Child objects loaded during execution are treated like objects passed by value: their
+    // previous versions are needed to write or delete them
+    by_value_object_map.extend(
+        loaded_child_objects
+            .iter()
+            .map(|(id, object)| (*id, (object.owner, object.version()))),
+    );
+    state_view.save_loaded_child_objects(loaded_child_objects);
     let session = new_session(vm, &*state_view, BTreeMap::new());
     let writes = writes
         .into_iter()
@@ -273,6 +362,7 @@ fn execute_internal<
     process_successful_execution(
         state_view,
         module_id,
+        PackageLineage::new(module_id, lineage),
         &by_value_object_map,
         mutable_refs,
         writes,
@@ -286,7 +376,11 @@ fn execute_internal<
 
 pub fn publish<
     E: Debug,
-    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage + ParentSync,
+    S: ResourceResolver<Error = E>
+        + ModuleResolver<Error = E>
+        + Storage
+        + ParentSync
+        + ChildObjectResolver,
 >(
     state_view: &mut S,
     natives: NativeFunctionTable,
@@ -315,10 +409,156 @@ pub fn publish<
     store_package_and_init_modules(state_view, &vm, modules, ctx, gas_status)
 }
 
+/// Publish `module_bytes` as the next version of the package `package_id`, which `upgrade_cap`
+/// must be the upgrade capability of. The new version gets a fresh ID like a newly published
+/// package, but every module of the previous version must still be present and compatible: struct
+/// layouts and public function signatures cannot change, so that objects created with previous
+/// versions can be passed to the new one. Module initializers are not called.
+pub fn upgrade<
+    E: Debug,
+    S: ResourceResolver<Error = E>
+        + ModuleResolver<Error = E>
+        + Storage
+        + ParentSync
+        + ChildObjectResolver,
+>(
+    state_view: &mut S,
+    natives: NativeFunctionTable,
+    package_id: ObjectID,
+    mut upgrade_cap: Object,
+    module_bytes: Vec<Vec<u8>>,
+    ctx: &mut TxContext,
+    gas_status: &mut GasStatus,
+) -> Result<(), ExecutionError> {
+    let mut modules = module_bytes
+        .iter()
+        .map(|b| {
+            CompiledModule::deserialize(b)
+                .map_err(|e| e.finish(move_binary_format::errors::Location::Undefined))
+        })
+        .collect::<move_binary_format::errors::VMResult<Vec<CompiledModule>>>()?;
+
+    if modules.is_empty() {
+        return Err(ExecutionErrorKind::PublishErrorEmptyPackage.into());
+    }
+
+    let cap_id = upgrade_cap.id();
+    let cap_object = upgrade_cap
+        .data
+        .try_as_move_mut()
+        .filter(|cap| UpgradeCap::is_upgrade_cap(&cap.type_))
+        .ok_or_else(|| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::InvalidUpgradeCap,
+                format!("Object {cap_id} is not an upgrade capability"),
+            )
+        })?;
+    let mut cap = UpgradeCap::from_bcs_bytes(cap_object.contents())?;
+    if cap.package.bytes != package_id {
+        return Err(ExecutionError::new_with_source(
+            ExecutionErrorKind::InvalidUpgradeCap,
+            format!(
+                "The upgrade capability is for package {}, not {package_id}",
+                cap.package.bytes
+            ),
+        ));
+    }
+    let previous = state_view
+        .read_object(&package_id)
+        .and_then(|object| object.data.try_as_package())
+        .cloned()
+        .ok_or_else(|| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::InvariantViolation,
+                format!("Package {package_id} to upgrade is not an input of the transaction"),
+            )
+        })?;
+
+    let new_package_id = generate_package_id(&mut modules, ctx)?;
+    check_upgrade_compatibility(&previous, new_package_id, &modules)?;
+    verify_and_link(state_view, &modules, new_package_id, natives, gas_status)?;
+    state_view.log_event(Event::Publish {
+        sender: ctx.sender(),
+        package_id: new_package_id,
+    });
+
+    cap.package.bytes = new_package_id;
+    cap.version += 1;
+    cap_object.update_contents_and_increment_version(cap.to_bcs_bytes());
+    let package_object = Object::new_upgraded_package(modules, &previous, ctx.digest());
+    let changes = BTreeMap::from([
+        (
+            package_object.id(),
+            ObjectChange::Write(package_object, WriteKind::Create),
+        ),
+        (cap_id, ObjectChange::Write(upgrade_cap, WriteKind::Mutate)),
+    ]);
+    state_view.apply_object_changes(changes);
+    Ok(())
+}
+
+/// Check that `modules`, published at `package_id`, can replace `previous`: every module of
+/// `previous` must have a compatible module with the same name.
+fn check_upgrade_compatibility(
+    previous: &MovePackage,
+    package_id: ObjectID,
+    modules: &[CompiledModule],
+) -> Result<(), ExecutionError> {
+    // Compare the modules as if they were published at the address of the previous version, so
+    // that the types they define and use are comparable.
+    let sub_map = modules
+        .iter()
+        .map(|module| {
+            let name = module.self_id().name().to_owned();
+            (
+                ModuleId::new(AccountAddress::from(package_id), name.clone()),
+                ModuleId::new(AccountAddress::from(previous.id()), name),
+            )
+        })
+        .collect();
+    // Safe to unwrap because the range entries are at the address of the previous version,
+    // which is not the fresh ID of the domain entries
+    let rewriter = ModuleHandleRewriter::new(sub_map).unwrap();
+    let new_modules: BTreeMap<_, _> = modules
+        .iter()
+        .map(|module| {
+            let mut module = module.clone();
+            rewriter.sub_module_ids(&mut module);
+            let normalized = normalized::Module::new(&module);
+            (normalized.name.to_string(), normalized)
+        })
+        .collect();
+
+    let previous_modules = previous.normalize().map_err(|e| {
+        ExecutionError::new_with_source(ExecutionErrorKind::InvariantViolation, e.to_string())
+    })?;
+    for (name, previous_module) in previous_modules {
+        let module = new_modules.get(&name).ok_or_else(|| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::IncompatiblePackageUpgrade,
+                format!("Module {name} of the previous version is missing"),
+            )
+        })?;
+        Compatibility::full_check()
+            .check(&previous_module, module)
+            .map_err(|e| {
+                ExecutionError::new_with_source(
+                    ExecutionErrorKind::IncompatiblePackageUpgrade,
+                    format!("Module {name} is not compatible with its previous version: {e:?}"),
+                )
+            })?;
+    }
+    Ok(())
+}
+
 /// Store package in state_view and call module initializers
 pub fn store_package_and_init_modules<
     E: Debug,
-    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage + ParentSync,
+    S: ResourceResolver<Error = E>
+        + ModuleResolver<Error = E>
+        + Storage
+        + ParentSync
+        + ChildObjectResolver,
 >(
     state_view: &mut S,
     vm: &MoveVM,
@@ -356,7 +596,11 @@ pub fn store_package_and_init_modules<
 /// Modules in module_ids_to_init must have the init method defined
 fn init_modules<
     E: Debug,
-    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage + ParentSync,
+    S: ResourceResolver<Error = E>
+        + ModuleResolver<Error = E>
+        + Storage
+        + ParentSync
+        + ChildObjectResolver,
 >(
     state_view: &mut S,
     vm: &MoveVM,
@@ -383,6 +627,7 @@ fn init_modules<
             vm,
             state_view,
             &module_id,
+            &[],
             &init_ident,
             Vec::new(),
             args,
@@ -402,7 +647,7 @@ fn init_modules<
 /// and the Sui verifier.
 pub fn verify_and_link<
     E: Debug,
-    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage,
+    S: ResourceResolver<Error = E> + ModuleResolver<Error = E> + Storage + ChildObjectResolver,
 >(
     state_view: &S,
     modules: &[CompiledModule],
@@ -493,6 +738,7 @@ pub fn generate_package_id(
 fn process_successful_execution<S: Storage + ParentSync>(
     state_view: &mut S,
     module_id: &ModuleId,
+    lineage: PackageLineage,
     by_value_objects: &BTreeMap<ObjectID, (object::Owner, SequenceNumber)>,
     mutable_refs: Vec<(ObjectID, Vec<u8>)>,
     writes: LinkedHashMap<ObjectID, (WriteKind, Owner, StructTag, AbilitySet, Vec<u8>)>,
@@ -555,6 +801,19 @@ fn process_successful_execution<S: Storage + ParentSync>(
                 (WriteKind::Create, SequenceNumber::new())
             }
         };
+        // An object passed by value keeps the type it was created with, which may be defined by a
+        // previous version of the called package
+        let tag = match old_object
+            .and_then(|_| state_view.read_object(&id))
+            .and_then(|object| object.type_())
+        {
+            Some(old_tag)
+                if lineage.resolve_struct_tag(old_tag) == lineage.resolve_struct_tag(&tag) =>
+            {
+                old_tag.clone()
+            }
+            _ => tag,
+        };
         // safe because `has_public_transfer` was properly determined from the abilities
         let mut move_obj =
             unsafe { MoveObject::new_from_execution(tag, has_public_transfer, version, contents) };
@@ -589,12 +848,14 @@ fn process_successful_execution<S: Storage + ParentSync>(
         //   2. Created in this transaction (in `newly_generated_ids`)
         //   3. Unwrapped in this transaction
         // The following condition checks if this object was unwrapped in this transaction.
-        if let Some((_old_owner, old_obj_ver)) = old_object {
+        if let Some((old_owner, old_obj_ver)) = old_object {
             debug_assert!(write_kind == WriteKind::Mutate);
             // Some kind of transfer since there's an old object
-            // Add an event for the transfer
+            // Add an event for the transfer, unless it is a child object that was modified in
+            // place and stays with its parent
             let transfer_type = match recipient {
                 Owner::AddressOwner(_) => Some(TransferType::ToAddress),
+                Owner::ObjectOwner(_) if recipient == *old_owner => None,
                 Owner::ObjectOwner(_) => Some(TransferType::ToObject),
                 _ => None,
             };
@@ -702,12 +963,15 @@ pub struct TypeCheckSuccess {
 }
 
 /// - Check that `package_object`, `module` and `function` are valid
-/// - Check that the the signature of `function` is well-typed w.r.t `type_args`, `object_args`, and `pure_args`
+/// - Check that the the signature of `function` is well-typed w.r.t `type_args`, `object_args`, and `pure_args`,
+/// where types defined by the packages in `lineage` (the previous versions of the package of `module`) are
+/// checked as the corresponding types of the package of `module`
 /// - Return the ID of the resolved module, a vector of BCS encoded arguments to pass to the VM, and a partitioning
 /// of the input objects into objects passed by value vs by mutable reference
 pub fn resolve_and_type_check(
     objects: &BTreeMap<ObjectID, impl Borrow<Object>>,
     module: &CompiledModule,
+    lineage: &[ObjectID],
     function: &Identifier,
     type_args: &[TypeTag],
     args: Vec<CallArg>,
@@ -782,6 +1046,11 @@ pub fn resolve_and_type_check(
     }
 
     // type check object arguments passed in by value and by reference
+    let lineage = PackageLineage::new(&module_id, lineage);
+    let resolved_type_args: Vec<_> = type_args
+        .iter()
+        .map(|type_arg| lineage.resolve_type_tag(type_arg))
+        .collect();
     let mut object_data = BTreeMap::new();
     let mut mutable_ref_objects = BTreeMap::new();
     let mut by_value_objects = BTreeSet::new();
@@ -827,7 +1096,14 @@ pub fn resolve_and_type_check(
                         &mut by_value_objects,
                         &mut object_type_map,
                     )?;
-                    type_check_struct(view, type_args, idx, arg_type, param_type)?;
+                    type_check_struct(
+                        view,
+                        &resolved_type_args,
+                        lineage,
+                        idx,
+                        arg_type,
+                        param_type,
+                    )?;
                     o
                 }
                 CallArg::Object(ObjectArg::SharedObject {
@@ -847,7 +1123,14 @@ pub fn resolve_and_type_check(
                         &mut by_value_objects,
                         &mut object_type_map,
                     )?;
-                    type_check_struct(view, type_args, idx, arg_type, param_type)?;
+                    type_check_struct(
+                        view,
+                        &resolved_type_args,
+                        lineage,
+                        idx,
+                        arg_type,
+                        param_type,
+                    )?;
                     o
                 }
                 CallArg::ObjVec(vec) => {
@@ -882,17 +1165,38 @@ pub fn resolve_and_type_check(
                             &mut by_value_objects,
                             &mut object_type_map,
                         )?;
-                        type_check_struct(view, type_args, idx, arg_type, param_type)?;
+                        type_check_struct(
+                            view,
+                            &resolved_type_args,
+                            lineage,
+                            idx,
+                            arg_type,
+                            param_type,
+                        )?;
                         res.extend(o);
                     }
                     res
                 }
+                CallArg::Result(result) => {
+                    // Objects created by earlier steps of a batch transaction are resolved by the
+                    // execution engine before the call.
+                    return Err(ExecutionError::new_with_source(
+                        ExecutionErrorKind::InvariantViolation,
+                        format!("Unresolved batch result {} at index {}", result, idx),
+                    ));
+                }
             };
 
             Ok(object_arg)
         })
         .collect::<Result<Vec<_>, _>>()?;
 
+    // Objects of types defined by previous versions of the package count as defined by the
+    // called version
+    let object_type_map = object_type_map
+        .into_iter()
+        .map(|(id, module_id)| (id, lineage.resolve_module_id(module_id)))
+        .collect();
     check_shared_object_rules(
         objects,
         &by_value_objects,
@@ -1280,11 +1584,18 @@ fn check_shared_object_rules(
 fn type_check_struct(
     view: &BinaryIndexedView,
     function_type_arguments: &[TypeTag],
+    lineage: PackageLineage,
     idx: LocalIndex,
     arg_type: &StructTag,
     param_type: &SignatureToken,
 ) -> Result<(), ExecutionError> {
-    if !struct_tag_equals_sig_token(view, function_type_arguments, arg_type, param_type) {
+    let resolved_arg_type = lineage.resolve_struct_tag(arg_type);
+    if !struct_tag_equals_sig_token(
+        view,
+        function_type_arguments,
+        &resolved_arg_type,
+        param_type,
+    ) {
         Err(ExecutionError::new_with_source(
             ExecutionErrorKind::entry_argument_error(idx, EntryArgumentErrorKind::TypeMismatch),
             format!(
@@ -288,6 +288,8 @@ impl<R: rand::RngCore + rand::CryptoRng> ConfigBuilder<R> {
                     grpc_load_shed: initial_accounts_config.grpc_load_shed,
                     grpc_concurrency_limit: initial_accounts_config.grpc_concurrency_limit,
                     p2p_config,
+                    authority_store_pruning_config: Default::default(),
+                    state_snapshot_path: None,
                 }
             })
             .collect();
@@ -19,7 +19,7 @@ pub mod p2p;
 mod swarm;
 pub mod utils;
 
-pub use node::{ConsensusConfig, NodeConfig, ValidatorInfo};
+pub use node::{AuthorityStorePruningConfig, ConsensusConfig, NodeConfig, ValidatorInfo};
 pub use swarm::NetworkConfig;
 
 const SUI_DIR: &str = ".sui";
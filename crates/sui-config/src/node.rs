@@ -83,6 +83,14 @@ pub struct NodeConfig {
     #[serde(default)]
     pub p2p_config: P2pConfig,
 
+    #[serde(default)]
+    pub authority_store_pruning_config: AuthorityStorePruningConfig,
+
+    /// State snapshot to bootstrap the node from, instead of genesis, when its database is
+    /// empty. Checkpoint sync then resumes from the checkpoint the snapshot was taken at.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_snapshot_path: Option<PathBuf>,
+
     pub genesis: Genesis,
 }
 
@@ -196,6 +204,54 @@ impl ConsensusConfig {
     }
 }
 
+/// Configuration of the background pruning of historical data in the authority store.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct AuthorityStorePruningConfig {
+    /// Whether old object versions are pruned at all. Disabled by default.
+    #[serde(default)]
+    pub enable_pruning: bool,
+    /// Object versions consumed by transactions in the latest `num_latest_checkpoints_to_retain`
+    /// certified checkpoints are kept, older ones are deleted.
+    #[serde(default = "default_num_latest_checkpoints_to_retain")]
+    pub num_latest_checkpoints_to_retain: u64,
+    /// If set, certificates and effects of transactions outside of the latest
+    /// `num_latest_checkpoints_with_certificates` certified checkpoints are deleted as well.
+    /// This node will then no longer be able to serve those transactions to its peers.
+    #[serde(default)]
+    pub num_latest_checkpoints_with_certificates: Option<u64>,
+    /// Time between two pruning passes.
+    #[serde(default = "default_pruning_interval_secs")]
+    pub pruning_interval_secs: u64,
+    /// Maximum number of checkpoints processed in a single pruning pass.
+    #[serde(default = "default_max_checkpoints_per_pass")]
+    pub max_checkpoints_per_pass: u64,
+}
+
+fn default_num_latest_checkpoints_to_retain() -> u64 {
+    10
+}
+
+fn default_pruning_interval_secs() -> u64 {
+    60
+}
+
+fn default_max_checkpoints_per_pass() -> u64 {
+    10
+}
+
+impl Default for AuthorityStorePruningConfig {
+    fn default() -> Self {
+        Self {
+            enable_pruning: false,
+            num_latest_checkpoints_to_retain: default_num_latest_checkpoints_to_retain(),
+            num_latest_checkpoints_with_certificates: None,
+            pruning_interval_secs: default_pruning_interval_secs(),
+            max_checkpoints_per_pass: default_max_checkpoints_per_pass(),
+        }
+    }
+}
+
 /// Publicly known information about a validator
 /// TODO read most of this from on-chain
 #[serde_as]
@@ -127,6 +127,8 @@ impl NetworkConfig {
             grpc_load_shed: None,
             grpc_concurrency_limit: None,
             p2p_config,
+            authority_store_pruning_config: Default::default(),
+            state_snapshot_path: None,
         }
     }
 }
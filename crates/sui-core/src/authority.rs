@@ -21,20 +21,22 @@ use chrono::prelude::*;
 use fastcrypto::traits::KeyPair;
 use futures::stream::{self, Stream};
 use move_bytecode_utils::module_cache::SyncModuleCache;
-use move_core_types::{language_storage::ModuleId, resolver::ModuleResolver};
+use move_core_types::language_storage::StructTag;
+use move_core_types::resolver::ModuleResolver;
 use move_vm_runtime::{move_vm::MoveVM, native_functions::NativeFunctionTable};
 use parking_lot::Mutex;
 use prometheus::{
     exponential_buckets, register_histogram_with_registry, register_int_counter_with_registry,
     register_int_gauge_with_registry, Histogram, IntCounter, IntGauge,
 };
+use serde::de::DeserializeOwned;
 use tap::TapFallible;
 use tokio::sync::{
     broadcast::{self, error::RecvError},
     mpsc,
 };
 use tracing::Instrument;
-use tracing::{debug, error, instrument, warn};
+use tracing::{debug, error, info, instrument, warn};
 use typed_store::Map;
 
 pub use authority_store::{
@@ -47,16 +49,20 @@ use narwhal_config::{
 
 use sui_adapter::adapter;
 use sui_config::genesis::Genesis;
-use sui_json_rpc_types::{SuiEventEnvelope, SuiTransactionEffects};
+use sui_json_rpc_types::{SuiEventEnvelope, SuiMoveValue, SuiTransactionEffects};
 use sui_simulator::nondeterministic;
 use sui_storage::{
     event_store::{EventStore, EventStoreType, StoredEvent},
+    indexes::{CoinIndexKey, CoinInfo},
     node_sync_store::NodeSyncStore,
     write_ahead_log::{DBTxGuard, TxGuard, WriteAheadLog},
     IndexStore,
 };
+use sui_types::coin::Coin;
 use sui_types::committee::EpochId;
 use sui_types::crypto::{AuthorityKeyPair, NetworkKeyPair};
+use sui_types::dynamic_field::DynamicFieldInfo;
+use sui_types::governance::{Delegation, StakedSui};
 use sui_types::messages_checkpoint::{
     AuthenticatedCheckpoint, CertifiedCheckpointSummary, CheckpointRequest, CheckpointRequestType,
     CheckpointResponse, CheckpointSequenceNumber,
@@ -72,6 +78,8 @@ use sui_types::{
     committee::Committee,
     crypto::AuthoritySignature,
     error::{SuiError, SuiResult},
+    event::EventID,
+    filter::EventFilter,
     fp_ensure,
     messages::*,
     object::{Object, ObjectFormatOptions, ObjectRead},
@@ -114,6 +122,11 @@ pub mod move_integration_tests;
 #[path = "unit_tests/gas_tests.rs"]
 mod gas_tests;
 
+#[cfg(test)]
+#[path = "unit_tests/authority_store_pruner_tests.rs"]
+mod authority_store_pruner_tests;
+
+pub mod authority_store_pruner;
 pub mod authority_store_tables;
 
 pub mod authority_notifier;
@@ -539,6 +552,12 @@ impl AuthorityState {
             return Err(SuiError::ValidatorHaltedAtEpochEnd);
         }
 
+        // Do not sign transactions that expired in the epoch we would sign them in.
+        transaction
+            .signed_data
+            .data
+            .check_expiration(self.epoch())?;
+
         let (_gas_status, input_objects) =
             transaction_input_checker::check_transaction_input(&self.database, &transaction)
                 .await?;
@@ -987,6 +1006,25 @@ impl AuthorityState {
         effects: &SignedTransactionEffects,
         timestamp_ms: u64,
     ) -> SuiResult {
+        // Coins consumed by the transaction are looked up at their input version, coins written
+        // by the transaction at their output version.
+        let mut removed_coins = Vec::new();
+        for input in cert.signed_data.data.input_objects()? {
+            if let InputObjectKind::ImmOrOwnedMoveObject((object_id, version, _)) = input {
+                if let Some(object) = self.database.get_object_by_key(&object_id, version)? {
+                    if let Some((key, _)) = Self::coin_index_entry(&object) {
+                        removed_coins.push((key, version));
+                    }
+                }
+            }
+        }
+        let mut added_coins = Vec::new();
+        for ((object_id, version, _), _, _) in effects.effects.all_mutated() {
+            if let Some(object) = self.database.get_object_by_key(object_id, *version)? {
+                added_coins.extend(Self::coin_index_entry(&object));
+            }
+        }
+
         indexes.index_tx(
             cert.sender_address(),
             cert.signed_data
@@ -1003,12 +1041,77 @@ impl AuthorityState {
                 .move_calls()
                 .iter()
                 .map(|mc| (mc.package.0, mc.module.clone(), mc.function.clone())),
+            removed_coins.into_iter(),
+            added_coins.into_iter(),
             seq,
             digest,
             timestamp_ms,
         )
     }
 
+    /// Returns the coin index entry for `object` if it is a coin owned by an address.
+    pub(crate) fn coin_index_entry(object: &Object) -> Option<(CoinIndexKey, CoinInfo)> {
+        let owner = match object.owner {
+            Owner::AddressOwner(owner) => owner,
+            _ => return None,
+        };
+        let coin_type = match object.type_() {
+            Some(type_) if Coin::is_coin(type_) => type_.type_params.first()?.to_string(),
+            _ => return None,
+        };
+        let balance = Coin::extract_balance_if_coin(object).ok()??;
+        Some((
+            (owner, coin_type, object.id()),
+            CoinInfo {
+                version: object.version(),
+                digest: object.digest(),
+                balance,
+                previous_transaction: object.previous_transaction,
+            },
+        ))
+    }
+
+    /// Indexes the executed transactions that post processing has not indexed yet, up to
+    /// `end` (exclusive), e.g. those executed before a restart or skipped when post processing
+    /// lagged behind. Transactions are read back one at a time from the store.
+    async fn index_missed_txes(&self, indexes: &IndexStore, end: TxSequenceNumber) -> SuiResult {
+        loop {
+            let next = indexes.next_sequence_to_index()?;
+            let missed = self
+                .database
+                .perpetual_tables
+                .executed_sequence
+                .iter()
+                .skip_to(&next)?
+                .next();
+            let (seq, digest) = match missed {
+                Some((seq, digests)) if seq < end => (seq, digests.transaction),
+                _ => return Ok(()),
+            };
+            debug!(?seq, tx_digest = ?digest, "Indexing missed transaction");
+            let (cert, effects) = match self.make_transaction_info(&digest).await? {
+                TransactionInfoResponse {
+                    certified_transaction: Some(cert),
+                    signed_effects: Some(effects),
+                    ..
+                } => (cert, effects),
+                _ => {
+                    return Err(SuiError::CertificateNotfound {
+                        certificate_digest: digest,
+                    })
+                }
+            };
+            self.index_tx(
+                indexes,
+                seq,
+                &digest,
+                &cert,
+                &effects,
+                Self::unixtime_now_ms(),
+            )?;
+        }
+    }
+
     #[instrument(level = "debug", skip_all, fields(seq=?seq, tx_digest=?digest), err)]
     async fn post_process_one_tx(
         &self,
@@ -1032,12 +1135,19 @@ impl AuthorityState {
 
         let timestamp_ms = Self::unixtime_now_ms();
 
-        // Index tx
+        // Index tx, after any transaction before it that was not indexed yet. A transaction that
+        // fails to be indexed is then indexed again along with the next one.
         if let Some(indexes) = &self.indexes {
-            let _ = self
-                .index_tx(indexes.as_ref(), seq, digest, &cert, &effects, timestamp_ms)
-                .tap_ok(|_| self.metrics.post_processing_total_tx_indexed.inc())
-                .tap_err(|e| warn!(tx_digest=?digest, "Post processing - Couldn't index tx: {e}"));
+            let result = match self.index_missed_txes(indexes, seq).await {
+                Ok(()) => {
+                    self.index_tx(indexes.as_ref(), seq, digest, &cert, &effects, timestamp_ms)
+                }
+                Err(e) => Err(e),
+            };
+            match result {
+                Ok(_) => self.metrics.post_processing_total_tx_indexed.inc(),
+                Err(e) => error!(tx_digest=?digest, "Post processing - Couldn't index tx: {e}"),
+            }
         }
 
         // Stream transaction
@@ -1064,11 +1174,19 @@ impl AuthorityState {
         Ok(())
     }
 
-    // TODO: This should persist the last successfully-processed sequence to disk, and upon
-    // starting up, look for any sequences in the store since then and process them.
+    // TODO: Events and the transaction stream of transactions executed before a restart, or
+    // skipped when lagging behind, are not processed. Only the indexes catch up on them.
     pub async fn run_tx_post_processing_process(&self) -> SuiResult {
         let mut subscriber = self.subscribe_batch();
 
+        // Index the transactions executed since the last indexed one, e.g. before a restart.
+        if let Some(indexes) = &self.indexes {
+            let end = self.batch_notifier.low_watermark();
+            if let Err(e) = self.index_missed_txes(indexes, end).await {
+                error!("Post processing - Couldn't index missed txes: {e}");
+            }
+        }
+
         loop {
             match subscriber.recv().await {
                 Ok(item) => {
@@ -1103,6 +1221,7 @@ impl AuthorityState {
                         "run_tx_post_processing_process too slow, skipped {} txes",
                         number_skipped
                     );
+                    // The skipped txes are indexed from the store along with the next one.
                 }
             }
         }
@@ -1780,6 +1899,125 @@ impl AuthorityState {
         self.database.get_owner_objects(owner)
     }
 
+    /// Returns at most `limit` objects owned by `owner`, in object ID order, starting from
+    /// (and including) the object with ID `cursor` if given.
+    pub fn get_owner_objects_page(
+        &self,
+        owner: Owner,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> SuiResult<Vec<ObjectInfo>> {
+        self.database.get_owner_objects_page(owner, cursor, limit)
+    }
+
+    /// Returns at most `limit` dynamic fields of the object `parent`, as the objects holding them,
+    /// in object ID order, starting from (and including) the field with ID `cursor` if given.
+    pub fn get_dynamic_fields_page(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> SuiResult<Vec<ObjectInfo>> {
+        self.database.get_dynamic_fields_page(parent, cursor, limit)
+    }
+
+    /// Describes the dynamic field held by the object `field`, as returned by
+    /// `get_dynamic_fields_page`.
+    pub fn get_dynamic_field_info(&self, field: &ObjectInfo) -> SuiResult<DynamicFieldInfo> {
+        let object =
+            self.database
+                .get_object(&field.object_id)?
+                .ok_or(SuiError::ObjectNotFound {
+                    object_id: field.object_id,
+                })?;
+        let move_object = object
+            .data
+            .try_as_move()
+            .ok_or(SuiError::MoveObjectAsPackage {
+                object_id: field.object_id,
+            })?;
+        let layout =
+            move_object.get_layout(ObjectFormatOptions::default(), self.module_cache.as_ref())?;
+        let move_struct = move_object.to_move_struct(&layout)?;
+        let (name, type_, bound_object_id) =
+            DynamicFieldInfo::parse_move_object(&move_object.type_, move_struct)?;
+        let name = SuiMoveValue::from(name).to_string();
+
+        Ok(match bound_object_id {
+            // The value is stored in the field object itself
+            None => DynamicFieldInfo {
+                name,
+                type_,
+                object_type: move_object.type_.type_params[1].to_string(),
+                object_id: field.object_id,
+                version: field.version,
+                digest: field.digest,
+            },
+            // The value is an object of its own, owned by the field object
+            Some(object_id) => {
+                let bound_object = self
+                    .database
+                    .get_object(&object_id)?
+                    .ok_or(SuiError::ObjectNotFound { object_id })?;
+                let object_type = bound_object
+                    .data
+                    .type_()
+                    .map(|tag| tag.to_string())
+                    .ok_or(SuiError::MoveObjectAsPackage { object_id })?;
+                let (object_id, version, digest) = bound_object.compute_object_reference();
+                DynamicFieldInfo {
+                    name,
+                    type_,
+                    object_type,
+                    object_id,
+                    version,
+                    digest,
+                }
+            }
+        })
+    }
+
+    /// Returns the delegations owned by `owner`.
+    pub fn get_delegations(&self, owner: SuiAddress) -> SuiResult<Vec<Delegation>> {
+        self.get_owned_move_objects(owner, &Delegation::type_())
+    }
+
+    /// Returns the staked SUI objects owned by `owner`.
+    pub fn get_staked_sui(&self, owner: SuiAddress) -> SuiResult<Vec<StakedSui>> {
+        self.get_owned_move_objects(owner, &StakedSui::type_())
+    }
+
+    /// Returns the objects of type `type_` owned by `owner`, deserialized into their Rust
+    /// counterpart `T`.
+    fn get_owned_move_objects<T: DeserializeOwned>(
+        &self,
+        owner: SuiAddress,
+        type_: &StructTag,
+    ) -> SuiResult<Vec<T>> {
+        let type_ = type_.to_string();
+        let mut objects = vec![];
+        for info in self.get_owner_objects(Owner::AddressOwner(owner))? {
+            if info.type_ != type_ {
+                continue;
+            }
+            let object_id = info.object_id;
+            let object = self
+                .database
+                .get_object(&object_id)?
+                .ok_or(SuiError::ObjectNotFound { object_id })?;
+            let move_object = object
+                .data
+                .try_as_move()
+                .ok_or(SuiError::MoveObjectAsPackage { object_id })?;
+            objects.push(bcs::from_bytes(move_object.contents()).map_err(|e| {
+                SuiError::ObjectSerializationError {
+                    error: e.to_string(),
+                }
+            })?);
+        }
+        Ok(objects)
+    }
+
     pub fn get_total_transaction_number(&self) -> Result<u64, anyhow::Error> {
         QueryHelpers::get_total_transaction_number(&self.database)
     }
@@ -1815,6 +2053,29 @@ impl AuthorityState {
         }
     }
 
+    /// Returns the total balance and number of coins of `coin_type` owned by `owner`.
+    pub fn get_balance(&self, owner: SuiAddress, coin_type: String) -> SuiResult<(u128, usize)> {
+        self.get_indexes()?.get_balance(owner, coin_type)
+    }
+
+    /// Returns the total balance and number of coins owned by `owner` for each coin type.
+    pub fn get_all_balances(&self, owner: SuiAddress) -> SuiResult<Vec<(String, u128, usize)>> {
+        self.get_indexes()?.get_all_balances(owner)
+    }
+
+    /// Returns at most `limit` coins of `coin_type` owned by `owner`, starting from the coin
+    /// with id `cursor`, sorted by object id.
+    pub fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: String,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> SuiResult<Vec<(ObjectID, CoinInfo)>> {
+        self.get_indexes()?
+            .get_coins(owner, coin_type, cursor.unwrap_or(ObjectID::ZERO), limit)
+    }
+
     pub fn get_transactions(
         &self,
         query: TransactionQuery,
@@ -1892,118 +2153,24 @@ impl AuthorityState {
             .map(|handler| handler.event_store.clone())
     }
 
-    /// Returns at most `limit` events emitted in the given transaction,
-    /// emitted within [start_time, end_time) in order of events emitted.
-    /// `limit` is capped to EVENT_STORE_QUERY_MAX_LIMIT
-    pub async fn get_events_by_transaction(
-        &self,
-        digest: TransactionDigest,
-        limit: usize,
-    ) -> Result<Vec<SuiEventEnvelope>, anyhow::Error> {
-        let es = self.get_event_store().ok_or(SuiError::NoEventStore)?;
-        let stored_events = es.events_by_transaction(digest, limit).await?;
-        StoredEvent::into_event_envelopes(stored_events)
-    }
-
-    /// Returns at most `limit` events emitted in the given module,
-    /// emitted within [start_time, end_time), sorted in in descending time.
-    /// `limit` is capped to EVENT_STORE_QUERY_MAX_LIMIT
-    pub async fn get_events_by_transaction_module(
-        &self,
-        module_id: &ModuleId,
-        start_time: u64,
-        end_time: u64,
-        limit: usize,
-    ) -> Result<Vec<SuiEventEnvelope>, anyhow::Error> {
-        let es = self.get_event_store().ok_or(SuiError::NoEventStore)?;
-        let stored_events = es
-            .events_by_module_id(start_time, end_time, module_id, limit)
-            .await?;
-        StoredEvent::into_event_envelopes(stored_events)
-    }
-
-    /// Returns at most `limit` events with the given move event struct name, e.g.
-    /// `0x2::devnet_nft::MintNFTEvent` or
-    /// `0x2::SUI::test_foo<address, vector<u8>>` with type params,
-    /// emitted within [start_time, end_time), sorted in in descending time.
-    /// `limit` is capped to EVENT_STORE_QUERY_MAX_LIMIT
-    pub async fn get_events_by_move_event_struct_name(
-        &self,
-        move_event_struct_name: &str,
-        start_time: u64,
-        end_time: u64,
-        limit: usize,
-    ) -> Result<Vec<SuiEventEnvelope>, anyhow::Error> {
-        let es = self.get_event_store().ok_or(SuiError::NoEventStore)?;
-        let stored_events = es
-            .events_by_move_event_struct_name(start_time, end_time, move_event_struct_name, limit)
-            .await?;
-        StoredEvent::into_event_envelopes(stored_events)
-    }
-
-    /// Returns at most `limit` events associated with the given sender,
-    /// emitted within [start_time, end_time), sorted in in descending time.
-    /// `limit` is capped to EVENT_STORE_QUERY_MAX_LIMIT
-    pub async fn get_events_by_sender(
-        &self,
-        sender: &SuiAddress,
-        start_time: u64,
-        end_time: u64,
-        limit: usize,
-    ) -> Result<Vec<SuiEventEnvelope>, anyhow::Error> {
-        let es = self.get_event_store().ok_or(SuiError::NoEventStore)?;
-        let stored_events = es
-            .events_by_sender(start_time, end_time, sender, limit)
-            .await?;
-        StoredEvent::into_event_envelopes(stored_events)
-    }
-
-    /// Returns at most `limit` events associated with the given recipient,
-    /// emitted within [start_time, end_time), sorted in in descending time.
-    /// `limit` is capped to EVENT_STORE_QUERY_MAX_LIMIT
-    pub async fn get_events_by_recipient(
-        &self,
-        recipient: &Owner,
-        start_time: u64,
-        end_time: u64,
-        limit: usize,
-    ) -> Result<Vec<SuiEventEnvelope>, anyhow::Error> {
-        let es = self.get_event_store().ok_or(SuiError::NoEventStore)?;
-        let stored_events = es
-            .events_by_recipient(start_time, end_time, recipient, limit)
-            .await?;
-        StoredEvent::into_event_envelopes(stored_events)
-    }
-
-    /// Returns at most `limit` events associated with the given object,
-    /// emitted within [start_time, end_time), sorted in in descending time.
-    /// `limit` is capped to EVENT_STORE_QUERY_MAX_LIMIT
-    pub async fn get_events_by_object(
-        &self,
-        object: &ObjectID,
-        start_time: u64,
-        end_time: u64,
-        limit: usize,
-    ) -> Result<Vec<SuiEventEnvelope>, anyhow::Error> {
-        let es = self.get_event_store().ok_or(SuiError::NoEventStore)?;
-        let stored_events = es
-            .events_by_object(start_time, end_time, object, limit)
-            .await?;
-        StoredEvent::into_event_envelopes(stored_events)
-    }
-
-    /// Returns at most `limit` events emitted within [start_time, end_time),
-    /// sorted in in descending time.
+    /// Returns at most `limit` events matching `filter`, starting from `cursor` if given, sorted
+    /// in ascending order of emission, or descending if `descending` is set, along with the
+    /// cursor of the next page if there may be more events.
     /// `limit` is capped to EVENT_STORE_QUERY_MAX_LIMIT
-    pub async fn get_events_by_timerange(
+    pub async fn get_events(
         &self,
-        start_time: u64,
-        end_time: u64,
+        filter: &EventFilter,
+        cursor: Option<EventID>,
         limit: usize,
-    ) -> Result<Vec<SuiEventEnvelope>, anyhow::Error> {
+        descending: bool,
+    ) -> Result<(Vec<SuiEventEnvelope>, Option<EventID>), anyhow::Error> {
         let es = self.get_event_store().ok_or(SuiError::NoEventStore)?;
-        let stored_events = es.event_iterator(start_time, end_time, limit).await?;
-        StoredEvent::into_event_envelopes(stored_events)
+        let (stored_events, next_cursor) =
+            es.query_events(filter, cursor, limit, descending).await?;
+        Ok((
+            StoredEvent::into_event_envelopes(stored_events)?,
+            next_cursor,
+        ))
     }
 
     pub async fn insert_genesis_object(&self, object: Object) {
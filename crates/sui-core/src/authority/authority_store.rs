@@ -22,8 +22,11 @@ use sui_storage::{
 };
 use sui_types::batch::{SignedBatch, TxSequenceNumber};
 use sui_types::crypto::{AuthoritySignInfo, EmptySignInfo};
+use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::object::Owner;
-use sui_types::storage::WriteKind;
+use sui_types::parse_sui_struct_tag;
+use sui_types::storage::{ChildObjectResolver, WriteKind};
+use sui_types::waypoint::Accumulator;
 use sui_types::{base_types::SequenceNumber, storage::ParentSync};
 use tokio::sync::Notify;
 use tokio_retry::strategy::{jitter, ExponentialBackoff};
@@ -285,6 +288,50 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
             .collect())
     }
 
+    pub fn get_owner_objects_page(
+        &self,
+        owner: Owner,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<Vec<ObjectInfo>, SuiError> {
+        debug!(?owner, ?cursor, limit, "get_owner_objects_page");
+        Ok(self
+            .perpetual_tables
+            .owner_index
+            .iter()
+            .skip_to(&(owner, cursor.unwrap_or(ObjectID::ZERO)))?
+            .take_while(|((object_owner, _), _)| (object_owner == &owner))
+            .take(limit)
+            .map(|(_, object_info)| object_info)
+            .collect())
+    }
+
+    /// Returns at most `limit` dynamic fields of the object `parent`, i.e. its child objects of
+    /// type `sui::dynamic_field::Field`, in object ID order, starting from (and including) the
+    /// field with ID `cursor` if given.
+    pub fn get_dynamic_fields_page(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<Vec<ObjectInfo>, SuiError> {
+        debug!(?parent, ?cursor, limit, "get_dynamic_fields_page");
+        let owner = Owner::ObjectOwner(parent.into());
+        Ok(self
+            .perpetual_tables
+            .owner_index
+            .iter()
+            .skip_to(&(owner, cursor.unwrap_or(ObjectID::ZERO)))?
+            .take_while(|((object_owner, _), _)| (object_owner == &owner))
+            .map(|(_, object_info)| object_info)
+            .filter(|object_info| {
+                parse_sui_struct_tag(&object_info.type_)
+                    .map_or(false, |tag| DynamicFieldInfo::is_dynamic_field(&tag))
+            })
+            .take(limit)
+            .collect())
+    }
+
     pub fn get_object_by_key(
         &self,
         object_id: &ObjectID,
@@ -503,7 +550,12 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
         // We only side load objects with a genesis parent transaction.
         debug_assert!(object.previous_transaction == TransactionDigest::genesis());
         let object_ref = object.compute_object_reference();
-        self.insert_object_direct(object_ref, &object).await
+        self.insert_object_direct(object_ref, &object).await?;
+        self.perpetual_tables.state_accumulator_deltas.insert(
+            &TransactionDigest::genesis(),
+            &self.genesis_state_accumulator_with(iter::once(&object_ref))?,
+        )?;
+        Ok(())
     }
 
     /// Insert an object directly into the store, and also update relevant tables
@@ -537,6 +589,50 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
         Ok(())
     }
 
+    /// Insert the objects of a state snapshot into an empty store. Like genesis objects, the
+    /// objects are written directly, and locks are only initialized for owned objects.
+    pub async fn insert_snapshot_objects(&self, objects: &[Object]) -> SuiResult {
+        let ref_and_objects: Vec<_> = objects
+            .iter()
+            .map(|o| (o.compute_object_reference(), o))
+            .collect();
+
+        self.perpetual_tables
+            .objects
+            .batch()
+            .insert_batch(
+                &self.perpetual_tables.objects,
+                ref_and_objects
+                    .iter()
+                    .map(|(oref, o)| (ObjectKey::from(oref), *o)),
+            )?
+            .insert_batch(
+                &self.perpetual_tables.owner_index,
+                ref_and_objects
+                    .iter()
+                    .filter(|(_, o)| o.get_single_owner().is_some())
+                    .map(|(oref, o)| ((o.owner, oref.0), ObjectInfo::new(oref, o))),
+            )?
+            .insert_batch(
+                &self.perpetual_tables.parent_sync,
+                ref_and_objects
+                    .iter()
+                    .map(|(oref, o)| (oref, o.previous_transaction)),
+            )?
+            .write()?;
+
+        let owned_refs: Vec<_> = ref_and_objects
+            .iter()
+            .filter(|(_, o)| o.get_single_owner().is_some())
+            .map(|(oref, _)| *oref)
+            .collect();
+        self.lock_service
+            .initialize_locks(&owned_refs, false /* is_force_reset */)
+            .await?;
+
+        Ok(())
+    }
+
     /// This function is used by the bench.rs script, and should not be used in other contexts
     /// In particular it does not check the old locks before inserting new ones, so the objects
     /// must be new.
@@ -566,6 +662,15 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
                     .iter()
                     .map(|(oref, o)| (oref, o.previous_transaction)),
             )?
+            .insert_batch(
+                &self.perpetual_tables.state_accumulator_deltas,
+                iter::once((
+                    TransactionDigest::genesis(),
+                    self.genesis_state_accumulator_with(
+                        ref_and_objects.iter().map(|(oref, _)| oref),
+                    )?,
+                )),
+            )?
             .write()?;
 
         let refs: Vec<_> = ref_and_objects.iter().map(|(oref, _)| *oref).collect();
@@ -576,6 +681,21 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
         Ok(())
     }
 
+    /// Objects inserted directly at genesis are not written by any executed transaction, so
+    /// their references are accumulated in the entry of the genesis transaction digest.
+    fn genesis_state_accumulator_with<'a>(
+        &self,
+        object_refs: impl IntoIterator<Item = &'a ObjectRef>,
+    ) -> SuiResult<Accumulator> {
+        let mut accumulator = self
+            .perpetual_tables
+            .state_accumulator_deltas
+            .get(&TransactionDigest::genesis())?
+            .unwrap_or_default();
+        accumulator.insert_all(object_refs);
+        Ok(accumulator)
+    }
+
     /// Acquires the transaction lock for a specific transaction, writing the transaction
     /// to the transaction column family if acquiring the lock succeeds.
     /// The lock service is used to atomically acquire locks.
@@ -877,6 +997,30 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> SuiDataStore<S> {
                 .map(|(_, (obj_ref, new_object, _kind))| (ObjectKey::from(obj_ref), new_object)),
         )?;
 
+        // Record the change to the live object set: the written objects replace the input
+        // versions they were read at, while deleted and wrapped objects leave the set. Created
+        // and unwrapped objects were not live before, hence have no input version to remove.
+        let mut state_delta = match update_type {
+            UpdateType::Genesis => self
+                .perpetual_tables
+                .state_accumulator_deltas
+                .get(&transaction_digest)?
+                .unwrap_or_default(),
+            UpdateType::Transaction(..) => Accumulator::default(),
+        };
+        state_delta.insert_all(written.values().map(|(object_ref, _, _)| object_ref));
+        for old_object in written
+            .keys()
+            .chain(deleted.keys())
+            .filter_map(|id| objects.get(id))
+        {
+            state_delta.remove(&old_object.compute_object_reference());
+        }
+        write_batch = write_batch.insert_batch(
+            &self.perpetual_tables.state_accumulator_deltas,
+            iter::once((transaction_digest, state_delta)),
+        )?;
+
         // Atomic write of all data other than locks
         write_batch.write()?;
         trace!("Finished writing batch");
@@ -1414,6 +1558,26 @@ impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> ParentSync for SuiDa
     }
 }
 
+impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> ChildObjectResolver
+    for SuiDataStore<S>
+{
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>> {
+        let child_object = match self.get_object(child)? {
+            None => return Ok(None),
+            Some(object) => object,
+        };
+        let parent = *parent;
+        if child_object.owner != Owner::ObjectOwner(parent.into()) {
+            return Err(SuiError::InvalidChildObjectAccess {
+                object: *child,
+                given_parent: parent,
+                actual_owner: child_object.owner,
+            });
+        }
+        Ok(Some(child_object))
+    }
+}
+
 impl<S: Eq + Debug + Serialize + for<'de> Deserialize<'de>> ModuleResolver for SuiDataStore<S> {
     type Error = SuiError;
 
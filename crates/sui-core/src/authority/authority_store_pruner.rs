@@ -0,0 +1,321 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::authority_store::ObjectKey;
+use super::authority_store_tables::PruningWatermark;
+use crate::authority::AuthorityStore;
+use crate::checkpoints::CheckpointStore;
+use parking_lot::Mutex;
+use prometheus::{
+    register_int_counter_with_registry, register_int_gauge_with_registry, IntCounter, IntGauge,
+    Registry,
+};
+use std::iter;
+use std::sync::Arc;
+use std::time::Duration;
+use sui_config::AuthorityStorePruningConfig;
+use sui_storage::IndexStore;
+use sui_types::base_types::{ObjectDigest, ObjectRef, SequenceNumber, TransactionDigest};
+use sui_types::error::SuiResult;
+use sui_types::messages_checkpoint::{CheckpointContents, CheckpointSequenceNumber};
+use tracing::{debug, error, info, warn};
+use typed_store::rocks::DBBatch;
+use typed_store::Map;
+
+pub struct AuthorityStorePruningMetrics {
+    pub pruned_objects_checkpoint: IntGauge,
+    pub pruned_certificates_checkpoint: IntGauge,
+    pub num_pruned_objects: IntCounter,
+    pub num_pruned_certificates: IntCounter,
+}
+
+impl AuthorityStorePruningMetrics {
+    pub fn new(registry: &Registry) -> Self {
+        Self {
+            pruned_objects_checkpoint: register_int_gauge_with_registry!(
+                "authority_store_pruned_objects_checkpoint",
+                "Sequence number of the next checkpoint whose input object versions are to be pruned",
+                registry,
+            )
+            .unwrap(),
+            pruned_certificates_checkpoint: register_int_gauge_with_registry!(
+                "authority_store_pruned_certificates_checkpoint",
+                "Sequence number of the next checkpoint whose certificates and effects are to be pruned",
+                registry,
+            )
+            .unwrap(),
+            num_pruned_objects: register_int_counter_with_registry!(
+                "authority_store_num_pruned_objects",
+                "Total number of object versions pruned from the authority store",
+                registry,
+            )
+            .unwrap(),
+            num_pruned_certificates: register_int_counter_with_registry!(
+                "authority_store_num_pruned_certificates",
+                "Total number of certificates (and their effects) pruned from the authority store",
+                registry,
+            )
+            .unwrap(),
+        }
+    }
+
+    pub fn new_for_tests() -> Self {
+        let registry = Registry::new();
+        Self::new(&registry)
+    }
+}
+
+/// Deletes historical data from the authority store, based on the latest certified checkpoint.
+///
+/// An object version is only needed as a transaction input until the transaction consuming it
+/// is executed. Once a transaction is part of a certified checkpoint, all its causal
+/// dependencies are part of the same or earlier checkpoints, so every version of an object
+/// older than the one written by a checkpointed transaction has been consumed by a
+/// checkpointed transaction and can be deleted, along with its parent_sync entry. The latest
+/// version of every object, and the tombstones of deleted objects, are never pruned.
+///
+/// The state accumulator deltas of checkpointed transactions are pruned with their object
+/// versions, since they were folded into the state root of their checkpoint, which is all the
+/// next checkpoint builds on.
+///
+/// Indexing reads the input versions of the objects of a transaction, so when the node keeps
+/// indexes, the object versions of a checkpoint are only pruned once all its transactions are
+/// indexed.
+///
+/// Certificates and effects are optionally pruned as well, but only for checkpoints whose
+/// object versions have already been pruned, since object pruning relies on the effects.
+pub struct AuthorityStorePruner {
+    store: Arc<AuthorityStore>,
+    checkpoints: Arc<Mutex<CheckpointStore>>,
+    indexes: Option<Arc<IndexStore>>,
+    config: AuthorityStorePruningConfig,
+    metrics: AuthorityStorePruningMetrics,
+}
+
+impl AuthorityStorePruner {
+    pub fn new(
+        store: Arc<AuthorityStore>,
+        checkpoints: Arc<Mutex<CheckpointStore>>,
+        indexes: Option<Arc<IndexStore>>,
+        config: AuthorityStorePruningConfig,
+        metrics: AuthorityStorePruningMetrics,
+    ) -> Self {
+        Self {
+            store,
+            checkpoints,
+            indexes,
+            config,
+            metrics,
+        }
+    }
+
+    /// Run pruning passes forever, every `pruning_interval_secs`. Passes scan and delete from
+    /// the store synchronously, so they run on the blocking thread pool.
+    pub async fn run(self) {
+        info!(config = ?self.config, "Starting authority store pruner");
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.pruning_interval_secs));
+        let pruner = Arc::new(self);
+        loop {
+            interval.tick().await;
+            let pass = pruner.clone();
+            match tokio::task::spawn_blocking(move || pass.prune()).await {
+                Ok(Ok(())) => (),
+                Ok(Err(err)) => error!("Failed to prune the authority store: {:?}", err),
+                Err(err) => error!("Authority store pruning pass panicked: {:?}", err),
+            }
+        }
+    }
+
+    /// Run a single pruning pass, processing at most `max_checkpoints_per_pass` checkpoints
+    /// for each kind of pruned data.
+    pub fn prune(&self) -> SuiResult {
+        let latest_checkpoint = match self
+            .checkpoints
+            .lock()
+            .latest_certified_checkpoint()
+            .map(|checkpoint| checkpoint.sequence_number())
+        {
+            Some(seq) => seq,
+            None => return Ok(()),
+        };
+        let next_checkpoint = latest_checkpoint + 1;
+
+        let objects_target =
+            next_checkpoint.saturating_sub(self.config.num_latest_checkpoints_to_retain);
+        let objects_watermark = self.prune_objects(objects_target)?;
+
+        if let Some(retained) = self.config.num_latest_checkpoints_with_certificates {
+            let certificates_target = next_checkpoint
+                .saturating_sub(retained)
+                .min(objects_watermark);
+            self.prune_certificates(certificates_target)?;
+        }
+        Ok(())
+    }
+
+    /// Prune the object versions consumed by the transactions of checkpoints up to `target`
+    /// (excluded), and return the new watermark.
+    fn prune_objects(
+        &self,
+        target: CheckpointSequenceNumber,
+    ) -> SuiResult<CheckpointSequenceNumber> {
+        let tables = &self.store.perpetual_tables;
+        let mut next = self.watermark(PruningWatermark::Objects)?;
+        let end = target.min(next + self.config.max_checkpoints_per_pass);
+        while next < end {
+            let mut batch = tables.objects.batch();
+            if next == 0 {
+                // The genesis delta is only the base of the state root of the first checkpoint.
+                batch = batch.delete_batch(
+                    &tables.state_accumulator_deltas,
+                    iter::once(TransactionDigest::genesis()),
+                )?;
+            }
+            if let Some(contents) = self.checkpoint_contents(next)? {
+                match prune_objects_for_checkpoint(
+                    &self.store,
+                    self.indexes.as_deref(),
+                    &contents,
+                    batch,
+                )? {
+                    Some((new_batch, num_pruned)) => {
+                        batch = new_batch;
+                        self.metrics.num_pruned_objects.inc_by(num_pruned as u64);
+                    }
+                    None => {
+                        // Some transactions of the checkpoint are not executed or indexed
+                        // locally yet.
+                        debug!(
+                            checkpoint = next,
+                            "Waiting for execution and indexing before pruning"
+                        );
+                        break;
+                    }
+                }
+            }
+            next += 1;
+            batch
+                .insert_batch(
+                    &tables.pruned_checkpoints,
+                    iter::once((PruningWatermark::Objects, next)),
+                )?
+                .write()?;
+            self.metrics.pruned_objects_checkpoint.set(next as i64);
+        }
+        Ok(next)
+    }
+
+    /// Prune the certificates and effects of the transactions of checkpoints up to `target`
+    /// (excluded).
+    fn prune_certificates(&self, target: CheckpointSequenceNumber) -> SuiResult {
+        let tables = &self.store.perpetual_tables;
+        let mut next = self.watermark(PruningWatermark::Certificates)?;
+        let end = target.min(next + self.config.max_checkpoints_per_pass);
+        while next < end {
+            let mut batch = tables.certificates.batch();
+            if let Some(contents) = self.checkpoint_contents(next)? {
+                let digests: Vec<TransactionDigest> =
+                    contents.iter().map(|digests| digests.transaction).collect();
+                batch = batch
+                    .delete_batch(&tables.certificates, digests.iter())?
+                    .delete_batch(&tables.effects, digests.iter())?;
+                self.metrics
+                    .num_pruned_certificates
+                    .inc_by(digests.len() as u64);
+            }
+            next += 1;
+            batch
+                .insert_batch(
+                    &tables.pruned_checkpoints,
+                    iter::once((PruningWatermark::Certificates, next)),
+                )?
+                .write()?;
+            self.metrics.pruned_certificates_checkpoint.set(next as i64);
+        }
+        Ok(())
+    }
+
+    fn watermark(&self, kind: PruningWatermark) -> SuiResult<CheckpointSequenceNumber> {
+        Ok(self
+            .store
+            .perpetual_tables
+            .pruned_checkpoints
+            .get(&kind)?
+            .unwrap_or_default())
+    }
+
+    fn checkpoint_contents(
+        &self,
+        seq: CheckpointSequenceNumber,
+    ) -> SuiResult<Option<CheckpointContents>> {
+        let contents = self
+            .checkpoints
+            .lock()
+            .tables
+            .checkpoint_contents
+            .get(&seq)?;
+        if contents.is_none() {
+            warn!(checkpoint = seq, "Checkpoint contents missing, skipping");
+        }
+        Ok(contents)
+    }
+}
+
+/// Add to `batch` the deletion of all object versions (and their parent_sync entries) older
+/// than the versions written by the transactions in `contents`, and of the state accumulator
+/// deltas of these transactions. Returns `None` if the effects
+/// of some of these transactions are not available yet, or if `indexes` has not indexed them
+/// yet.
+pub(crate) fn prune_objects_for_checkpoint(
+    store: &AuthorityStore,
+    indexes: Option<&IndexStore>,
+    contents: &CheckpointContents,
+    mut batch: DBBatch,
+) -> SuiResult<Option<(DBBatch, usize)>> {
+    let tables = &store.perpetual_tables;
+    if let Some(indexes) = indexes {
+        for digests in contents.iter() {
+            if indexes.get_transaction_seq(&digests.transaction)?.is_none() {
+                return Ok(None);
+            }
+        }
+    }
+    let mut num_pruned = 0;
+    for digests in contents.iter() {
+        let effects = match tables.effects.get(&digests.transaction)? {
+            Some(effects) => effects.effects,
+            None => return Ok(None),
+        };
+        let written = effects
+            .all_mutated()
+            .map(|(object_ref, _, _)| object_ref)
+            .chain(effects.deleted.iter())
+            .chain(effects.wrapped.iter());
+        for (object_id, version, _) in written {
+            let object_keys: Vec<ObjectKey> = tables
+                .objects
+                .iter()
+                .skip_to(&ObjectKey(*object_id, SequenceNumber::MIN))?
+                .take_while(|(key, _)| key.0 == *object_id && key.1 < *version)
+                .map(|(key, _)| key)
+                .collect();
+            let parent_refs: Vec<ObjectRef> = tables
+                .parent_sync
+                .iter()
+                .skip_to(&(*object_id, SequenceNumber::MIN, ObjectDigest::MIN))?
+                .take_while(|((id, seq, _), _)| id == object_id && seq < version)
+                .map(|(object_ref, _)| object_ref)
+                .collect();
+            num_pruned += object_keys.len();
+            batch = batch
+                .delete_batch(&tables.objects, object_keys)?
+                .delete_batch(&tables.parent_sync, parent_refs)?;
+        }
+    }
+    batch = batch.delete_batch(
+        &tables.state_accumulator_deltas,
+        contents.iter().map(|digests| digests.transaction),
+    )?;
+    Ok(Some((batch, num_pruned)))
+}
@@ -12,6 +12,7 @@ use std::path::Path;
 use sui_storage::default_db_options;
 use sui_types::base_types::{ExecutionDigests, SequenceNumber};
 use sui_types::batch::{SignedBatch, TxSequenceNumber};
+use sui_types::waypoint::Accumulator;
 use typed_store::rocks::DBMap;
 use typed_store::traits::TypedStoreDebug;
 
@@ -80,8 +81,8 @@ pub struct AuthorityPerpetualTables<S> {
     /// This is a map between the object (ID, version) and the latest state of the object, namely the
     /// state that is needed to process new transactions.
     ///
-    /// Note that while this map can store all versions of an object, we will eventually
-    /// prune old object versions from the db.
+    /// Note that while this map can store all versions of an object, old object versions are
+    /// pruned from the db by the `AuthorityStorePruner` when pruning is enabled.
     ///
     /// IMPORTANT: object versions must *only* be pruned if they appear as inputs in some
     /// TransactionEffects. Simply pruning all objects but the most recent is an error!
@@ -137,6 +138,18 @@ pub struct AuthorityPerpetualTables<S> {
 
     /// A sequence of batches indexing into the sequence of executed transactions.
     pub batches: DBMap<TxSequenceNumber, SignedBatch>,
+
+    /// Progress of the authority store pruner: for each kind of pruned data, the sequence
+    /// number of the next checkpoint whose transactions have not been pruned yet.
+    pub(crate) pruned_checkpoints: DBMap<PruningWatermark, CheckpointSequenceNumber>,
+
+    /// The change each executed transaction made to the accumulator of live object references,
+    /// i.e. the references of the objects it wrote minus the references of the objects it
+    /// mutated, deleted or wrapped. It is written atomically with the objects of the transaction.
+    /// The entry of the genesis transaction digest holds the accumulator of the genesis objects.
+    /// Summing the entries of the transactions of a checkpoint on top of the state root of the
+    /// previous checkpoint gives the state root of the checkpoint.
+    pub(crate) state_accumulator_deltas: DBMap<TransactionDigest, Accumulator>,
 }
 
 impl<S> AuthorityPerpetualTables<S>
@@ -239,6 +252,15 @@ where
     }
 }
 
+/// Keys of the `pruned_checkpoints` table.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PruningWatermark {
+    /// Object versions and their parent_sync entries.
+    Objects,
+    /// Certificates and effects.
+    Certificates,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
 pub struct ExecutionIndicesWithHash {
     pub index: ExecutionIndices,
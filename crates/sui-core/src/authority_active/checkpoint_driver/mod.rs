@@ -102,6 +102,7 @@ pub struct CheckpointMetrics {
     pub checkpoint_sequence_number: IntGauge,
     checkpoints_signed: IntCounter,
     checkpoint_frequency: Histogram,
+    checkpoint_state_diverged: IntGauge,
 }
 
 impl CheckpointMetrics {
@@ -128,6 +129,12 @@ impl CheckpointMetrics {
                 registry,
             )
             .unwrap(),
+            checkpoint_state_diverged: register_int_gauge_with_registry!(
+                "checkpoint_state_diverged",
+                "Set to 1 when the local state diverged from a certified checkpoint and checkpointing halted",
+                registry,
+            )
+            .unwrap(),
         }
     }
 
@@ -150,6 +157,19 @@ pub enum CheckpointStepError {
     ProposalFailed(Box<SuiError>),
     CheckpointCreationFailed,
     CheckpointSignBlocked(Box<SuiError>),
+    /// The local state does not match the state root of this certified checkpoint. Retrying
+    /// cannot fix it, so the checkpoint process halts.
+    StateDiverged(CheckpointSequenceNumber),
+}
+
+impl CheckpointStepError {
+    /// Wrap an error from syncing checkpoints, singling out state divergence.
+    fn from_sync_error(err: SuiError, wrap: fn(Box<SuiError>) -> Self) -> Self {
+        match err {
+            SuiError::StateDivergence { checkpoint } => Self::StateDiverged(checkpoint),
+            err => wrap(Box::new(err)),
+        }
+    }
 }
 
 pub async fn checkpoint_process<A>(
@@ -254,6 +274,14 @@ pub async fn checkpoint_process<A>(
                     CheckpointStepError::WaitForCheckpointCert => {
                         // This is very common due to missing transactions, nothing to do here.
                     }
+                    CheckpointStepError::StateDiverged(cp_seq) => {
+                        error!(
+                            ?cp_seq,
+                            "Local state diverged from the certified checkpoint, halting the checkpoint process"
+                        );
+                        metrics.checkpoint_state_diverged.set(1);
+                        return;
+                    }
                 }
                 tokio::time::sleep(timing.delay_on_local_failure).await;
             }
@@ -313,12 +341,22 @@ where
                 checkpoint.clone(),
             )
             .await
-            .map_err(|err| CheckpointStepError::SyncCheckpointFromQuorumFailed(Box::new(err)))?;
+            .map_err(|err| {
+                CheckpointStepError::from_sync_error(
+                    err,
+                    CheckpointStepError::SyncCheckpointFromQuorumFailed,
+                )
+            })?;
         }
 
         if update_latest_checkpoint(active_authority.clone(), state_checkpoints, &checkpoint)
             .await
-            .map_err(|err| CheckpointStepError::UpdateLatestCheckpointFailed(Box::new(err)))?
+            .map_err(|err| {
+                CheckpointStepError::from_sync_error(
+                    err,
+                    CheckpointStepError::UpdateLatestCheckpointFailed,
+                )
+            })?
         {
             return Ok(CheckpointStepResult::NewCheckpointCertStored);
         }
@@ -756,9 +794,11 @@ where
         return Err(SuiError::CheckpointingError { error });
     }
 
-    checkpoint_db
-        .lock()
-        .process_synced_checkpoint_certificate(checkpoint_cert, contents, committee)
+    // All transactions of the checkpoint are executed, so the local state must now match the
+    // state root certified by the committee.
+    let mut checkpoint_db = checkpoint_db.lock();
+    checkpoint_db.check_state_root(checkpoint_cert, contents, &active_authority.state.database)?;
+    checkpoint_db.process_synced_checkpoint_certificate(checkpoint_cert, contents, committee)
 }
 
 pub async fn get_one_checkpoint_with_contents<A>(
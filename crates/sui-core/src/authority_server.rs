@@ -35,6 +35,7 @@ use sui_types::messages_checkpoint::CheckpointRequest;
 use sui_types::messages_checkpoint::CheckpointResponse;
 
 use crate::consensus_handler::ConsensusHandler;
+use crate::consensus_validator::SuiTxValidator;
 use tracing::{error, info, Instrument};
 
 #[cfg(test)]
@@ -270,6 +271,7 @@ impl ValidatorService {
             ConsensusHandler::new(state.clone(), tx_consensus_listener.clone());
         let consensus_execution_state = Arc::new(consensus_execution_state);
         let consensus_parameters = consensus_config.narwhal_config().to_owned();
+        let consensus_tx_validator = SuiTxValidator::new(state.clone());
         let network_keypair = config.network_key_pair.copy();
 
         let registry = prometheus_registry.clone();
@@ -283,6 +285,7 @@ impl ValidatorService {
                 consensus_storage_base_path,
                 consensus_execution_state,
                 consensus_parameters,
+                consensus_tx_validator,
                 rx_reconfigure_consensus,
                 &registry,
             )
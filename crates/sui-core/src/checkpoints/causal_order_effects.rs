@@ -10,6 +10,7 @@ use sui_types::{
     base_types::{ExecutionDigests, TransactionDigest},
     error::{SuiError, SuiResult},
     messages::TransactionEffects,
+    waypoint::Accumulator,
 };
 use typed_store::Map;
 
@@ -34,6 +35,16 @@ pub trait CausalOrder {
     ) -> SuiResult<Vec<ExecutionDigests>>;
 }
 
+/// Gives access to the change each executed transaction made to the accumulator of live object
+/// references, so that the checkpoint store can compute the state root of a checkpoint without
+/// knowing about objects. The genesis objects are accumulated under the genesis transaction digest.
+pub trait StateAccumulatorStore {
+    fn get_state_accumulator_deltas<'a>(
+        &self,
+        transactions: impl Iterator<Item = &'a TransactionDigest>,
+    ) -> SuiResult<Vec<Option<Accumulator>>>;
+}
+
 pub trait EffectsStore {
     fn get_effects<'a>(
         &self,
@@ -205,6 +216,16 @@ impl CausalOrder for TestCausalOrderNoop {
     }
 }
 
+/// The noop causal order does not track objects, so transactions leave the state unchanged.
+impl StateAccumulatorStore for TestCausalOrderNoop {
+    fn get_state_accumulator_deltas<'a>(
+        &self,
+        transactions: impl Iterator<Item = &'a TransactionDigest>,
+    ) -> SuiResult<Vec<Option<Accumulator>>> {
+        Ok(transactions.map(|_| Some(Accumulator::default())).collect())
+    }
+}
+
 /// Now this is a real causal orderer based on having an Arc<AuthorityStore> handy.
 impl CausalOrder for Arc<AuthorityStore> {
     fn get_complete_causal_order<'a>(
@@ -216,6 +237,18 @@ impl CausalOrder for Arc<AuthorityStore> {
     }
 }
 
+impl StateAccumulatorStore for Arc<AuthorityStore> {
+    fn get_state_accumulator_deltas<'a>(
+        &self,
+        transactions: impl Iterator<Item = &'a TransactionDigest>,
+    ) -> SuiResult<Vec<Option<Accumulator>>> {
+        Ok(self
+            .perpetual_tables
+            .state_accumulator_deltas
+            .multi_get(transactions)?)
+    }
+}
+
 impl EffectsStore for BTreeMap<TransactionDigest, TransactionEffects> {
     fn get_effects<'a>(
         &self,
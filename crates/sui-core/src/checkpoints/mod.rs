@@ -12,11 +12,12 @@ use narwhal_executor::ExecutionIndices;
 use rocksdb::Options;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeSet;
+use std::iter;
 use std::{path::Path, sync::Arc};
 use sui_storage::default_db_options;
 use sui_types::messages_checkpoint::{CheckpointProposal, CheckpointProposalContents};
 use sui_types::{
-    base_types::{AuthorityName, ExecutionDigests},
+    base_types::{AuthorityName, ExecutionDigests, TransactionDigest},
     batch::TxSequenceNumber,
     committee::{Committee, EpochId},
     error::{SuiError, SuiResult},
@@ -26,6 +27,7 @@ use sui_types::{
         CheckpointFragment, CheckpointResponse, CheckpointSequenceNumber, CheckpointSummary,
         SignedCheckpointSummary,
     },
+    waypoint::Accumulator,
 };
 use tap::TapFallible;
 use tokio::sync::broadcast;
@@ -38,7 +40,7 @@ use typed_store::{
 };
 use typed_store_derive::DBMapUtils;
 
-use crate::checkpoints::causal_order_effects::CausalOrder;
+use crate::checkpoints::causal_order_effects::{CausalOrder, StateAccumulatorStore};
 use crate::checkpoints::reconstruction::SpanGraph;
 use crate::{
     authority::StableSyncAuthoritySigner,
@@ -378,7 +380,7 @@ impl CheckpointStore {
         epoch: EpochId,
         sequence_number: CheckpointSequenceNumber,
         transactions: impl Iterator<Item = &'a ExecutionDigests> + Clone,
-        effects_store: impl CausalOrder,
+        effects_store: impl CausalOrder + StateAccumulatorStore,
         next_epoch_committee: Option<Committee>,
     ) -> SuiResult {
         // Make sure that all transactions in the checkpoint show up in extra_transactions.
@@ -398,12 +400,19 @@ impl CheckpointStore {
                 .into_iter(),
         );
 
+        let state_root = self
+            .compute_state_root(sequence_number, &ordered_contents, &effects_store)?
+            .ok_or_else(|| {
+                SuiError::from("Cannot compute the state root of checkpoint transactions.")
+            })?;
+
         let summary = CheckpointSummary::new(
             epoch,
             sequence_number,
             &ordered_contents,
             previous_digest,
             next_epoch_committee,
+            state_root,
         );
 
         let checkpoint = AuthenticatedCheckpoint::Signed(
@@ -412,6 +421,68 @@ impl CheckpointStore {
         self.handle_internal_set_checkpoint(&checkpoint, &ordered_contents)
     }
 
+    /// Compute the state root of a checkpoint, by adding the state changes of its transactions
+    /// to the state root of the previous checkpoint (or to the genesis objects for the first
+    /// checkpoint). Returns None if the previous checkpoint is not known locally, or if some
+    /// transactions have not been executed locally.
+    pub fn compute_state_root(
+        &self,
+        sequence_number: CheckpointSequenceNumber,
+        contents: &CheckpointContents,
+        accumulator_store: &impl StateAccumulatorStore,
+    ) -> SuiResult<Option<Accumulator>> {
+        let previous_root = if sequence_number > 0 {
+            self.get_checkpoint(sequence_number - 1)?
+                .map(|prev_checkpoint| prev_checkpoint.summary().state_root.clone())
+        } else {
+            accumulator_store
+                .get_state_accumulator_deltas(iter::once(&TransactionDigest::genesis()))?
+                .pop()
+                .map(Option::unwrap_or_default)
+        };
+        let mut state_root = match previous_root {
+            Some(root) => root,
+            None => return Ok(None),
+        };
+
+        let deltas = accumulator_store
+            .get_state_accumulator_deltas(contents.iter().map(|digests| &digests.transaction))?;
+        for delta in deltas {
+            match delta {
+                Some(delta) => state_root.union(&delta),
+                None => return Ok(None),
+            }
+        }
+        Ok(Some(state_root))
+    }
+
+    /// Check that the state root of a checkpoint certificate matches the local state, once all
+    /// its transactions are executed. A mismatch means the local state has diverged from the
+    /// state agreed by the committee, and is reported as `SuiError::StateDivergence`.
+    pub fn check_state_root(
+        &self,
+        checkpoint: &CertifiedCheckpointSummary,
+        contents: &CheckpointContents,
+        accumulator_store: &impl StateAccumulatorStore,
+    ) -> SuiResult {
+        let seq = checkpoint.summary.sequence_number;
+        match self.compute_state_root(seq, contents, accumulator_store)? {
+            Some(state_root) if state_root != checkpoint.summary.state_root => {
+                error!(
+                    cp_seq = seq,
+                    "Local state has diverged from the state root of checkpoint {}",
+                    checkpoint.summary,
+                );
+                Err(SuiError::StateDivergence { checkpoint: seq })
+            }
+            Some(_) => Ok(()),
+            None => {
+                debug!(cp_seq = seq, "Cannot compute the local state root yet");
+                Ok(())
+            }
+        }
+    }
+
     /// Call this function internally to update the latest checkpoint.
     /// Internally it is called with an unsigned checkpoint, and results
     /// in the checkpoint being signed, stored and the contents
@@ -832,6 +903,23 @@ impl CheckpointStore {
         Ok(())
     }
 
+    /// Store the certified checkpoint a state snapshot was taken at, in an empty checkpoint
+    /// store, so that checkpoint sync resumes right after it.
+    pub fn restore_from_snapshot(
+        &mut self,
+        checkpoint: &CertifiedCheckpointSummary,
+        contents: &CheckpointContents,
+        committee: &Committee,
+    ) -> SuiResult {
+        fp_ensure!(
+            self.latest_stored_checkpoint().is_none(),
+            SuiError::from("Cannot restore a snapshot into a non-empty checkpoint store")
+        );
+        let seq = *checkpoint.summary.sequence_number();
+        self.clear_proposal(seq)?;
+        self.process_synced_checkpoint_certificate(checkpoint, contents, committee)
+    }
+
     fn notify_new_checkpoint(&self, ckpt: CertifiedCheckpointSummary) {
         let sequence = ckpt.summary.sequence_number;
         let _ = self.notify_new_checkpoint_tx.send(ckpt).tap_err(|_| {
@@ -885,11 +973,31 @@ impl CheckpointStore {
             .iter()
             .skip_to_last()
             .reverse()
-            .take_while(|(_, ckp)| !matches!(ckp, AuthenticatedCheckpoint::Certified(_)))
-            .next()
+            .find(|(_, ckp)| matches!(ckp, AuthenticatedCheckpoint::Certified(_)))
             .map(|(_, ckp)| ckp)
     }
 
+    /// Return the transactions included in the checkpoints of `epoch`, most recent checkpoint
+    /// first.
+    pub fn get_epoch_transactions(&self, epoch: EpochId) -> SuiResult<Vec<ExecutionDigests>> {
+        let mut transactions = Vec::new();
+        for (seq, checkpoint) in self.tables.checkpoints.iter().skip_to_last().reverse() {
+            if checkpoint.epoch() < epoch {
+                break;
+            }
+            if checkpoint.epoch() > epoch {
+                continue;
+            }
+            let contents = self.tables.checkpoint_contents.get(&seq)?.ok_or_else(|| {
+                SuiError::CheckpointingError {
+                    error: format!("Missing contents of checkpoint {seq}"),
+                }
+            })?;
+            transactions.extend(contents.iter().cloned());
+        }
+        Ok(transactions)
+    }
+
     pub fn is_ready_to_start_epoch_change(&mut self) -> bool {
         let next_seq = self.next_checkpoint();
         self.enable_reconfig && next_seq % CHECKPOINT_COUNT_PER_EPOCH == 0 && next_seq != 0
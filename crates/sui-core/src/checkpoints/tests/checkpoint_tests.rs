@@ -669,6 +669,9 @@ fn set_get_checkpoint() {
         panic!("Unexpected response");
     }
 
+    // A signed checkpoint is not a certified one
+    assert!(cps1.latest_certified_checkpoint().is_none());
+
     // Make a certificate
     let mut signed_checkpoint: Vec<SignedCheckpointSummary> = Vec::new();
     for x in [&mut cps1, &mut cps2, &mut cps3] {
@@ -703,6 +706,10 @@ fn set_get_checkpoint() {
             ..
         }
     ));
+    assert!(matches!(
+        cps1.latest_certified_checkpoint(),
+        Some(AuthenticatedCheckpoint::Certified(cert)) if cert.summary.sequence_number == 0
+    ));
 
     // --- TEST 3 ---
 
@@ -729,6 +736,17 @@ fn set_get_checkpoint() {
             ..
         }
     ));
+
+    // --- TEST 4 ---
+
+    // The transactions of the checkpoint are attributed to its epoch.
+    let epoch_transactions: BTreeSet<_> = cps4
+        .get_epoch_transactions(epoch)
+        .unwrap()
+        .into_iter()
+        .collect();
+    assert_eq!(epoch_transactions, contents.iter().cloned().collect());
+    assert!(cps4.get_epoch_transactions(epoch + 1).unwrap().is_empty());
 }
 
 #[test]
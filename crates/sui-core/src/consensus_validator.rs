@@ -0,0 +1,57 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::authority::AuthorityState;
+use anyhow::anyhow;
+use narwhal_worker::TransactionValidator;
+use std::sync::Arc;
+use sui_types::committee::{Committee, EpochId};
+use sui_types::messages::ConsensusTransaction;
+
+#[cfg(test)]
+#[path = "unit_tests/consensus_validator_tests.rs"]
+mod consensus_validator_tests;
+
+/// Allows the Narwhal workers of this authority to reject malformed consensus transactions, so
+/// that they are never sequenced.
+#[derive(Clone)]
+pub struct SuiTxValidator {
+    state: Arc<AuthorityState>,
+}
+
+impl SuiTxValidator {
+    pub fn new(state: Arc<AuthorityState>) -> Self {
+        Self { state }
+    }
+
+    /// The committee that signed a transaction of the given epoch. During reconfiguration the
+    /// workers may still receive transactions of the previous epoch, or already receive ones of
+    /// the next epoch, so the committees of both adjacent epochs are accepted as well.
+    fn committee(&self, epoch: EpochId) -> anyhow::Result<Arc<Committee>> {
+        let current = self.state.committee.load_full();
+        if epoch == current.epoch {
+            return Ok(current);
+        }
+        if epoch.saturating_add(1) != current.epoch && epoch != current.epoch.saturating_add(1) {
+            return Err(anyhow!(
+                "Transaction of epoch {epoch} received in epoch {}",
+                current.epoch
+            ));
+        }
+        self.state
+            .committee_store()
+            .get_committee(&epoch)?
+            .map(Arc::new)
+            .ok_or_else(|| anyhow!("Unknown committee for epoch {epoch}"))
+    }
+}
+
+impl TransactionValidator for SuiTxValidator {
+    type Error = anyhow::Error;
+
+    fn validate(&self, transaction: &[u8]) -> Result<(), Self::Error> {
+        let transaction: ConsensusTransaction = bincode::deserialize(transaction)?;
+        transaction.verify(&*self.committee(transaction.epoch())?)?;
+        Ok(())
+    }
+}
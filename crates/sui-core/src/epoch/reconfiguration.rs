@@ -14,6 +14,7 @@ use sui_network::{default_mysten_network_config, tonic};
 use sui_types::base_types::AuthorityName;
 use sui_types::crypto::AuthorityPublicKeyBytes;
 use sui_types::error::SuiResult;
+use sui_types::gas::GasCostSummary;
 use sui_types::messages::SignedTransaction;
 use sui_types::sui_system_state::SuiSystemState;
 use tracing::{debug, error, info, warn};
@@ -60,7 +61,7 @@ where
         let epoch = self.state.committee.load().epoch;
         info!(?epoch, "Finishing epoch change");
         let checkpoints = &self.state.checkpoints;
-        {
+        let epoch_transactions = {
             let mut checkpoints = checkpoints.lock();
             assert!(
                 checkpoints.is_ready_to_finish_epoch_change(),
@@ -86,7 +87,18 @@ where
             checkpoints.tables.extra_transactions.clear()?;
 
             self.state.database.remove_all_pending_certificates()?;
-        }
+
+            checkpoints.get_epoch_transactions(epoch)?
+        };
+
+        // Aggregate the fees paid by all transactions checkpointed in this epoch, so that they can
+        // be distributed to validators and delegators when advancing the epoch.
+        let gas_cost_summary = GasCostSummary::new_from_txn_effects(
+            epoch_transactions
+                .iter()
+                .map(|digests| self.state.database.get_effects(&digests.transaction)),
+        )?;
+        debug!(?epoch, ?gas_cost_summary, "Gas costs of the epoch");
 
         let sui_system_state = self.state.get_sui_system_state_object().await?;
         let next_epoch = epoch + 1;
@@ -125,8 +137,8 @@ where
 
         let advance_epoch_tx = SignedTransaction::new_change_epoch(
             next_epoch,
-            0, // TODO: fill in storage_charge
-            0, // TODO: fill in computation_charge
+            gas_cost_summary.net_storage_charge(),
+            gas_cost_summary.computation_cost,
             self.state.name,
             &*self.state.secret,
         );
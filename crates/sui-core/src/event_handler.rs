@@ -3,22 +3,25 @@
 
 use std::sync::Arc;
 
+use futures::StreamExt;
 use move_bytecode_utils::module_cache::SyncModuleCache;
 use sui_json_rpc_types::SuiMoveStruct;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
 use tokio_stream::Stream;
 use tracing::{debug, error, instrument, trace};
 
-use sui_storage::event_store::{EventStore, EventStoreType};
+use sui_storage::event_store::{EventStore, EventStoreType, EVENT_STORE_QUERY_MAX_LIMIT};
 use sui_types::base_types::TransactionDigest;
 use sui_types::{
     error::{SuiError, SuiResult},
-    event::{Event, EventEnvelope},
+    event::{Event, EventEnvelope, EventID},
     messages::TransactionEffects,
 };
 
 use crate::authority::{AuthorityStore, ResolverWrapper};
 use crate::streamer::Streamer;
-use sui_types::filter::EventFilter;
+use sui_types::filter::{EventFilter, Filter};
 
 #[cfg(test)]
 #[path = "unit_tests/event_handler_tests.rs"]
@@ -52,7 +55,16 @@ impl EventHandler {
         let res: Result<Vec<_>, _> = effects
             .events
             .iter()
-            .map(|e| self.create_envelope(e, effects.transaction_digest, seq_num, timestamp_ms))
+            .enumerate()
+            .map(|(event_num, e)| {
+                self.create_envelope(
+                    e,
+                    effects.transaction_digest,
+                    seq_num,
+                    event_num as u64,
+                    timestamp_ms,
+                )
+            })
             .collect();
         let envelopes = res?;
 
@@ -79,6 +91,7 @@ impl EventHandler {
         event: &Event,
         digest: TransactionDigest,
         seq_num: u64,
+        event_num: u64,
         timestamp_ms: u64,
     ) -> Result<EventEnvelope, SuiError> {
         let json_value = match event {
@@ -103,6 +116,7 @@ impl EventHandler {
             timestamp_ms,
             Some(digest),
             seq_num,
+            event_num,
             event.clone(),
             json_value,
         ))
@@ -111,4 +125,72 @@ impl EventHandler {
     pub fn subscribe(&self, filter: EventFilter) -> impl Stream<Item = EventEnvelope> {
         self.event_streamer.subscribe(filter)
     }
+
+    /// Subscribe to events matching `filter`, starting from the event with ID `cursor`.
+    /// Events already in the event store are replayed first, then the stream switches over
+    /// to live events without gaps or duplicates.
+    /// The stream ends early if reading from the event store fails, in which case the client
+    /// can resubscribe from the last event it received.
+    pub fn subscribe_from(
+        &self,
+        filter: EventFilter,
+        cursor: EventID,
+    ) -> impl Stream<Item = EventEnvelope> {
+        // Subscribe to the live stream before reading the store, so that every event is
+        // either in the store when we read it, or buffered in the live subscription.
+        let mut live = self.event_streamer.subscribe(filter.clone());
+        let event_store = self.event_store.clone();
+        let (tx, rx) = mpsc::channel(EVENT_DISPATCH_BUFFER_SIZE);
+        tokio::spawn(async move {
+            let mut cursor = cursor;
+            let mut last_replayed = None;
+            loop {
+                let stored_events = match event_store
+                    .events_from_cursor(cursor, EVENT_STORE_QUERY_MAX_LIMIT)
+                    .await
+                {
+                    Ok(events) => events,
+                    Err(e) => {
+                        error!(error =? e, ?cursor, "Failed to replay events from event store");
+                        return;
+                    }
+                };
+                let done = stored_events.len() < EVENT_STORE_QUERY_MAX_LIMIT;
+                for stored_event in stored_events {
+                    let id = stored_event.id();
+                    last_replayed = Some(id);
+                    cursor = EventID {
+                        tx_seq: id.tx_seq,
+                        event_seq: id.event_seq + 1,
+                    };
+                    let envelope = match stored_event.into_event_envelope() {
+                        Ok(envelope) => envelope,
+                        Err(e) => {
+                            debug!(error =? e, ?id, "Skipping stored event that cannot be replayed");
+                            continue;
+                        }
+                    };
+                    if filter.matches(&envelope) && tx.send(envelope).await.is_err() {
+                        return;
+                    }
+                }
+                if done {
+                    break;
+                }
+            }
+            trace!(
+                ?last_replayed,
+                "Finished replaying events, switching to live stream"
+            );
+            while let Some(envelope) = live.next().await {
+                if matches!(last_replayed, Some(last) if envelope.id() <= last) {
+                    continue;
+                }
+                if tx.send(envelope).await.is_err() {
+                    return;
+                }
+            }
+        });
+        ReceiverStream::new(rx)
+    }
 }
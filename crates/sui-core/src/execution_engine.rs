@@ -5,7 +5,7 @@ use move_core_types::ident_str;
 use move_core_types::identifier::Identifier;
 use std::{collections::BTreeSet, sync::Arc};
 use sui_types::id::UID;
-use sui_types::storage::{DeleteKind, ObjectResolver, ParentSync, WriteKind};
+use sui_types::storage::{ChildObjectResolver, DeleteKind, ObjectResolver, ParentSync, WriteKind};
 #[cfg(test)]
 use sui_types::temporary_store;
 use sui_types::temporary_store::InnerTemporaryStore;
@@ -24,15 +24,15 @@ use sui_types::gas_coin::GasCoin;
 use sui_types::messages::ExecutionFailureStatus;
 #[cfg(test)]
 use sui_types::messages::InputObjects;
-use sui_types::messages::{ObjectArg, Pay};
+use sui_types::messages::{BatchResult, ObjectArg, Pay, PayBatchResults, TransferBatchResult};
 use sui_types::object::{Data, MoveObject, Owner, OBJECT_START_VERSION};
 use sui_types::{
     base_types::{ObjectID, ObjectRef, SuiAddress, TransactionDigest, TxContext},
     event::{Event, TransferType},
     gas::{self, SuiGasStatus},
     messages::{
-        CallArg, ChangeEpoch, ExecutionStatus, MoveCall, MoveModulePublish, SingleTransactionKind,
-        TransactionData, TransactionEffects, TransferObject, TransferSui,
+        CallArg, ChangeEpoch, ExecutionStatus, MoveCall, MoveModulePublish, MoveModuleUpgrade,
+        SingleTransactionKind, TransactionData, TransactionEffects, TransferObject, TransferSui,
     },
     object::Object,
     storage::{BackingPackageStore, Storage},
@@ -42,7 +42,7 @@ use sui_types::{
 use tracing::{debug, instrument, trace};
 
 #[instrument(name = "tx_execute_to_effects", level = "debug", skip_all)]
-pub fn execute_transaction_to_effects<S: BackingPackageStore + ParentSync>(
+pub fn execute_transaction_to_effects<S: BackingPackageStore + ParentSync + ChildObjectResolver>(
     shared_object_refs: Vec<ObjectRef>,
     mut temporary_store: TemporaryStore<S>,
     transaction_data: TransactionData,
@@ -115,7 +115,7 @@ fn charge_gas_for_object_read<S>(
 }
 
 #[instrument(name = "tx_execute", level = "debug", skip_all)]
-fn execute_transaction<S: BackingPackageStore + ParentSync>(
+fn execute_transaction<S: BackingPackageStore + ParentSync + ChildObjectResolver>(
     temporary_store: &mut TemporaryStore<S>,
     transaction_data: TransactionData,
     gas_object_id: ObjectID,
@@ -128,9 +128,13 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
     // we must still ensure an effect is committed and all objects versions incremented.
     let mut result = charge_gas_for_object_read(temporary_store, &mut gas_status);
     if result.is_ok() {
+        // The objects created by each step that has been executed, for later steps of a batch
+        // transaction to use.
+        let mut batch_results = Vec::new();
         // TODO: Since we require all mutable objects to not show up more than
         // once across single tx, we should be able to run them in parallel.
         for single_tx in transaction_data.kind.into_single_transactions() {
+            let ids_created = tx_ctx.ids_created();
             result = match single_tx {
                 SingleTransactionKind::TransferObject(TransferObject {
                     recipient,
@@ -165,6 +169,19 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
                         break;
                     }
 
+                    let arguments = match resolve_call_args(
+                        temporary_store,
+                        &batch_results,
+                        arguments,
+                        tx_ctx.sender(),
+                    ) {
+                        Ok(arguments) => arguments,
+                        Err(e) => {
+                            result = Err(e);
+                            break;
+                        }
+                    };
+
                     let module_id = ModuleId::new(package.0.into(), module);
                     adapter::execute(
                         move_vm,
@@ -198,6 +215,39 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
                         &mut gas_status.create_move_gas_status(),
                     )
                 }
+                SingleTransactionKind::Upgrade(MoveModuleUpgrade {
+                    package,
+                    upgrade_cap,
+                    modules,
+                }) => {
+                    // Charge gas for this VM execution
+                    if let Err(e) = gas_status.charge_vm_gas() {
+                        result = Err(e);
+                        break;
+                    }
+                    // Charge gas for publishing the new version
+                    if let Err(e) =
+                        gas_status.charge_publish_package(modules.iter().map(|v| v.len()).sum())
+                    {
+                        result = Err(e);
+                        break;
+                    }
+                    // unwrap is safe because we built the object map from the transaction
+                    let upgrade_cap = temporary_store
+                        .objects()
+                        .get(&upgrade_cap.0)
+                        .unwrap()
+                        .clone();
+                    adapter::upgrade(
+                        temporary_store,
+                        native_functions.clone(),
+                        package.0,
+                        upgrade_cap,
+                        modules,
+                        tx_ctx,
+                        &mut gas_status.create_move_gas_status(),
+                    )
+                }
                 SingleTransactionKind::Pay(Pay {
                     coins,
                     recipients,
@@ -213,6 +263,36 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
                     ).collect();
                     pay(temporary_store, coin_objects, recipients, amounts, tx_ctx)
                 }
+                SingleTransactionKind::TransferBatchResult(TransferBatchResult {
+                    recipient,
+                    object,
+                }) => resolve_owned_batch_result(
+                    temporary_store,
+                    &batch_results,
+                    object,
+                    tx_ctx.sender(),
+                )
+                .and_then(|object| {
+                    transfer_object(temporary_store, object, tx_ctx.sender(), recipient)
+                }),
+                SingleTransactionKind::PayBatchResults(PayBatchResults {
+                    coins,
+                    recipients,
+                    amounts,
+                }) => coins
+                    .into_iter()
+                    .map(|coin| {
+                        resolve_owned_batch_result(
+                            temporary_store,
+                            &batch_results,
+                            coin,
+                            tx_ctx.sender(),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+                    .and_then(|coin_objects| {
+                        pay(temporary_store, coin_objects, recipients, amounts, tx_ctx)
+                    }),
                 SingleTransactionKind::ChangeEpoch(ChangeEpoch {
                     epoch,
                     storage_charge,
@@ -244,6 +324,7 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
             if result.is_err() {
                 break;
             }
+            batch_results.push(created_objects(temporary_store, tx_ctx, ids_created));
         }
         if result.is_err() {
             // Roll back the temporary store if execution failed.
@@ -299,6 +380,106 @@ fn execute_transaction<S: BackingPackageStore + ParentSync>(
     (cost_summary, result)
 }
 
+/// Return the objects created since `tx_ctx` had derived `ids_created` object IDs that still
+/// exist, in creation order.
+fn created_objects<S>(
+    temporary_store: &TemporaryStore<S>,
+    tx_ctx: &TxContext,
+    ids_created: u64,
+) -> Vec<ObjectID> {
+    let digest = tx_ctx.digest();
+    (ids_created..tx_ctx.ids_created())
+        .map(|i| digest.derive_id(i))
+        .filter(|id| {
+            !temporary_store.deleted().contains_key(id) && temporary_store.read_object(id).is_some()
+        })
+        .collect()
+}
+
+/// Look up the object an earlier step of a batch transaction created for `result`.
+fn resolve_batch_result<S>(
+    temporary_store: &TemporaryStore<S>,
+    batch_results: &[Vec<ObjectID>],
+    result: BatchResult,
+) -> Result<Object, ExecutionError> {
+    batch_results
+        .get(result.step as usize)
+        .and_then(|created| created.get(result.index as usize))
+        .filter(|id| !temporary_store.deleted().contains_key(id))
+        .and_then(|id| temporary_store.read_object(id))
+        .cloned()
+        .ok_or_else(|| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::InvalidBatchResult,
+                format!("No object was created for {result}, or it no longer exists"),
+            )
+        })
+}
+
+/// Look up the object an earlier step of a batch transaction created for `result`, which must
+/// be owned by `sender`.
+fn resolve_owned_batch_result<S>(
+    temporary_store: &TemporaryStore<S>,
+    batch_results: &[Vec<ObjectID>],
+    result: BatchResult,
+    sender: SuiAddress,
+) -> Result<Object, ExecutionError> {
+    let object = resolve_batch_result(temporary_store, batch_results, result)?;
+    if object.owner != Owner::AddressOwner(sender) {
+        return Err(ExecutionError::new_with_source(
+            ExecutionErrorKind::InvalidBatchResult,
+            format!(
+                "The object {} created for {result} is not owned by the sender",
+                object.id()
+            ),
+        ));
+    }
+    Ok(object)
+}
+
+/// Replace the arguments of a Move call that refer to objects created by earlier steps of a
+/// batch transaction with those objects.
+fn resolve_call_args<S>(
+    temporary_store: &TemporaryStore<S>,
+    batch_results: &[Vec<ObjectID>],
+    arguments: Vec<CallArg>,
+    sender: SuiAddress,
+) -> Result<Vec<CallArg>, ExecutionError> {
+    arguments
+        .into_iter()
+        .map(|arg| {
+            let result = match arg {
+                CallArg::Result(result) => result,
+                arg => return Ok(arg),
+            };
+            let object = resolve_batch_result(temporary_store, batch_results, result)?;
+            match object.owner {
+                Owner::AddressOwner(owner) if owner == sender => Ok(CallArg::Object(
+                    ObjectArg::ImmOrOwnedObject(object.compute_object_reference()),
+                )),
+                Owner::Immutable => Ok(CallArg::Object(ObjectArg::ImmOrOwnedObject(
+                    object.compute_object_reference(),
+                ))),
+                Owner::Shared {
+                    initial_shared_version,
+                } => Ok(CallArg::Object(ObjectArg::SharedObject {
+                    id: object.id(),
+                    initial_shared_version,
+                })),
+                Owner::AddressOwner(_) | Owner::ObjectOwner(_) => {
+                    Err(ExecutionError::new_with_source(
+                        ExecutionErrorKind::InvalidBatchResult,
+                        format!(
+                            "The object {} created for {result} is not owned by the sender",
+                            object.id()
+                        ),
+                    ))
+                }
+            }
+        })
+        .collect()
+}
+
 fn transfer_object<S>(
     temporary_store: &mut TemporaryStore<S>,
     mut object: Object,
@@ -1235,14 +1235,15 @@ where
             .into_iter()
             .map(|arg| arg.try_into())
             .collect::<Result<Vec<_>, _>>()?;
-        let compiled_module = package_obj
+        let package = package_obj
             .data
             .try_as_package()
-            .ok_or_else(|| anyhow!("Cannot get package from object"))?
-            .deserialize_module(&module)?;
+            .ok_or_else(|| anyhow!("Cannot get package from object"))?;
+        let compiled_module = package.deserialize_module(&module)?;
         resolve_and_type_check(
             &objects,
             &compiled_module,
+            package.lineage(),
             &function,
             &type_arguments,
             args.clone(),
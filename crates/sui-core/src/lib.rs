@@ -9,6 +9,7 @@ pub mod authority_client;
 pub mod authority_server;
 pub mod checkpoints;
 pub mod consensus_adapter;
+pub mod consensus_validator;
 pub mod epoch;
 pub mod event_handler;
 pub mod execution_engine;
@@ -16,6 +17,7 @@ pub mod gateway_state;
 pub mod metrics;
 pub mod quorum_driver;
 pub mod safe_client;
+pub mod state_snapshot;
 pub mod streamer;
 pub mod transaction_input_checker;
 pub mod transaction_orchestrator;
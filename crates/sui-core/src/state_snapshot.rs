@@ -0,0 +1,460 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! State snapshots contain the live object set at a certified checkpoint, and allow to
+//! bootstrap a node from that checkpoint instead of replaying all transactions since genesis.
+//!
+//! A snapshot file starts with `STATE_SNAPSHOT_MAGIC` and the little-endian `u32` format
+//! version, followed by a sequence of records: a header holding the certified checkpoint, its
+//! contents and the certified last checkpoint of each previous epoch, chunks of objects, and an
+//! end marker holding the total number of objects and chunks. Each record is BCS encoded and
+//! framed as its little-endian `u32` length, the payload, and the SHA3-256 digest of the payload.
+
+use crate::authority::authority_store_tables::AuthorityPerpetualTables;
+use crate::authority::{AuthorityState, AuthorityStore};
+use crate::checkpoints::{CheckpointStore, CheckpointStoreTables};
+use crate::epoch::committee_store::CommitteeStore;
+use anyhow::{anyhow, bail, ensure};
+use parking_lot::Mutex;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+use std::collections::{BTreeMap, HashSet};
+use std::io::{Read, Write};
+use sui_storage::IndexStore;
+use sui_types::base_types::{ObjectRef, TransactionDigest};
+use sui_types::committee::Committee;
+use sui_types::crypto::AuthoritySignInfo;
+use sui_types::messages_checkpoint::{
+    AuthenticatedCheckpoint, CertifiedCheckpointSummary, CheckpointContents,
+    CheckpointSequenceNumber,
+};
+use sui_types::object::Object;
+use sui_types::waypoint::Accumulator;
+use tracing::info;
+use typed_store::Map;
+
+#[cfg(test)]
+#[path = "unit_tests/state_snapshot_tests.rs"]
+mod state_snapshot_tests;
+
+pub const STATE_SNAPSHOT_MAGIC: [u8; 8] = *b"SUISNAP\0";
+pub const STATE_SNAPSHOT_FORMAT_VERSION: u32 = 2;
+pub const DEFAULT_OBJECTS_PER_CHUNK: usize = 10_000;
+
+/// Records larger than this are rejected when reading a snapshot, to avoid allocating
+/// arbitrary amounts of memory on corrupted input.
+const MAX_RECORD_SIZE: usize = 1 << 30;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StateSnapshotHeader {
+    pub checkpoint: CertifiedCheckpointSummary,
+    pub contents: CheckpointContents,
+    /// The last checkpoint of each epoch before the one of `checkpoint`, in epoch order. Their
+    /// `next_epoch_committee` lead from the genesis committee to the committee that certified
+    /// `checkpoint`.
+    pub epoch_checkpoints: Vec<CertifiedCheckpointSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum StateSnapshotRecord {
+    Header(StateSnapshotHeader),
+    Objects(Vec<Object>),
+    End { num_objects: u64, num_chunks: u64 },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateSnapshotSummary {
+    pub sequence_number: CheckpointSequenceNumber,
+    pub num_objects: u64,
+    pub num_chunks: u64,
+}
+
+fn write_record<W: Write, T: Serialize>(writer: &mut W, record: &T) -> anyhow::Result<()> {
+    let payload = bcs::to_bytes(record)?;
+    let len = u32::try_from(payload.len()).map_err(|_| anyhow!("Snapshot record too large"))?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(&payload)?;
+    writer.write_all(&Sha3_256::digest(&payload))?;
+    Ok(())
+}
+
+fn read_record<R: Read, T: DeserializeOwned>(reader: &mut R) -> anyhow::Result<T> {
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_le_bytes(len) as usize;
+    ensure!(
+        len <= MAX_RECORD_SIZE,
+        "Snapshot record of {len} bytes exceeds the maximum size"
+    );
+    let mut payload = vec![0u8; len];
+    reader.read_exact(&mut payload)?;
+    let mut checksum = [0u8; 32];
+    reader.read_exact(&mut checksum)?;
+    ensure!(
+        Sha3_256::digest(&payload).as_slice() == checksum,
+        "Snapshot record checksum mismatch, the file is corrupted"
+    );
+    Ok(bcs::from_bytes(&payload)?)
+}
+
+/// Writes a state snapshot, buffering objects into chunks of `objects_per_chunk` objects.
+pub struct StateSnapshotWriter<W> {
+    writer: W,
+    sequence_number: CheckpointSequenceNumber,
+    objects_per_chunk: usize,
+    chunk: Vec<Object>,
+    num_objects: u64,
+    num_chunks: u64,
+}
+
+impl<W: Write> StateSnapshotWriter<W> {
+    pub fn new(
+        mut writer: W,
+        header: StateSnapshotHeader,
+        objects_per_chunk: usize,
+    ) -> anyhow::Result<Self> {
+        ensure!(
+            objects_per_chunk > 0,
+            "Chunks must hold at least one object"
+        );
+        writer.write_all(&STATE_SNAPSHOT_MAGIC)?;
+        writer.write_all(&STATE_SNAPSHOT_FORMAT_VERSION.to_le_bytes())?;
+        let sequence_number = header.checkpoint.summary.sequence_number;
+        write_record(&mut writer, &StateSnapshotRecord::Header(header))?;
+        Ok(Self {
+            writer,
+            sequence_number,
+            objects_per_chunk,
+            chunk: Vec::with_capacity(objects_per_chunk),
+            num_objects: 0,
+            num_chunks: 0,
+        })
+    }
+
+    pub fn push_object(&mut self, object: Object) -> anyhow::Result<()> {
+        self.chunk.push(object);
+        self.num_objects += 1;
+        if self.chunk.len() >= self.objects_per_chunk {
+            self.flush_chunk()?;
+        }
+        Ok(())
+    }
+
+    fn flush_chunk(&mut self) -> anyhow::Result<()> {
+        if !self.chunk.is_empty() {
+            let chunk =
+                std::mem::replace(&mut self.chunk, Vec::with_capacity(self.objects_per_chunk));
+            write_record(&mut self.writer, &StateSnapshotRecord::Objects(chunk))?;
+            self.num_chunks += 1;
+        }
+        Ok(())
+    }
+
+    /// Write the last chunk and the end marker, and flush the underlying writer.
+    pub fn finish(mut self) -> anyhow::Result<StateSnapshotSummary> {
+        self.flush_chunk()?;
+        write_record(
+            &mut self.writer,
+            &StateSnapshotRecord::End {
+                num_objects: self.num_objects,
+                num_chunks: self.num_chunks,
+            },
+        )?;
+        self.writer.flush()?;
+        Ok(StateSnapshotSummary {
+            sequence_number: self.sequence_number,
+            num_objects: self.num_objects,
+            num_chunks: self.num_chunks,
+        })
+    }
+}
+
+/// Reads a state snapshot chunk by chunk, checking the checksum of every record. Once all
+/// chunks are read, the accumulator of the objects is checked against the state root of the
+/// snapshot checkpoint.
+pub struct StateSnapshotReader<R> {
+    reader: R,
+    header: StateSnapshotHeader,
+    num_objects: u64,
+    num_chunks: u64,
+    state_root: Accumulator,
+    finished: bool,
+}
+
+impl<R: Read> StateSnapshotReader<R> {
+    pub fn new(mut reader: R) -> anyhow::Result<Self> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        ensure!(magic == STATE_SNAPSHOT_MAGIC, "Not a state snapshot file");
+        let mut version = [0u8; 4];
+        reader.read_exact(&mut version)?;
+        let version = u32::from_le_bytes(version);
+        ensure!(
+            version == STATE_SNAPSHOT_FORMAT_VERSION,
+            "Unsupported state snapshot format version {version}, expected {STATE_SNAPSHOT_FORMAT_VERSION}"
+        );
+        let header = match read_record(&mut reader)? {
+            StateSnapshotRecord::Header(header) => header,
+            _ => bail!("State snapshot does not start with a header"),
+        };
+        Ok(Self {
+            reader,
+            header,
+            num_objects: 0,
+            num_chunks: 0,
+            state_root: Accumulator::default(),
+            finished: false,
+        })
+    }
+
+    pub fn header(&self) -> &StateSnapshotHeader {
+        &self.header
+    }
+
+    /// Return the next chunk of objects, or `None` once the end marker has been read and
+    /// checked against the chunks read so far, and the objects against the state root.
+    pub fn next_chunk(&mut self) -> anyhow::Result<Option<Vec<Object>>> {
+        if self.finished {
+            return Ok(None);
+        }
+        match read_record(&mut self.reader)? {
+            StateSnapshotRecord::Objects(objects) => {
+                self.num_objects += objects.len() as u64;
+                self.num_chunks += 1;
+                for object in &objects {
+                    self.state_root.insert(&object.compute_object_reference());
+                }
+                Ok(Some(objects))
+            }
+            StateSnapshotRecord::End {
+                num_objects,
+                num_chunks,
+            } => {
+                ensure!(
+                    num_objects == self.num_objects && num_chunks == self.num_chunks,
+                    "State snapshot is truncated: expected {num_objects} objects in {num_chunks} chunks, read {} objects in {} chunks",
+                    self.num_objects,
+                    self.num_chunks
+                );
+                ensure!(
+                    self.state_root == self.header.checkpoint.summary.state_root,
+                    "State snapshot objects do not match the state root of the checkpoint"
+                );
+                self.finished = true;
+                Ok(None)
+            }
+            StateSnapshotRecord::Header(_) => bail!("Unexpected header in state snapshot"),
+        }
+    }
+
+    pub fn summary(&self) -> StateSnapshotSummary {
+        StateSnapshotSummary {
+            sequence_number: self.header.checkpoint.summary.sequence_number,
+            num_objects: self.num_objects,
+            num_chunks: self.num_chunks,
+        }
+    }
+}
+
+/// Export the live object set at the given certified checkpoint (the latest certified
+/// checkpoint if `None`). The tables must not be written to while the export runs, i.e. the
+/// node must be stopped.
+///
+/// Objects are exported at their latest version written by a transaction included in a
+/// checkpoint up to the requested one, so the node may have executed past that checkpoint.
+pub fn export_state_snapshot<W: Write>(
+    perpetual_tables: &AuthorityPerpetualTables<AuthoritySignInfo>,
+    checkpoint_tables: &CheckpointStoreTables,
+    sequence_number: Option<CheckpointSequenceNumber>,
+    objects_per_chunk: usize,
+    writer: W,
+) -> anyhow::Result<StateSnapshotSummary> {
+    let checkpoint = match sequence_number {
+        Some(seq) => checkpoint_tables.checkpoints.get(&seq)?,
+        None => checkpoint_tables
+            .checkpoints
+            .iter()
+            .skip_to_last()
+            .reverse()
+            .map(|(_, checkpoint)| checkpoint)
+            .find(|checkpoint| matches!(checkpoint, AuthenticatedCheckpoint::Certified(_))),
+    };
+    let checkpoint = match checkpoint {
+        Some(AuthenticatedCheckpoint::Certified(checkpoint)) => checkpoint,
+        Some(AuthenticatedCheckpoint::Signed(_)) => bail!("Checkpoint is not certified"),
+        None => bail!("Checkpoint not found"),
+    };
+    let seq = checkpoint.summary.sequence_number;
+    let contents = checkpoint_tables
+        .checkpoint_contents
+        .get(&seq)?
+        .ok_or_else(|| anyhow!("Contents of checkpoint {seq} not found"))?;
+    // The last checkpoint of each epoch commits to the committee of the next one.
+    let mut epoch_checkpoints = Vec::new();
+    for (_, epoch_checkpoint) in checkpoint_tables
+        .checkpoints
+        .iter()
+        .take_while(|(s, _)| *s < seq)
+    {
+        if epoch_checkpoint.summary().next_epoch_committee.is_none() {
+            continue;
+        }
+        match epoch_checkpoint {
+            AuthenticatedCheckpoint::Certified(certified) => epoch_checkpoints.push(certified),
+            AuthenticatedCheckpoint::Signed(signed) => bail!(
+                "Last checkpoint {} of epoch {} is not certified",
+                signed.summary.sequence_number,
+                signed.summary.epoch
+            ),
+        }
+    }
+
+    // All transactions whose outputs are part of the state at the checkpoint.
+    let mut checkpointed = HashSet::from([TransactionDigest::genesis()]);
+    for (_, contents) in checkpoint_tables
+        .checkpoint_contents
+        .iter()
+        .take_while(|(s, _)| *s <= seq)
+    {
+        checkpointed.extend(contents.iter().map(|digests| digests.transaction));
+    }
+
+    info!(checkpoint = seq, "Exporting state snapshot");
+    let mut writer = StateSnapshotWriter::new(
+        writer,
+        StateSnapshotHeader {
+            checkpoint,
+            contents,
+            epoch_checkpoints,
+        },
+        objects_per_chunk,
+    )?;
+
+    // parent_sync is ordered by object id and version: for each object, remember its latest
+    // entry written by a checkpointed transaction, and export it if the object is alive.
+    let mut export = |object_ref: ObjectRef| -> anyhow::Result<()> {
+        if object_ref.2.is_alive() {
+            let object = perpetual_tables
+                .objects
+                .get(&object_ref.into())?
+                .ok_or_else(|| anyhow!("Object {object_ref:?} not found, was it pruned?"))?;
+            writer.push_object(object)?;
+        }
+        Ok(())
+    };
+    let mut latest: Option<ObjectRef> = None;
+    for (object_ref, tx_digest) in perpetual_tables.parent_sync.iter() {
+        match latest {
+            Some(prev) if prev.0 != object_ref.0 => {
+                export(prev)?;
+                latest = None;
+            }
+            _ => {}
+        }
+        if checkpointed.contains(&tx_digest) {
+            latest = Some(object_ref);
+        }
+    }
+    if let Some(prev) = latest {
+        export(prev)?;
+    }
+
+    writer.finish()
+}
+
+/// Check the checksums and the completeness of a snapshot, the checkpoint certificate it was
+/// taken at, and that its objects are the live object set committed by the checkpoint.
+pub fn verify_state_snapshot<R: Read>(
+    reader: R,
+    genesis_committee: &Committee,
+) -> anyhow::Result<StateSnapshotSummary> {
+    let mut reader = StateSnapshotReader::new(reader)?;
+    verify_header(reader.header(), genesis_committee)?;
+    while reader.next_chunk()?.is_some() {}
+    Ok(reader.summary())
+}
+
+/// Follow the committees from the trusted `genesis_committee` through the last checkpoint of
+/// each epoch, and verify the snapshot checkpoint against the committee of its epoch, which is
+/// returned.
+fn verify_header(
+    header: &StateSnapshotHeader,
+    genesis_committee: &Committee,
+) -> anyhow::Result<Committee> {
+    let mut committee = genesis_committee.clone();
+    for checkpoint in &header.epoch_checkpoints {
+        let summary = &checkpoint.summary;
+        ensure!(
+            summary.epoch == committee.epoch,
+            "State snapshot is missing the last checkpoint of epoch {}",
+            committee.epoch
+        );
+        checkpoint.verify(&committee, None)?;
+        let next_epoch_committee = summary.next_epoch_committee.as_ref().ok_or_else(|| {
+            anyhow!(
+                "Checkpoint {} is not the last checkpoint of epoch {}",
+                summary.sequence_number,
+                summary.epoch
+            )
+        })?;
+        let voting_rights: BTreeMap<_, _> = next_epoch_committee.iter().cloned().collect();
+        committee = Committee::new(summary.epoch + 1, voting_rights)?;
+    }
+    ensure!(
+        header.checkpoint.summary.epoch == committee.epoch,
+        "State snapshot checkpoint is in epoch {}, but its epoch checkpoints lead to epoch {}",
+        header.checkpoint.summary.epoch,
+        committee.epoch
+    );
+    header
+        .checkpoint
+        .verify(&committee, Some(&header.contents))?;
+    Ok(committee)
+}
+
+/// Restore a state snapshot into the empty stores of a new node. Once restored, the node
+/// resumes checkpoint sync right after the snapshot checkpoint.
+///
+/// The snapshot checkpoint is verified against the committee of its epoch, as followed from
+/// `genesis_committee`, before anything is written.
+///
+/// Objects are written as they are read, so if the snapshot does not match the state root of
+/// its checkpoint, the restore fails after all objects are written and the stores must be
+/// deleted before trying again. Use `verify_state_snapshot` beforehand to avoid this.
+pub async fn restore_state_snapshot<R: Read>(
+    reader: R,
+    genesis_committee: &Committee,
+    store: &AuthorityStore,
+    committee_store: &CommitteeStore,
+    checkpoints: &Mutex<CheckpointStore>,
+    indexes: Option<&IndexStore>,
+) -> anyhow::Result<StateSnapshotSummary> {
+    ensure!(
+        store.database_is_empty()?,
+        "Cannot restore a state snapshot into a non-empty store"
+    );
+    let mut reader = StateSnapshotReader::new(reader)?;
+    let header = reader.header().clone();
+    let committee = verify_header(&header, genesis_committee)?;
+    let epoch = committee.epoch;
+
+    while let Some(objects) = reader.next_chunk()? {
+        store.insert_snapshot_objects(&objects).await?;
+        if let Some(indexes) = indexes {
+            indexes.index_coins(objects.iter().filter_map(AuthorityState::coin_index_entry))?;
+        }
+    }
+
+    if epoch > 0 {
+        committee_store.committee_map.insert(&epoch, &committee)?;
+        store.reopen_epoch_db(epoch);
+    }
+    checkpoints
+        .lock()
+        .restore_from_snapshot(&header.checkpoint, &header.contents, &committee)?;
+
+    let summary = reader.summary();
+    info!(?summary, "Restored state snapshot");
+    Ok(summary)
+}
@@ -53,7 +53,9 @@ pub async fn check_transaction_input<S, T>(
 where
     S: Eq + Debug + Serialize + for<'de> Deserialize<'de>,
 {
-    transaction.signed_data.data.kind.validity_check()?;
+    transaction.signed_data.data.validity_check()?;
+    let epoch = store.get_sui_system_state_object()?.epoch;
+    transaction.signed_data.data.check_expiration(epoch)?;
     let gas_status = get_gas_status(store, transaction).await?;
     let input_objects = transaction.signed_data.data.input_objects()?;
     let objects = store.get_input_objects(&input_objects)?;
@@ -69,6 +71,11 @@ pub async fn check_certificate_input<S>(
 where
     S: Eq + Debug + Serialize + for<'de> Deserialize<'de>,
 {
+    // Use the epoch of the certificate rather than the current one, so that all validators
+    // agree on whether it expired.
+    cert.signed_data
+        .data
+        .check_expiration(cert.auth_sign_info.epoch)?;
     let gas_status = get_gas_status(store, cert).await?;
     let input_objects = cert.signed_data.data.input_objects()?;
 
@@ -168,6 +175,7 @@ async fn check_objects(
     // Gather all objects and errors.
     let mut all_objects = Vec::with_capacity(input_objects.len());
     let mut errors = Vec::new();
+    let gas_object_id = transaction.gas_payment_object_ref().0;
     let transfer_object_ids: HashSet<_> = transaction
         .kind
         .single_transactions()
@@ -184,14 +192,16 @@ async fn check_objects(
         if transfer_object_ids.contains(&object.id()) {
             object.ensure_public_transfer_eligible()?;
         }
+        // The gas object is owned by the sponsor of the transaction if there is one, all
+        // other objects by the sender.
+        let owner = if object.id() == gas_object_id {
+            transaction.gas_owner()
+        } else {
+            transaction.signer()
+        };
         // Check if the object contents match the type of lock we need for
         // this object.
-        match check_one_object(
-            &transaction.signer(),
-            object_kind,
-            &object,
-            &owned_object_authenticators,
-        ) {
+        match check_one_object(&owner, object_kind, &object, &owned_object_authenticators) {
             Ok(()) => all_objects.push((object_kind, object)),
             Err(e) => {
                 errors.push(e);
@@ -267,7 +277,7 @@ fn check_one_object(
                     // Nothing else to check for Immutable.
                 }
                 Owner::AddressOwner(owner) => {
-                    // Check the owner is the transaction sender.
+                    // Check the owner is the transaction sender, or the sponsor for gas.
                     fp_ensure!(
                         sender == &owner,
                         SuiError::IncorrectSigner {
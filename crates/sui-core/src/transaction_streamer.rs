@@ -51,6 +51,8 @@ impl Default for TransactionStreamer {
 mod tests {
     use super::*;
     use futures::StreamExt;
+    use move_core_types::ident_str;
+    use sui_types::base_types::SuiAddress;
     use test_utils::messages::{make_tx_certs_and_signed_effects, test_shared_object_transactions};
     use tokio::time::{timeout, Duration};
 
@@ -79,4 +81,50 @@ mod tests {
             .is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_filtered_subscription() -> Result<(), anyhow::Error> {
+        let streamer = TransactionStreamer::new();
+        let tx = test_shared_object_transactions().swap_remove(0);
+        let sender = tx.signed_data.data.signer();
+        let package = tx.signed_data.data.move_calls()[0].package.0;
+
+        let mut matching = streamer.subscribe(TransactionFilter::MatchAll(vec![
+            TransactionFilter::FromAddress(sender),
+            TransactionFilter::MoveFunction {
+                package,
+                module: Some(ident_str!("object_basics").to_owned()),
+                function: None,
+            },
+            TransactionFilter::ExecutionSucceeded(true),
+        ]));
+        let mut wrong_sender = streamer.subscribe(TransactionFilter::FromAddress(
+            SuiAddress::random_for_testing_only(),
+        ));
+        let mut wrong_function = streamer.subscribe(TransactionFilter::MoveFunction {
+            package,
+            module: Some(ident_str!("object_basics").to_owned()),
+            function: Some(ident_str!("transfer").to_owned()),
+        });
+
+        let (mut tx_certs, mut signed_effects) = make_tx_certs_and_signed_effects(vec![tx]);
+        let tx_cert = tx_certs.swap_remove(0);
+        let tx_digest = *tx_cert.digest();
+        assert!(
+            streamer
+                .enqueue((tx_cert, signed_effects.swap_remove(0)))
+                .await
+        );
+
+        let (cert, _) = matching.next().await.unwrap();
+        assert_eq!(cert.digest(), &tx_digest);
+
+        assert!(timeout(Duration::from_millis(500), wrong_sender.next())
+            .await
+            .is_err());
+        assert!(timeout(Duration::from_millis(500), wrong_function.next())
+            .await
+            .is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,121 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+use super::authority_store::ObjectKey;
+use super::authority_store_pruner::prune_objects_for_checkpoint;
+use super::authority_tests::{
+    init_state_with_ids, init_transfer_transaction, send_and_confirm_transaction,
+};
+use sui_storage::IndexStore;
+use sui_types::crypto::{get_key_pair, AccountKeyPair};
+use sui_types::messages_checkpoint::CheckpointContents;
+
+#[tokio::test]
+async fn test_prune_objects_for_checkpoint() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let recipient = dbg_addr(2);
+    let object_id = ObjectID::random();
+    let gas_object_id = ObjectID::random();
+    let authority_state =
+        init_state_with_ids(vec![(sender, object_id), (sender, gas_object_id)]).await;
+    let object = authority_state
+        .get_object(&object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let gas_object = authority_state
+        .get_object(&gas_object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let old_object_ref = object.compute_object_reference();
+    let old_gas_ref = gas_object.compute_object_reference();
+
+    let transaction =
+        init_transfer_transaction(sender, &sender_key, recipient, old_object_ref, old_gas_ref);
+    let signed_effects = send_and_confirm_transaction(&authority_state, transaction)
+        .await
+        .unwrap()
+        .signed_effects
+        .unwrap();
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(
+        [ExecutionDigests::new(
+            signed_effects.effects.transaction_digest,
+            *signed_effects.digest(),
+        )]
+        .into_iter(),
+    );
+
+    let store = &authority_state.database;
+    let tables = &store.perpetual_tables;
+    assert!(tables
+        .objects
+        .contains_key(&ObjectKey::from(old_object_ref))
+        .unwrap());
+    let tx_digest = signed_effects.effects.transaction_digest;
+    assert!(tables
+        .state_accumulator_deltas
+        .contains_key(&tx_digest)
+        .unwrap());
+
+    // Nothing can be pruned until all transactions of the checkpoint are indexed.
+    let working_dir = tempfile::tempdir().unwrap();
+    let indexes = IndexStore::open_tables_read_write(working_dir.path().to_path_buf(), None, None);
+    assert!(
+        prune_objects_for_checkpoint(store, Some(&indexes), &contents, tables.objects.batch())
+            .unwrap()
+            .is_none()
+    );
+
+    let (batch, num_pruned) =
+        prune_objects_for_checkpoint(store, None, &contents, tables.objects.batch())
+            .unwrap()
+            .unwrap();
+    batch.write().unwrap();
+    assert_eq!(num_pruned, 2);
+
+    // The consumed versions are gone...
+    for object_ref in [old_object_ref, old_gas_ref] {
+        assert!(!tables
+            .objects
+            .contains_key(&ObjectKey::from(object_ref))
+            .unwrap());
+        assert!(!tables.parent_sync.contains_key(&object_ref).unwrap());
+    }
+    // ...and so is the state accumulator delta of the transaction...
+    assert!(!tables
+        .state_accumulator_deltas
+        .contains_key(&tx_digest)
+        .unwrap());
+    // ...while the latest ones are still readable.
+    let new_object = authority_state
+        .get_object(&object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(new_object.owner, recipient);
+    assert!(authority_state
+        .get_object(&gas_object_id)
+        .await
+        .unwrap()
+        .is_some());
+
+    // Pruning is idempotent.
+    let (_, num_pruned) =
+        prune_objects_for_checkpoint(store, None, &contents, tables.objects.batch())
+            .unwrap()
+            .unwrap();
+    assert_eq!(num_pruned, 0);
+
+    // Nothing can be pruned until all transactions of the checkpoint are executed.
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(
+        [ExecutionDigests::random()].into_iter(),
+    );
+    assert!(
+        prune_objects_for_checkpoint(store, None, &contents, tables.objects.batch())
+            .unwrap()
+            .is_none()
+    );
+}
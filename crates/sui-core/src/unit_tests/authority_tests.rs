@@ -18,7 +18,7 @@ use rand::{
     prelude::StdRng,
     Rng, SeedableRng,
 };
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::future::Future;
 use std::pin::Pin;
@@ -33,6 +33,7 @@ use sui_types::{
     messages::Transaction,
     object::{Owner, GAS_VALUE_FOR_TESTING, OBJECT_START_VERSION},
     sui_system_state::SuiSystemState,
+    waypoint::Accumulator,
     SUI_SYSTEM_STATE_OBJECT_ID,
 };
 use sui_types::{crypto::AuthorityPublicKeyBytes, object::Data};
@@ -272,6 +273,188 @@ async fn test_handle_transfer_transaction_bad_signature() {
         .is_none());
 }
 
+#[tokio::test]
+async fn test_handle_sponsored_transfer_transaction() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let (sponsor, sponsor_key): (_, AccountKeyPair) = get_key_pair();
+    let recipient = dbg_addr(2);
+    let object_id = ObjectID::random();
+    let sender_gas_object_id = ObjectID::random();
+    let gas_object_id = ObjectID::random();
+    let authority_state = init_state_with_ids(vec![
+        (sender, object_id),
+        (sender, sender_gas_object_id),
+        (sponsor, gas_object_id),
+    ])
+    .await;
+    let object_ref = authority_state
+        .get_object(&object_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .compute_object_reference();
+    let sender_gas_ref = authority_state
+        .get_object(&sender_gas_object_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .compute_object_reference();
+    let gas_ref = authority_state
+        .get_object(&gas_object_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .compute_object_reference();
+    let sign =
+        |data: &TransactionData, key: &AccountKeyPair| Signature::new_temp(&data.to_bytes(), key);
+
+    // The gas object must be owned by the sponsor.
+    let data = TransactionData::new_transfer(recipient, object_ref, sender, gas_ref, 10000)
+        .with_sponsor(sponsor, sender_gas_ref);
+    let transaction = Transaction::new_sponsored(
+        data.clone(),
+        sign(&data, &sender_key),
+        sign(&data, &sponsor_key),
+    );
+    assert!(authority_state
+        .handle_transaction(transaction)
+        .await
+        .is_err());
+
+    let data = TransactionData::new_transfer(recipient, object_ref, sender, gas_ref, 10000)
+        .with_sponsor(sponsor, gas_ref);
+
+    // Both the sender and the sponsor must sign.
+    let unsponsored = Transaction::new(data.clone(), sign(&data, &sender_key));
+    assert!(matches!(
+        authority_state
+            .handle_transaction(unsponsored)
+            .await
+            .unwrap_err(),
+        SuiError::IncorrectSigner { .. }
+    ));
+    let wrong_sponsor = Transaction::new_sponsored(
+        data.clone(),
+        sign(&data, &sender_key),
+        sign(&data, &sender_key),
+    );
+    assert!(authority_state
+        .handle_transaction(wrong_sponsor)
+        .await
+        .is_err());
+
+    let transaction = Transaction::new_sponsored(
+        data.clone(),
+        sign(&data, &sender_key),
+        sign(&data, &sponsor_key),
+    );
+    let effects = send_and_confirm_transaction(&authority_state, transaction)
+        .await
+        .unwrap()
+        .signed_effects
+        .unwrap()
+        .effects;
+    assert!(effects.status.is_ok());
+
+    let object = authority_state
+        .get_object(&object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(object.owner, Owner::AddressOwner(recipient));
+    let gas_object = authority_state
+        .get_object(&gas_object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(gas_object.owner, Owner::AddressOwner(sponsor));
+    // The sponsor paid for gas, the gas object of the sender is untouched.
+    assert!(
+        sui_types::gas_coin::GasCoin::try_from(&gas_object)
+            .unwrap()
+            .value()
+            < GAS_VALUE_FOR_TESTING
+    );
+    let sender_gas_object = authority_state
+        .get_object(&sender_gas_object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(sender_gas_object.compute_object_reference(), sender_gas_ref);
+
+    // TransferSui spends the gas object, so it cannot be sponsored.
+    let data = TransactionData::new_transfer_sui(recipient, sender, Some(1), gas_ref, 10000)
+        .with_sponsor(sponsor, gas_object.compute_object_reference());
+    let transaction = Transaction::new_sponsored(
+        data.clone(),
+        sign(&data, &sender_key),
+        sign(&data, &sponsor_key),
+    );
+    assert!(matches!(
+        authority_state
+            .handle_transaction(transaction)
+            .await
+            .unwrap_err(),
+        SuiError::InvalidSponsoredTransaction { .. }
+    ));
+}
+
+#[tokio::test]
+async fn test_handle_expired_transaction() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let recipient = dbg_addr(2);
+    let object_id = ObjectID::random();
+    let gas_object_id = ObjectID::random();
+    let authority_state =
+        init_state_with_ids(vec![(sender, object_id), (sender, gas_object_id)]).await;
+    let object_ref = authority_state
+        .get_object(&object_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .compute_object_reference();
+    let gas_ref = authority_state
+        .get_object(&gas_object_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .compute_object_reference();
+    let transfer = |expiration| {
+        let data = TransactionData::new_transfer(recipient, object_ref, sender, gas_ref, 10000)
+            .with_expiration(expiration);
+        to_sender_signed_transaction(data, &sender_key)
+    };
+
+    let committee = authority_state.clone_committee();
+    authority_state
+        .update_committee(Committee::new(1, committee.voting_rights.into_iter().collect()).unwrap())
+        .unwrap();
+
+    // The transaction expired at the end of the previous epoch.
+    assert!(matches!(
+        authority_state
+            .handle_transaction(transfer(TransactionExpiration::Epoch(0)))
+            .await
+            .unwrap_err(),
+        SuiError::TransactionExpired {
+            expiration_epoch: 0,
+            current_epoch: 1,
+        }
+    ));
+    assert!(authority_state
+        .get_transaction_lock(&object_ref)
+        .await
+        .unwrap()
+        .is_none());
+
+    // It can still be signed until the end of its expiration epoch.
+    let response = authority_state
+        .handle_transaction(transfer(TransactionExpiration::Epoch(1)))
+        .await
+        .unwrap();
+    assert!(response.signed_transaction.is_some());
+}
+
 #[tokio::test]
 async fn test_handle_transfer_transaction_with_max_sequence_number() {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
@@ -2484,3 +2667,62 @@ async fn test_consensus_message_processed() {
             .get_next_object_version(&shared_object_id),
     );
 }
+
+fn live_object_set_accumulator(authority_state: &AuthorityState) -> Accumulator {
+    let tables = &authority_state.database.perpetual_tables;
+    let mut accumulator = Accumulator::default();
+    let object_ids: BTreeSet<ObjectID> = tables.objects.keys().map(|key| key.0).collect();
+    for object_id in object_ids {
+        let (object_ref, _) = tables.get_latest_parent_entry(object_id).unwrap().unwrap();
+        if object_ref.2.is_alive() {
+            accumulator.insert(&object_ref);
+        }
+    }
+    accumulator
+}
+
+#[tokio::test]
+async fn test_state_accumulator_deltas() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let recipient = dbg_addr(2);
+    let object_id = ObjectID::random();
+    let gas_object_id = ObjectID::random();
+    let authority_state =
+        init_state_with_ids(vec![(sender, object_id), (sender, gas_object_id)]).await;
+    let deltas = &authority_state
+        .database
+        .perpetual_tables
+        .state_accumulator_deltas;
+
+    // The genesis entry accumulates all objects inserted at genesis.
+    let mut state_root = deltas.get(&TransactionDigest::genesis()).unwrap().unwrap();
+    assert_eq!(state_root, live_object_set_accumulator(&authority_state));
+
+    let object = authority_state
+        .get_object(&object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let gas_object = authority_state
+        .get_object(&gas_object_id)
+        .await
+        .unwrap()
+        .unwrap();
+    let transaction = init_transfer_transaction(
+        sender,
+        &sender_key,
+        recipient,
+        object.compute_object_reference(),
+        gas_object.compute_object_reference(),
+    );
+    let tx_digest = *transaction.digest();
+    send_and_confirm_transaction(&authority_state, transaction)
+        .await
+        .unwrap();
+
+    // Applying the delta of the transaction gives the accumulator of the new live object set.
+    let delta = deltas.get(&tx_digest).unwrap().unwrap();
+    assert_ne!(delta, Accumulator::default());
+    state_root.union(&delta);
+    assert_eq!(state_root, live_object_set_accumulator(&authority_state));
+}
@@ -246,3 +246,186 @@ async fn test_batch_insufficient_gas_balance() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_batch_transaction_with_results_ok() -> anyhow::Result<()> {
+    // Create an object, update it and transfer it in one batch transaction.
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let gas_object_id = ObjectID::random();
+    let (authority_state, package) =
+        init_state_with_ids_and_object_basics([(sender, gas_object_id)]).await;
+    let created = BatchResult { step: 0, index: 0 };
+    let transactions = vec![
+        SingleTransactionKind::Call(MoveCall {
+            package,
+            module: ident_str!("object_basics").to_owned(),
+            function: ident_str!("create").to_owned(),
+            type_arguments: vec![],
+            arguments: vec![
+                CallArg::Pure(16u64.to_le_bytes().to_vec()),
+                CallArg::Pure(bcs::to_bytes(&AccountAddress::from(sender)).unwrap()),
+            ],
+        }),
+        SingleTransactionKind::Call(MoveCall {
+            package,
+            module: ident_str!("object_basics").to_owned(),
+            function: ident_str!("set_value").to_owned(),
+            type_arguments: vec![],
+            arguments: vec![
+                CallArg::Result(created),
+                CallArg::Pure(42u64.to_le_bytes().to_vec()),
+            ],
+        }),
+        SingleTransactionKind::TransferBatchResult(TransferBatchResult {
+            recipient,
+            object: created,
+        }),
+    ];
+    let data = TransactionData::new(
+        TransactionKind::Batch(transactions),
+        sender,
+        authority_state
+            .get_object(&gas_object_id)
+            .await?
+            .unwrap()
+            .compute_object_reference(),
+        100000,
+    );
+
+    let tx = to_sender_signed_transaction(data, &sender_key);
+    let response = send_and_confirm_transaction(&authority_state, tx).await?;
+    let effects = response.signed_effects.unwrap().effects;
+    assert!(effects.status.is_ok(), "{:?}", effects.status);
+    // The object is created by the transaction, and only the gas object is mutated.
+    assert_eq!((effects.created.len(), effects.mutated.len()), (1, 1));
+    let ((object_id, _, _), owner) = effects.created[0];
+    assert_eq!(owner, Owner::AddressOwner(recipient));
+
+    let object = authority_state.get_object(&object_id).await?.unwrap();
+    let contents = object.data.try_as_move().unwrap().contents();
+    assert_eq!(contents[contents.len() - 8..], 42u64.to_le_bytes());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_transaction_deletes_result() -> anyhow::Result<()> {
+    // An object created and deleted by the same batch transaction leaves no trace in the effects.
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas_object_id = ObjectID::random();
+    let (authority_state, package) =
+        init_state_with_ids_and_object_basics([(sender, gas_object_id)]).await;
+    let transactions = vec![
+        SingleTransactionKind::Call(MoveCall {
+            package,
+            module: ident_str!("object_basics").to_owned(),
+            function: ident_str!("create").to_owned(),
+            type_arguments: vec![],
+            arguments: vec![
+                CallArg::Pure(16u64.to_le_bytes().to_vec()),
+                CallArg::Pure(bcs::to_bytes(&AccountAddress::from(sender)).unwrap()),
+            ],
+        }),
+        SingleTransactionKind::Call(MoveCall {
+            package,
+            module: ident_str!("object_basics").to_owned(),
+            function: ident_str!("delete").to_owned(),
+            type_arguments: vec![],
+            arguments: vec![CallArg::Result(BatchResult { step: 0, index: 0 })],
+        }),
+    ];
+    let data = TransactionData::new(
+        TransactionKind::Batch(transactions),
+        sender,
+        authority_state
+            .get_object(&gas_object_id)
+            .await?
+            .unwrap()
+            .compute_object_reference(),
+        100000,
+    );
+
+    let tx = to_sender_signed_transaction(data, &sender_key);
+    let response = send_and_confirm_transaction(&authority_state, tx).await?;
+    let effects = response.signed_effects.unwrap().effects;
+    assert!(effects.status.is_ok(), "{:?}", effects.status);
+    assert!(effects.created.is_empty());
+    assert!(effects.deleted.is_empty());
+    assert_eq!(effects.mutated.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_batch_result_not_created() -> anyhow::Result<()> {
+    // A step can only use objects created by earlier steps.
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let (recipient, _): (_, AccountKeyPair) = get_key_pair();
+    let gas_object_id = ObjectID::random();
+    let (authority_state, package) =
+        init_state_with_ids_and_object_basics([(sender, gas_object_id)]).await;
+    let gas_object_ref = authority_state
+        .get_object(&gas_object_id)
+        .await?
+        .unwrap()
+        .compute_object_reference();
+    let create = SingleTransactionKind::Call(MoveCall {
+        package,
+        module: ident_str!("object_basics").to_owned(),
+        function: ident_str!("create").to_owned(),
+        type_arguments: vec![],
+        arguments: vec![
+            CallArg::Pure(16u64.to_le_bytes().to_vec()),
+            CallArg::Pure(bcs::to_bytes(&AccountAddress::from(sender)).unwrap()),
+        ],
+    });
+
+    // The result of the step itself is rejected before execution.
+    let transactions = vec![
+        create.clone(),
+        SingleTransactionKind::TransferBatchResult(TransferBatchResult {
+            recipient,
+            object: BatchResult { step: 1, index: 0 },
+        }),
+    ];
+    let data = TransactionData::new(
+        TransactionKind::Batch(transactions),
+        sender,
+        gas_object_ref,
+        100000,
+    );
+    let tx = to_sender_signed_transaction(data, &sender_key);
+    let response = send_and_confirm_transaction(&authority_state, tx).await;
+    assert!(matches!(
+        response.unwrap_err(),
+        SuiError::InvalidBatchTransaction { .. }
+    ));
+
+    // An earlier step that created fewer objects fails the transaction.
+    let transactions = vec![
+        create,
+        SingleTransactionKind::TransferBatchResult(TransferBatchResult {
+            recipient,
+            object: BatchResult { step: 0, index: 1 },
+        }),
+    ];
+    let data = TransactionData::new(
+        TransactionKind::Batch(transactions),
+        sender,
+        gas_object_ref,
+        100000,
+    );
+    let tx = to_sender_signed_transaction(data, &sender_key);
+    let response = send_and_confirm_transaction(&authority_state, tx).await?;
+    let effects = response.signed_effects.unwrap().effects;
+    assert_eq!(
+        effects.status,
+        ExecutionStatus::Failure {
+            error: ExecutionFailureStatus::InvalidBatchResult
+        }
+    );
+    assert!(effects.created.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+use crate::authority::authority_tests::init_state_with_objects;
+use crate::consensus_adapter::consensus_tests::{
+    test_certificates, test_gas_objects, test_shared_object,
+};
+use narwhal_types::Batch;
+
+#[tokio::test]
+async fn accept_valid_transaction() {
+    // Make an authority state.
+    let mut objects = test_gas_objects();
+    objects.push(test_shared_object());
+    let state = init_state_with_objects(objects).await;
+
+    // Make a sample (serialized) consensus transaction.
+    let certificate = test_certificates(&state).await.pop().unwrap();
+    let message = ConsensusTransaction::new_certificate_message(&state.name, certificate);
+    let serialized = bincode::serialize(&message).unwrap();
+
+    let validator = SuiTxValidator::new(Arc::new(state));
+    assert!(validator.validate(&serialized).is_ok());
+    assert!(validator.validate_batch(&Batch(vec![serialized])).is_ok());
+}
+
+#[tokio::test]
+async fn reject_invalid_transaction() {
+    // Make an authority state.
+    let mut objects = test_gas_objects();
+    objects.push(test_shared_object());
+    let state = init_state_with_objects(objects).await;
+
+    // Make a certificate without any signer.
+    let mut certificate = test_certificates(&state).await.pop().unwrap();
+    certificate.auth_sign_info.signers_map.clear();
+    let message = ConsensusTransaction::new_certificate_message(&state.name, certificate);
+    let invalid = bincode::serialize(&message).unwrap();
+
+    let validator = SuiTxValidator::new(Arc::new(state));
+    assert!(validator.validate(&invalid).is_err());
+
+    // Malformed bytes are rejected as well, and so is any batch containing them.
+    let malformed = vec![0u8; 16];
+    assert!(validator.validate(&malformed).is_err());
+    assert!(validator
+        .validate_batch(&Batch(vec![invalid, malformed]))
+        .is_err());
+}
+
+#[tokio::test]
+async fn reject_transaction_of_distant_epoch() {
+    // Make an authority state.
+    let mut objects = test_gas_objects();
+    objects.push(test_shared_object());
+    let state = init_state_with_objects(objects).await;
+
+    // Make a certificate claiming to be signed two epochs ahead.
+    let mut certificate = test_certificates(&state).await.pop().unwrap();
+    certificate.auth_sign_info.epoch = state.committee.load().epoch + 2;
+    let message = ConsensusTransaction::new_certificate_message(&state.name, certificate);
+    let serialized = bincode::serialize(&message).unwrap();
+
+    let validator = SuiTxValidator::new(Arc::new(state));
+    assert!(validator.validate(&serialized).is_err());
+}
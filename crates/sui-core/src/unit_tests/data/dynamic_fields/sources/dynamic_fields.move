@@ -0,0 +1,59 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+module dynamic_fields::dynamic_fields {
+    use sui::dynamic_field;
+    use sui::dynamic_object_field;
+    use sui::object::{Self, UID};
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    struct Parent has key {
+        id: UID,
+    }
+
+    struct Child has key, store {
+        id: UID,
+        value: u64,
+    }
+
+    public entry fun create_parent(ctx: &mut TxContext) {
+        transfer::transfer(
+            Parent { id: object::new(ctx) },
+            tx_context::sender(ctx),
+        );
+    }
+
+    public entry fun add_field(parent: &mut Parent, name: u64, value: u64) {
+        dynamic_field::add(&mut parent.id, name, value);
+    }
+
+    public entry fun increment_field(parent: &mut Parent, name: u64) {
+        let value = dynamic_field::borrow_mut<u64, u64>(&mut parent.id, name);
+        *value = *value + 1;
+    }
+
+    public entry fun check_field(parent: &Parent, name: u64, expected: u64) {
+        assert!(*dynamic_field::borrow<u64, u64>(&parent.id, name) == expected, 0);
+    }
+
+    public entry fun remove_field(parent: &mut Parent, name: u64) {
+        let _: u64 = dynamic_field::remove(&mut parent.id, name);
+        assert!(!dynamic_field::exists_(&parent.id, name), 0);
+    }
+
+    public entry fun add_object_field(parent: &mut Parent, name: u64, value: u64, ctx: &mut TxContext) {
+        dynamic_object_field::add(&mut parent.id, name, Child { id: object::new(ctx), value });
+    }
+
+    public entry fun increment_object_field(parent: &mut Parent, name: u64) {
+        let child = dynamic_object_field::borrow_mut<u64, Child>(&mut parent.id, name);
+        child.value = child.value + 1;
+    }
+
+    public entry fun remove_object_field(parent: &mut Parent, name: u64, ctx: &mut TxContext) {
+        let child: Child = dynamic_object_field::remove(&mut parent.id, name);
+        assert!(!dynamic_object_field::exists_(&parent.id, name), 0);
+        transfer::transfer(child, tx_context::sender(ctx));
+    }
+}
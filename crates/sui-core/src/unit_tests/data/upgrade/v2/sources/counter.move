@@ -0,0 +1,28 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+module upgrade::counter {
+    use sui::object::{Self, UID};
+    use sui::package;
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    struct COUNTER has drop {}
+
+    struct Counter has key {
+        id: UID,
+        value: u64,
+    }
+
+    fun init(otw: COUNTER, ctx: &mut TxContext) {
+        transfer::transfer(package::claim_upgrade_cap(&otw, ctx), tx_context::sender(ctx));
+    }
+
+    public entry fun create(ctx: &mut TxContext) {
+        transfer::transfer(Counter { id: object::new(ctx), value: 0 }, tx_context::sender(ctx));
+    }
+
+    public entry fun increment(counter: &mut Counter) {
+        counter.value = counter.value + 1;
+    }
+}
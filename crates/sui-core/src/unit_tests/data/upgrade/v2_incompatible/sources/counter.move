@@ -0,0 +1,26 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+module upgrade::counter {
+    use sui::object::{Self, UID};
+    use sui::package;
+    use sui::transfer;
+    use sui::tx_context::{Self, TxContext};
+
+    struct COUNTER has drop {}
+
+    // The layout of `Counter` cannot change.
+    struct Counter has key {
+        id: UID,
+        value: u64,
+        step: u64,
+    }
+
+    fun init(otw: COUNTER, ctx: &mut TxContext) {
+        transfer::transfer(package::claim_upgrade_cap(&otw, ctx), tx_context::sender(ctx));
+    }
+
+    public entry fun create(ctx: &mut TxContext) {
+        transfer::transfer(Counter { id: object::new(ctx), value: 0, step: 1 }, tx_context::sender(ctx));
+    }
+}
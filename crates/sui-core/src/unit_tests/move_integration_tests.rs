@@ -18,9 +18,11 @@ use move_core_types::{
 use move_package::BuildConfig;
 use sui_types::{
     crypto::{get_key_pair, AccountKeyPair},
+    dynamic_field::DynamicFieldType,
     event::{Event, EventType, TransferType},
-    messages::ExecutionStatus,
-    object::OBJECT_START_VERSION,
+    messages::{ExecutionFailureStatus, ExecutionStatus, TransactionEffects},
+    move_package::UpgradeCap,
+    object::{Object, Owner, OBJECT_START_VERSION},
 };
 
 use std::path::PathBuf;
@@ -453,6 +455,287 @@ async fn test_object_owning_another_object() {
     assert_eq!(effects.deleted.len(), 2);
 }
 
+#[tokio::test]
+async fn test_dynamic_fields() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas = ObjectID::random();
+    let authority = init_state_with_ids(vec![(sender, gas)]).await;
+
+    let package =
+        build_and_publish_test_package(&authority, &sender, &sender_key, &gas, "dynamic_fields")
+            .await;
+
+    // Create a parent.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "create_parent",
+        vec![],
+        vec![],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+    let parent = effects.created[0].0;
+
+    // Add a dynamic field. The object holding it is created, and owned by the parent.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "add_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&0u64).unwrap()),
+            TestCallArg::Pure(bcs::to_bytes(&10u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+    assert_eq!(effects.created.len(), 1);
+    let field = effects.created[0].0;
+    assert_eq!(effects.created[0].1, parent.0);
+
+    let fields = authority
+        .get_dynamic_fields_page(parent.0, None, 10)
+        .unwrap();
+    assert_eq!(fields.len(), 1);
+    let info = authority.get_dynamic_field_info(&fields[0]).unwrap();
+    assert_eq!(info.name, "0");
+    assert_eq!(info.type_, DynamicFieldType::DynamicField);
+    assert_eq!(info.object_type, "u64");
+    assert_eq!(info.object_id, field.0);
+
+    // Adding the same field again fails.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "add_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&0u64).unwrap()),
+            TestCallArg::Pure(bcs::to_bytes(&20u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        effects.status.unwrap_err(),
+        ExecutionFailureStatus::MoveAbort(_, 0)
+    ));
+
+    assert!(authority.get_transaction_lock(&field).await.is_ok());
+
+    // Mutate the field. It is loaded from storage without being an input of the transaction,
+    // and it stays with its parent.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "increment_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&0u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+    let field_effect = effects
+        .mutated
+        .iter()
+        .find(|((id, _, _), _)| id == &field.0)
+        .unwrap();
+    assert_eq!(field_effect.1, parent.0);
+    assert!(effects
+        .events
+        .iter()
+        .all(|event| event.event_type() != EventType::TransferObject));
+    // The lock of the previous version of the field is replaced by one on the new version.
+    assert!(authority.get_transaction_lock(&field).await.is_err());
+    assert!(authority
+        .get_transaction_lock(&field_effect.0)
+        .await
+        .is_ok());
+
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "check_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&0u64).unwrap()),
+            TestCallArg::Pure(bcs::to_bytes(&11u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+
+    // Remove the field, which deletes the object holding it.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "remove_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&0u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+    assert!(effects.deleted.iter().any(|(id, _, _)| id == &field.0));
+    assert!(authority
+        .get_dynamic_fields_page(parent.0, None, 10)
+        .unwrap()
+        .is_empty());
+
+    // Reading the removed field fails.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "check_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&0u64).unwrap()),
+            TestCallArg::Pure(bcs::to_bytes(&11u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        effects.status.unwrap_err(),
+        ExecutionFailureStatus::MoveAbort(_, 1)
+    ));
+
+    // Add a dynamic object field. Both the object holding the field and the value are created,
+    // the value being owned by the former.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "add_object_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&1u64).unwrap()),
+            TestCallArg::Pure(bcs::to_bytes(&5u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+    assert_eq!(effects.created.len(), 2);
+    let (field, child) = if effects.created[0].1 == parent.0 {
+        (effects.created[0].0, effects.created[1].0)
+    } else {
+        (effects.created[1].0, effects.created[0].0)
+    };
+    assert_eq!(
+        effects
+            .created
+            .iter()
+            .find(|((id, _, _), _)| id == &child.0)
+            .unwrap()
+            .1,
+        field.0
+    );
+
+    let fields = authority
+        .get_dynamic_fields_page(parent.0, None, 10)
+        .unwrap();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].object_id, field.0);
+    let info = authority.get_dynamic_field_info(&fields[0]).unwrap();
+    assert_eq!(info.name, "1");
+    assert_eq!(info.type_, DynamicFieldType::DynamicObject);
+    assert_eq!(info.object_id, child.0);
+    assert!(info.object_type.ends_with("::dynamic_fields::Child"));
+
+    // Mutate the value through its parent.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "increment_object_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&1u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+    assert!(effects.mutated.iter().any(|((id, _, _), _)| id == &child.0));
+
+    // Remove the value and send it to the sender.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "dynamic_fields",
+        "remove_object_field",
+        vec![],
+        vec![
+            TestCallArg::Object(parent.0),
+            TestCallArg::Pure(bcs::to_bytes(&1u64).unwrap()),
+        ],
+    )
+    .await
+    .unwrap();
+    assert!(effects.status.is_ok());
+    assert!(effects.deleted.iter().any(|(id, _, _)| id == &field.0));
+    let child_effect = effects
+        .mutated
+        .iter()
+        .find(|((id, _, _), _)| id == &child.0)
+        .unwrap();
+    assert_eq!(child_effect.1, sender);
+}
+
 #[tokio::test]
 async fn test_entry_point_vector_empty() {
     let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
@@ -1582,6 +1865,186 @@ async fn test_entry_point_string_vec_error() {
     );
 }
 
+#[tokio::test]
+async fn test_package_upgrade() {
+    let (sender, sender_key): (_, AccountKeyPair) = get_key_pair();
+    let gas = ObjectID::random();
+    let authority = init_state_with_ids(vec![(sender, gas)]).await;
+
+    // Publishing the first version claims its upgrade capability.
+    let effects = build_and_try_publish_test_package(
+        &authority,
+        &sender,
+        &sender_key,
+        &gas,
+        "upgrade/v1",
+        MAX_GAS,
+    )
+    .await
+    .signed_effects
+    .unwrap()
+    .effects;
+    assert!(
+        matches!(effects.status, ExecutionStatus::Success { .. }),
+        "{:?}",
+        effects.status
+    );
+    let (package, _) = *effects
+        .created
+        .iter()
+        .find(|(_, owner)| owner.is_immutable())
+        .unwrap();
+    let (upgrade_cap, _) = *effects
+        .created
+        .iter()
+        .find(|(_, owner)| *owner == Owner::AddressOwner(sender))
+        .unwrap();
+
+    // An incompatible version is rejected.
+    let effects = upgrade_test_package(
+        &authority,
+        &sender,
+        &sender_key,
+        &gas,
+        package,
+        upgrade_cap,
+        "upgrade/v2_incompatible",
+    )
+    .await;
+    assert!(
+        matches!(
+            effects.status,
+            ExecutionStatus::Failure {
+                error: ExecutionFailureStatus::IncompatiblePackageUpgrade
+            }
+        ),
+        "{:?}",
+        effects.status
+    );
+
+    // A compatible version gets a new ID, and the upgrade capability now refers to it.
+    let upgrade_cap = authority
+        .get_object(&upgrade_cap.0)
+        .await
+        .unwrap()
+        .unwrap()
+        .compute_object_reference();
+    let effects = upgrade_test_package(
+        &authority,
+        &sender,
+        &sender_key,
+        &gas,
+        package,
+        upgrade_cap,
+        "upgrade/v2",
+    )
+    .await;
+    assert!(
+        matches!(effects.status, ExecutionStatus::Success { .. }),
+        "{:?}",
+        effects.status
+    );
+    assert_eq!(effects.created.len(), 1);
+    let (new_package, _) = effects.created[0];
+    let (new_upgrade_cap, _) = *effects
+        .mutated
+        .iter()
+        .find(|(object_ref, _)| object_ref.0 == upgrade_cap.0)
+        .unwrap();
+    check_latest_object_ref(&authority, &new_upgrade_cap).await;
+
+    let new_package_object = authority.get_object(&new_package.0).await.unwrap().unwrap();
+    let new_package_data = new_package_object.data.try_as_package().unwrap();
+    assert_eq!(new_package_data.lineage(), &[package.0]);
+    assert_eq!(new_package_data.version(), 2);
+    let cap_object = authority.get_object(&upgrade_cap.0).await.unwrap().unwrap();
+    let cap =
+        UpgradeCap::from_bcs_bytes(cap_object.data.try_as_move().unwrap().contents()).unwrap();
+    assert_eq!(cap.package.bytes, new_package.0);
+    assert_eq!(cap.version, 2);
+
+    // Objects created with the previous version can be used with the new one.
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &package,
+        "counter",
+        "create",
+        vec![],
+        vec![],
+    )
+    .await
+    .unwrap();
+    assert!(
+        matches!(effects.status, ExecutionStatus::Success { .. }),
+        "{:?}",
+        effects.status
+    );
+    let counter = effects.created[0].0;
+    let effects = call_move(
+        &authority,
+        &gas,
+        &sender,
+        &sender_key,
+        &new_package,
+        "counter",
+        "increment",
+        vec![],
+        vec![TestCallArg::Object(counter.0)],
+    )
+    .await
+    .unwrap();
+    assert!(
+        matches!(effects.status, ExecutionStatus::Success { .. }),
+        "{:?}",
+        effects.status
+    );
+    // The object keeps the type defined by the version it was created with.
+    let counter_object = authority.get_object(&counter.0).await.unwrap().unwrap();
+    assert_eq!(
+        ObjectID::from(counter_object.data.type_().unwrap().address),
+        package.0
+    );
+
+    // The first version is encoded as packages were before upgrades, while the lineage of the
+    // new version survives a round trip through its encoding.
+    let package_object = authority.get_object(&package.0).await.unwrap().unwrap();
+    let package_data = package_object.data.try_as_package().unwrap();
+    assert_eq!(
+        bcs::to_bytes(&package_object.data).unwrap(),
+        [vec![1u8], bcs::to_bytes(package_data).unwrap()].concat()
+    );
+    let encoded = bcs::to_bytes(&new_package_object).unwrap();
+    assert_eq!(
+        bcs::from_bytes::<Object>(&encoded).unwrap(),
+        new_package_object
+    );
+
+    // The previous version can no longer be upgraded with the capability.
+    let effects = upgrade_test_package(
+        &authority,
+        &sender,
+        &sender_key,
+        &gas,
+        package,
+        new_upgrade_cap,
+        "upgrade/v2",
+    )
+    .await;
+    assert!(
+        matches!(
+            effects.status,
+            ExecutionStatus::Failure {
+                error: ExecutionFailureStatus::InvalidUpgradeCap
+            }
+        ),
+        "{:?}",
+        effects.status
+    );
+}
+
 pub async fn build_and_try_publish_test_package(
     authority: &AuthorityState,
     sender: &SuiAddress,
@@ -1590,30 +2053,34 @@ pub async fn build_and_try_publish_test_package(
     test_dir: &str,
     gas_budget: u64,
 ) -> TransactionInfoResponse {
+    let all_module_bytes = build_test_modules(test_dir);
+
+    let gas_object = authority.get_object(gas_object_id).await.unwrap();
+    let gas_object_ref = gas_object.unwrap().compute_object_reference();
+
+    let data = TransactionData::new_module(*sender, gas_object_ref, all_module_bytes, gas_budget);
+    let transaction = to_sender_signed_transaction(data, sender_key);
+
+    send_and_confirm_transaction(authority, transaction)
+        .await
+        .unwrap()
+}
+
+fn build_test_modules(test_dir: &str) -> Vec<Vec<u8>> {
     let build_config = BuildConfig::default();
     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
     path.push("src/unit_tests/data/");
     path.push(test_dir);
     let modules = sui_framework::build_move_package(&path, build_config).unwrap();
 
-    let all_module_bytes = modules
+    modules
         .iter()
         .map(|m| {
             let mut module_bytes = Vec::new();
             m.serialize(&mut module_bytes).unwrap();
             module_bytes
         })
-        .collect();
-
-    let gas_object = authority.get_object(gas_object_id).await.unwrap();
-    let gas_object_ref = gas_object.unwrap().compute_object_reference();
-
-    let data = TransactionData::new_module(*sender, gas_object_ref, all_module_bytes, gas_budget);
-    let transaction = to_sender_signed_transaction(data, sender_key);
-
-    send_and_confirm_transaction(authority, transaction)
-        .await
-        .unwrap()
+        .collect()
 }
 
 async fn build_and_publish_test_package(
@@ -1643,6 +2110,36 @@ async fn build_and_publish_test_package(
     effects.created[0].0
 }
 
+async fn upgrade_test_package(
+    authority: &AuthorityState,
+    sender: &SuiAddress,
+    sender_key: &AccountKeyPair,
+    gas_object_id: &ObjectID,
+    package: ObjectRef,
+    upgrade_cap: ObjectRef,
+    test_dir: &str,
+) -> TransactionEffects {
+    let gas_object = authority.get_object(gas_object_id).await.unwrap();
+    let gas_object_ref = gas_object.unwrap().compute_object_reference();
+
+    let data = TransactionData::new_upgrade(
+        *sender,
+        gas_object_ref,
+        package,
+        upgrade_cap,
+        build_test_modules(test_dir),
+        MAX_GAS,
+    );
+    let transaction = to_sender_signed_transaction(data, sender_key);
+
+    send_and_confirm_transaction(authority, transaction)
+        .await
+        .unwrap()
+        .signed_effects
+        .unwrap()
+        .effects
+}
+
 async fn check_latest_object_ref(authority: &AuthorityState, object_ref: &ObjectRef) {
     let response = authority
         .handle_object_info_request(ObjectInfoRequest {
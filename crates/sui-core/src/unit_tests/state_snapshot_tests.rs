@@ -0,0 +1,204 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+use rand::prelude::StdRng;
+use rand::SeedableRng;
+use sui_types::base_types::{dbg_addr, ExecutionDigests, ObjectID};
+use sui_types::crypto::{AuthorityKeyPair, KeypairTraits};
+use sui_types::messages_checkpoint::SignedCheckpointSummary;
+use sui_types::utils::make_committee_key;
+use sui_types::waypoint::Accumulator;
+
+fn state_root(objects: &[Object]) -> Accumulator {
+    let mut state_root = Accumulator::default();
+    for object in objects {
+        state_root.insert(&object.compute_object_reference());
+    }
+    state_root
+}
+
+fn certify(
+    keys: &[AuthorityKeyPair],
+    committee: &Committee,
+    sequence_number: CheckpointSequenceNumber,
+    contents: &CheckpointContents,
+    next_epoch_committee: Option<Committee>,
+    state_root: Accumulator,
+) -> CertifiedCheckpointSummary {
+    let signed = keys
+        .iter()
+        .map(|key| {
+            SignedCheckpointSummary::new(
+                committee.epoch,
+                sequence_number,
+                key.public().into(),
+                key,
+                contents,
+                None,
+                next_epoch_committee.clone(),
+                state_root.clone(),
+            )
+        })
+        .collect();
+    CertifiedCheckpointSummary::aggregate(signed, committee).unwrap()
+}
+
+/// Returns the header of a snapshot of `objects` at checkpoint 0, and the genesis committee.
+fn test_header(objects: &[Object]) -> (StateSnapshotHeader, Committee) {
+    let mut rng = StdRng::from_seed([0; 32]);
+    let (keys, committee) = make_committee_key(&mut rng);
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(
+        [ExecutionDigests::random()].into_iter(),
+    );
+    let checkpoint = certify(&keys, &committee, 0, &contents, None, state_root(objects));
+    let header = StateSnapshotHeader {
+        checkpoint,
+        contents,
+        epoch_checkpoints: vec![],
+    };
+    (header, committee)
+}
+
+fn write_snapshot(
+    header: StateSnapshotHeader,
+    objects: &[Object],
+    objects_per_chunk: usize,
+) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    let mut writer = StateSnapshotWriter::new(&mut bytes, header, objects_per_chunk).unwrap();
+    for object in objects {
+        writer.push_object(object.clone()).unwrap();
+    }
+    let summary = writer.finish().unwrap();
+    assert_eq!(summary.num_objects, objects.len() as u64);
+    bytes
+}
+
+#[test]
+fn test_state_snapshot_round_trip() {
+    let objects: Vec<_> = (0..5)
+        .map(|_| Object::with_id_owner_for_testing(ObjectID::random(), dbg_addr(1)))
+        .collect();
+    let (header, committee) = test_header(&objects);
+    let bytes = write_snapshot(header, &objects, 2);
+
+    let mut reader = StateSnapshotReader::new(bytes.as_slice()).unwrap();
+    assert_eq!(reader.header().checkpoint.summary.sequence_number, 0);
+    let mut chunks = Vec::new();
+    while let Some(chunk) = reader.next_chunk().unwrap() {
+        chunks.push(chunk);
+    }
+    assert_eq!(
+        chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+        vec![2, 2, 1]
+    );
+    assert_eq!(chunks.concat(), objects);
+    assert_eq!(
+        reader.summary(),
+        StateSnapshotSummary {
+            sequence_number: 0,
+            num_objects: 5,
+            num_chunks: 3,
+        }
+    );
+
+    assert_eq!(
+        verify_state_snapshot(bytes.as_slice(), &committee).unwrap(),
+        reader.summary()
+    );
+}
+
+#[test]
+fn test_state_snapshot_corruption() {
+    let objects: Vec<_> = (0..3)
+        .map(|_| Object::with_id_owner_for_testing(ObjectID::random(), dbg_addr(1)))
+        .collect();
+    let (header, committee) = test_header(&objects);
+    let bytes = write_snapshot(header, &objects, 1);
+
+    // Flipping a byte of the last object chunk is caught by its checksum.
+    let mut corrupted = bytes.clone();
+    let index = corrupted.len() - 100;
+    corrupted[index] ^= 0xff;
+    assert!(verify_state_snapshot(corrupted.as_slice(), &committee).is_err());
+
+    // A truncated file is rejected.
+    assert!(verify_state_snapshot(&bytes[..bytes.len() - 1], &committee).is_err());
+
+    // An unknown format version is rejected.
+    let mut corrupted = bytes;
+    corrupted[STATE_SNAPSHOT_MAGIC.len()] += 1;
+    assert!(StateSnapshotReader::new(corrupted.as_slice()).is_err());
+}
+
+#[test]
+fn test_state_snapshot_state_root_mismatch() {
+    let objects: Vec<_> = (0..3)
+        .map(|_| Object::with_id_owner_for_testing(ObjectID::random(), dbg_addr(1)))
+        .collect();
+
+    // A snapshot missing an object of the checkpoint state is rejected...
+    let (header, committee) = test_header(&objects);
+    let bytes = write_snapshot(header, &objects[1..], 2);
+    assert!(verify_state_snapshot(bytes.as_slice(), &committee).is_err());
+
+    // ...as well as one with an object that is not part of it.
+    let (header, committee) = test_header(&objects[1..]);
+    let bytes = write_snapshot(header, &objects, 2);
+    assert!(verify_state_snapshot(bytes.as_slice(), &committee).is_err());
+}
+
+#[test]
+fn test_state_snapshot_committee_chain() {
+    let objects: Vec<_> = (0..3)
+        .map(|_| Object::with_id_owner_for_testing(ObjectID::random(), dbg_addr(1)))
+        .collect();
+    let mut rng = StdRng::from_seed([1; 32]);
+    let (genesis_keys, genesis_committee) = make_committee_key(&mut rng);
+    let (keys, committee) = make_committee_key(&mut rng);
+    let committee = Committee::new(1, committee.voting_rights.into_iter().collect()).unwrap();
+
+    // The last checkpoint of epoch 0 hands over to the committee of epoch 1, which certifies
+    // the snapshot checkpoint.
+    let epoch_contents = CheckpointContents::new_with_causally_ordered_transactions(
+        [ExecutionDigests::random()].into_iter(),
+    );
+    let epoch_checkpoint = certify(
+        &genesis_keys,
+        &genesis_committee,
+        0,
+        &epoch_contents,
+        Some(committee.clone()),
+        Accumulator::default(),
+    );
+    let contents = CheckpointContents::new_with_causally_ordered_transactions(
+        [ExecutionDigests::random()].into_iter(),
+    );
+    let checkpoint = certify(&keys, &committee, 1, &contents, None, state_root(&objects));
+    let header = StateSnapshotHeader {
+        checkpoint,
+        contents,
+        epoch_checkpoints: vec![epoch_checkpoint],
+    };
+    assert_eq!(
+        verify_header(&header, &genesis_committee).unwrap(),
+        committee
+    );
+    let bytes = write_snapshot(header.clone(), &objects, 2);
+    assert!(verify_state_snapshot(bytes.as_slice(), &genesis_committee).is_ok());
+
+    // The committee of epoch 1 is only trusted through the last checkpoint of epoch 0.
+    let unchained = StateSnapshotHeader {
+        epoch_checkpoints: vec![],
+        ..header.clone()
+    };
+    assert!(verify_header(&unchained, &genesis_committee).is_err());
+
+    // A snapshot certified by a committee that does not follow from genesis is rejected.
+    let (forged, _) = test_header(&objects);
+    assert!(verify_header(&forged, &genesis_committee).is_err());
+    let bytes = write_snapshot(forged, &objects, 2);
+    assert!(verify_state_snapshot(bytes.as_slice(), &genesis_committee).is_err());
+}
@@ -1,6 +1,9 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use move_core_types::gas_algebra::InternalGas;
+use once_cell::sync::Lazy;
+
 use crate::{
     non_execution_tables::{MAX_NUM_EVENT_EMIT, MAX_TX_GAS},
     units_types::GasCost,
@@ -27,11 +30,21 @@ pub enum SuiNativeCostIndex {
 
     TX_CONTEXT_DERIVE_ID = 7,
     TX_CONTEXT_NEW_SIGNER_FROM_ADDR = 8,
+
+    HASH_SHA2_256 = 9,
+    HASH_BLAKE2B256 = 10,
+    HMAC_SHA3_256 = 11,
+    SECP256R1_VERIFY = 12,
+
+    GROTH16_PREPARE_VERIFYING_KEY_BLS12381 = 13,
+    GROTH16_PREPARE_VERIFYING_KEY_BN254 = 14,
+    GROTH16_VERIFY_PROOF_BLS12381 = 15,
+    GROTH16_VERIFY_PROOF_BN254 = 16,
 }
 
 // Native costs are currently flat
 // TODO recalibrate wrt bytecode costs
-pub fn _native_cost_schedule() -> Vec<GasCost> {
+pub fn native_cost_schedule() -> Vec<GasCost> {
     use SuiNativeCostIndex as N;
 
     let mut native_table = vec![
@@ -49,6 +62,14 @@ pub fn _native_cost_schedule() -> Vec<GasCost> {
         (N::TRANSFER_SHARE_OBJECT, GasCost::new(80, 1)),
         (N::TX_CONTEXT_DERIVE_ID, GasCost::new(110, 1)),
         (N::TX_CONTEXT_NEW_SIGNER_FROM_ADDR, GasCost::new(200, 1)),
+        (N::HASH_SHA2_256, GasCost::new(50, 1)),
+        (N::HASH_BLAKE2B256, GasCost::new(50, 1)),
+        (N::HMAC_SHA3_256, GasCost::new(100, 1)),
+        (N::SECP256R1_VERIFY, GasCost::new(1000, 1)),
+        (N::GROTH16_PREPARE_VERIFYING_KEY_BLS12381, GasCost::new(5000, 20)),
+        (N::GROTH16_PREPARE_VERIFYING_KEY_BN254, GasCost::new(4000, 15)),
+        (N::GROTH16_VERIFY_PROOF_BLS12381, GasCost::new(10000, 50)),
+        (N::GROTH16_VERIFY_PROOF_BN254, GasCost::new(8000, 40)),
     ];
     native_table.sort_by_key(|cost| cost.0 as u64);
     native_table
@@ -56,3 +77,15 @@ pub fn _native_cost_schedule() -> Vec<GasCost> {
         .map(|(_, cost)| cost)
         .collect::<Vec<_>>()
 }
+
+static NATIVE_COST_SCHEDULE: Lazy<Vec<GasCost>> = Lazy::new(native_cost_schedule);
+
+/// The cost of a call to the native function at `index` on `num_bytes` bytes of input: the
+/// instruction gas of its entry is a base cost, and its memory gas is charged per byte.
+pub fn native_cost(index: SuiNativeCostIndex, num_bytes: usize) -> InternalGas {
+    let cost = &NATIVE_COST_SCHEDULE[index as usize];
+    InternalGas::new(
+        cost.instruction_gas
+            .saturating_add(cost.memory_gas.saturating_mul(num_bytes as u64)),
+    )
+}
@@ -20,6 +20,8 @@ use sui_types::gas::GasCostSummary;
 use sui_types::gas::SuiGas;
 use sui_types::gas::MAX_GAS_BUDGET;
 use sui_types::gas_coin::GasCoin;
+use sui_types::messages::MoveModulePublish;
+use sui_types::messages::MoveModuleUpgrade;
 use sui_types::messages::SingleTransactionKind;
 use sui_types::messages::TransactionData;
 use sui_types::messages::TransactionKind;
@@ -99,8 +101,9 @@ fn estimate_transaction_inner<S>(
     // Steps 4 & 5
     for single_tx in tx.single_transactions() {
         match single_tx {
-            SingleTransactionKind::Publish(module) => {
-                gas_status.charge_publish_package(module.modules.iter().map(|v| v.len()).sum())?
+            SingleTransactionKind::Publish(MoveModulePublish { modules })
+            | SingleTransactionKind::Upgrade(MoveModuleUpgrade { modules, .. }) => {
+                gas_status.charge_publish_package(modules.iter().map(|v| v.len()).sum())?
             }
             SingleTransactionKind::Call(_) => (),
             _ => continue,
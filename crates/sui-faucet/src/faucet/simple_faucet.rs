@@ -19,7 +19,9 @@ use sui_types::object::Owner;
 use sui_types::{
     base_types::{ObjectID, SuiAddress, TransactionDigest},
     gas_coin::GasCoin,
-    messages::{ExecuteTransactionRequestType, Transaction, TransactionData},
+    messages::{
+        ExecuteTransactionRequestType, Transaction, TransactionData, TransactionExpiration,
+    },
 };
 use tokio::sync::{
     mpsc::{self, Receiver, Sender},
@@ -62,18 +64,38 @@ impl SimpleFaucet {
             .map_err(|err| FaucetError::Wallet(format!("Fail to sync client state: {}", err)))?;
         }
 
-        let coins = wallet
-            .gas_objects(active_address)
-            .await
-            .map_err(|e| FaucetError::Wallet(e.to_string()))?
-            .iter()
-            // Ok to unwrap() since `get_gas_objects` guarantees gas
-            .map(|q| GasCoin::try_from(&q.1).unwrap())
-            .collect::<Vec<GasCoin>>();
+        let coins = if wallet.client.is_gateway() {
+            wallet
+                .gas_objects(active_address)
+                .await
+                .map_err(|e| FaucetError::Wallet(e.to_string()))?
+                .iter()
+                // Ok to unwrap() since `get_gas_objects` guarantees gas
+                .map(|q| *GasCoin::try_from(&q.1).unwrap().id())
+                .collect::<Vec<ObjectID>>()
+        } else {
+            // Full nodes index coins by owner, so there is no need to fetch every owned object
+            let mut coins = Vec::new();
+            let mut cursor = None;
+            loop {
+                let page = wallet
+                    .client
+                    .full_node_api()
+                    .get_coins(active_address, None, cursor, None)
+                    .await
+                    .map_err(|e| FaucetError::Wallet(e.to_string()))?;
+                coins.extend(page.data.iter().map(|coin| coin.coin_object_id));
+                cursor = page.next_cursor;
+                if cursor.is_none() {
+                    break;
+                }
+            }
+            coins
+        };
 
         let (producer, consumer) = mpsc::channel(coins.len());
         for coin in &coins {
-            if let Err(e) = producer.send(*coin.id()).await {
+            if let Err(e) = producer.send(*coin).await {
                 panic!("Failed to set up gas pools: {:?}", e);
             }
         }
@@ -297,7 +319,14 @@ impl SimpleFaucet {
         self.wallet
             .client
             .transaction_builder()
-            .transfer_sui(signer, coin_id, budget, recipient, Some(amount))
+            .transfer_sui(
+                signer,
+                coin_id,
+                budget,
+                recipient,
+                Some(amount),
+                TransactionExpiration::None,
+            )
             .await
             .map_err(|e| {
                 anyhow::anyhow!(
@@ -516,6 +545,7 @@ mod tests {
             sui_coin_object_id: *bad_gas.id(),
             amount: None,
             gas_budget: 50000,
+            expiration_epoch: None,
         }
         .execute(faucet.wallet_mut())
         .await
@@ -568,6 +598,7 @@ mod tests {
             gas_budget: 50000,
             gas: None,
             count: None,
+            expiration_epoch: None,
         }
         .execute(&mut context)
         .await
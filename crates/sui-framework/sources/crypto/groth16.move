@@ -0,0 +1,136 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Library for verifying Groth16 zk-SNARK proofs over the BLS12-381 and BN254 curves.
+module sui::groth16 {
+    use std::vector;
+
+    /// Represents an elliptic curve construction to be used in the verifier.
+    struct Curve has store, copy, drop {
+        id: u8,
+    }
+
+    /// Return the `Curve` value specifying the use of the BLS12-381 construction.
+    public fun bls12381(): Curve {
+        Curve { id: 0 }
+    }
+
+    /// Return the `Curve` value specifying the use of the BN254 construction.
+    public fun bn254(): Curve {
+        Curve { id: 1 }
+    }
+
+    /// A `PreparedVerifyingKey` consisting of four components in serialized form.
+    struct PreparedVerifyingKey has store, copy, drop {
+        vk_gamma_abc_g1_bytes: vector<u8>,
+        alpha_g1_beta_g2_bytes: vector<u8>,
+        gamma_g2_neg_bytes: vector<u8>,
+        delta_g2_neg_bytes: vector<u8>,
+    }
+
+    /// Creates a `PreparedVerifyingKey` from its four serialized components, as returned
+    /// by `pvk_to_bytes`.
+    public fun pvk_from_bytes(
+        vk_gamma_abc_g1_bytes: vector<u8>,
+        alpha_g1_beta_g2_bytes: vector<u8>,
+        gamma_g2_neg_bytes: vector<u8>,
+        delta_g2_neg_bytes: vector<u8>,
+    ): PreparedVerifyingKey {
+        PreparedVerifyingKey {
+            vk_gamma_abc_g1_bytes,
+            alpha_g1_beta_g2_bytes,
+            gamma_g2_neg_bytes,
+            delta_g2_neg_bytes,
+        }
+    }
+
+    /// Returns the four serialized components of a `PreparedVerifyingKey`, so that it can be
+    /// stored and later restored with `pvk_from_bytes`.
+    public fun pvk_to_bytes(pvk: PreparedVerifyingKey): vector<vector<u8>> {
+        let res = vector::empty();
+        vector::push_back(&mut res, pvk.vk_gamma_abc_g1_bytes);
+        vector::push_back(&mut res, pvk.alpha_g1_beta_g2_bytes);
+        vector::push_back(&mut res, pvk.gamma_g2_neg_bytes);
+        vector::push_back(&mut res, pvk.delta_g2_neg_bytes);
+        res
+    }
+
+    /// The public inputs of a proof, each serialized as a 32-byte little-endian scalar.
+    struct PublicProofInputs has store, copy, drop {
+        bytes: vector<u8>,
+    }
+
+    /// Creates a `PublicProofInputs` wrapper from bytes.
+    public fun public_proof_inputs_from_bytes(bytes: vector<u8>): PublicProofInputs {
+        PublicProofInputs { bytes }
+    }
+
+    /// A Groth16 proof, serialized as the compressed points A, B and C.
+    struct ProofPoints has store, copy, drop {
+        bytes: vector<u8>,
+    }
+
+    /// Creates a Groth16 `ProofPoints` from bytes.
+    public fun proof_points_from_bytes(bytes: vector<u8>): ProofPoints {
+        ProofPoints { bytes }
+    }
+
+    /// @param curve: What elliptic curve construction to use. See `bls12381` and `bn254`.
+    /// @param verifying_key: An Arkworks canonical compressed serialization of a verifying key.
+    ///
+    /// Returns four vectors of bytes representing the four components of a prepared verifying key.
+    /// This step computes one pairing e(P, Q), and binds the verification to one particular proof statement.
+    /// This can be used as inputs for the `verify_groth16_proof` function. Aborts if the verifying
+    /// key cannot be deserialized or has more than 8 public inputs.
+    public fun prepare_verifying_key(curve: &Curve, verifying_key: &vector<u8>): PreparedVerifyingKey {
+        let (vk_gamma_abc_g1_bytes, alpha_g1_beta_g2_bytes, gamma_g2_neg_bytes, delta_g2_neg_bytes) =
+            prepare_verifying_key_internal(curve.id, verifying_key);
+        PreparedVerifyingKey {
+            vk_gamma_abc_g1_bytes,
+            alpha_g1_beta_g2_bytes,
+            gamma_g2_neg_bytes,
+            delta_g2_neg_bytes,
+        }
+    }
+
+    /// Native function returning the serialized components of the prepared verifying key.
+    native fun prepare_verifying_key_internal(
+        curve: u8,
+        verifying_key: &vector<u8>
+    ): (vector<u8>, vector<u8>, vector<u8>, vector<u8>);
+
+    /// @param curve: What elliptic curve construction to use. See `bls12381` and `bn254`.
+    /// @param prepared_verifying_key: Consists of four vectors of bytes representing the four components of a prepared verifying key.
+    /// @param public_proof_inputs: Represent inputs that are public, at most 8 of them.
+    /// @param proof_points: Represent three proof points.
+    ///
+    /// Returns a boolean indicating whether the proof is valid. Aborts if there are more than
+    /// 8 public inputs.
+    public fun verify_groth16_proof(
+        curve: &Curve,
+        prepared_verifying_key: &PreparedVerifyingKey,
+        public_proof_inputs: &PublicProofInputs,
+        proof_points: &ProofPoints
+    ): bool {
+        verify_groth16_proof_internal(
+            curve.id,
+            &prepared_verifying_key.vk_gamma_abc_g1_bytes,
+            &prepared_verifying_key.alpha_g1_beta_g2_bytes,
+            &prepared_verifying_key.gamma_g2_neg_bytes,
+            &prepared_verifying_key.delta_g2_neg_bytes,
+            &public_proof_inputs.bytes,
+            &proof_points.bytes
+        )
+    }
+
+    /// Native function taking the serialized components of the prepared verifying key.
+    native fun verify_groth16_proof_internal(
+        curve: u8,
+        vk_gamma_abc_g1_bytes: &vector<u8>,
+        alpha_g1_beta_g2_bytes: &vector<u8>,
+        gamma_g2_neg_bytes: &vector<u8>,
+        delta_g2_neg_bytes: &vector<u8>,
+        public_proof_inputs: &vector<u8>,
+        proof_points: &vector<u8>
+    ): bool;
+}
@@ -0,0 +1,12 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+module sui::hash {
+    /// @param data: Arbitrary binary data to hash
+    /// Hash the input bytes using SHA2-256 and returns 32 bytes.
+    public native fun sha2_256(data: &vector<u8>): vector<u8>;
+
+    /// @param data: Arbitrary binary data to hash
+    /// Hash the input bytes using Blake2b-256 and returns 32 bytes.
+    public native fun blake2b256(data: &vector<u8>): vector<u8>;
+}
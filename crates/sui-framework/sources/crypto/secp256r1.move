@@ -0,0 +1,13 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+module sui::secp256r1 {
+    /// @param signature: A 64-bytes signature in form (r, s) that is signed using
+    /// Secp256r1 (NIST P-256) and SHA-256.
+    /// @param public_key: A 33-bytes compressed or 65-bytes uncompressed SEC1 encoded public key.
+    /// @param msg: The message that the signature is signed against. It is hashed with SHA-256
+    /// before verification, so it must not be hashed beforehand.
+    ///
+    /// If the signature is valid to the public key and message, return true. Else false.
+    public native fun secp256r1_verify(signature: &vector<u8>, public_key: &vector<u8>, msg: &vector<u8>): bool;
+}
@@ -281,8 +281,11 @@ module sui::sui_system {
         let storage_fund = balance::value(&self.storage_fund);
         let total_stake = delegation_stake + validator_stake + storage_fund;
 
-        let delegator_reward_amount = delegation_stake * computation_charge / total_stake;
-        let delegator_reward = balance::split(&mut computation_reward, delegator_reward_amount);
+        // Use u128 to avoid multiplication overflow.
+        let delegator_reward_amount = (delegation_stake as u128)
+            * (computation_charge as u128)
+            / (total_stake as u128);
+        let delegator_reward = balance::split(&mut computation_reward, (delegator_reward_amount as u64));
         balance::join(&mut self.storage_fund, storage_reward);
 
         self.epoch = self.epoch + 1;
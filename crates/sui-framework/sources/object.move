@@ -6,6 +6,8 @@ module sui::object {
     use std::bcs;
     use sui::tx_context::{Self, TxContext};
 
+    friend sui::dynamic_field;
+    friend sui::package;
     friend sui::sui_system;
     friend sui::transfer;
 
@@ -60,6 +62,11 @@ module sui::object {
         id.bytes
     }
 
+    /// Make the `ID` of a package from its address.
+    public(friend) fun id_from_package_address(bytes: address): ID {
+        ID { bytes }
+    }
+
     // === uid ===
 
     /// Create the `UID` for the singleton `SuiSystemState` object.
@@ -100,6 +107,14 @@ module sui::object {
         }
     }
 
+    /// Create a new `UID` from `bytes`, the hash of a parent and a key, as done for the
+    /// objects holding dynamic fields.
+    /// This must only be used with hashes that are never reused, for the `UID` to be unique.
+    public(friend) fun new_uid_from_hash(bytes: address): UID {
+        record_new_uid(bytes);
+        UID { id: ID { bytes } }
+    }
+
     /// Delete the object and it's `UID`. This is the only way to eliminate a `UID`.
     // This exists to inform Sui of object deletions. When an object
     // gets unpacked, the programmer will have to do something with its
@@ -151,6 +166,9 @@ module sui::object {
     // helper for delete
     native fun delete_impl(id: address);
 
+    // marks newly created UIDs from hash
+    native fun record_new_uid(id: address);
+
     // Cost calibration functions
     #[test_only]
     public fun calibrate_address_from_bytes(bytes: vector<u8>) {
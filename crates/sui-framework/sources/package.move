@@ -0,0 +1,54 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+/// Upgrade capabilities for published packages. A package can be upgraded with an `Upgrade`
+/// transaction by the owner of its `UpgradeCap`, which one of its modules can claim in its
+/// initializer with its one-time witness.
+module sui::package {
+    use sui::object::{Self, ID, UID};
+    use sui::tx_context::TxContext;
+
+    /// For when a type passed to claim_upgrade_cap is not a one-time witness.
+    const EBadWitness: u64 = 0;
+
+    /// Capability allowing the bearer to upgrade a package. It always refers to the
+    /// latest version of the package, and is updated by every upgrade.
+    struct UpgradeCap has key, store {
+        id: UID,
+        /// The latest version of the package.
+        package: ID,
+        /// The version of `package`, starting at 1 when first published.
+        version: u64,
+    }
+
+    /// Claim the upgrade capability of the package that defines the one-time witness `otw`.
+    /// Aborts if `otw` is not a one-time witness, so it can only be called from a module
+    /// initializer, and once per module.
+    public fun claim_upgrade_cap<T: drop>(otw: &T, ctx: &mut TxContext): UpgradeCap {
+        assert!(sui::types::is_one_time_witness(otw), EBadWitness);
+        UpgradeCap {
+            id: object::new(ctx),
+            package: object::id_from_package_address(type_address<T>()),
+            version: 1,
+        }
+    }
+
+    /// The ID of the latest version of the package that `cap` can upgrade.
+    public fun package(cap: &UpgradeCap): ID {
+        cap.package
+    }
+
+    /// The version of the latest version of the package that `cap` can upgrade.
+    public fun version(cap: &UpgradeCap): u64 {
+        cap.version
+    }
+
+    /// Make the package that `cap` can upgrade immutable, by destroying its upgrade capability.
+    public entry fun make_immutable(cap: UpgradeCap) {
+        let UpgradeCap { id, package: _, version: _ } = cap;
+        object::delete(id);
+    }
+
+    /// The address of the package defining the struct type `T`.
+    native fun type_address<T>(): address;
+}
@@ -1,6 +1,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{legacy_emit_cost, legacy_empty_cost};
+use blake2::digest::consts::U32;
 use curve25519_dalek_ng::scalar::Scalar;
 use fastcrypto::{
     bls12381::{BLS12381PublicKey, BLS12381Signature},
@@ -10,6 +11,7 @@ use fastcrypto::{
     traits::ToFromBytes,
     Verifier,
 };
+use hmac::{Hmac, Mac};
 use move_binary_format::errors::PartialVMResult;
 use move_vm_runtime::native_functions::NativeContext;
 use move_vm_types::{
@@ -18,8 +20,13 @@ use move_vm_types::{
     pop_arg,
     values::{Value, VectorRef},
 };
+use p256::ecdsa::{
+    signature::Verifier as P256Verifier, Signature as P256Signature,
+    VerifyingKey as P256VerifyingKey,
+};
 use smallvec::smallvec;
 use std::collections::VecDeque;
+use sui_cost_tables::natives_tables::{native_cost, SuiNativeCostIndex};
 use sui_types::error::SuiError;
 
 pub const FAIL_TO_RECOVER_PUBKEY: u64 = 0;
@@ -408,3 +415,115 @@ pub fn ed25519_verify(
         Err(_) => Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
     }
 }
+
+/// Native implementation of sha2_256 in public Move API, see hash.move for specifications.
+pub fn sha2_256(
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let msg = pop_arg!(args, VectorRef);
+    let msg_ref = msg.as_bytes_ref();
+    let cost = native_cost(SuiNativeCostIndex::HASH_SHA2_256, msg_ref.len());
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(
+            <sha2::Sha256 as sha2::Digest>::digest(&*msg_ref)
+                .as_slice()
+                .to_vec()
+        )],
+    ))
+}
+
+/// Native implementation of blake2b256 in public Move API, see hash.move for specifications.
+pub fn blake2b256(
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let msg = pop_arg!(args, VectorRef);
+    let msg_ref = msg.as_bytes_ref();
+    let cost = native_cost(SuiNativeCostIndex::HASH_BLAKE2B256, msg_ref.len());
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(
+            <blake2::Blake2b<U32> as blake2::Digest>::digest(&*msg_ref)
+                .as_slice()
+                .to_vec()
+        )],
+    ))
+}
+
+/// Native implementation of hmac_sha3_256 in public Move API, see hmac.move for specifications.
+pub fn hmac_sha3_256(
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let msg = pop_arg!(args, VectorRef);
+    let key = pop_arg!(args, VectorRef);
+    let msg_ref = msg.as_bytes_ref();
+    let key_ref = key.as_bytes_ref();
+    let cost = native_cost(
+        SuiNativeCostIndex::HMAC_SHA3_256,
+        key_ref.len() + msg_ref.len(),
+    );
+
+    // HMAC accepts keys of any length, so this never fails.
+    let mut mac = <Hmac<sha3::Sha3_256> as Mac>::new_from_slice(&key_ref)
+        .expect("HMAC can take a key of any size");
+    mac.update(&msg_ref);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(mac.finalize().into_bytes().to_vec())],
+    ))
+}
+
+/// Native implementation of secp256r1_verify in public Move API, see secp256r1.move for
+/// specifications.
+pub fn secp256r1_verify(
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let msg = pop_arg!(args, VectorRef);
+    let public_key_bytes = pop_arg!(args, VectorRef);
+    let signature_bytes = pop_arg!(args, VectorRef);
+
+    let msg_ref = msg.as_bytes_ref();
+    let public_key_bytes_ref = public_key_bytes.as_bytes_ref();
+    let signature_bytes_ref = signature_bytes.as_bytes_ref();
+
+    let cost = native_cost(SuiNativeCostIndex::SECP256R1_VERIFY, msg_ref.len());
+
+    let signature = match P256Signature::try_from(&*signature_bytes_ref) {
+        Ok(signature) => signature,
+        Err(_) => return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+    };
+
+    let public_key = match P256VerifyingKey::from_sec1_bytes(&public_key_bytes_ref) {
+        Ok(public_key) => public_key,
+        Err(_) => return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+    };
+
+    // The message is hashed with SHA-256 before verification.
+    match P256Verifier::verify(&public_key, &msg_ref, &signature) {
+        Ok(_) => Ok(NativeResult::ok(cost, smallvec![Value::bool(true)])),
+        Err(_) => Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+    }
+}
@@ -0,0 +1,285 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    legacy_emit_cost,
+    natives::{
+        get_nested_struct_field,
+        object_runtime::{object_store::ObjectResult, ObjectRuntime},
+    },
+};
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::{
+    account_address::AccountAddress,
+    language_storage::{StructTag, TypeTag},
+    value::MoveTypeLayout,
+    vm_status::StatusCode,
+};
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{StructRef, Value},
+};
+use sha3::{Digest, Sha3_256};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+use sui_types::base_types::ObjectID;
+
+const E_KEY_DOES_NOT_EXIST: u64 = 1;
+const E_FIELD_TYPE_MISMATCH: u64 = 2;
+const E_BCS_SERIALIZATION_FAILURE: u64 = 3;
+
+macro_rules! get_or_fetch_object {
+    ($context:ident, $ty_args:ident, $parent:ident, $child_id:ident) => {{
+        let child_ty = $ty_args.pop().unwrap();
+        debug_assert!($ty_args.is_empty());
+        let (layout, tag) = match get_tag_and_layout($context, &child_ty)? {
+            Some(res) => res,
+            None => {
+                return Ok(NativeResult::err(
+                    legacy_emit_cost(),
+                    E_BCS_SERIALIZATION_FAILURE,
+                ))
+            }
+        };
+        let object_runtime: &mut ObjectRuntime = $context.extensions_mut().get_mut();
+        object_runtime.get_or_fetch_child_object(
+            $parent.into(),
+            $child_id.into(),
+            &child_ty,
+            &layout,
+            tag,
+        )?
+    }};
+}
+
+// native fun hash_type_and_key<K: copy + drop + store>(parent: address, k: K): address;
+pub fn hash_type_and_key(
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+    let k_ty = ty_args.pop().unwrap();
+    let k: Value = args.pop_back().unwrap();
+    let parent: AccountAddress = pop_arg!(args, AccountAddress);
+
+    // TODO: what should the cost of this be?
+    let cost = legacy_emit_cost();
+
+    let k_tag = context.type_to_type_tag(&k_ty)?;
+    let k_layout = match context.type_to_type_layout(&k_ty) {
+        Ok(Some(layout)) => layout,
+        _ => return Ok(NativeResult::err(cost, E_BCS_SERIALIZATION_FAILURE)),
+    };
+    let k_bytes = match k.simple_serialize(&k_layout) {
+        Some(bytes) => bytes,
+        None => return Ok(NativeResult::err(cost, E_BCS_SERIALIZATION_FAILURE)),
+    };
+    let k_tag_bytes = match bcs::to_bytes(&k_tag) {
+        Ok(bytes) => bytes,
+        Err(_) => return Ok(NativeResult::err(cost, E_BCS_SERIALIZATION_FAILURE)),
+    };
+
+    // hash(parent || k || K)
+    let mut hasher = Sha3_256::default();
+    hasher.update(parent.into_bytes());
+    hasher.update(k_bytes);
+    hasher.update(k_tag_bytes);
+    let hash = hasher.finalize();
+
+    // truncate into an ObjectID and return
+    // OK to access slice because Sha3_256 should never be shorter than ObjectID::LENGTH.
+    let id = ObjectID::try_from(&hash[0..ObjectID::LENGTH]).unwrap();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::address(id.into())]))
+}
+
+// native fun add_child_object<Child: key>(parent: address, child: Child);
+pub fn add_child_object(
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+    let child = args.pop_back().unwrap();
+    let parent = pop_arg!(args, AccountAddress).into();
+    debug_assert!(args.is_empty());
+    // TODO: what should the cost of this be?
+    let cost = legacy_emit_cost();
+
+    let child_id = get_nested_struct_field(child.copy_value().unwrap(), &[0, 0, 0])
+        .unwrap()
+        .value_as::<AccountAddress>()
+        .unwrap()
+        .into();
+    let child_ty = ty_args.pop().unwrap();
+    debug_assert!(ty_args.is_empty());
+    let tag = match context.type_to_type_tag(&child_ty)? {
+        TypeTag::Struct(s) => s,
+        _ => {
+            return Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("Sui verifier guarantees this is a struct".to_string()),
+            )
+        }
+    };
+    let object_runtime: &mut ObjectRuntime = context.extensions_mut().get_mut();
+    object_runtime.add_child_object(parent, child_id, &child_ty, tag, child)?;
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+// native fun borrow_child_object<Child: key>(object: &UID, id: address): &Child;
+pub fn borrow_child_object(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    borrow_child_object_impl(context, ty_args, args)
+}
+
+// native fun borrow_child_object_mut<Child: key>(object: &mut UID, id: address): &mut Child;
+pub fn borrow_child_object_mut(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    // The mutable borrow is the same as the immutable one, the parent being passed by mutable
+    // reference is what allows the child to be borrowed mutably in Move
+    borrow_child_object_impl(context, ty_args, args)
+}
+
+fn borrow_child_object_impl(
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let child_id = pop_arg!(args, AccountAddress);
+
+    let parent_uid = pop_arg!(args, StructRef).read_ref().unwrap();
+    // UID { id: ID { bytes: address } }
+    let parent = get_nested_struct_field(parent_uid, &[0, 0])
+        .unwrap()
+        .value_as::<AccountAddress>()
+        .unwrap();
+
+    debug_assert!(args.is_empty());
+    // TODO: what should the cost of this be?
+    let cost = legacy_emit_cost();
+    let global_value_result = get_or_fetch_object!(context, ty_args, parent, child_id);
+    let global_value = match global_value_result {
+        ObjectResult::MismatchedType => return Ok(NativeResult::err(cost, E_FIELD_TYPE_MISMATCH)),
+        ObjectResult::Loaded(gv) => gv,
+    };
+    if !global_value.exists()? {
+        return Ok(NativeResult::err(cost, E_KEY_DOES_NOT_EXIST));
+    }
+    let child_ref = global_value.borrow_global().map_err(|err| {
+        debug_assert!(err.major_status() != StatusCode::MISSING_DATA);
+        err
+    })?;
+    Ok(NativeResult::ok(cost, smallvec![child_ref]))
+}
+
+// native fun remove_child_object<Child: key>(parent: address, id: address): Child;
+pub fn remove_child_object(
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+    let child_id = pop_arg!(args, AccountAddress);
+    let parent = pop_arg!(args, AccountAddress);
+    debug_assert!(args.is_empty());
+    // TODO: what should the cost of this be?
+    let cost = legacy_emit_cost();
+    let global_value_result = get_or_fetch_object!(context, ty_args, parent, child_id);
+    let global_value = match global_value_result {
+        ObjectResult::MismatchedType => return Ok(NativeResult::err(cost, E_FIELD_TYPE_MISMATCH)),
+        ObjectResult::Loaded(gv) => gv,
+    };
+
+    if !global_value.exists()? {
+        return Ok(NativeResult::err(cost, E_KEY_DOES_NOT_EXIST));
+    }
+    let child = global_value.move_from().map_err(|err| {
+        debug_assert!(err.major_status() != StatusCode::MISSING_DATA);
+        err
+    })?;
+    Ok(NativeResult::ok(cost, smallvec![child]))
+}
+
+// native fun has_child_object(parent: address, id: address): bool;
+pub fn has_child_object(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+    let child_id = pop_arg!(args, AccountAddress);
+    let parent = pop_arg!(args, AccountAddress);
+    // TODO: what should the cost of this be?
+    let cost = legacy_emit_cost();
+    let object_runtime: &mut ObjectRuntime = context.extensions_mut().get_mut();
+    let has_child = object_runtime.child_object_exists(parent.into(), child_id.into())?;
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(has_child)]))
+}
+
+// native fun has_child_object_with_ty<Child: key>(parent: address, id: address): bool;
+pub fn has_child_object_with_ty(
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+    let child_id = pop_arg!(args, AccountAddress);
+    let parent = pop_arg!(args, AccountAddress);
+    debug_assert!(args.is_empty());
+    let ty = ty_args.pop().unwrap();
+    // TODO: what should the cost of this be?
+    let cost = legacy_emit_cost();
+    let tag: TypeTag = context.type_to_type_tag(&ty)?;
+    let tag = match tag {
+        TypeTag::Struct(s) => s,
+        _ => {
+            return Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("Sui verifier guarantees this is a struct".to_string()),
+            )
+        }
+    };
+    let object_runtime: &mut ObjectRuntime = context.extensions_mut().get_mut();
+    let has_child =
+        object_runtime.child_object_exists_and_has_type(parent.into(), child_id.into(), &tag)?;
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(has_child)]))
+}
+
+fn get_tag_and_layout(
+    context: &NativeContext,
+    ty: &Type,
+) -> PartialVMResult<Option<(MoveTypeLayout, StructTag)>> {
+    let layout = match context.type_to_type_layout(ty)? {
+        None => return Ok(None),
+        Some(layout) => layout,
+    };
+    let tag = match context.type_to_type_tag(ty)? {
+        TypeTag::Struct(s) => s,
+        _ => {
+            return Err(
+                PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                    .with_message("Sui verifier guarantees this is a struct".to_string()),
+            )
+        }
+    };
+    Ok(Some((layout, tag)))
+}
@@ -0,0 +1,213 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use crate::legacy_empty_cost;
+use ark_bls12_381::Bls12_381;
+use ark_bn254::Bn254;
+use ark_ec::PairingEngine;
+use ark_groth16::{PreparedVerifyingKey, Proof, VerifyingKey};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, SerializationError};
+use move_binary_format::errors::PartialVMResult;
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    natives::function::NativeResult,
+    pop_arg,
+    values::{Value, VectorRef},
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+use sui_cost_tables::natives_tables::{native_cost, SuiNativeCostIndex};
+
+pub const INVALID_VERIFYING_KEY: u64 = 0;
+pub const INVALID_CURVE: u64 = 1;
+pub const TOO_MANY_PUBLIC_INPUTS: u64 = 2;
+
+/// Curve identifiers, must match the ones in groth16.move.
+const BLS12381: u8 = 0;
+const BN254: u8 = 1;
+
+/// Scalars of both curves are serialized as 32 little-endian bytes.
+const SCALAR_SIZE: usize = 32;
+
+/// Bounds the number of pairing inputs (and so the cost) of a single proof verification.
+const MAX_PUBLIC_INPUTS: usize = 8;
+
+/// The serialized components of a prepared verifying key, in the order of the fields of the
+/// Move `PreparedVerifyingKey` struct.
+type PreparedVerifyingKeyBytes = (Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>);
+
+type PrepareFn = fn(&[u8]) -> Result<PreparedVerifyingKeyBytes, u64>;
+type VerifyFn = fn(&[u8], &[u8], &[u8], &[u8], &[u8], &[u8]) -> Result<bool, SerializationError>;
+
+fn serialize<T: CanonicalSerialize>(value: &T) -> Result<Vec<u8>, SerializationError> {
+    let mut bytes = Vec::with_capacity(value.serialized_size());
+    value.serialize(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// The largest serialization of the `gamma_abc_g1` points of a verifying key: one point per
+/// public input plus one, after the length of the vector.
+fn max_vk_gamma_abc_g1_size<E: PairingEngine>() -> usize {
+    8 + (MAX_PUBLIC_INPUTS + 1) * E::G1Affine::default().serialized_size()
+}
+
+/// Prepare a verifying key, failing with the abort code of `prepare_verifying_key`.
+fn prepare_vk<E: PairingEngine>(verifying_key: &[u8]) -> Result<PreparedVerifyingKeyBytes, u64> {
+    let vk = VerifyingKey::<E>::deserialize(verifying_key).map_err(|_| INVALID_VERIFYING_KEY)?;
+    if vk.gamma_abc_g1.len() > MAX_PUBLIC_INPUTS + 1 {
+        return Err(TOO_MANY_PUBLIC_INPUTS);
+    }
+    let pvk = ark_groth16::prepare_verifying_key(&vk);
+    // The pairing-friendly form of G2 points does not have a canonical encoding, so the negated
+    // affine points are kept instead and prepared again at verification time.
+    let serialize_all = || -> Result<_, SerializationError> {
+        Ok((
+            serialize(&vk.gamma_abc_g1)?,
+            serialize(&pvk.alpha_g1_beta_g2)?,
+            serialize(&-vk.gamma_g2)?,
+            serialize(&-vk.delta_g2)?,
+        ))
+    };
+    serialize_all().map_err(|_| INVALID_VERIFYING_KEY)
+}
+
+fn verify_proof<E: PairingEngine>(
+    vk_gamma_abc_g1: &[u8],
+    alpha_g1_beta_g2: &[u8],
+    gamma_g2_neg: &[u8],
+    delta_g2_neg: &[u8],
+    public_inputs: &[u8],
+    proof_points: &[u8],
+) -> Result<bool, SerializationError> {
+    let pvk = PreparedVerifyingKey::<E> {
+        vk: VerifyingKey {
+            alpha_g1: Default::default(),
+            beta_g2: Default::default(),
+            gamma_g2: Default::default(),
+            delta_g2: Default::default(),
+            gamma_abc_g1: Vec::<E::G1Affine>::deserialize(vk_gamma_abc_g1)?,
+        },
+        alpha_g1_beta_g2: E::Fqk::deserialize(alpha_g1_beta_g2)?,
+        gamma_g2_neg_pc: E::G2Affine::deserialize(gamma_g2_neg)?.into(),
+        delta_g2_neg_pc: E::G2Affine::deserialize(delta_g2_neg)?.into(),
+    };
+    let public_inputs = public_inputs
+        .chunks(SCALAR_SIZE)
+        .map(E::Fr::deserialize)
+        .collect::<Result<Vec<_>, _>>()?;
+    let proof = Proof::<E>::deserialize(proof_points)?;
+
+    // A mismatch between the number of public inputs and the verifying key is reported as an
+    // error, which is just an invalid proof from the caller's point of view.
+    Ok(ark_groth16::verify_proof(&pvk, &proof, &public_inputs).unwrap_or(false))
+}
+
+/// Native implementation of prepare_verifying_key in public Move API, see groth16.move for
+/// specifications.
+pub fn prepare_verifying_key(
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let verifying_key = pop_arg!(args, VectorRef);
+    let curve = pop_arg!(args, u8);
+
+    let verifying_key_ref = verifying_key.as_bytes_ref();
+
+    let (cost_index, prepare): (_, PrepareFn) = match curve {
+        BLS12381 => (
+            SuiNativeCostIndex::GROTH16_PREPARE_VERIFYING_KEY_BLS12381,
+            prepare_vk::<Bls12_381>,
+        ),
+        BN254 => (
+            SuiNativeCostIndex::GROTH16_PREPARE_VERIFYING_KEY_BN254,
+            prepare_vk::<Bn254>,
+        ),
+        _ => return Ok(NativeResult::err(legacy_empty_cost(), INVALID_CURVE)),
+    };
+    let cost = native_cost(cost_index, verifying_key_ref.len());
+    let result = prepare(&verifying_key_ref);
+
+    match result {
+        Ok((vk_gamma_abc_g1, alpha_g1_beta_g2, gamma_g2_neg, delta_g2_neg)) => {
+            Ok(NativeResult::ok(
+                cost,
+                smallvec![
+                    Value::vector_u8(vk_gamma_abc_g1),
+                    Value::vector_u8(alpha_g1_beta_g2),
+                    Value::vector_u8(gamma_g2_neg),
+                    Value::vector_u8(delta_g2_neg),
+                ],
+            ))
+        }
+        Err(code) => Ok(NativeResult::err(cost, code)),
+    }
+}
+
+/// Native implementation of verify_groth16_proof in public Move API, see groth16.move for
+/// specifications.
+pub fn verify_groth16_proof(
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 7);
+
+    let proof_points = pop_arg!(args, VectorRef);
+    let public_inputs = pop_arg!(args, VectorRef);
+    let delta_g2_neg = pop_arg!(args, VectorRef);
+    let gamma_g2_neg = pop_arg!(args, VectorRef);
+    let alpha_g1_beta_g2 = pop_arg!(args, VectorRef);
+    let vk_gamma_abc_g1 = pop_arg!(args, VectorRef);
+    let curve = pop_arg!(args, u8);
+
+    let proof_points_ref = proof_points.as_bytes_ref();
+    let public_inputs_ref = public_inputs.as_bytes_ref();
+    let delta_g2_neg_ref = delta_g2_neg.as_bytes_ref();
+    let gamma_g2_neg_ref = gamma_g2_neg.as_bytes_ref();
+    let alpha_g1_beta_g2_ref = alpha_g1_beta_g2.as_bytes_ref();
+    let vk_gamma_abc_g1_ref = vk_gamma_abc_g1.as_bytes_ref();
+
+    let (cost_index, max_vk_gamma_abc_g1_size, verify): (_, _, VerifyFn) = match curve {
+        BLS12381 => (
+            SuiNativeCostIndex::GROTH16_VERIFY_PROOF_BLS12381,
+            max_vk_gamma_abc_g1_size::<Bls12_381>(),
+            verify_proof::<Bls12_381>,
+        ),
+        BN254 => (
+            SuiNativeCostIndex::GROTH16_VERIFY_PROOF_BN254,
+            max_vk_gamma_abc_g1_size::<Bn254>(),
+            verify_proof::<Bn254>,
+        ),
+        _ => return Ok(NativeResult::err(legacy_empty_cost(), INVALID_CURVE)),
+    };
+    // Both the public inputs and the points of the verifying key they are multiplied with are
+    // deserialized and checked.
+    let cost = native_cost(
+        cost_index,
+        public_inputs_ref.len() + vk_gamma_abc_g1_ref.len(),
+    );
+
+    // A prepared verifying key may be built from arbitrary bytes with `pvk_from_bytes`, so its
+    // size is bounded here as well.
+    if public_inputs_ref.len() > MAX_PUBLIC_INPUTS * SCALAR_SIZE
+        || vk_gamma_abc_g1_ref.len() > max_vk_gamma_abc_g1_size
+    {
+        return Ok(NativeResult::err(cost, TOO_MANY_PUBLIC_INPUTS));
+    }
+
+    let valid = verify(
+        &vk_gamma_abc_g1_ref,
+        &alpha_g1_beta_g2_ref,
+        &gamma_g2_neg_ref,
+        &delta_g2_neg_ref,
+        &public_inputs_ref,
+        &proof_points_ref,
+    )
+    .unwrap_or(false);
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(valid)]))
+}
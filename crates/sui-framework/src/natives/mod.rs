@@ -2,9 +2,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod crypto;
+mod dynamic_field;
 mod event;
+mod groth16;
 mod object;
 pub mod object_runtime;
+mod package;
 mod test_scenario;
 mod transfer;
 mod tx_context;
@@ -43,6 +46,41 @@ pub fn all_natives(
             "bls12381_verify_g1_sig",
             make_native!(crypto::bls12381_verify_g1_sig),
         ),
+        (
+            "dynamic_field",
+            "hash_type_and_key",
+            make_native!(dynamic_field::hash_type_and_key),
+        ),
+        (
+            "dynamic_field",
+            "add_child_object",
+            make_native!(dynamic_field::add_child_object),
+        ),
+        (
+            "dynamic_field",
+            "borrow_child_object",
+            make_native!(dynamic_field::borrow_child_object),
+        ),
+        (
+            "dynamic_field",
+            "borrow_child_object_mut",
+            make_native!(dynamic_field::borrow_child_object_mut),
+        ),
+        (
+            "dynamic_field",
+            "remove_child_object",
+            make_native!(dynamic_field::remove_child_object),
+        ),
+        (
+            "dynamic_field",
+            "has_child_object",
+            make_native!(dynamic_field::has_child_object),
+        ),
+        (
+            "dynamic_field",
+            "has_child_object_with_ty",
+            make_native!(dynamic_field::has_child_object_with_ty),
+        ),
         ("event", "emit", make_native!(event::emit)),
         (
             "object",
@@ -51,6 +89,16 @@ pub fn all_natives(
         ),
         ("object", "delete_impl", make_native!(object::delete_impl)),
         ("object", "borrow_uid", make_native!(object::borrow_uid)),
+        (
+            "object",
+            "record_new_uid",
+            make_native!(object::record_new_uid),
+        ),
+        (
+            "package",
+            "type_address",
+            make_native!(package::type_address),
+        ),
         (
             "bulletproofs",
             "native_verify_full_range_proof",
@@ -86,6 +134,24 @@ pub fn all_natives(
             "ed25519_verify",
             make_native!(crypto::ed25519_verify),
         ),
+        ("hash", "sha2_256", make_native!(crypto::sha2_256)),
+        ("hash", "blake2b256", make_native!(crypto::blake2b256)),
+        ("hmac", "hmac_sha3_256", make_native!(crypto::hmac_sha3_256)),
+        (
+            "secp256r1",
+            "secp256r1_verify",
+            make_native!(crypto::secp256r1_verify),
+        ),
+        (
+            "groth16",
+            "prepare_verifying_key_internal",
+            make_native!(groth16::prepare_verifying_key),
+        ),
+        (
+            "groth16",
+            "verify_groth16_proof_internal",
+            make_native!(groth16::verify_groth16_proof),
+        ),
         (
             "test_scenario",
             "take_from_address_by_id",
@@ -74,3 +74,23 @@ pub fn delete_impl(
     obj_runtime.delete_id(uid_bytes.into());
     Ok(NativeResult::ok(cost, smallvec![]))
 }
+
+// native fun record_new_uid(id: address);
+pub fn record_new_uid(
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    // unwrap safe because the interface of native function guarantees it.
+    let uid_bytes = pop_arg!(args, AccountAddress);
+
+    // TODO: what should the cost of this be?
+    let cost = legacy_emit_cost();
+
+    let obj_runtime: &mut ObjectRuntime = context.extensions_mut().get_mut();
+    obj_runtime.new_id(uid_bytes.into());
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
@@ -1,21 +1,31 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod object_store;
+
 use std::collections::BTreeMap;
 
 use better_any::{Tid, TidAble};
 use linked_hash_map::LinkedHashMap;
 use move_binary_format::errors::PartialVMResult;
-use move_core_types::{account_address::AccountAddress, language_storage::StructTag};
-use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use move_core_types::{
+    account_address::AccountAddress, effects::Op, language_storage::StructTag,
+    value::MoveTypeLayout,
+};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{GlobalValue, Value},
+};
 use sui_types::{
     base_types::{ObjectID, SuiAddress},
     error::{ExecutionError, ExecutionErrorKind},
-    object::{MoveObject, Owner},
-    storage::{DeleteKind, ObjectResolver, WriteKind},
+    object::{MoveObject, Object, Owner},
+    storage::{ChildObjectResolver, DeleteKind, WriteKind},
     SUI_SYSTEM_STATE_OBJECT_ID,
 };
 
+use self::object_store::{ChildObjectEffect, ObjectResult, ObjectStore};
+
 use super::get_object_id;
 
 pub enum ObjectEvent {
@@ -43,12 +53,12 @@ pub struct RuntimeResults {
     pub writes: LinkedHashMap<ObjectID, (WriteKind, Owner, Type, StructTag, Value)>,
     pub deletions: LinkedHashMap<ObjectID, DeleteKind>,
     pub user_events: Vec<(Type, StructTag, Value)>,
+    // the child objects loaded from storage that were changed, with their previous values
+    pub loaded_child_objects: BTreeMap<ObjectID, Object>,
 }
 
 #[derive(Default)]
 pub(crate) struct ObjectRuntimeState {
-    // will eventually need a reference to the state view to access child objects
-    // pub(crate) state_view: &'a mut dyn ____,
     pub(crate) input_objects: BTreeMap<ObjectID, (/* by_value */ bool, Owner)>,
     // new ids from object::new
     new_ids: Set<ObjectID>,
@@ -62,8 +72,8 @@ pub(crate) struct ObjectRuntimeState {
 
 #[derive(Tid)]
 pub struct ObjectRuntime<'a> {
-    // eventually used to load dynamic child objects
-    _object_resolver: Box<dyn ObjectResolver + 'a>,
+    // the child objects (e.g. dynamic fields) loaded or added during execution
+    object_store: ObjectStore<'a>,
     // inventories for test scenario
     pub(crate) test_inventories: TestInventories,
     // the internal state
@@ -78,11 +88,11 @@ impl TestInventories {
 
 impl<'a> ObjectRuntime<'a> {
     pub fn new(
-        _object_resolver: Box<dyn ObjectResolver + 'a>,
+        object_resolver: Box<dyn ChildObjectResolver + 'a>,
         input_objects: BTreeMap<ObjectID, (/* by_value */ bool, Owner)>,
     ) -> Self {
         Self {
-            _object_resolver,
+            object_store: ObjectStore::new(object_resolver),
             test_inventories: TestInventories::new(),
             state: ObjectRuntimeState {
                 input_objects,
@@ -95,7 +105,12 @@ impl<'a> ObjectRuntime<'a> {
     }
 
     pub fn new_id(&mut self, id: ObjectID) {
-        self.state.new_ids.insert(id, ());
+        // If the ID was deleted in this transaction, it is being recreated, e.g. a dynamic field
+        // that is removed and then added again. It is then no longer deleted, nor new.
+        let was_deleted = self.state.deleted_ids.remove(&id).is_some();
+        if !was_deleted {
+            self.state.new_ids.insert(id, ());
+        }
     }
 
     pub fn delete_id(&mut self, id: ObjectID) {
@@ -123,12 +138,67 @@ impl<'a> ObjectRuntime<'a> {
         self.state.events.push((ty, tag, event))
     }
 
+    pub(crate) fn child_object_exists(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+    ) -> PartialVMResult<bool> {
+        self.object_store.object_exists(parent, child)
+    }
+
+    pub(crate) fn child_object_exists_and_has_type(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+        child_tag: &StructTag,
+    ) -> PartialVMResult<bool> {
+        self.object_store
+            .object_exists_and_has_type(parent, child, child_tag)
+    }
+
+    /// The value of the child object, loaded from storage the first time it is accessed. The
+    /// caller borrows or removes the value from the returned `GlobalValue`.
+    pub(crate) fn get_or_fetch_child_object(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+        child_ty: &Type,
+        child_layout: &MoveTypeLayout,
+        child_tag: StructTag,
+    ) -> PartialVMResult<ObjectResult<&mut GlobalValue>> {
+        let res = self.object_store.get_or_fetch_object(
+            parent,
+            child,
+            child_ty,
+            child_layout,
+            child_tag,
+        )?;
+        Ok(match res {
+            ObjectResult::MismatchedType => ObjectResult::MismatchedType,
+            ObjectResult::Loaded(child_object) => ObjectResult::Loaded(&mut child_object.value),
+        })
+    }
+
+    pub(crate) fn add_child_object(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+        child_ty: &Type,
+        child_tag: StructTag,
+        child_value: Value,
+    ) -> PartialVMResult<()> {
+        self.object_store
+            .add_object(parent, child, child_ty, child_tag, child_value)
+    }
+
     pub(crate) fn take_state(&mut self) -> ObjectRuntimeState {
         std::mem::take(&mut self.state)
     }
 
     pub fn finish(self) -> Result<RuntimeResults, ExecutionError> {
-        self.state.finish()
+        let (loaded_child_objects, child_object_effects) = self.object_store.into_inner();
+        self.state
+            .finish(loaded_child_objects, child_object_effects)
     }
 }
 
@@ -139,14 +209,39 @@ impl ObjectRuntimeState {
     /// - Process `deleted_ids` with previously determiend information to determine the
     ///   DeleteKind
     /// - Passes through user events
-    pub fn finish(self) -> Result<RuntimeResults, ExecutionError> {
+    /// Child objects with effects are treated as transferred to their parent if they are still
+    /// there at the end of the transaction, and as inputs passed by value if they were loaded
+    /// from storage, so that removed children that are not written back end up wrapped.
+    pub(crate) fn finish(
+        self,
+        loaded_child_objects: BTreeMap<ObjectID, Object>,
+        child_object_effects: BTreeMap<ObjectID, ChildObjectEffect>,
+    ) -> Result<RuntimeResults, ExecutionError> {
         let ObjectRuntimeState {
-            input_objects,
+            mut input_objects,
             new_ids,
             deleted_ids,
-            transfers,
+            mut transfers,
             events: user_events,
         } = self;
+        for (id, object) in &loaded_child_objects {
+            input_objects.insert(*id, (/* by_value */ true, object.owner));
+        }
+        for (child, child_object_effect) in child_object_effects {
+            let ChildObjectEffect {
+                owner: parent,
+                ty,
+                tag,
+                effect,
+            } = child_object_effect;
+            match effect {
+                Op::New(value) | Op::Modify(value) => {
+                    transfers.push((child, Owner::ObjectOwner(parent.into()), ty, tag, value))
+                }
+                // the child was removed: it was transferred, wrapped or deleted
+                Op::Delete => (),
+            }
+        }
         let input_owner_map = input_objects
             .iter()
             .filter_map(|(id, (_by_value, owner))| match owner {
@@ -205,6 +300,7 @@ impl ObjectRuntimeState {
             writes,
             deletions,
             user_events,
+            loaded_child_objects,
         })
     }
 }
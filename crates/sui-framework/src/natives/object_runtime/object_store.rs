@@ -0,0 +1,275 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{btree_map, BTreeMap};
+
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::{
+    effects::Op, language_storage::StructTag, value::MoveTypeLayout, vm_status::StatusCode,
+};
+use move_vm_types::{
+    loaded_data::runtime_types::Type,
+    values::{GlobalValue, Value},
+};
+use sui_types::{
+    base_types::ObjectID,
+    object::{Data, Object, Owner},
+    storage::ChildObjectResolver,
+};
+
+pub(super) struct ChildObject {
+    pub(super) owner: ObjectID,
+    pub(super) ty: Type,
+    pub(super) tag: StructTag,
+    pub(super) value: GlobalValue,
+}
+
+/// The change to a child object, as seen from its parent.
+pub(crate) struct ChildObjectEffect {
+    pub(crate) owner: ObjectID,
+    pub(crate) ty: Type,
+    pub(crate) tag: StructTag,
+    pub(crate) effect: Op<Value>,
+}
+
+struct Inner<'a> {
+    // used to load child objects
+    resolver: Box<dyn ChildObjectResolver + 'a>,
+    // cached objects from the resolver. An object might be in this map but not in the store
+    // if its existence was queried, but the value was not used.
+    cached_objects: BTreeMap<ObjectID, Option<Object>>,
+}
+
+/// The child objects accessed in a transaction, loaded lazily from the resolver.
+pub(super) struct ObjectStore<'a> {
+    inner: Inner<'a>,
+    // the child objects loaded or added during execution
+    store: BTreeMap<ObjectID, ChildObject>,
+}
+
+pub(crate) enum ObjectResult<V> {
+    // object exists but type does not match. Should result in an abort
+    MismatchedType,
+    Loaded(V),
+}
+
+impl<'a> Inner<'a> {
+    fn get_or_fetch_object_from_store(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+    ) -> PartialVMResult<Option<&Object>> {
+        if let btree_map::Entry::Vacant(e) = self.cached_objects.entry(child) {
+            let child_opt = self
+                .resolver
+                .read_child_object(&parent, &child)
+                .map_err(|msg| {
+                    PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(format!("{msg}"))
+                })?;
+            if let Some(object) = &child_opt {
+                if object.owner != Owner::ObjectOwner(parent.into()) {
+                    return Err(PartialVMError::new(StatusCode::STORAGE_ERROR).with_message(
+                        format!(
+                            "Bad owner for {child}. Expected owner {parent} but found owner {}",
+                            object.owner
+                        ),
+                    ));
+                }
+            }
+            e.insert(child_opt);
+        }
+        Ok(self.cached_objects.get(&child).unwrap().as_ref())
+    }
+
+    fn fetch_object_impl(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+        child_ty_layout: &MoveTypeLayout,
+        child_tag: &StructTag,
+    ) -> PartialVMResult<ObjectResult<GlobalValue>> {
+        let object = match self.get_or_fetch_object_from_store(parent, child)? {
+            None => return Ok(ObjectResult::Loaded(GlobalValue::none())),
+            Some(object) => object,
+        };
+        let move_object = match &object.data {
+            Data::Move(move_object) => move_object,
+            Data::Package(_) => {
+                return Err(
+                    PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                        .with_message(format!("Parent {parent} owns the package {child}")),
+                )
+            }
+        };
+        if &move_object.type_ != child_tag {
+            return Ok(ObjectResult::MismatchedType);
+        }
+        let value = match Value::simple_deserialize(move_object.contents(), child_ty_layout) {
+            Some(value) => value,
+            None => {
+                return Err(
+                    PartialVMError::new(StatusCode::FAILED_TO_DESERIALIZE_RESOURCE).with_message(
+                        format!("Failed to deserialize object {child} with type {child_tag}"),
+                    ),
+                )
+            }
+        };
+        Ok(ObjectResult::Loaded(GlobalValue::cached(value)?))
+    }
+}
+
+impl<'a> ObjectStore<'a> {
+    pub(super) fn new(resolver: Box<dyn ChildObjectResolver + 'a>) -> Self {
+        Self {
+            inner: Inner {
+                resolver,
+                cached_objects: BTreeMap::new(),
+            },
+            store: BTreeMap::new(),
+        }
+    }
+
+    pub(super) fn object_exists(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+    ) -> PartialVMResult<bool> {
+        if let Some(child_object) = self.store.get(&child) {
+            return child_object.value.exists();
+        }
+        Ok(self
+            .inner
+            .get_or_fetch_object_from_store(parent, child)?
+            .is_some())
+    }
+
+    pub(super) fn object_exists_and_has_type(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+        child_tag: &StructTag,
+    ) -> PartialVMResult<bool> {
+        if let Some(child_object) = self.store.get(&child) {
+            // exists and has same type
+            return Ok(child_object.value.exists()? && &child_object.tag == child_tag);
+        }
+        Ok(self
+            .inner
+            .get_or_fetch_object_from_store(parent, child)?
+            .map(|object| object.type_() == Some(child_tag))
+            .unwrap_or(false))
+    }
+
+    pub(super) fn get_or_fetch_object(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+        child_ty: &Type,
+        child_ty_layout: &MoveTypeLayout,
+        child_tag: StructTag,
+    ) -> PartialVMResult<ObjectResult<&mut ChildObject>> {
+        let child_object = match self.store.entry(child) {
+            btree_map::Entry::Vacant(e) => {
+                let value = match self.inner.fetch_object_impl(
+                    parent,
+                    child,
+                    child_ty_layout,
+                    &child_tag,
+                )? {
+                    ObjectResult::MismatchedType => return Ok(ObjectResult::MismatchedType),
+                    ObjectResult::Loaded(value) => value,
+                };
+                e.insert(ChildObject {
+                    owner: parent,
+                    ty: child_ty.clone(),
+                    tag: child_tag,
+                    value,
+                })
+            }
+            btree_map::Entry::Occupied(e) => {
+                let child_object = e.into_mut();
+                if &child_object.ty != child_ty {
+                    return Ok(ObjectResult::MismatchedType);
+                }
+                child_object
+            }
+        };
+        Ok(ObjectResult::Loaded(child_object))
+    }
+
+    pub(super) fn add_object(
+        &mut self,
+        parent: ObjectID,
+        child: ObjectID,
+        child_ty: &Type,
+        child_tag: StructTag,
+        child_value: Value,
+    ) -> PartialVMResult<()> {
+        let mut child_object = ChildObject {
+            owner: parent,
+            ty: child_ty.clone(),
+            tag: child_tag,
+            value: GlobalValue::none(),
+        };
+        child_object
+            .value
+            .move_to(child_value)
+            .map_err(|(e, _)| e)?;
+        if let Some(prev) = self.store.insert(child, child_object) {
+            if prev.value.exists()? {
+                return Err(
+                    PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR)
+                        .with_message(
+                            "Duplicate addition of a child object. \
+                            The previous value cannot be dropped."
+                                .to_string(),
+                        ),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// The objects loaded from the resolver whose values changed, and the effects on all child
+    /// objects.
+    pub(super) fn into_inner(
+        self,
+    ) -> (
+        BTreeMap<ObjectID, Object>,
+        BTreeMap<ObjectID, ChildObjectEffect>,
+    ) {
+        let Self {
+            inner: Inner {
+                mut cached_objects, ..
+            },
+            store,
+        } = self;
+        let mut loaded_child_objects = BTreeMap::new();
+        let mut child_object_effects = BTreeMap::new();
+        for (id, child_object) in store {
+            let ChildObject {
+                owner,
+                ty,
+                tag,
+                value,
+            } = child_object;
+            let effect = match value.into_effect() {
+                Some(effect) => effect,
+                None => continue,
+            };
+            if let Some(Some(object)) = cached_objects.remove(&id) {
+                loaded_child_objects.insert(id, object);
+            }
+            child_object_effects.insert(
+                id,
+                ChildObjectEffect {
+                    owner,
+                    ty,
+                    tag,
+                    effect,
+                },
+            );
+        }
+        (loaded_child_objects, child_object_effects)
+    }
+}
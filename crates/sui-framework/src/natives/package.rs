@@ -0,0 +1,36 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::legacy_length_cost;
+use move_binary_format::errors::{PartialVMError, PartialVMResult};
+use move_core_types::{language_storage::TypeTag, vm_status::StatusCode};
+use move_vm_runtime::native_functions::NativeContext;
+use move_vm_types::{
+    loaded_data::runtime_types::Type, natives::function::NativeResult, values::Value,
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+/// The address of the package defining the struct type argument.
+pub fn type_address(
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.is_empty());
+
+    // unwrap safe because the interface of native function guarantees it.
+    let ty = ty_args.pop().unwrap();
+    let address = match context.type_to_type_tag(&ty)? {
+        TypeTag::Struct(struct_tag) => struct_tag.address,
+        _ => {
+            return Err(PartialVMError::new(StatusCode::TYPE_MISMATCH)
+                .with_message("The type argument of type_address must be a struct".to_string()))
+        }
+    };
+
+    // TODO: what should the cost of this be?
+    let cost = legacy_length_cost();
+    Ok(NativeResult::ok(cost, smallvec![Value::address(address)]))
+}
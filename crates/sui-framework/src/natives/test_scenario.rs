@@ -103,12 +103,14 @@ pub fn end_transaction(
             .entry(*wrapped)
             .and_modify(|(by_value, _owner)| *by_value = true);
     }
-    // Determine writes and deletes
-    let results = object_runtime_state.finish();
+    // Determine writes and deletes. Child objects stay in the object runtime across the
+    // transactions of the test, so there are no child object effects to report
+    let results = object_runtime_state.finish(BTreeMap::new(), BTreeMap::new());
     let RuntimeResults {
         writes,
         deletions,
         user_events,
+        loaded_child_objects: _,
     } = match results {
         Ok(res) => res,
         Err(_) => {
@@ -7,6 +7,10 @@ module sui::crypto_tests {
     use sui::bls12381;
     use sui::bulletproofs;
     use sui::elliptic_curve as ec;
+    use sui::groth16;
+    use sui::hash;
+    use sui::hmac;
+    use sui::secp256r1;
     use std::vector;
 
     #[test]
@@ -443,6 +447,165 @@ module sui::crypto_tests {
         assert!(addr == addr1, 0);
     }
 
+    #[test]
+    fun test_sha2_256_hash() {
+        let msg = b"hello world!";
+        let hashed_msg_bytes = vector[117, 9, 229, 189, 160, 199, 98, 210, 186, 199, 249, 13, 117, 139, 91, 34, 99, 250,
+        1, 204, 188, 84, 42, 181, 227, 223, 22, 59, 224, 142, 108, 169];
+        let hashed_msg = hash::sha2_256(&msg);
+        assert!(hashed_msg == hashed_msg_bytes, 0);
+    }
+
+    #[test]
+    fun test_blake2b256_hash() {
+        let msg = b"hello world!";
+        let hashed_msg_bytes = vector[79, 204, 251, 77, 152, 208, 105, 85, 138, 169, 62, 149, 101, 249, 151, 216, 28, 51,
+        176, 128, 54, 78, 253, 88, 110, 119, 164, 51, 221, 255, 197, 226];
+        let hashed_msg = hash::blake2b256(&msg);
+        assert!(hashed_msg == hashed_msg_bytes, 0);
+    }
+
+    #[test]
+    fun test_hmac_sha3_256() {
+        let key = b"my key";
+        let msg = b"hello world!";
+        let expected = vector[18, 49, 229, 178, 246, 232, 18, 51, 22, 116, 140, 226, 151, 184, 228, 141, 138, 119, 164,
+        211, 132, 93, 224, 180, 99, 59, 45, 86, 123, 155, 168, 184];
+        let mac = hmac::hmac_sha3_256(&key, &msg);
+        assert!(mac == expected, 0);
+    }
+
+    #[test]
+    fun test_secp256r1_verify_fails_with_wrong_msg() {
+        let msg = b"sample!";
+        let pk = vector[3, 96, 254, 212, 186, 37, 90, 157, 49, 201, 97, 235, 116, 198, 53, 109, 104, 192, 73, 184, 146,
+        59, 97, 250, 108, 230, 105, 98, 46, 96, 242, 159, 182];
+        let sig = vector[239, 212, 139, 42, 172, 182, 168, 253, 17, 64, 221, 156, 212, 94, 129, 214, 157, 44, 135, 123,
+        86, 170, 249, 145, 195, 77, 14, 168, 78, 175, 55, 22, 247, 203, 28, 148, 45, 101, 124, 65, 212, 54, 199, 161,
+        182, 226, 159, 101, 243, 233, 0, 219, 185, 175, 244, 6, 77, 196, 171, 47, 132, 58, 205, 168];
+        let verify = secp256r1::secp256r1_verify(&sig, &pk, &msg);
+        assert!(verify == false, 0)
+    }
+
+    #[test]
+    fun test_secp256r1_verify_success() {
+        // Test case from RFC 6979 A.2.5, curve P-256 with SHA-256 and message "sample".
+        let msg = b"sample";
+        let pk = vector[3, 96, 254, 212, 186, 37, 90, 157, 49, 201, 97, 235, 116, 198, 53, 109, 104, 192, 73, 184, 146,
+        59, 97, 250, 108, 230, 105, 98, 46, 96, 242, 159, 182];
+        let sig = vector[239, 212, 139, 42, 172, 182, 168, 253, 17, 64, 221, 156, 212, 94, 129, 214, 157, 44, 135, 123,
+        86, 170, 249, 145, 195, 77, 14, 168, 78, 175, 55, 22, 247, 203, 28, 148, 45, 101, 124, 65, 212, 54, 199, 161,
+        182, 226, 159, 101, 243, 233, 0, 219, 185, 175, 244, 6, 77, 196, 171, 47, 132, 58, 205, 168];
+        let verify = secp256r1::secp256r1_verify(&sig, &pk, &msg);
+        assert!(verify == true, 0)
+    }
+
+    #[test]
+    fun test_secp256r1_invalid_sig_length() {
+        let msg = b"sample";
+        let pk = vector[3, 96, 254, 212, 186, 37, 90, 157, 49, 201, 97, 235, 116, 198, 53, 109, 104, 192, 73, 184, 146,
+        59, 97, 250, 108, 230, 105, 98, 46, 96, 242, 159, 182];
+        let sig = vector[239, 212, 139, 42, 172, 182, 168, 253, 17, 64, 221, 156, 212, 94, 129, 214, 157, 44, 135, 123,
+        86, 170, 249, 145, 195, 77, 14, 168, 78, 175, 55, 22, 247, 203, 28, 148, 45, 101, 124, 65, 212, 54, 199, 161,
+        182, 226, 159, 101, 243, 233, 0, 219, 185, 175, 244, 6, 77, 196, 171, 47, 132, 58, 205];
+        let verify = secp256r1::secp256r1_verify(&sig, &pk, &msg);
+        assert!(verify == false, 0)
+    }
+
+    #[test]
+    fun test_secp256r1_invalid_public_key_length() {
+        let msg = b"sample";
+        let pk = vector[96, 254, 212, 186, 37, 90, 157, 49, 201, 97, 235, 116, 198, 53, 109, 104, 192, 73, 184, 146,
+        59, 97, 250, 108, 230, 105, 98, 46, 96, 242, 159, 182];
+        let sig = vector[239, 212, 139, 42, 172, 182, 168, 253, 17, 64, 221, 156, 212, 94, 129, 214, 157, 44, 135, 123,
+        86, 170, 249, 145, 195, 77, 14, 168, 78, 175, 55, 22, 247, 203, 28, 148, 45, 101, 124, 65, 212, 54, 199, 161,
+        182, 226, 159, 101, 243, 233, 0, 219, 185, 175, 244, 6, 77, 196, 171, 47, 132, 58, 205, 168];
+        let verify = secp256r1::secp256r1_verify(&sig, &pk, &msg);
+        assert!(verify == false, 0)
+    }
+
+    #[test]
+    fun test_groth16_verify_bn254() {
+        // Simulated proof for a single public input 42, generated with the trapdoor of a random
+        // verifying key.
+        let vk = x"e7d4c8859d08900e8dba276730aba5c154bce7c450d21fa57ab9a0e1a8663e294044161a801cd2dddf6949ecec1b1070910f0b2e199a414dfbdb37b304ccc60999e7005afe3431dac2a94263489f1768a2c5b85d0bf73e7a4797af55c11ea7277a8b528e04c0977743f14cdb0fe74d0f535a2b29f422964130f012e38b99bc1cf791886cee71f0c199c328efdd99a1822b328bdbf7d575770b2b09f2268c9980a40a4b34e66d244a85a94bcc95d6b9b6d608f25a969baa98cf39b1d6cfef18208a66fe14de73be42b6bf6824c970c0c302387cae7408f87267281d1b327936a00200000000000000772807bc4d52bf83092886a18aa09d076c8feb94a9fea36ae30cda60a73bca14efb37c4c9162f1b1ee7c62433242af967a7677e917fbcd8fc3226fce2a9d4395";
+        let pvk = groth16::prepare_verifying_key(&groth16::bn254(), &vk);
+
+        let inputs = groth16::public_proof_inputs_from_bytes(x"2a00000000000000000000000000000000000000000000000000000000000000");
+        let proof = groth16::proof_points_from_bytes(x"8ea63dc2af5b36b6010b2b937ba47c464434b487d9b5350dfc516e515aaa9c1fdd4daeb45d4efb4835e3d78aaf12e4d0ea4b2b41dd1fbf27e94914d9197cd61f7a12927f51e797edf462b5620b95b0691aed8c0584f8cca7778d7d0f823bde036192842d8cdee90485d4a906d33f24e34f6b5edf12b0d94c8cdb056c1e0dcd16");
+        assert!(groth16::verify_groth16_proof(&groth16::bn254(), &pvk, &inputs, &proof), 0);
+
+        // The proof is not valid on the other curve.
+        assert!(!groth16::verify_groth16_proof(&groth16::bls12381(), &pvk, &inputs, &proof), 1);
+
+        // The proof does not hold for a different public input.
+        let inputs = groth16::public_proof_inputs_from_bytes(x"2b00000000000000000000000000000000000000000000000000000000000000");
+        assert!(!groth16::verify_groth16_proof(&groth16::bn254(), &pvk, &inputs, &proof), 2);
+    }
+
+    #[test]
+    fun test_groth16_verify_bls12381() {
+        // Simulated proof for a single public input 42, generated with the trapdoor of a random
+        // verifying key.
+        let vk = x"ed08b9948c9a824ee69302bc24c247fc896b98bf98391c25a2b4d844fc88b0d2a0b053fbbb8a72f9b7306b91655c6e9512020decfe00b3e6cfa635edcca7d2474c340dafae8c73280b61fc902b532d898c2857a224982ac72dc58a46bdc85f0111431660542f0d77b1dce11ca3620de1423bad1c7ca05ed697d420d16361c5e2ac8e1c5b5e8ab7ec56e395ec3e1fa280251ea372360d8141c07d5e65d3af5ee5b25ca51832cdf578d069cf85fb5849f59a83eefcdbb958e5d3885a57eb64a11917ed7a239a6e8d7afcb88a6c9571e50d51e82cf07dd748fce4777d125220ddd6167c56fb23d561a5bb57a22847553201b475e2f8ba96e22ed783421f68387afe84c07cabf8e3c3e5746482d3762e1e3bac43d29730959768d5ce15096614f20605f5b4b605a6bb3f6cfc838e3d6853bd6dd7e56ecfabeaa4f934c6ad556ed815241478bf3e8095e2ace285300531f08d020000000000000026d7b7a7a7f93cb9361c8b310d3a4460ef2f974726e88ba78d6c1a3f0a984d38e501093723ad4bada4bd437d9d8cfa196d0ff260da0784999f96136470613914d8a0776055ad1e7dc1fa0d1342e8ac4090471fa2c2d83b5c32eb9c5aa5a6ef03";
+        let pvk = groth16::prepare_verifying_key(&groth16::bls12381(), &vk);
+
+        let inputs = groth16::public_proof_inputs_from_bytes(x"2a00000000000000000000000000000000000000000000000000000000000000");
+        let proof = groth16::proof_points_from_bytes(x"3fc6768e63cc7f4fb735964a854da220c3dc5ce3867f593c862083c64bc877ecc5f7bc0f0a038351db4d8fe3687b620d00b12ea46c59f43616f82383b95863aff78445e8ddb6d0de4bf1dfb014e0408b97a47fb9c3125545429933989df39903354b52ed157bf50b6c22bb3c72ea6480d7ebd1fb594ced1c3472849445857d3ffb34329c0c0c4d3d8c1bde9d3a402b0d5d1dc462fa392e84a29ec66b95dcf5aab73cf56f839c5fce710a3206d4ec42694f1366ca810004d72bf6462ebbcba08e");
+        assert!(groth16::verify_groth16_proof(&groth16::bls12381(), &pvk, &inputs, &proof), 0);
+
+        // The proof is not valid on the other curve.
+        assert!(!groth16::verify_groth16_proof(&groth16::bn254(), &pvk, &inputs, &proof), 1);
+
+        // The proof does not hold for a different public input.
+        let inputs = groth16::public_proof_inputs_from_bytes(x"2b00000000000000000000000000000000000000000000000000000000000000");
+        assert!(!groth16::verify_groth16_proof(&groth16::bls12381(), &pvk, &inputs, &proof), 2);
+    }
+
+    #[test]
+    fun test_groth16_pvk_bytes_roundtrip() {
+        let vk = x"e7d4c8859d08900e8dba276730aba5c154bce7c450d21fa57ab9a0e1a8663e294044161a801cd2dddf6949ecec1b1070910f0b2e199a414dfbdb37b304ccc60999e7005afe3431dac2a94263489f1768a2c5b85d0bf73e7a4797af55c11ea7277a8b528e04c0977743f14cdb0fe74d0f535a2b29f422964130f012e38b99bc1cf791886cee71f0c199c328efdd99a1822b328bdbf7d575770b2b09f2268c9980a40a4b34e66d244a85a94bcc95d6b9b6d608f25a969baa98cf39b1d6cfef18208a66fe14de73be42b6bf6824c970c0c302387cae7408f87267281d1b327936a00200000000000000772807bc4d52bf83092886a18aa09d076c8feb94a9fea36ae30cda60a73bca14efb37c4c9162f1b1ee7c62433242af967a7677e917fbcd8fc3226fce2a9d4395";
+        let pvk = groth16::prepare_verifying_key(&groth16::bn254(), &vk);
+        let bytes = groth16::pvk_to_bytes(pvk);
+        let delta_g2_neg_bytes = vector::pop_back(&mut bytes);
+        let gamma_g2_neg_bytes = vector::pop_back(&mut bytes);
+        let alpha_g1_beta_g2_bytes = vector::pop_back(&mut bytes);
+        let vk_gamma_abc_g1_bytes = vector::pop_back(&mut bytes);
+        let pvk2 = groth16::pvk_from_bytes(
+            vk_gamma_abc_g1_bytes,
+            alpha_g1_beta_g2_bytes,
+            gamma_g2_neg_bytes,
+            delta_g2_neg_bytes,
+        );
+        assert!(pvk2 == pvk, 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0)]
+    fun test_groth16_invalid_verifying_key() {
+        let vk = x"e7d4c8859d08900e8dba276730aba5c154bce7c450d21fa57ab9a0e1a8663e294044161a801cd2dddf6949ecec1b1070910f0b2e199a414dfbdb37b304ccc60999e7005afe3431dac2a94263489f1768a2c5b85d0bf73e7a4797af55c11ea7277a8b528e04c0977743f14cdb0fe74d0f535a2b29f422964130f012e38b99bc1cf791886cee71f0c199c328efdd99a1822b328bdbf7d575770b2b09f2268c9980a40a4b34e66d244a85a94bcc95d6b9b6d608f25a969baa98cf39b1d6cfef18208a66fe14de73be42b6bf6824c970c0c302387cae7408f87267281d1b327936a00200000000000000772807bc4d52bf83092886a18aa09d076c8feb94a9fea36ae30cda60a73bca14efb37c4c9162f1b1ee7c62433242af967a7677e917fbcd8fc3226fce2a9d43";
+        groth16::prepare_verifying_key(&groth16::bn254(), &vk);
+    }
+
+    #[test]
+    fun test_groth16_invalid_proof_length() {
+        let vk = x"e7d4c8859d08900e8dba276730aba5c154bce7c450d21fa57ab9a0e1a8663e294044161a801cd2dddf6949ecec1b1070910f0b2e199a414dfbdb37b304ccc60999e7005afe3431dac2a94263489f1768a2c5b85d0bf73e7a4797af55c11ea7277a8b528e04c0977743f14cdb0fe74d0f535a2b29f422964130f012e38b99bc1cf791886cee71f0c199c328efdd99a1822b328bdbf7d575770b2b09f2268c9980a40a4b34e66d244a85a94bcc95d6b9b6d608f25a969baa98cf39b1d6cfef18208a66fe14de73be42b6bf6824c970c0c302387cae7408f87267281d1b327936a00200000000000000772807bc4d52bf83092886a18aa09d076c8feb94a9fea36ae30cda60a73bca14efb37c4c9162f1b1ee7c62433242af967a7677e917fbcd8fc3226fce2a9d4395";
+        let pvk = groth16::prepare_verifying_key(&groth16::bn254(), &vk);
+        let inputs = groth16::public_proof_inputs_from_bytes(x"2a00000000000000000000000000000000000000000000000000000000000000");
+        let proof = groth16::proof_points_from_bytes(x"8ea63dc2af5b36b6010b2b937ba47c464434b487d9b5350dfc516e515aaa9c1fdd4daeb45d4efb4835e3d78aaf12e4d0ea4b2b41dd1fbf27e94914d9197cd61f7a12927f51e797edf462b5620b95b0691aed8c0584f8cca7778d7d0f823bde036192842d8cdee90485d4a906d33f24e34f6b5edf12b0d94c8cdb056c1e0dcd");
+        assert!(!groth16::verify_groth16_proof(&groth16::bn254(), &pvk, &inputs, &proof), 0);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 2)]
+    fun test_groth16_too_many_public_inputs() {
+        let vk = x"e7d4c8859d08900e8dba276730aba5c154bce7c450d21fa57ab9a0e1a8663e294044161a801cd2dddf6949ecec1b1070910f0b2e199a414dfbdb37b304ccc60999e7005afe3431dac2a94263489f1768a2c5b85d0bf73e7a4797af55c11ea7277a8b528e04c0977743f14cdb0fe74d0f535a2b29f422964130f012e38b99bc1cf791886cee71f0c199c328efdd99a1822b328bdbf7d575770b2b09f2268c9980a40a4b34e66d244a85a94bcc95d6b9b6d608f25a969baa98cf39b1d6cfef18208a66fe14de73be42b6bf6824c970c0c302387cae7408f87267281d1b327936a00200000000000000772807bc4d52bf83092886a18aa09d076c8feb94a9fea36ae30cda60a73bca14efb37c4c9162f1b1ee7c62433242af967a7677e917fbcd8fc3226fce2a9d4395";
+        let pvk = groth16::prepare_verifying_key(&groth16::bn254(), &vk);
+        let inputs = groth16::public_proof_inputs_from_bytes(x"2a000000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002a000000000000000000000000000000000000000000000000000000000000002a00000000000000000000000000000000000000000000000000000000000000");
+        let proof = groth16::proof_points_from_bytes(x"8ea63dc2af5b36b6010b2b937ba47c464434b487d9b5350dfc516e515aaa9c1fdd4daeb45d4efb4835e3d78aaf12e4d0ea4b2b41dd1fbf27e94914d9197cd61f7a12927f51e797edf462b5620b95b0691aed8c0584f8cca7778d7d0f823bde036192842d8cdee90485d4a906d33f24e34f6b5edf12b0d94c8cdb056c1e0dcd16");
+        groth16::verify_groth16_proof(&groth16::bn254(), &pvk, &inputs, &proof);
+    }
+
     // Helper Move function to recover signature directly to an ETH address.
     fun ecrecover_eth_address(sig: vector<u8>, hashed_msg: vector<u8>): vector<u8> {
         // Normalize the last byte of the signature to be 0 or 1.
@@ -90,6 +90,26 @@ module sui::rewards_distribution_tests {
         test_scenario::end(scenario_val);
     }
 
+    #[test]
+    fun test_delegation_rewards_with_large_amounts() {
+        let scenario_val = test_scenario::begin(VALIDATOR_ADDR_1);
+        let scenario = &mut scenario_val;
+        set_up_sui_system_state(scenario);
+
+        // need to advance epoch so validator's staking starts counting
+        governance_test_utils::advance_epoch(scenario);
+
+        delegate_to(DELEGATOR_ADDR_1, VALIDATOR_ADDR_1, 10000000000, scenario);
+        governance_test_utils::advance_epoch(scenario);
+
+        // The delegation stake times the computation charge does not fit in a u64.
+        advance_epoch_with_reward_amounts(0, 10000000000, scenario);
+        governance_test_utils::advance_epoch(scenario);
+        undelegate(DELEGATOR_ADDR_1, 0, 0, 10000000000, scenario);
+        assert!(total_sui_balance(DELEGATOR_ADDR_1, scenario) == 19999999000, 0);
+        test_scenario::end(scenario_val);
+    }
+
     fun set_up_sui_system_state(scenario: &mut Scenario) {
         let ctx = test_scenario::ctx(scenario);
 
@@ -22,7 +22,7 @@ use sui_sdk::SuiClient;
 use sui_types::base_types::ObjectID;
 use sui_types::base_types::TransactionDigest;
 use sui_types::gas_coin::GAS;
-use sui_types::messages::ExecuteTransactionRequestType;
+use sui_types::messages::{ExecuteTransactionRequestType, TransactionExpiration};
 use sui_types::query::{Ordering, TransactionQuery};
 use sui_types::sui_serde::Base64;
 use sui_types::SUI_FRAMEWORK_ADDRESS;
@@ -40,8 +40,26 @@ async fn test_get_objects() -> Result<(), anyhow::Error> {
     let address = cluster.accounts.first().unwrap();
 
     http_client.sync_account_state(*address).await?;
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
-    assert_eq!(5, objects.len());
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None)
+        .await?;
+    assert_eq!(5, objects.data.len());
+    assert!(!objects.has_next_page);
+
+    // Page through the same objects two at a time
+    let page = http_client
+        .get_objects_owned_by_address(*address, None, Some(2))
+        .await?;
+    assert_eq!(objects.data[..2], page.data[..]);
+    assert!(page.has_next_page);
+    assert_eq!(Some(objects.data[2].object_id), page.next_cursor);
+
+    let page = http_client
+        .get_objects_owned_by_address(*address, page.next_cursor, Some(4))
+        .await?;
+    assert_eq!(objects.data[2..], page.data[..]);
+    assert!(!page.has_next_page);
+    assert_eq!(None, page.next_cursor);
     Ok(())
 }
 
@@ -55,7 +73,10 @@ async fn test_public_transfer_object() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client().unwrap();
     let address = cluster.accounts.first().unwrap();
     http_client.sync_account_state(*address).await?;
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None)
+        .await?
+        .data;
 
     let transaction_bytes: TransactionBytes = http_client
         .transfer_object(
@@ -64,6 +85,7 @@ async fn test_public_transfer_object() -> Result<(), anyhow::Error> {
             Some(objects.last().unwrap().object_id),
             1000,
             *address,
+            None,
         )
         .await?;
 
@@ -92,7 +114,10 @@ async fn test_publish() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client().unwrap();
     let address = cluster.accounts.first().unwrap();
     http_client.sync_account_state(*address).await?;
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None)
+        .await?
+        .data;
     let gas = objects.first().unwrap();
 
     let compiled_modules = build_move_package_to_bytes(
@@ -104,7 +129,7 @@ async fn test_publish() -> Result<(), anyhow::Error> {
     .collect::<Vec<_>>();
 
     let transaction_bytes: TransactionBytes = http_client
-        .publish(*address, compiled_modules, Some(gas.object_id), 10000)
+        .publish(*address, compiled_modules, Some(gas.object_id), 10000, None)
         .await?;
 
     let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
@@ -129,7 +154,10 @@ async fn test_move_call() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client().unwrap();
     let address = cluster.accounts.first().unwrap();
     http_client.sync_account_state(*address).await?;
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None)
+        .await?
+        .data;
     let gas = objects.first().unwrap();
     let coin = &objects[1];
 
@@ -153,6 +181,7 @@ async fn test_move_call() -> Result<(), anyhow::Error> {
             json_args,
             Some(gas.object_id),
             10_000,
+            None,
         )
         .await?;
 
@@ -180,7 +209,10 @@ async fn test_get_object_info() -> Result<(), anyhow::Error> {
     let http_client = cluster.rpc_client().unwrap();
     let address = cluster.accounts.first().unwrap();
     http_client.sync_account_state(*address).await?;
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None)
+        .await?
+        .data;
 
     for oref in objects {
         let result: GetObjectDataResponse = http_client.get_object(oref.object_id).await?;
@@ -203,14 +235,17 @@ async fn test_get_transaction() -> Result<(), anyhow::Error> {
 
     http_client.sync_account_state(*address).await?;
 
-    let objects = http_client.get_objects_owned_by_address(*address).await?;
+    let objects = http_client
+        .get_objects_owned_by_address(*address, None, None)
+        .await?
+        .data;
     let gas_id = objects.last().unwrap().object_id;
 
     // Make some transactions
     let mut tx_responses = Vec::new();
     for oref in &objects[..objects.len() - 1] {
         let transaction_bytes: TransactionBytes = http_client
-            .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
+            .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address, None)
             .await?;
         let keystore_path = cluster.swarm.dir().join(SUI_KEYSTORE_FILENAME);
         let keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
@@ -271,7 +306,14 @@ async fn test_get_fullnode_transaction() -> Result<(), anyhow::Error> {
         for oref in &objects[..objects.len() - 1] {
             let data = client
                 .transaction_builder()
-                .transfer_object(*address, oref.object_id, Some(gas_id), 1000, *address)
+                .transfer_object(
+                    *address,
+                    oref.object_id,
+                    Some(gas_id),
+                    1000,
+                    *address,
+                    TransactionExpiration::None,
+                )
                 .await?;
             let tx = to_sender_signed_transaction(data, keystore.get_key(address).unwrap());
 
@@ -35,16 +35,18 @@ use sui_types::base_types::{
 };
 use sui_types::committee::EpochId;
 use sui_types::crypto::{AuthorityStrongQuorumSignInfo, SignableBytes, Signature};
+use sui_types::dynamic_field::DynamicFieldInfo;
 use sui_types::error::SuiError;
 use sui_types::event::{Event, TransferType};
-use sui_types::event::{EventEnvelope, EventType};
+use sui_types::event::{EventEnvelope, EventID, EventType};
 use sui_types::filter::{EventFilter, TransactionFilter};
 use sui_types::gas::GasCostSummary;
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::{
-    CallArg, CertifiedTransaction, CertifiedTransactionEffects, ExecuteTransactionResponse,
-    ExecutionStatus, InputObjectKind, MoveModulePublish, ObjectArg, Pay, SingleTransactionKind,
-    TransactionData, TransactionEffects, TransactionKind,
+    BatchResult, CallArg, CertifiedTransaction, CertifiedTransactionEffects,
+    ExecuteTransactionResponse, ExecutionStatus, InputObjectKind, MoveModulePublish,
+    MoveModuleUpgrade, ObjectArg, Pay, PayBatchResults, SingleTransactionKind, TransactionData,
+    TransactionEffects, TransactionExpiration, TransactionKind,
 };
 use sui_types::messages_checkpoint::CheckpointSequenceNumber;
 use sui_types::move_package::{disassemble_modules, MovePackage};
@@ -60,6 +62,35 @@ mod rpc_types_tests;
 
 pub type SuiMoveTypeParameterIndex = u16;
 pub type TransactionsPage = Page<TransactionDigest, TransactionDigest>;
+pub type CoinPage = Page<SuiCoin, ObjectID>;
+pub type ObjectsPage = Page<SuiObjectInfo, ObjectID>;
+pub type EventPage = Page<SuiEventEnvelope, EventID>;
+pub type DynamicFieldPage = Page<DynamicFieldInfo, ObjectID>;
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, PartialEq, Eq, Clone)]
+#[serde(rename = "Balance", rename_all = "camelCase")]
+pub struct SuiBalance {
+    pub coin_type: String,
+    pub coin_object_count: usize,
+    pub total_balance: u128,
+}
+
+#[derive(Serialize, Deserialize, Debug, JsonSchema, PartialEq, Eq, Clone)]
+#[serde(rename = "Coin", rename_all = "camelCase")]
+pub struct SuiCoin {
+    pub coin_type: String,
+    pub coin_object_id: ObjectID,
+    pub version: SequenceNumber,
+    pub digest: ObjectDigest,
+    pub balance: u64,
+    pub previous_transaction: TransactionDigest,
+}
+
+impl SuiCoin {
+    pub fn object_ref(&self) -> ObjectRef {
+        (self.coin_object_id, self.version, self.digest)
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug, JsonSchema)]
 pub enum SuiMoveAbility {
@@ -493,7 +524,9 @@ impl TryInto<Object> for SuiObject<SuiRawData> {
                     )
                 })
             }
-            SuiRawData::Package(p) => Data::Package(MovePackage::new(p.id, &p.module_map)),
+            SuiRawData::Package(p) => {
+                Data::Package(MovePackage::new(p.id, &p.module_map).with_lineage(p.lineage))
+            }
         };
         Ok(Object {
             data,
@@ -853,6 +886,9 @@ pub struct SuiRawMovePackage {
     #[schemars(with = "BTreeMap<String, Base64>")]
     #[serde_as(as = "BTreeMap<_, Base64>")]
     pub module_map: BTreeMap<String, Vec<u8>>,
+    /// The IDs of the previous versions of this package, from the originally published one.
+    #[serde(default)]
+    pub lineage: Vec<ObjectID>,
 }
 
 impl From<MovePackage> for SuiRawMovePackage {
@@ -860,6 +896,7 @@ impl From<MovePackage> for SuiRawMovePackage {
         Self {
             id: p.id(),
             module_map: p.serialized_module_map().clone(),
+            lineage: p.lineage().to_vec(),
         }
     }
 }
@@ -1355,6 +1392,28 @@ impl TryFrom<MoveModulePublish> for SuiMovePackage {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename = "MovePackageUpgrade")]
+pub struct SuiMovePackageUpgrade {
+    /// The package being upgraded
+    pub package: SuiObjectRef,
+    /// The `UpgradeCap` authorizing the upgrade
+    pub upgrade_cap: SuiObjectRef,
+    pub disassembled: BTreeMap<String, Value>,
+}
+
+impl TryFrom<MoveModuleUpgrade> for SuiMovePackageUpgrade {
+    type Error = anyhow::Error;
+
+    fn try_from(m: MoveModuleUpgrade) -> Result<Self, Self::Error> {
+        Ok(Self {
+            package: m.package.into(),
+            upgrade_cap: m.upgrade_cap.into(),
+            disassembled: disassemble_modules(m.modules.iter())?,
+        })
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Eq, PartialEq)]
 #[serde(rename = "Pay")]
 pub struct SuiPay {
@@ -1385,6 +1444,12 @@ pub struct SuiTransactionData {
     pub sender: SuiAddress,
     pub gas_payment: SuiObjectRef,
     pub gas_budget: u64,
+    /// The address paying for gas, if it is not the sender.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sponsor: Option<SuiAddress>,
+    /// The last epoch in which the transaction can be executed, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<EpochId>,
 }
 
 impl Display for SuiTransactionData {
@@ -1421,6 +1486,11 @@ impl TryFrom<TransactionData> for SuiTransactionData {
             sender: data.signer(),
             gas_payment: data.gas().into(),
             gas_budget: data.gas_budget,
+            sponsor: data.sponsor(),
+            expiration: match data.expiration() {
+                TransactionExpiration::None => None,
+                TransactionExpiration::Epoch(epoch) => Some(epoch),
+            },
         })
     }
 }
@@ -1440,6 +1510,12 @@ pub enum SuiTransactionKind {
     TransferSui(SuiTransferSui),
     /// A system transaction that will update epoch information on-chain.
     ChangeEpoch(SuiChangeEpoch),
+    /// Upgrade a published Move package
+    Upgrade(SuiMovePackageUpgrade),
+    /// Transfer an object created by an earlier step of the batch
+    TransferBatchResult(SuiTransferBatchResult),
+    /// Pay one or more recipients from coins created by earlier steps of the batch
+    PayBatchResults(SuiPayBatchResults),
     // .. more transaction types go here
 }
 
@@ -1503,6 +1579,38 @@ impl Display for SuiTransactionKind {
                 writeln!(writer, "Storage gas reward: {}", e.storage_charge)?;
                 writeln!(writer, "Computation gas reward: {}", e.computation_charge)?;
             }
+            Self::Upgrade(u) => {
+                writeln!(writer, "Transaction Kind : Upgrade")?;
+                write!(
+                    writer,
+                    "Package ID : {}",
+                    u.package.object_id.to_hex_literal()
+                )?;
+            }
+            Self::TransferBatchResult(t) => {
+                writeln!(writer, "Transaction Kind : Transfer Batch Result")?;
+                writeln!(writer, "Recipient : {}", t.recipient)?;
+                write!(
+                    writer,
+                    "Object : Result({}, {})",
+                    t.object.step, t.object.index
+                )?;
+            }
+            Self::PayBatchResults(p) => {
+                writeln!(writer, "Transaction Kind : Pay Batch Results")?;
+                writeln!(writer, "Coins:")?;
+                for coin in &p.coins {
+                    writeln!(writer, "Result({}, {})", coin.step, coin.index)?;
+                }
+                writeln!(writer, "Recipients:")?;
+                for recipient in &p.recipients {
+                    writeln!(writer, "{}", recipient)?;
+                }
+                writeln!(writer, "Amounts:")?;
+                for amount in &p.amounts {
+                    writeln!(writer, "{}", amount)?
+                }
+            }
         }
         write!(f, "{}", writer)
     }
@@ -1547,6 +1655,9 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
                                 })
                                 .collect(),
                         )),
+                        CallArg::Result(result) => {
+                            SuiJsonValue::new(Value::String(result.to_string()))
+                        }
                     })
                     .collect::<Result<Vec<_>, _>>()?,
             }),
@@ -1555,6 +1666,14 @@ impl TryFrom<SingleTransactionKind> for SuiTransactionKind {
                 storage_charge: e.storage_charge,
                 computation_charge: e.computation_charge,
             }),
+            SingleTransactionKind::Upgrade(u) => Self::Upgrade(u.try_into()?),
+            SingleTransactionKind::TransferBatchResult(t) => {
+                Self::TransferBatchResult(SuiTransferBatchResult {
+                    recipient: t.recipient,
+                    object: t.object.into(),
+                })
+            }
+            SingleTransactionKind::PayBatchResults(p) => Self::PayBatchResults(p.into()),
         })
     }
 }
@@ -1585,6 +1704,9 @@ pub struct SuiCertifiedTransaction {
     pub data: SuiTransactionData,
     /// tx_signature is signed by the transaction sender, applied on `data`.
     pub tx_signature: Signature,
+    /// sponsor_signature is signed by the gas sponsor, if any, applied on `data`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sponsor_signature: Option<Signature>,
     /// authority signature information, if available, is signed by an authority, applied on `data`.
     pub auth_sign_info: AuthorityStrongQuorumSignInfo,
 }
@@ -1594,6 +1716,9 @@ impl Display for SuiCertifiedTransaction {
         let mut writer = String::new();
         writeln!(writer, "Transaction Hash: {:?}", self.transaction_digest)?;
         writeln!(writer, "Transaction Signature: {:?}", self.tx_signature)?;
+        if let Some(sponsor_signature) = &self.sponsor_signature {
+            writeln!(writer, "Sponsor Signature: {:?}", sponsor_signature)?;
+        }
         writeln!(
             writer,
             "Signed Authorities Bitmap: {:?}",
@@ -1612,6 +1737,7 @@ impl TryFrom<CertifiedTransaction> for SuiCertifiedTransaction {
             transaction_digest: *cert.digest(),
             data: cert.signed_data.data.try_into()?,
             tx_signature: cert.signed_data.tx_signature,
+            sponsor_signature: cert.signed_data.sponsor_signature,
             auth_sign_info: cert.auth_sign_info,
         })
     }
@@ -1858,6 +1984,8 @@ pub struct SuiEventEnvelope {
     pub timestamp: u64,
     /// Transaction digest of associated transaction, if any
     pub tx_digest: Option<TransactionDigest>,
+    /// Sequence number of the event, can be used as a cursor to resume an event subscription
+    pub id: EventID,
     /// Specific event type
     pub event: SuiEvent,
 }
@@ -2160,6 +2288,54 @@ pub struct SuiTransferSui {
     pub amount: Option<u64>,
 }
 
+/// An object created by an earlier step of a batch transaction
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "BatchResult")]
+pub struct SuiBatchResult {
+    /// The index of the step that created the object
+    pub step: u16,
+    /// The index of the object among the objects created by the step, in creation order
+    pub index: u16,
+}
+
+impl From<BatchResult> for SuiBatchResult {
+    fn from(result: BatchResult) -> Self {
+        Self {
+            step: result.step,
+            index: result.index,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename = "TransferBatchResult", rename_all = "camelCase")]
+pub struct SuiTransferBatchResult {
+    pub recipient: SuiAddress,
+    pub object: SuiBatchResult,
+}
+
+#[derive(Debug, Deserialize, Serialize, JsonSchema, Clone, Eq, PartialEq)]
+#[serde(rename = "PayBatchResults")]
+pub struct SuiPayBatchResults {
+    /// The coins to be used for payment
+    pub coins: Vec<SuiBatchResult>,
+    /// The addresses that will receive payment
+    pub recipients: Vec<SuiAddress>,
+    /// The amounts each recipient will receive.
+    /// Must be the same length as amounts
+    pub amounts: Vec<u64>,
+}
+
+impl From<PayBatchResults> for SuiPayBatchResults {
+    fn from(p: PayBatchResults) -> Self {
+        SuiPayBatchResults {
+            coins: p.coins.into_iter().map(|c| c.into()).collect(),
+            recipients: p.recipients,
+            amounts: p.amounts,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(rename = "InputObjectKind")]
 pub enum SuiInputObjectKind {
@@ -2282,14 +2458,55 @@ pub struct MoveCallParams {
 #[serde(rename = "SuiTransactionFilter")]
 pub enum SuiTransactionFilter {
     Any,
+    MoveFunction {
+        package: ObjectID,
+        module: Option<String>,
+        function: Option<String>,
+    },
+    InputObject(ObjectID),
+    MutatedObject(ObjectID),
+    FromAddress(SuiAddress),
+    ToAddress(SuiAddress),
+    /// `true` to match successfully executed transactions, `false` to match failed ones.
+    ExecutionSucceeded(bool),
+    MatchAll(Vec<SuiTransactionFilter>),
+    MatchAny(Vec<SuiTransactionFilter>),
 }
 
-impl From<SuiTransactionFilter> for TransactionFilter {
-    fn from(filter: SuiTransactionFilter) -> Self {
+impl TryInto<TransactionFilter> for SuiTransactionFilter {
+    type Error = anyhow::Error;
+
+    fn try_into(self) -> Result<TransactionFilter, anyhow::Error> {
         use SuiTransactionFilter::*;
-        match filter {
+        Ok(match self {
             Any => TransactionFilter::Any,
-        }
+            MoveFunction {
+                package,
+                module,
+                function,
+            } => TransactionFilter::MoveFunction {
+                package,
+                module: module.map(Identifier::new).transpose()?,
+                function: function.map(Identifier::new).transpose()?,
+            },
+            InputObject(id) => TransactionFilter::InputObject(id),
+            MutatedObject(id) => TransactionFilter::MutatedObject(id),
+            FromAddress(address) => TransactionFilter::FromAddress(address),
+            ToAddress(address) => TransactionFilter::ToAddress(address),
+            ExecutionSucceeded(success) => TransactionFilter::ExecutionSucceeded(success),
+            MatchAll(filters) => TransactionFilter::MatchAll(
+                filters
+                    .into_iter()
+                    .map(SuiTransactionFilter::try_into)
+                    .collect::<Result<_, _>>()?,
+            ),
+            MatchAny(filters) => TransactionFilter::MatchAny(
+                filters
+                    .into_iter()
+                    .map(SuiTransactionFilter::try_into)
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
     }
 }
 
@@ -2305,8 +2522,16 @@ pub enum SuiEventFilter {
         value: Value,
     },
     SenderAddress(SuiAddress),
+    Recipient(Owner),
     EventType(EventType),
     ObjectId(ObjectID),
+    /// Events emitted by the given transaction
+    Transaction(TransactionDigest),
+    /// Events emitted within [start_time, end_time), in milliseconds since the UNIX epoch
+    TimeRange {
+        start_time: u64,
+        end_time: u64,
+    },
     All(Vec<SuiEventFilter>),
     Any(Vec<SuiEventFilter>),
     And(Box<SuiEventFilter>, Box<SuiEventFilter>),
@@ -2327,7 +2552,16 @@ impl TryInto<EventFilter> for SuiEventFilter {
             }
             MoveEventField { path, value } => EventFilter::MoveEventField { path, value },
             SenderAddress(address) => EventFilter::SenderAddress(address),
+            Recipient(owner) => EventFilter::Recipient(owner),
             ObjectId(id) => EventFilter::ObjectId(id),
+            Transaction(digest) => EventFilter::Transaction(digest),
+            TimeRange {
+                start_time,
+                end_time,
+            } => EventFilter::TimeRange {
+                start_time,
+                end_time,
+            },
             All(filters) => EventFilter::MatchAll(
                 filters
                     .into_iter()
@@ -2381,5 +2615,7 @@ impl TransactionBytes {
 #[serde(rename_all = "camelCase")]
 pub struct Page<T, C> {
     pub data: Vec<T>,
+    /// Cursor of the first item of the next page, pass it back to fetch the next page.
     pub next_cursor: Option<C>,
+    pub has_next_page: bool,
 }
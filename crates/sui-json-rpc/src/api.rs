@@ -8,22 +8,25 @@ use jsonrpsee_proc_macros::rpc;
 
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, GetPastObjectDataResponse, GetRawObjectDataResponse,
-    MoveFunctionArgType, RPCTransactionRequestParams, SuiEventEnvelope, SuiEventFilter,
-    SuiExecuteTransactionResponse, SuiGasCostSummary, SuiMoveNormalizedFunction,
-    SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo, SuiTransactionEffects,
-    SuiTransactionFilter, SuiTransactionResponse, SuiTypeTag, TransactionBytes, TransactionsPage,
+    CoinPage, DynamicFieldPage, EventPage, GetObjectDataResponse, GetPastObjectDataResponse,
+    GetRawObjectDataResponse, MoveFunctionArgType, ObjectsPage, RPCTransactionRequestParams,
+    SuiBalance, SuiEventEnvelope, SuiEventFilter, SuiExecuteTransactionResponse, SuiGasCostSummary,
+    SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct,
+    SuiTransactionEffects, SuiTransactionFilter, SuiTransactionResponse, SuiTypeTag,
+    TransactionBytes, TransactionsPage,
 };
 use sui_open_rpc_macros::open_rpc;
 use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, TransactionDigest};
 use sui_types::batch::TxSequenceNumber;
 use sui_types::committee::EpochId;
 use sui_types::crypto::SignatureScheme;
+use sui_types::event::EventID;
+use sui_types::governance::{Delegation, StakedSui};
 use sui_types::messages::CommitteeInfoResponse;
 use sui_types::messages::ExecuteTransactionRequestType;
-use sui_types::object::Owner;
 use sui_types::query::{Ordering, TransactionQuery};
 use sui_types::sui_serde::Base64;
+use sui_types::sui_system_state::{SuiSystemState, Validator};
 
 /// Maximum number of events returned in an event query.
 /// This is equivalent to EVENT_STORE_QUERY_MAX_LIMIT in `sui-storage` crate.
@@ -31,6 +34,10 @@ use sui_types::sui_serde::Base64;
 /// for document purposes.
 pub const MAX_RESULT_SIZE: usize = 4096;
 
+/// Maximum number of events returned in a single page of an event query, and the default page
+/// size. This must not exceed EVENT_STORE_QUERY_MAX_LIMIT in `sui-storage`.
+pub const EVENT_QUERY_MAX_LIMIT: usize = 100;
+
 #[open_rpc(namespace = "sui", tag = "Gateway Transaction Execution API")]
 #[rpc(server, client, namespace = "sui")]
 pub trait RpcGatewayApi {
@@ -70,7 +77,11 @@ pub trait RpcReadApi {
         &self,
         /// the owner's Sui address
         address: SuiAddress,
-    ) -> RpcResult<Vec<SuiObjectInfo>>;
+        /// Optional paging cursor
+        cursor: Option<ObjectID>,
+        /// Maximum item returned per page
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage>;
 
     /// Return the list of objects owned by an object.
     #[method(name = "getObjectsOwnedByObject")]
@@ -78,7 +89,23 @@ pub trait RpcReadApi {
         &self,
         /// the ID of the owner object
         object_id: ObjectID,
-    ) -> RpcResult<Vec<SuiObjectInfo>>;
+        /// Optional paging cursor
+        cursor: Option<ObjectID>,
+        /// Maximum item returned per page
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage>;
+
+    /// Return the list of dynamic field objects owned by an object.
+    #[method(name = "getDynamicFields")]
+    async fn get_dynamic_fields(
+        &self,
+        /// the ID of the parent object
+        parent_object_id: ObjectID,
+        /// Optional paging cursor
+        cursor: Option<ObjectID>,
+        /// Maximum item returned per page
+        limit: Option<usize>,
+    ) -> RpcResult<DynamicFieldPage>;
 
     /// Return the total number of transactions known to the server.
     #[method(name = "getTotalTransactionNumber")]
@@ -138,6 +165,8 @@ pub trait RpcFullNodeReadApi {
     async fn get_normalized_move_modules_by_package(
         &self,
         package: ObjectID,
+        /// optional version of the package in its lineage, starting at 1 when first published, default to the version with ID `package` if not specified.
+        version: Option<u64>,
     ) -> RpcResult<BTreeMap<String, SuiMoveNormalizedModule>>;
 
     /// Return a structured representation of Move module
@@ -166,6 +195,38 @@ pub trait RpcFullNodeReadApi {
         function_name: String,
     ) -> RpcResult<SuiMoveNormalizedFunction>;
 
+    /// Return the total coin balance for one coin type, owned by the address owner.
+    #[method(name = "getBalance")]
+    async fn get_balance(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+        /// optional fully qualified type names for the coin (e.g., 0x168da5bf1f48dafc111b0a488fa454aca95e0b5e::usdc::USDC), default to 0x2::sui::SUI if not specified.
+        coin_type: Option<String>,
+    ) -> RpcResult<SuiBalance>;
+
+    /// Return the total coin balance for all coin types, owned by the address owner.
+    #[method(name = "getAllBalances")]
+    async fn get_all_balances(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+    ) -> RpcResult<Vec<SuiBalance>>;
+
+    /// Return all coin objects of the given coin type owned by an address, sorted by object id.
+    #[method(name = "getCoins")]
+    async fn get_coins(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+        /// optional fully qualified type names for the coin (e.g., 0x168da5bf1f48dafc111b0a488fa454aca95e0b5e::usdc::USDC), default to 0x2::sui::SUI if not specified.
+        coin_type: Option<String>,
+        /// Optional paging cursor
+        cursor: Option<ObjectID>,
+        /// Maximum item returned per page
+        limit: Option<usize>,
+    ) -> RpcResult<CoinPage>;
+
     /// Return list of transactions for a specified query criteria.
     #[method(name = "getTransactions")]
     async fn get_transactions(
@@ -220,6 +281,8 @@ pub trait RpcTransactionBuilder {
         gas_budget: u64,
         /// the recipient's Sui address
         recipient: SuiAddress,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     /// Create an unsigned transaction to send SUI coin object to a Sui address. The SUI object is also used as the gas object.
@@ -236,6 +299,8 @@ pub trait RpcTransactionBuilder {
         recipient: SuiAddress,
         /// the amount to be split out and transferred
         amount: Option<u64>,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     #[method(name = "pay")]
@@ -253,6 +318,8 @@ pub trait RpcTransactionBuilder {
         gas: Option<ObjectID>,
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     /// Create an unsigned transaction to execute a Move call on the network, by calling the specified function in the module of a given package.
@@ -275,6 +342,8 @@ pub trait RpcTransactionBuilder {
         gas: Option<ObjectID>,
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     /// Create an unsigned transaction to publish Move module.
@@ -289,6 +358,8 @@ pub trait RpcTransactionBuilder {
         gas: Option<ObjectID>,
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     /// Create an unsigned transaction to split a coin object into multiple coins.
@@ -305,6 +376,8 @@ pub trait RpcTransactionBuilder {
         gas: Option<ObjectID>,
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     /// Create an unsigned transaction to split a coin object into multiple equal-size coins.
@@ -321,6 +394,8 @@ pub trait RpcTransactionBuilder {
         gas: Option<ObjectID>,
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     /// Create an unsigned transaction to merge multiple coins into one coin.
@@ -337,6 +412,8 @@ pub trait RpcTransactionBuilder {
         gas: Option<ObjectID>,
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 
     /// Create an unsigned batched transaction.
@@ -351,6 +428,8 @@ pub trait RpcTransactionBuilder {
         gas: Option<ObjectID>,
         /// the gas budget, the transaction will fail if the gas cost exceed the budget
         gas_budget: u64,
+        /// the last epoch in which the transaction can be executed, the transaction never expires if not provided
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes>;
 }
 
@@ -387,105 +466,28 @@ pub trait EventStreamingApi {
         &self,
         /// the filter criteria of the event stream, see the [Sui docs](https://docs.sui.io/build/pubsub#event-filters) for detailed examples.
         filter: SuiEventFilter,
+        /// optional ID of the first event to stream, stored events from this ID onwards are replayed before live events
+        cursor: Option<EventID>,
     );
 }
 
 #[open_rpc(namespace = "sui", tag = "Event Read API")]
 #[rpc(server, client, namespace = "sui")]
 pub trait EventReadApi {
-    /// Return events emitted by the given transaction.
-    #[method(name = "getEventsByTransaction")]
-    async fn get_events_by_transaction(
+    /// Return events matching the given filter, the same filters that can be used to subscribe
+    /// to events are accepted.
+    #[method(name = "getEvents")]
+    async fn get_events(
         &self,
-        /// digest of the transaction, as base-64 encoded string
-        digest: TransactionDigest,
-        /// maximum size of the result, capped to EVENT_QUERY_MAX_LIMIT
-        count: usize,
-    ) -> RpcResult<Vec<SuiEventEnvelope>>;
-
-    /// Return events emitted in a specified Move module
-    #[method(name = "getEventsByModule")]
-    async fn get_events_by_transaction_module(
-        &self,
-        /// the Move package ID
-        package: ObjectID,
-        /// the module name
-        module: String,
-        /// maximum size of the result, capped to EVENT_QUERY_MAX_LIMIT
-        count: usize,
-        /// left endpoint of time interval, inclusive
-        start_time: u64,
-        /// right endpoint of time interval, exclusive
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>>;
-
-    /// Return events with the given move event struct name
-    #[method(name = "getEventsByMoveEventStructName")]
-    async fn get_events_by_move_event_struct_name(
-        &self,
-        /// the event struct name type, e.g. `0x2::devnet_nft::MintNFTEvent` or `0x2::SUI::test_foo<address, vector<u8>>` with type params
-        move_event_struct_name: String,
-        /// maximum size of the result, capped to EVENT_QUERY_MAX_LIMIT
-        count: usize,
-        /// left endpoint of time interval, inclusive
-        start_time: u64,
-        /// right endpoint of time interval, exclusive
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>>;
-
-    /// Return events associated with the given sender.
-    #[method(name = "getEventsBySender")]
-    async fn get_events_by_sender(
-        &self,
-        /// the sender's Sui address
-        sender: SuiAddress,
-        /// maximum size of the result, capped to EVENT_QUERY_MAX_LIMIT
-        count: usize,
-        /// left endpoint of time interval, inclusive
-        start_time: u64,
-        /// right endpoint of time interval, exclusive
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>>;
-
-    /// Return events associated with the given recipient
-    #[method(name = "getEventsByRecipient")]
-    async fn get_events_by_recipient(
-        &self,
-        /// the recipient
-        recipient: Owner,
-        /// maximum size of the result, capped to EVENT_QUERY_MAX_LIMIT
-        count: usize,
-        /// left endpoint of time interval, inclusive
-        start_time: u64,
-        /// right endpoint of time interval, exclusive
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>>;
-
-    /// Return events associated with the given object
-    #[method(name = "getEventsByObject")]
-    async fn get_events_by_object(
-        &self,
-        /// the object ID
-        object: ObjectID,
-        /// maximum size of the result, capped to EVENT_QUERY_MAX_LIMIT
-        count: usize,
-        /// left endpoint of time interval, inclusive
-        start_time: u64,
-        /// right endpoint of time interval, exclusive
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>>;
-
-    /// Return events emitted in [start_time, end_time) interval
-    #[method(name = "getEventsByTimeRange")]
-    async fn get_events_by_timerange(
-        &self,
-        /// maximum size of the result, capped to EVENT_QUERY_MAX_LIMIT
-        count: usize,
-        /// left endpoint of time interval, inclusive
-        start_time: u64,
-        /// right endpoint of time interval, exclusive
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>>;
+        /// the event query criteria.
+        query: SuiEventFilter,
+        /// Optional paging cursor
+        cursor: Option<EventID>,
+        /// Maximum item returned per page, capped to EVENT_QUERY_MAX_LIMIT
+        limit: Option<usize>,
+        /// Query result ordering, default to false (ascending order), oldest record first.
+        descending_order: Option<bool>,
+    ) -> RpcResult<EventPage>;
 }
 
 #[open_rpc(namespace = "sui", tag = "APIs to execute transactions.")]
@@ -519,6 +521,21 @@ pub trait TransactionExecutionApi {
         /// The request type
         request_type: ExecuteTransactionRequestType,
     ) -> RpcResult<SuiExecuteTransactionResponse>;
+
+    /// Execute a transaction whose gas is paid by a sponsor, signed by both the sender and
+    /// the sponsor. Request types are the same as for `executeTransaction`.
+    #[method(name = "executeSponsoredTransaction")]
+    async fn execute_sponsored_transaction(
+        &self,
+        /// transaction data bytes, as base-64 encoded string
+        tx_bytes: Base64,
+        /// sender's `flag || signature || pubkey` bytes, as base-64 encoded string
+        signature: Base64,
+        /// sponsor's `flag || signature || pubkey` bytes, as base-64 encoded string
+        sponsor_signature: Base64,
+        /// The request type
+        request_type: ExecuteTransactionRequestType,
+    ) -> RpcResult<SuiExecuteTransactionResponse>;
 }
 
 #[open_rpc(
@@ -539,3 +556,37 @@ pub trait EstimatorApi {
         storage_rebate: Option<u64>,
     ) -> RpcResult<SuiGasCostSummary>;
 }
+
+#[open_rpc(namespace = "sui", tag = "Governance Read API")]
+#[rpc(server, client, namespace = "sui")]
+pub trait GovernanceReadApi {
+    /// Return the Sui system state object, holding the validator set, the stake, the gas prices
+    /// and the system parameters of the current epoch.
+    #[method(name = "getSuiSystemState")]
+    async fn get_sui_system_state(&self) -> RpcResult<SuiSystemState>;
+
+    /// Return the active validators of the current epoch, with their stake, delegations and gas
+    /// price.
+    #[method(name = "getValidators")]
+    async fn get_validators(&self) -> RpcResult<Vec<Validator>>;
+
+    /// Return the validators that will join the validator set at the next epoch.
+    #[method(name = "getPendingValidators")]
+    async fn get_pending_validators(&self) -> RpcResult<Vec<Validator>>;
+
+    /// Return the delegations owned by an address.
+    #[method(name = "getDelegations")]
+    async fn get_delegations(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+    ) -> RpcResult<Vec<Delegation>>;
+
+    /// Return the staked SUI objects owned by an address.
+    #[method(name = "getStakedSui")]
+    async fn get_staked_sui(
+        &self,
+        /// the owner's Sui address
+        owner: SuiAddress,
+    ) -> RpcResult<Vec<StakedSui>>;
+}
@@ -2,25 +2,24 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::api::EventReadApiServer;
 use crate::api::EventStreamingApiServer;
+use crate::api::EVENT_QUERY_MAX_LIMIT;
 use crate::streaming_api::spawn_subscription;
 use crate::SuiRpcModule;
+use anyhow::anyhow;
 use async_trait::async_trait;
 use futures::StreamExt;
 use jsonrpsee::core::RpcResult;
 use jsonrpsee::types::SubscriptionResult;
 use jsonrpsee_core::server::rpc_module::RpcModule;
 use jsonrpsee_core::server::rpc_module::SubscriptionSink;
-use move_core_types::account_address::AccountAddress;
-use move_core_types::identifier::Identifier;
-use move_core_types::language_storage::ModuleId;
-use std::str::FromStr;
+use std::cmp::min;
 use std::sync::Arc;
 use sui_core::authority::AuthorityState;
 use sui_core::event_handler::EventHandler;
-use sui_json_rpc_types::{SuiEvent, SuiEventEnvelope, SuiEventFilter};
+use sui_json_rpc_types::{EventPage, Page, SuiEvent, SuiEventEnvelope, SuiEventFilter};
 use sui_open_rpc::Module;
-use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest};
-use sui_types::object::Owner;
+use sui_types::event::EventID;
+use sui_types::filter::EventFilter;
 use tracing::warn;
 
 pub struct EventStreamingApiImpl {
@@ -43,6 +42,7 @@ impl EventStreamingApiServer for EventStreamingApiImpl {
         &self,
         mut sink: SubscriptionSink,
         filter: SuiEventFilter,
+        cursor: Option<EventID>,
     ) -> SubscriptionResult {
         let filter = match filter.try_into() {
             Ok(filter) => filter,
@@ -54,12 +54,20 @@ impl EventStreamingApiServer for EventStreamingApiImpl {
         };
 
         let state = self.state.clone();
-        let stream = self.event_handler.subscribe(filter);
+        let stream = match cursor {
+            Some(cursor) => self
+                .event_handler
+                .subscribe_from(filter, cursor)
+                .left_stream(),
+            None => self.event_handler.subscribe(filter).right_stream(),
+        };
         let stream = stream.map(move |e| {
+            let id = e.id();
             let event = SuiEvent::try_from(e.event, state.module_cache.as_ref());
             event.map(|event| SuiEventEnvelope {
                 timestamp: e.timestamp,
                 tx_digest: e.tx_digest,
+                id,
                 event,
             })
         });
@@ -97,107 +105,29 @@ impl EventReadApiImpl {
 #[allow(unused)]
 #[async_trait]
 impl EventReadApiServer for EventReadApiImpl {
-    async fn get_events_by_transaction(
-        &self,
-        digest: TransactionDigest,
-        count: usize,
-    ) -> RpcResult<Vec<SuiEventEnvelope>> {
-        let events = self.state.get_events_by_transaction(digest, count).await?;
-        Ok(events)
-    }
-
-    async fn get_events_by_transaction_module(
-        &self,
-        package: ObjectID,
-        module: String,
-        count: usize,
-        start_time: u64,
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>> {
-        let module_id = ModuleId::new(
-            AccountAddress::from(package),
-            Identifier::from_str(&module)?,
-        );
-
-        let events = self
-            .state
-            .get_events_by_transaction_module(&module_id, start_time, end_time, count)
-            .await?;
-        Ok(events)
-    }
-
-    async fn get_events_by_move_event_struct_name(
-        &self,
-        move_event_struct_name: String,
-        count: usize,
-        start_time: u64,
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>> {
-        let events = self
-            .state
-            .get_events_by_move_event_struct_name(
-                &move_event_struct_name,
-                start_time,
-                end_time,
-                count,
-            )
-            .await?;
-        Ok(events)
-    }
-
-    async fn get_events_by_sender(
+    async fn get_events(
         &self,
-        sender: SuiAddress,
-        count: usize,
-        start_time: u64,
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>> {
-        let events = self
-            .state
-            .get_events_by_sender(&sender, start_time, end_time, count)
-            .await?;
-        Ok(events)
-    }
-
-    async fn get_events_by_recipient(
-        &self,
-        recipient: Owner,
-        count: usize,
-        start_time: u64,
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>> {
-        let events = self
-            .state
-            .get_events_by_recipient(&recipient, start_time, end_time, count)
-            .await?;
-        Ok(events)
-    }
-
-    async fn get_events_by_object(
-        &self,
-        object: ObjectID,
-        count: usize,
-        start_time: u64,
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>> {
-        let events = self
-            .state
-            .get_events_by_object(&object, start_time, end_time, count)
-            .await?;
-        Ok(events)
-    }
+        query: SuiEventFilter,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> RpcResult<EventPage> {
+        let limit = limit.unwrap_or(EVENT_QUERY_MAX_LIMIT);
+        if limit == 0 {
+            Err(anyhow!("Page result limit must be larger then 0."))?;
+        }
+        let limit = min(limit, EVENT_QUERY_MAX_LIMIT);
+        let filter: EventFilter = query.try_into()?;
 
-    async fn get_events_by_timerange(
-        &self,
-        count: usize,
-        start_time: u64,
-        end_time: u64,
-    ) -> RpcResult<Vec<SuiEventEnvelope>> {
-        let events = self
+        let (data, next_cursor) = self
             .state
-            .get_events_by_timerange(start_time, end_time, count)
+            .get_events(&filter, cursor, limit, descending_order.unwrap_or_default())
             .await?;
-        Ok(events)
+        Ok(Page {
+            data,
+            has_next_page: next_cursor.is_some(),
+            next_cursor,
+        })
     }
 }
 
@@ -3,6 +3,7 @@
 
 use crate::api::{
     RpcGatewayApiServer, RpcReadApiServer, RpcTransactionBuilderServer, WalletSyncApiServer,
+    MAX_RESULT_SIZE,
 };
 use crate::SuiRpcModule;
 use anyhow::anyhow;
@@ -13,11 +14,12 @@ use signature::Signature;
 use sui_core::gateway_state::GatewayClient;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, RPCTransactionRequestParams, SuiObjectInfo, SuiTransactionResponse,
-    SuiTypeTag, TransactionBytes,
+    DynamicFieldPage, GetObjectDataResponse, ObjectsPage, Page, RPCTransactionRequestParams,
+    SuiObjectInfo, SuiTransactionResponse, SuiTypeTag, TransactionBytes,
 };
 use sui_open_rpc::Module;
 use sui_types::batch::TxSequenceNumber;
+use sui_types::committee::EpochId;
 use sui_types::crypto::SignatureScheme;
 use sui_types::sui_serde::Base64;
 use sui_types::{
@@ -124,17 +126,36 @@ impl RpcReadApiServer for GatewayReadApiImpl {
     async fn get_objects_owned_by_address(
         &self,
         address: SuiAddress,
-    ) -> RpcResult<Vec<SuiObjectInfo>> {
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
         debug!("get_objects_own_by_address : {}", address);
-        Ok(self.client.get_objects_owned_by_address(address).await?)
+        let objects = self.client.get_objects_owned_by_address(address).await?;
+        objects_page(objects, cursor, limit)
     }
 
     async fn get_objects_owned_by_object(
         &self,
         object_id: ObjectID,
-    ) -> RpcResult<Vec<SuiObjectInfo>> {
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
         debug!("get_objects_own_by_object : {}", object_id);
-        Ok(self.client.get_objects_owned_by_object(object_id).await?)
+        let objects = self.client.get_objects_owned_by_object(object_id).await?;
+        objects_page(objects, cursor, limit)
+    }
+
+    async fn get_dynamic_fields(
+        &self,
+        parent_object_id: ObjectID,
+        _cursor: Option<ObjectID>,
+        _limit: Option<usize>,
+    ) -> RpcResult<DynamicFieldPage> {
+        // The gateway does not keep track of dynamic fields
+        Err(
+            anyhow!("Dynamic fields of {parent_object_id} can only be queried from a full node.")
+                .into(),
+        )
     }
 
     async fn get_object(&self, object_id: ObjectID) -> RpcResult<GetObjectDataResponse> {
@@ -161,6 +182,34 @@ impl RpcReadApiServer for GatewayReadApiImpl {
     }
 }
 
+/// Pages through the objects returned by the gateway, which are sorted by object ID.
+fn objects_page(
+    objects: Vec<SuiObjectInfo>,
+    cursor: Option<ObjectID>,
+    limit: Option<usize>,
+) -> RpcResult<ObjectsPage> {
+    let limit = limit.unwrap_or(MAX_RESULT_SIZE);
+    if limit == 0 {
+        Err(anyhow!("Page result limit must be larger then 0."))?;
+    }
+
+    // Retrieve 1 extra item for next cursor
+    let mut data = objects
+        .into_iter()
+        .skip_while(|object| matches!(cursor, Some(cursor) if object.object_id < cursor))
+        .take(limit + 1)
+        .collect::<Vec<_>>();
+
+    // extract next cursor
+    let next_cursor = data.get(limit).map(|object| object.object_id);
+    data.truncate(limit);
+    Ok(Page {
+        data,
+        has_next_page: next_cursor.is_some(),
+        next_cursor,
+    })
+}
+
 impl SuiRpcModule for GatewayReadApiImpl {
     fn rpc(self) -> RpcModule<Self> {
         self.into_rpc()
@@ -180,12 +229,15 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         gas: Option<ObjectID>,
         gas_budget: u64,
         recipient: SuiAddress,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .client
             .public_transfer_object(signer, object_id, gas, gas_budget, recipient)
             .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn transfer_sui(
@@ -195,12 +247,15 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         gas_budget: u64,
         recipient: SuiAddress,
         amount: Option<u64>,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .client
             .transfer_sui(signer, sui_object_id, gas_budget, recipient, amount)
             .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn pay(
@@ -211,12 +266,15 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         amounts: Vec<u64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .client
             .pay(signer, input_coins, recipients, amounts, gas, gas_budget)
             .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn publish(
@@ -225,6 +283,7 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         compiled_modules: Vec<Base64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let compiled_modules = compiled_modules
             .into_iter()
@@ -235,7 +294,9 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
             .publish(sender, compiled_modules, gas, gas_budget)
             .await?;
 
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn split_coin(
@@ -245,12 +306,15 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         split_amounts: Vec<u64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .client
             .split_coin(signer, coin_object_id, split_amounts, gas, gas_budget)
             .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn split_coin_equal(
@@ -260,12 +324,15 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         split_count: u64,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .client
             .split_coin_equal(signer, coin_object_id, split_count, gas, gas_budget)
             .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn merge_coin(
@@ -275,12 +342,15 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         coin_to_merge: ObjectID,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .client
             .merge_coins(signer, primary_coin, coin_to_merge, gas, gas_budget)
             .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn move_call(
@@ -293,6 +363,7 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         rpc_arguments: Vec<SuiJsonValue>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = async {
             self.client
@@ -309,7 +380,9 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
                 .await
         }
         .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 
     async fn batch_transaction(
@@ -318,6 +391,7 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
         params: Vec<RPCTransactionRequestParams>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = async {
             self.client
@@ -325,7 +399,9 @@ impl RpcTransactionBuilderServer for TransactionBuilderImpl {
                 .await
         }
         .await?;
-        Ok(TransactionBytes::from_data(data)?)
+        Ok(TransactionBytes::from_data(
+            data.with_expiration(expiration_epoch.into()),
+        )?)
     }
 }
 
@@ -0,0 +1,76 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::api::GovernanceReadApiServer;
+use crate::SuiRpcModule;
+use anyhow::anyhow;
+use async_trait::async_trait;
+use jsonrpsee::core::RpcResult;
+use jsonrpsee_core::server::rpc_module::RpcModule;
+use std::sync::Arc;
+use sui_core::authority::AuthorityState;
+use sui_open_rpc::Module;
+use sui_types::base_types::SuiAddress;
+use sui_types::governance::{Delegation, StakedSui};
+use sui_types::sui_system_state::{SuiSystemState, Validator};
+
+pub struct GovernanceReadApiImpl {
+    state: Arc<AuthorityState>,
+}
+
+impl GovernanceReadApiImpl {
+    pub fn new(state: Arc<AuthorityState>) -> Self {
+        Self { state }
+    }
+}
+
+#[async_trait]
+impl GovernanceReadApiServer for GovernanceReadApiImpl {
+    async fn get_sui_system_state(&self) -> RpcResult<SuiSystemState> {
+        Ok(self
+            .state
+            .get_sui_system_state_object()
+            .await
+            .map_err(|e| anyhow!("{e}"))?)
+    }
+
+    async fn get_validators(&self) -> RpcResult<Vec<Validator>> {
+        Ok(self
+            .get_sui_system_state()
+            .await?
+            .validators
+            .active_validators)
+    }
+
+    async fn get_pending_validators(&self) -> RpcResult<Vec<Validator>> {
+        Ok(self
+            .get_sui_system_state()
+            .await?
+            .validators
+            .pending_validators)
+    }
+
+    async fn get_delegations(&self, owner: SuiAddress) -> RpcResult<Vec<Delegation>> {
+        Ok(self
+            .state
+            .get_delegations(owner)
+            .map_err(|e| anyhow!("{e}"))?)
+    }
+
+    async fn get_staked_sui(&self, owner: SuiAddress) -> RpcResult<Vec<StakedSui>> {
+        Ok(self
+            .state
+            .get_staked_sui(owner)
+            .map_err(|e| anyhow!("{e}"))?)
+    }
+}
+
+impl SuiRpcModule for GovernanceReadApiImpl {
+    fn rpc(self) -> RpcModule<Self> {
+        self.into_rpc()
+    }
+
+    fn rpc_doc_module() -> Module {
+        crate::api::GovernanceReadApiOpenRpc::module_doc()
+    }
+}
@@ -14,7 +14,8 @@ use signature::Signature;
 
 use sui_core::authority::AuthorityState;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, GetPastObjectDataResponse, MoveFunctionArgType, ObjectValueKind, Page,
+    CoinPage, DynamicFieldPage, GetObjectDataResponse, GetPastObjectDataResponse,
+    MoveFunctionArgType, ObjectValueKind, ObjectsPage, Page, SuiBalance, SuiCoin,
     SuiMoveNormalizedFunction, SuiMoveNormalizedModule, SuiMoveNormalizedStruct, SuiObjectInfo,
     SuiTransactionEffects, SuiTransactionResponse, TransactionsPage,
 };
@@ -24,11 +25,13 @@ use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest};
 use sui_types::batch::TxSequenceNumber;
 use sui_types::committee::EpochId;
 use sui_types::crypto::{SignableBytes, SignatureScheme};
+use sui_types::gas_coin::GAS;
 use sui_types::messages::{
     CommitteeInfoRequest, CommitteeInfoResponse, Transaction, TransactionData,
 };
 use sui_types::move_package::normalize_modules;
 use sui_types::object::{Data, ObjectRead, Owner};
+use sui_types::parse_sui_type_tag;
 use sui_types::query::{Ordering, TransactionQuery};
 use sui_types::sui_serde::Base64;
 
@@ -56,6 +59,37 @@ impl ReadApi {
     pub fn new(state: Arc<AuthorityState>) -> Self {
         Self { state }
     }
+
+    fn get_owner_objects_page(
+        &self,
+        owner: Owner,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        let limit = limit.unwrap_or(MAX_RESULT_SIZE);
+
+        if limit == 0 {
+            Err(anyhow!("Page result limit must be larger then 0."))?;
+        }
+
+        // Retrieve 1 extra item for next cursor
+        let mut data = self
+            .state
+            .get_owner_objects_page(owner, cursor, limit + 1)
+            .map_err(|e| anyhow!("{e}"))?
+            .into_iter()
+            .map(SuiObjectInfo::from)
+            .collect::<Vec<_>>();
+
+        // extract next cursor
+        let next_cursor = data.get(limit).map(|object| object.object_id);
+        data.truncate(limit);
+        Ok(Page {
+            data,
+            has_next_page: next_cursor.is_some(),
+            next_cursor,
+        })
+    }
 }
 
 #[async_trait]
@@ -63,27 +97,52 @@ impl RpcReadApiServer for ReadApi {
     async fn get_objects_owned_by_address(
         &self,
         address: SuiAddress,
-    ) -> RpcResult<Vec<SuiObjectInfo>> {
-        Ok(self
-            .state
-            .get_owner_objects(Owner::AddressOwner(address))
-            .map_err(|e| anyhow!("{e}"))?
-            .into_iter()
-            .map(SuiObjectInfo::from)
-            .collect())
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        self.get_owner_objects_page(Owner::AddressOwner(address), cursor, limit)
     }
 
     async fn get_objects_owned_by_object(
         &self,
         object_id: ObjectID,
-    ) -> RpcResult<Vec<SuiObjectInfo>> {
-        Ok(self
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<ObjectsPage> {
+        self.get_owner_objects_page(Owner::ObjectOwner(object_id.into()), cursor, limit)
+    }
+
+    async fn get_dynamic_fields(
+        &self,
+        parent_object_id: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<DynamicFieldPage> {
+        let limit = limit.unwrap_or(MAX_RESULT_SIZE);
+
+        if limit == 0 {
+            Err(anyhow!("Page result limit must be larger then 0."))?;
+        }
+
+        // Retrieve 1 extra item for next cursor
+        let mut fields = self
             .state
-            .get_owner_objects(Owner::ObjectOwner(object_id.into()))
-            .map_err(|e| anyhow!("{e}"))?
-            .into_iter()
-            .map(SuiObjectInfo::from)
-            .collect())
+            .get_dynamic_fields_page(parent_object_id, cursor, limit + 1)
+            .map_err(|e| anyhow!("{e}"))?;
+
+        // extract next cursor
+        let next_cursor = fields.get(limit).map(|field| field.object_id);
+        fields.truncate(limit);
+        let data = fields
+            .iter()
+            .map(|field| self.state.get_dynamic_field_info(field))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!("{e}"))?;
+        Ok(Page {
+            data,
+            has_next_page: next_cursor.is_some(),
+            next_cursor,
+        })
     }
 
     async fn get_object(&self, object_id: ObjectID) -> RpcResult<GetObjectDataResponse> {
@@ -159,7 +218,12 @@ impl RpcFullNodeReadApiServer for FullNodeApi {
     async fn get_normalized_move_modules_by_package(
         &self,
         package: ObjectID,
+        version: Option<u64>,
     ) -> RpcResult<BTreeMap<String, SuiMoveNormalizedModule>> {
+        let package = match version {
+            Some(version) => get_package_version(self, package, version).await?,
+            None => package,
+        };
         let modules = get_move_modules_by_package(self, package).await?;
         Ok(modules
             .into_iter()
@@ -285,7 +349,82 @@ impl RpcFullNodeReadApiServer for FullNodeApi {
         // extract next cursor
         let next_cursor = data.get(limit).cloned();
         data.truncate(limit);
-        Ok(Page { data, next_cursor })
+        Ok(Page {
+            data,
+            has_next_page: next_cursor.is_some(),
+            next_cursor,
+        })
+    }
+
+    async fn get_balance(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+    ) -> RpcResult<SuiBalance> {
+        let coin_type = normalize_coin_type(coin_type)?;
+        let (total_balance, coin_object_count) = self
+            .state
+            .get_balance(owner, coin_type.clone())
+            .map_err(|e| anyhow!("{e}"))?;
+        Ok(SuiBalance {
+            coin_type,
+            coin_object_count,
+            total_balance,
+        })
+    }
+
+    async fn get_all_balances(&self, owner: SuiAddress) -> RpcResult<Vec<SuiBalance>> {
+        Ok(self
+            .state
+            .get_all_balances(owner)
+            .map_err(|e| anyhow!("{e}"))?
+            .into_iter()
+            .map(|(coin_type, total_balance, coin_object_count)| SuiBalance {
+                coin_type,
+                coin_object_count,
+                total_balance,
+            })
+            .collect())
+    }
+
+    async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> RpcResult<CoinPage> {
+        let coin_type = normalize_coin_type(coin_type)?;
+        let limit = limit.unwrap_or(MAX_RESULT_SIZE);
+
+        if limit == 0 {
+            Err(anyhow!("Page result limit must be larger then 0."))?;
+        }
+
+        // Retrieve 1 extra item for next cursor
+        let mut data = self
+            .state
+            .get_coins(owner, coin_type.clone(), cursor, Some(limit + 1))
+            .map_err(|e| anyhow!("{e}"))?
+            .into_iter()
+            .map(|(coin_object_id, info)| SuiCoin {
+                coin_type: coin_type.clone(),
+                coin_object_id,
+                version: info.version,
+                digest: info.digest,
+                balance: info.balance,
+                previous_transaction: info.previous_transaction,
+            })
+            .collect::<Vec<_>>();
+
+        // extract next cursor
+        let next_cursor = data.get(limit).map(|coin| coin.coin_object_id);
+        data.truncate(limit);
+        Ok(Page {
+            data,
+            has_next_page: next_cursor.is_some(),
+            next_cursor,
+        })
     }
 
     async fn try_get_past_object(
@@ -351,3 +490,50 @@ pub async fn get_move_modules_by_package(
         _ => Err(anyhow!("Package object does not exist with ID {}", package)),
     }?)
 }
+
+/// The ID of the given version of `package`, which can only be the version of `package` itself
+/// or one of the previous versions in its lineage.
+async fn get_package_version(
+    fullnode_api: &FullNodeApi,
+    package: ObjectID,
+    version: u64,
+) -> RpcResult<ObjectID> {
+    let object_read = fullnode_api
+        .state
+        .get_object_read(&package)
+        .await
+        .map_err(|e| anyhow!("{e}"))?;
+
+    let p = match object_read {
+        ObjectRead::Exists(_obj_ref, object, _layout) => match object.data {
+            Data::Package(p) => Ok(p),
+            _ => Err(anyhow!("Object is not a package with ID {}", package)),
+        },
+        _ => Err(anyhow!("Package object does not exist with ID {}", package)),
+    }?;
+
+    Ok(if version == p.version() {
+        package
+    } else {
+        match version
+            .checked_sub(1)
+            .and_then(|i| p.lineage().get(i as usize))
+        {
+            Some(id) => Ok(*id),
+            None => Err(anyhow!(
+                "Package {} is at version {}, version {} is not in its lineage",
+                package,
+                p.version(),
+                version
+            )),
+        }?
+    })
+}
+
+/// Parses a coin type argument into the canonical form used by the coin index, defaulting to SUI.
+fn normalize_coin_type(coin_type: Option<String>) -> Result<String, anyhow::Error> {
+    Ok(match coin_type {
+        Some(coin_type) => parse_sui_type_tag(&coin_type)?.to_string(),
+        None => GAS::type_tag().to_string(),
+    })
+}
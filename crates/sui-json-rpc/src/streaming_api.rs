@@ -39,10 +39,17 @@ impl TransactionStreamingApiImpl {
 impl TransactionStreamingApiServer for TransactionStreamingApiImpl {
     fn subscribe_transaction(
         &self,
-        sink: SubscriptionSink,
+        mut sink: SubscriptionSink,
         filter: SuiTransactionFilter,
     ) -> SubscriptionResult {
-        let filter: TransactionFilter = filter.into();
+        let filter: TransactionFilter = match filter.try_into() {
+            Ok(filter) => filter,
+            Err(e) => {
+                let e = jsonrpsee_core::Error::from(e);
+                warn!(error = ?e, "Rejecting subscription request.");
+                return Ok(sink.reject(e)?);
+            }
+        };
 
         let state = self.state.clone();
         let stream = self.transaction_streamer.subscribe(filter);
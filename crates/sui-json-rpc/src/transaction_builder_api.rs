@@ -12,6 +12,7 @@ use sui_json_rpc_types::{GetRawObjectDataResponse, SuiObjectInfo, SuiTypeTag, Tr
 use sui_open_rpc::Module;
 use sui_transaction_builder::{DataReader, TransactionBuilder};
 use sui_types::base_types::{ObjectID, SuiAddress};
+use sui_types::committee::EpochId;
 use sui_types::object::Owner;
 
 use sui_types::sui_serde::Base64;
@@ -73,10 +74,18 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         gas: Option<ObjectID>,
         gas_budget: u64,
         recipient: SuiAddress,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
-            .transfer_object(signer, object_id, gas, gas_budget, recipient)
+            .transfer_object(
+                signer,
+                object_id,
+                gas,
+                gas_budget,
+                recipient,
+                expiration_epoch.into(),
+            )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -88,10 +97,18 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         gas_budget: u64,
         recipient: SuiAddress,
         amount: Option<u64>,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
-            .transfer_sui(signer, sui_object_id, gas_budget, recipient, amount)
+            .transfer_sui(
+                signer,
+                sui_object_id,
+                gas_budget,
+                recipient,
+                amount,
+                expiration_epoch.into(),
+            )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -104,10 +121,19 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         amounts: Vec<u64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
-            .pay(signer, input_coins, recipients, amounts, gas, gas_budget)
+            .pay(
+                signer,
+                input_coins,
+                recipients,
+                amounts,
+                gas,
+                gas_budget,
+                expiration_epoch.into(),
+            )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -118,6 +144,7 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         compiled_modules: Vec<Base64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let compiled_modules = compiled_modules
             .into_iter()
@@ -125,7 +152,13 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
             .collect::<Result<Vec<_>, _>>()?;
         let data = self
             .builder
-            .publish(sender, compiled_modules, gas, gas_budget)
+            .publish(
+                sender,
+                compiled_modules,
+                gas,
+                gas_budget,
+                expiration_epoch.into(),
+            )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -137,10 +170,18 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         split_amounts: Vec<u64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
-            .split_coin(signer, coin_object_id, split_amounts, gas, gas_budget)
+            .split_coin(
+                signer,
+                coin_object_id,
+                split_amounts,
+                gas,
+                gas_budget,
+                expiration_epoch.into(),
+            )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -152,10 +193,18 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         split_count: u64,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
-            .split_coin_equal(signer, coin_object_id, split_count, gas, gas_budget)
+            .split_coin_equal(
+                signer,
+                coin_object_id,
+                split_count,
+                gas,
+                gas_budget,
+                expiration_epoch.into(),
+            )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -167,10 +216,18 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         coin_to_merge: ObjectID,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
-            .merge_coins(signer, primary_coin, coin_to_merge, gas, gas_budget)
+            .merge_coins(
+                signer,
+                primary_coin,
+                coin_to_merge,
+                gas,
+                gas_budget,
+                expiration_epoch.into(),
+            )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -185,6 +242,7 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         rpc_arguments: Vec<SuiJsonValue>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
@@ -197,6 +255,7 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
                 rpc_arguments,
                 gas,
                 gas_budget,
+                expiration_epoch.into(),
             )
             .await?;
         Ok(TransactionBytes::from_data(data)?)
@@ -208,10 +267,11 @@ impl RpcTransactionBuilderServer for FullNodeTransactionBuilderApi {
         params: Vec<RPCTransactionRequestParams>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration_epoch: Option<EpochId>,
     ) -> RpcResult<TransactionBytes> {
         let data = self
             .builder
-            .batch_transaction(signer, params, gas, gas_budget)
+            .batch_transaction(signer, params, gas, gas_budget, expiration_epoch.into())
             .await?;
         Ok(TransactionBytes::from_data(data)?)
     }
@@ -39,25 +39,12 @@ impl FullNodeTransactionExecutionApi {
             module_cache,
         }
     }
-}
 
-#[async_trait]
-impl TransactionExecutionApiServer for FullNodeTransactionExecutionApi {
-    async fn execute_transaction(
+    async fn execute(
         &self,
-        tx_bytes: Base64,
-        sig_scheme: SignatureScheme,
-        signature: Base64,
-        pub_key: Base64,
+        txn: Transaction,
         request_type: ExecuteTransactionRequestType,
     ) -> RpcResult<SuiExecuteTransactionResponse> {
-        let data = TransactionData::from_signable_bytes(&tx_bytes.to_vec()?)?;
-        let flag = vec![sig_scheme.flag()];
-        let signature = crypto::Signature::from_bytes(
-            &[&*flag, &*signature.to_vec()?, &pub_key.to_vec()?].concat(),
-        )
-        .map_err(|e| anyhow!(e))?;
-        let txn = Transaction::new(data, signature);
         let txn_digest = *txn.digest();
 
         let response = self
@@ -77,6 +64,46 @@ impl TransactionExecutionApiServer for FullNodeTransactionExecutionApi {
     }
 }
 
+#[async_trait]
+impl TransactionExecutionApiServer for FullNodeTransactionExecutionApi {
+    async fn execute_transaction(
+        &self,
+        tx_bytes: Base64,
+        sig_scheme: SignatureScheme,
+        signature: Base64,
+        pub_key: Base64,
+        request_type: ExecuteTransactionRequestType,
+    ) -> RpcResult<SuiExecuteTransactionResponse> {
+        let data = TransactionData::from_signable_bytes(&tx_bytes.to_vec()?)?;
+        let flag = vec![sig_scheme.flag()];
+        let signature = crypto::Signature::from_bytes(
+            &[&*flag, &*signature.to_vec()?, &pub_key.to_vec()?].concat(),
+        )
+        .map_err(|e| anyhow!(e))?;
+        self.execute(Transaction::new(data, signature), request_type)
+            .await
+    }
+
+    async fn execute_sponsored_transaction(
+        &self,
+        tx_bytes: Base64,
+        signature: Base64,
+        sponsor_signature: Base64,
+        request_type: ExecuteTransactionRequestType,
+    ) -> RpcResult<SuiExecuteTransactionResponse> {
+        let data = TransactionData::from_signable_bytes(&tx_bytes.to_vec()?)?;
+        let signature =
+            crypto::Signature::from_bytes(&signature.to_vec()?).map_err(|e| anyhow!(e))?;
+        let sponsor_signature =
+            crypto::Signature::from_bytes(&sponsor_signature.to_vec()?).map_err(|e| anyhow!(e))?;
+        self.execute(
+            Transaction::new_sponsored(data, signature, sponsor_signature),
+            request_type,
+        )
+        .await
+    }
+}
+
 impl SuiRpcModule for FullNodeTransactionExecutionApi {
     fn rpc(self) -> RpcModule<Self> {
         self.into_rpc()
@@ -12,6 +12,8 @@ use narwhal_network::metrics::MetricsMakeCallbackHandler;
 use narwhal_network::metrics::{NetworkConnectionMetrics, NetworkMetrics};
 use parking_lot::Mutex;
 use prometheus::Registry;
+use std::fs::File;
+use std::io::BufReader;
 use std::option::Option::None;
 use std::time::Instant;
 use std::{sync::Arc, time::Duration};
@@ -20,10 +22,14 @@ use sui_core::authority_active::checkpoint_driver::CheckpointMetrics;
 use sui_core::authority_aggregator::{AuthAggMetrics, AuthorityAggregator};
 use sui_core::authority_server::ValidatorService;
 use sui_core::safe_client::SafeClientMetrics;
+use sui_core::state_snapshot::restore_state_snapshot;
 use sui_core::transaction_orchestrator::TransactiondOrchestrator;
 use sui_core::transaction_streamer::TransactionStreamer;
 use sui_core::{
-    authority::{AuthorityState, AuthorityStore},
+    authority::{
+        authority_store_pruner::{AuthorityStorePruner, AuthorityStorePruningMetrics},
+        AuthorityState, AuthorityStore,
+    },
     authority_active::{gossip::GossipMetrics, ActiveAuthority},
     authority_client::{
         make_network_authority_client_sets_from_genesis,
@@ -51,6 +57,7 @@ use sui_core::authority_client::NetworkAuthorityClientMetrics;
 use sui_core::epoch::committee_store::CommitteeStore;
 use sui_json_rpc::event_api::EventReadApiImpl;
 use sui_json_rpc::event_api::EventStreamingApiImpl;
+use sui_json_rpc::governance_api::GovernanceReadApiImpl;
 use sui_json_rpc::http_server::HttpServerHandle;
 use sui_json_rpc::read_api::FullNodeApi;
 use sui_json_rpc::read_api::ReadApi;
@@ -74,6 +81,7 @@ pub struct SuiNode {
     _gossip_handle: Option<tokio::task::JoinHandle<()>>,
     _execute_driver_handle: tokio::task::JoinHandle<()>,
     _checkpoint_process_handle: Option<tokio::task::JoinHandle<()>>,
+    _pruning_handle: Option<tokio::task::JoinHandle<()>>,
     state: Arc<AuthorityState>,
     active: Arc<ActiveAuthority<NetworkAuthorityClient>>,
     transaction_orchestrator: Option<Arc<TransactiondOrchestrator<NetworkAuthorityClient>>>,
@@ -125,6 +133,23 @@ impl SuiNode {
             )))
         };
 
+        if let Some(snapshot_path) = &config.state_snapshot_path {
+            if store.database_is_empty()? {
+                info!(path = ?snapshot_path, "Restoring state from snapshot");
+                restore_state_snapshot(
+                    BufReader::new(File::open(snapshot_path)?),
+                    &committee,
+                    &store,
+                    &committee_store,
+                    &checkpoint_store,
+                    index_store.as_deref(),
+                )
+                .await?;
+            } else {
+                info!("Database is not empty, skipping state snapshot restore");
+            }
+        }
+
         let event_store = if config.enable_event_processing {
             let path = config.db_path().join("events.db");
             let db = SqlEventStore::new_from_file(&path).await?;
@@ -150,13 +175,13 @@ impl SuiNode {
             AuthorityState::new(
                 config.protocol_public_key(),
                 secret,
-                store,
+                store.clone(),
                 node_sync_store,
                 committee_store.clone(),
                 index_store.clone(),
                 event_store,
                 transaction_streamer,
-                checkpoint_store,
+                checkpoint_store.clone(),
                 genesis,
                 &prometheus_registry,
                 tx_reconfigure_consensus,
@@ -221,6 +246,19 @@ impl SuiNode {
             })
         };
 
+        let pruning_handle = if config.authority_store_pruning_config.enable_pruning {
+            let pruner = AuthorityStorePruner::new(
+                store,
+                checkpoint_store,
+                index_store.clone(),
+                config.authority_store_pruning_config.clone(),
+                AuthorityStorePruningMetrics::new(&prometheus_registry),
+            );
+            Some(tokio::task::spawn(pruner.run()))
+        } else {
+            None
+        };
+
         let post_processing_subsystem_handle =
             if index_store.is_some() || config.enable_event_processing {
                 let indexing_state = state.clone();
@@ -356,6 +394,7 @@ impl SuiNode {
             _gossip_handle: gossip_handle,
             _execute_driver_handle: execute_driver_handle,
             _checkpoint_process_handle: checkpoint_process_handle,
+            _pruning_handle: pruning_handle,
             _batch_subsystem_handle: batch_subsystem_handle,
             _post_processing_subsystem_handle: post_processing_subsystem_handle,
             state,
@@ -430,6 +469,7 @@ pub async fn build_http_servers(
     server.register_module(FullNodeApi::new(state.clone()))?;
     server.register_module(BcsApiImpl::new(state.clone()))?;
     server.register_module(FullNodeTransactionBuilderApi::new(state.clone()))?;
+    server.register_module(GovernanceReadApiImpl::new(state.clone()))?;
 
     if let Some(transaction_orchestrator) = transaction_orchestrator {
         server.register_module(FullNodeTransactionExecutionApi::new(
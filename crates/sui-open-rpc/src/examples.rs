@@ -16,11 +16,12 @@ use sui::client_commands::EXAMPLE_NFT_URL;
 use sui_core::test_utils::to_sender_signed_transaction;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
-    MoveCallParams, OwnedObjectRef, RPCTransactionRequestParams, SuiCertifiedTransaction, SuiData,
-    SuiEvent, SuiEventEnvelope, SuiExecutionStatus, SuiGasCostSummary, SuiObject, SuiObjectRead,
-    SuiObjectRef, SuiParsedData, SuiPastObjectRead, SuiRawData, SuiRawMoveObject,
-    SuiTransactionData, SuiTransactionEffects, SuiTransactionResponse, TransactionBytes,
-    TransactionsPage, TransferObjectParams,
+    EventPage, MoveCallParams, ObjectsPage, OwnedObjectRef, RPCTransactionRequestParams,
+    SuiCertifiedTransaction, SuiData, SuiEvent, SuiEventEnvelope, SuiEventFilter,
+    SuiExecutionStatus, SuiGasCostSummary, SuiObject, SuiObjectInfo, SuiObjectRead, SuiObjectRef,
+    SuiParsedData, SuiPastObjectRead, SuiRawData, SuiRawMoveObject, SuiTransactionData,
+    SuiTransactionEffects, SuiTransactionResponse, TransactionBytes, TransactionsPage,
+    TransferObjectParams,
 };
 use sui_open_rpc::ExamplePairing;
 use sui_types::base_types::{
@@ -28,7 +29,7 @@ use sui_types::base_types::{
 };
 use sui_types::crypto::{get_key_pair_from_rng, AccountKeyPair, Signature};
 use sui_types::crypto::{AuthorityQuorumSignInfo, SuiSignature};
-use sui_types::event::TransferType;
+use sui_types::event::{EventID, TransferType};
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::{
     CallArg, ExecuteTransactionRequestType, MoveCall, SingleTransactionKind, Transaction,
@@ -78,13 +79,7 @@ impl RpcExampleProvider {
             self.get_total_transaction_number(),
             self.get_transaction(),
             self.get_transactions(),
-            self.get_events_by_transaction(),
-            self.get_events_by_object(),
-            self.get_events_by_sender(),
-            self.get_events_by_recipient(),
-            self.get_events_by_move_event_struct_name(),
-            self.get_events_by_transaction_module(),
-            self.get_events_by_timerange(),
+            self.get_events(),
         ]
         .into_iter()
         .map(|example| (example.function_name, example.examples))
@@ -269,13 +264,19 @@ impl RpcExampleProvider {
                 owner: Owner::AddressOwner(owner),
                 previous_transaction: TransactionDigest::new(self.rng.gen()),
             })
+            .map(SuiObjectInfo::from)
             .collect::<Vec<_>>();
+        let result = ObjectsPage {
+            data: result,
+            next_cursor: None,
+            has_next_page: false,
+        };
 
         Examples::new(
             "sui_getObjectsOwnedByAddress",
             vec![ExamplePairing::new(
                 "Get objects owned by an address",
-                vec![("address", json!(owner))],
+                vec![("address", json!(owner)), ("limit", json!(4))],
                 json!(result),
             )],
         )
@@ -291,13 +292,19 @@ impl RpcExampleProvider {
                 owner: Owner::ObjectOwner(SuiAddress::from(owner)),
                 previous_transaction: TransactionDigest::new(self.rng.gen()),
             })
+            .map(SuiObjectInfo::from)
             .collect::<Vec<_>>();
+        let result = ObjectsPage {
+            data: result,
+            next_cursor: None,
+            has_next_page: false,
+        };
 
         Examples::new(
             "sui_getObjectsOwnedByObject",
             vec![ExamplePairing::new(
                 "Get objects owned by an object",
-                vec![("object_id", json!(owner))],
+                vec![("object_id", json!(owner)), ("limit", json!(4))],
                 json!(result),
             )],
         )
@@ -377,7 +384,11 @@ impl RpcExampleProvider {
         let mut data = self.get_transaction_digests(5..9);
         let next_cursor = data.pop();
 
-        let result = TransactionsPage { data, next_cursor };
+        let result = TransactionsPage {
+            data,
+            next_cursor,
+            has_next_page: true,
+        };
         Examples::new(
             "sui_getTransactions",
             vec![ExamplePairing::new(
@@ -449,6 +460,7 @@ impl RpcExampleProvider {
         let events = vec![SuiEventEnvelope {
             timestamp: std::time::Instant::now().elapsed().as_secs(),
             tx_digest: Some(*tx_digest),
+            id: EventID::default(),
             event: sui_event.clone(),
         }];
         let result = SuiTransactionResponse {
@@ -456,6 +468,7 @@ impl RpcExampleProvider {
                 transaction_digest: *tx_digest,
                 data: SuiTransactionData::try_from(data1).unwrap(),
                 tx_signature: signature.clone(),
+                sponsor_signature: None,
                 auth_sign_info: AuthorityQuorumSignInfo {
                     epoch: 0,
                     signature: Default::default(),
@@ -499,145 +512,29 @@ impl RpcExampleProvider {
         (data2, signature, recipient, obj_id, result, events)
     }
 
-    fn get_events_by_transaction(&mut self) -> Examples {
+    fn get_events(&mut self) -> Examples {
         let (_, _, _, _, result, events) = self.get_transfer_data_response();
-        Examples::new(
-            "sui_getEventsByTransaction",
-            vec![ExamplePairing::new(
-                "Return the Events emitted by a transaction",
-                vec![
-                    (
-                        "digest",
-                        json!(result.certificate.transaction_digest.clone()),
-                    ),
-                    ("count", json!(2)),
-                ],
-                json!(events),
-            )],
-        )
-    }
-
-    fn get_events_by_sender(&mut self) -> Examples {
-        let ts = std::time::Instant::now().elapsed().as_secs();
-        let (tx_data, _, _, _, _, events) = self.get_transfer_data_response();
-        Examples::new(
-            "sui_getEventsBySender",
-            vec![ExamplePairing::new(
-                "Return the Events associated with the given sender",
-                vec![
-                    ("sender", json!(tx_data.signer())),
-                    ("count", json!(2)),
-                    ("start_time", json!(ts)),
-                    ("end_time", json!(ts + 10)),
-                ],
-                json!(events),
-            )],
-        )
-    }
-
-    fn get_events_by_recipient(&mut self) -> Examples {
-        let ts = std::time::Instant::now().elapsed().as_secs();
-        let (_, _, recipient, _, _, events) = self.get_transfer_data_response();
-        Examples::new(
-            "sui_getEventsByRecipient",
-            vec![ExamplePairing::new(
-                "Return the Events associated with the given recipient",
-                vec![
-                    ("recipient", json!(Owner::AddressOwner(recipient))),
-                    ("count", json!(2)),
-                    ("start_time", json!(ts)),
-                    ("end_time", json!(ts + 10)),
-                ],
-                json!(events),
-            )],
-        )
-    }
-
-    fn get_events_by_object(&mut self) -> Examples {
-        let ts = std::time::Instant::now().elapsed().as_secs();
-        let (_, _, _, obj_id, _, events) = self.get_transfer_data_response();
-        Examples::new(
-            "sui_getEventsByObject",
-            vec![ExamplePairing::new(
-                "Return the Events associated with the given object",
-                vec![
-                    ("object", json!(obj_id)),
-                    ("count", json!(2)),
-                    ("start_time", json!(ts)),
-                    ("end_time", json!(ts + 10)),
-                ],
-                json!(events),
-            )],
-        )
-    }
-
-    fn get_events_by_timerange(&mut self) -> Examples {
-        let ts = std::time::Instant::now().elapsed().as_secs();
-        let (_, _, _, _, _, events) = self.get_transfer_data_response();
-        Examples::new(
-            "sui_getEventsByTimeRange",
-            vec![ExamplePairing::new(
-                "Return the Events emitted in [start_time, end_time) interval",
-                vec![
-                    ("count", json!(2)),
-                    ("start_time", json!(ts)),
-                    ("end_time", json!(ts + 10)),
-                ],
-                json!(events),
-            )],
-        )
-    }
-
-    fn get_events_by_move_event_struct_name(&mut self) -> Examples {
-        let ts = std::time::Instant::now().elapsed().as_secs();
-        let (data, signature, _, _, _, _) = self.get_transfer_data_response();
-        let tx = Transaction::new(data, signature);
-
-        let event = SuiEventEnvelope {
-            timestamp: ts,
-            tx_digest: Some(*tx.digest()),
-            event: SuiEvent::MoveEvent {
-                package_id: ObjectID::from_hex_literal("0x2").unwrap(),
-                transaction_module: String::from("devnet_nft"),
-                sender: SuiAddress::from_str("0x9421e7ad826ba13aca8ae41316644f06759b4506").unwrap(),
-                type_: String::from("0x2::devnet_nft::MintNFTEvent"),
-                fields: None,
-                bcs: vec![],
-            },
+        let page = EventPage {
+            data: events,
+            next_cursor: None,
+            has_next_page: false,
         };
         Examples::new(
-            "sui_getEventsByMoveEventStructName",
+            "sui_getEvents",
             vec![ExamplePairing::new(
-                "Return the Events with the given move event struct name",
+                "Return the Events emitted by a transaction",
                 vec![
                     (
-                        "move_event_struct_name",
-                        json!("0x2::devnet_nft::MintNFTEvent"),
+                        "query",
+                        json!(SuiEventFilter::Transaction(
+                            result.certificate.transaction_digest
+                        )),
                     ),
-                    ("count", json!(5)),
-                    ("start_time", json!(ts)),
-                    ("end_time", json!(ts + 10)),
-                ],
-                json!(vec![event]),
-            )],
-        )
-    }
-
-    fn get_events_by_transaction_module(&mut self) -> Examples {
-        let ts = std::time::Instant::now().elapsed().as_secs();
-        let (_, _, _, _, _, events) = self.get_transfer_data_response();
-        Examples::new(
-            "sui_getEventsByModule",
-            vec![ExamplePairing::new(
-                "Return the Events emitted in a specified Move module",
-                vec![
-                    ("package", json!(ObjectID::from_hex_literal("0x2").unwrap())),
-                    ("module", json!("devnet_nft")),
-                    ("count", json!(5)),
-                    ("start_time", json!(ts)),
-                    ("end_time", json!(ts + 10)),
+                    ("cursor", json!(None::<EventID>)),
+                    ("limit", json!(2)),
+                    ("descending_order", json!(false)),
                 ],
-                json!(events),
+                json!(page),
             )],
         )
     }
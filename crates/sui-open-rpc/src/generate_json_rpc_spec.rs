@@ -12,6 +12,7 @@ use sui_core::SUI_CORE_VERSION;
 use sui_json_rpc::api::EventReadApiOpenRpc;
 use sui_json_rpc::api::EventStreamingApiOpenRpc;
 use sui_json_rpc::bcs_api::BcsApiImpl;
+use sui_json_rpc::governance_api::GovernanceReadApiImpl;
 use sui_json_rpc::read_api::{FullNodeApi, ReadApi};
 use sui_json_rpc::sui_rpc_doc;
 use sui_json_rpc::transaction_builder_api::FullNodeTransactionBuilderApi;
@@ -53,6 +54,7 @@ async fn main() {
     open_rpc.add_module(EventReadApiOpenRpc::module_doc());
     open_rpc.add_module(FullNodeTransactionExecutionApi::rpc_doc_module());
     open_rpc.add_module(FullNodeTransactionBuilderApi::rpc_doc_module());
+    open_rpc.add_module(GovernanceReadApiImpl::rpc_doc_module());
 
     open_rpc.add_examples(RpcExampleProvider::new().examples());
 
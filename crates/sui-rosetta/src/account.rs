@@ -8,7 +8,8 @@ use axum::{Extension, Json};
 
 use sui_core::authority::AuthorityState;
 use sui_types::base_types::SuiAddress;
-use sui_types::gas_coin::GasCoin;
+use sui_types::error::SuiError;
+use sui_types::gas_coin::{GasCoin, GAS};
 use sui_types::object::Owner;
 
 use crate::errors::Error;
@@ -47,8 +48,20 @@ pub async fn balance(
                 balances: vec![Amount::new(balance.into())],
             })
     } else {
-        let gas_coins = get_coins(&context.state, request.account_identifier.address).await?;
-        let amount: u128 = gas_coins.iter().map(|coin| coin.amount.value.abs()).sum();
+        let address = request.account_identifier.address;
+        let amount = match context
+            .state
+            .get_balance(address, GAS::type_tag().to_string())
+        {
+            Ok((amount, _)) => amount,
+            // Without the coin index, sum up the owned gas coins instead.
+            Err(SuiError::UnsupportedFeatureError { .. }) => get_coins(&context.state, address)
+                .await?
+                .iter()
+                .map(|coin| coin.amount.value.abs())
+                .sum(),
+            Err(e) => return Err(e.into()),
+        };
         Ok(AccountBalanceResponse {
             block_identifier: context.blocks().current_block_identifier().await?,
             balances: vec![Amount::new(amount.into())],
@@ -71,7 +84,37 @@ pub async fn coins(
     })
 }
 
+/// Returns the gas coins owned by `address`, from the coin index if it is enabled on the node or
+/// from the owned objects otherwise.
 async fn get_coins(state: &AuthorityState, address: SuiAddress) -> Result<Vec<Coin>, Error> {
+    let coins = match state.get_coins(address, GAS::type_tag().to_string(), None, None) {
+        Ok(coins) => coins,
+        Err(SuiError::UnsupportedFeatureError { .. }) => {
+            return get_coins_from_objects(state, address).await
+        }
+        Err(e) => return Err(e.into()),
+    };
+    Ok(coins
+        .into_iter()
+        .map(|(id, info)| Coin {
+            coin_identifier: CoinIdentifier {
+                identifier: CoinID {
+                    id,
+                    version: info.version,
+                },
+            },
+            amount: Amount {
+                value: SignedValue::from(info.balance),
+                currency: SUI.clone(),
+            },
+        })
+        .collect())
+}
+
+async fn get_coins_from_objects(
+    state: &AuthorityState,
+    address: SuiAddress,
+) -> Result<Vec<Coin>, Error> {
     let object_infos = state.get_owner_objects(Owner::AddressOwner(address))?;
     let coin_infos = object_infos
         .iter()
@@ -15,8 +15,8 @@ use sui_types::coin::{PAY_JOIN_FUNC_NAME, PAY_MODULE_NAME, PAY_SPLIT_VEC_FUNC_NA
 use sui_types::event::Event;
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::{
-    CallArg, InputObjectKind, MoveCall, ObjectArg, Pay, SingleTransactionKind, TransactionData,
-    TransactionEffects, TransferObject,
+    CallArg, InputObjectKind, MoveCall, MoveModulePublish, MoveModuleUpgrade, ObjectArg, Pay,
+    SingleTransactionKind, TransactionData, TransactionEffects, TransferObject,
 };
 use sui_types::move_package::disassemble_modules;
 use sui_types::{parse_sui_struct_tag, SUI_FRAMEWORK_OBJECT_ID};
@@ -310,8 +310,9 @@ fn parse_operations(
         SingleTransactionKind::Call(c) => {
             move_call_operations(sender, gas, budget, c, counter, status)
         }
-        SingleTransactionKind::Publish(p) => {
-            let disassembled = disassemble_modules(p.modules.iter())?;
+        SingleTransactionKind::Publish(MoveModulePublish { modules })
+        | SingleTransactionKind::Upgrade(MoveModuleUpgrade { modules, .. }) => {
+            let disassembled = disassemble_modules(modules.iter())?;
             vec![Operation {
                 operation_identifier: counter.next_idx().into(),
                 related_operations: vec![],
@@ -334,6 +335,32 @@ fn parse_operations(
             metadata: Some(json!(change)),
         }],
         SingleTransactionKind::Pay(pay) => parse_pay(sender, gas, budget, pay, counter, status),
+        SingleTransactionKind::TransferBatchResult(transfer) => vec![
+            Operation {
+                operation_identifier: counter.next_idx().into(),
+                related_operations: vec![],
+                type_: OperationType::TransferObject,
+                status,
+                account: Some(AccountIdentifier { address: sender }),
+                amount: None,
+                coin_change: None,
+                metadata: Some(json!(transfer)),
+            },
+            Operation::gas_budget(counter, status, gas, budget, sender),
+        ],
+        SingleTransactionKind::PayBatchResults(pay) => vec![
+            Operation {
+                operation_identifier: counter.next_idx().into(),
+                related_operations: vec![],
+                type_: OperationType::Pay,
+                status,
+                account: Some(AccountIdentifier { address: sender }),
+                amount: None,
+                coin_change: None,
+                metadata: Some(json!(pay)),
+            },
+            Operation::gas_budget(counter, status, gas, budget, sender),
+        ],
     };
     if let Some(effects) = effects {
         let coin_change_operations = Operation::get_coin_operation_from_events(
@@ -21,7 +21,7 @@ use sui_sdk::{
     types::{
         base_types::{ObjectID, SuiAddress},
         id::UID,
-        messages::Transaction,
+        messages::{Transaction, TransactionExpiration},
     },
     SuiClient,
 };
@@ -86,6 +86,7 @@ impl TicTacToe {
                 ],
                 None, // The gateway server will pick a gas object belong to the signer if not provided.
                 1000,
+                TransactionExpiration::None,
             )
             .await?;
 
@@ -183,6 +184,7 @@ impl TicTacToe {
                     ],
                     None,
                     1000,
+                    TransactionExpiration::None,
                 )
                 .await?;
 
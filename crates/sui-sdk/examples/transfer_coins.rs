@@ -6,7 +6,7 @@ use sui_sdk::{
     crypto::{AccountKeystore, FileBasedKeystore, Keystore},
     types::{
         base_types::{ObjectID, SuiAddress},
-        messages::Transaction,
+        messages::{Transaction, TransactionExpiration},
     },
     SuiClient,
 };
@@ -28,7 +28,14 @@ async fn main() -> Result<(), anyhow::Error> {
     // Create a sui transfer transaction
     let transfer_tx = sui
         .transaction_builder()
-        .transfer_sui(my_address, gas_object_id, 1000, recipient, Some(1000))
+        .transfer_sui(
+            my_address,
+            gas_object_id,
+            1000,
+            recipient,
+            Some(1000),
+            TransactionExpiration::None,
+        )
         .await?;
 
     // Sign transaction
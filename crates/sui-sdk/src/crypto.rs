@@ -1,6 +1,8 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
+use aes_gcm::aead::{Aead, KeyInit};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
 use anyhow::anyhow;
 use bip32::DerivationPath;
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
@@ -12,7 +14,7 @@ use std::fmt::Write;
 use std::fmt::{Display, Formatter};
 use std::fs;
 use std::fs::File;
-use std::io::BufReader;
+use std::io::{BufReader, Write as _};
 use std::path::{Path, PathBuf};
 
 use sui_types::base_types::SuiAddress;
@@ -20,12 +22,14 @@ use sui_types::crypto::{
     derive_key_pair_from_path, enum_dispatch, get_key_pair_from_rng, EncodeDecodeBase64, PublicKey,
     Signature, SignatureScheme, SuiKeyPair,
 };
+use sui_types::sui_serde::{Base64, Encoding};
 
 #[derive(Serialize, Deserialize)]
 #[enum_dispatch(AccountKeystore)]
 pub enum Keystore {
     File(FileBasedKeystore),
     InMem(InMemKeystore),
+    Encrypted(EncryptedFileKeystore),
 }
 #[enum_dispatch]
 pub trait AccountKeystore: Send + Sync {
@@ -88,6 +92,12 @@ impl Display for Keystore {
                 writeln!(writer, "Keystore Type : InMem")?;
                 write!(f, "{}", writer)
             }
+            Keystore::Encrypted(file) => {
+                writeln!(writer, "Keystore Type : Encrypted File")?;
+                writeln!(writer, "Keystore Path : {:?}", file.path)?;
+                write!(writer, "Locked : {}", file.is_locked())?;
+                write!(f, "{}", writer)
+            }
         }
     }
 }
@@ -180,6 +190,10 @@ impl FileBasedKeystore {
         self.path = Some(path.to_path_buf());
     }
 
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
     pub fn save(&self) -> Result<(), anyhow::Error> {
         if let Some(path) = &self.path {
             let store = serde_json::to_string_pretty(
@@ -244,3 +258,322 @@ impl InMemKeystore {
         Self { keys }
     }
 }
+
+pub const ENCRYPTED_KEYSTORE_VERSION: u32 = 1;
+
+/// Argon2id parameters used to derive the encryption key from the password: 64 MiB of memory,
+/// 3 passes, 1 lane (the RFC 9106 second recommended option).
+const ARGON2_MEMORY_COST_KIB: u32 = 64 * 1024;
+const ARGON2_TIME_COST: u32 = 3;
+const ARGON2_PARALLELISM: u32 = 1;
+const ENCRYPTION_KEY_LENGTH: usize = 32;
+const SALT_LENGTH: usize = 16;
+const NONCE_LENGTH: usize = 12;
+
+/// The on-disk format of an encrypted keystore. The plaintext is the JSON list of base64
+/// encoded keypairs of a `FileBasedKeystore`, encrypted with AES-256-GCM under a key derived
+/// from the password with Argon2id. Every save uses a fresh nonce.
+#[derive(Serialize, Deserialize)]
+struct EncryptedKeystoreFile {
+    version: u32,
+    kdf: KdfParams,
+    /// Base64 encoded AES-GCM nonce.
+    nonce: String,
+    /// Base64 encoded ciphertext, including the authentication tag.
+    ciphertext: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "algorithm", rename_all = "kebab-case")]
+enum KdfParams {
+    Argon2id {
+        /// Base64 encoded salt.
+        salt: String,
+        memory_cost_kib: u32,
+        time_cost: u32,
+        parallelism: u32,
+    },
+}
+
+impl KdfParams {
+    fn new_argon2id() -> Self {
+        let salt: [u8; SALT_LENGTH] = rand::random();
+        KdfParams::Argon2id {
+            salt: Base64::encode(salt),
+            memory_cost_kib: ARGON2_MEMORY_COST_KIB,
+            time_cost: ARGON2_TIME_COST,
+            parallelism: ARGON2_PARALLELISM,
+        }
+    }
+
+    fn derive_key(&self, password: &str) -> Result<[u8; ENCRYPTION_KEY_LENGTH], anyhow::Error> {
+        match self {
+            KdfParams::Argon2id {
+                salt,
+                memory_cost_kib,
+                time_cost,
+                parallelism,
+            } => {
+                let params = argon2::Params::new(
+                    *memory_cost_kib,
+                    *time_cost,
+                    *parallelism,
+                    Some(ENCRYPTION_KEY_LENGTH),
+                )
+                .map_err(|e| anyhow!("Invalid Argon2 parameters: {e}"))?;
+                let mut key = [0u8; ENCRYPTION_KEY_LENGTH];
+                argon2::Argon2::new(argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+                    .hash_password_into(password.as_bytes(), &Base64::decode(salt)?, &mut key)
+                    .map_err(|e| anyhow!("Cannot derive keystore encryption key: {e}"))?;
+                Ok(key)
+            }
+        }
+    }
+}
+
+/// The decrypted state of an unlocked `EncryptedFileKeystore`.
+struct UnlockedKeys {
+    keys: BTreeMap<SuiAddress, SuiKeyPair>,
+    kdf: KdfParams,
+    encryption_key: [u8; ENCRYPTION_KEY_LENGTH],
+}
+
+/// A file based keystore whose keys are encrypted with a password.
+///
+/// The keystore is locked when opened: its keys are only decrypted in memory once `unlock` is
+/// called with the password, and until `lock` is called. While locked, it lists no keys and
+/// cannot sign nor add keys.
+pub struct EncryptedFileKeystore {
+    path: PathBuf,
+    unlocked: Option<UnlockedKeys>,
+}
+
+impl Serialize for EncryptedFileKeystore {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.path.to_str().unwrap_or(""))
+    }
+}
+
+impl<'de> Deserialize<'de> for EncryptedFileKeystore {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(EncryptedFileKeystore::new(&PathBuf::from(
+            String::deserialize(deserializer)?,
+        )))
+    }
+}
+
+impl AccountKeystore for EncryptedFileKeystore {
+    fn sign(&self, address: &SuiAddress, msg: &[u8]) -> Result<Signature, signature::Error> {
+        self.unlocked
+            .as_ref()
+            .ok_or_else(|| signature::Error::from_source("Keystore is locked"))?
+            .keys
+            .get(address)
+            .ok_or_else(|| {
+                signature::Error::from_source(format!("Cannot find key for address: [{address}]"))
+            })?
+            .try_sign(msg)
+    }
+
+    fn add_key(&mut self, keypair: SuiKeyPair) -> Result<(), anyhow::Error> {
+        let address: SuiAddress = (&keypair.public()).into();
+        self.unlocked_keys_mut()?.keys.insert(address, keypair);
+        self.save()
+    }
+
+    fn keys(&self) -> Vec<PublicKey> {
+        self.unlocked
+            .iter()
+            .flat_map(|unlocked| unlocked.keys.values().map(|key| key.public()))
+            .collect()
+    }
+
+    fn get_key(&self, address: &SuiAddress) -> Result<&SuiKeyPair, anyhow::Error> {
+        let unlocked = self
+            .unlocked
+            .as_ref()
+            .ok_or_else(|| anyhow!("Keystore is locked"))?;
+        match unlocked.keys.get(address) {
+            Some(key) => Ok(key),
+            None => Err(anyhow!("Cannot find key for address: [{address}]")),
+        }
+    }
+}
+
+impl EncryptedFileKeystore {
+    /// Open the (locked) encrypted keystore at `path`. Nothing is read until it is unlocked.
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            unlocked: None,
+        }
+    }
+
+    /// Create a new encrypted keystore at `path`, holding `keys` and protected by `password`.
+    /// The returned keystore is unlocked.
+    pub fn create(
+        path: &Path,
+        password: &str,
+        keys: impl IntoIterator<Item = SuiKeyPair>,
+    ) -> Result<Self, anyhow::Error> {
+        if path.exists() {
+            return Err(anyhow!("Keystore file {:?} already exists", path));
+        }
+        let kdf = KdfParams::new_argon2id();
+        let encryption_key = kdf.derive_key(password)?;
+        let keystore = Self {
+            path: path.to_path_buf(),
+            unlocked: Some(UnlockedKeys {
+                keys: keys
+                    .into_iter()
+                    .map(|key| ((&key.public()).into(), key))
+                    .collect(),
+                kdf,
+                encryption_key,
+            }),
+        };
+        keystore.save()?;
+        Ok(keystore)
+    }
+
+    /// Whether `path` holds an encrypted keystore, rather than a plaintext one.
+    pub fn is_encrypted_keystore(path: &Path) -> bool {
+        fs::read(path)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<EncryptedKeystoreFile>(&bytes).ok())
+            .is_some()
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.unlocked.is_none()
+    }
+
+    /// Decrypt the keys with `password`, and keep them in memory until the keystore is locked.
+    pub fn unlock(&mut self, password: &str) -> Result<(), anyhow::Error> {
+        let reader = BufReader::new(File::open(&self.path)?);
+        let file: EncryptedKeystoreFile = serde_json::from_reader(reader)
+            .map_err(|e| anyhow!("Invalid encrypted keystore file {:?}: {e}", self.path))?;
+        if file.version != ENCRYPTED_KEYSTORE_VERSION {
+            return Err(anyhow!(
+                "Unsupported encrypted keystore version {}, expected {ENCRYPTED_KEYSTORE_VERSION}",
+                file.version
+            ));
+        }
+
+        let encryption_key = file.kdf.derive_key(password)?;
+        let nonce = Base64::decode(&file.nonce)?;
+        if nonce.len() != NONCE_LENGTH {
+            return Err(anyhow!("Invalid encrypted keystore nonce"));
+        }
+        let plaintext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&encryption_key))
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Base64::decode(&file.ciphertext)?.as_slice(),
+            )
+            .map_err(|_| anyhow!("Incorrect keystore password, or corrupted keystore file"))?;
+        let kp_strings: Vec<String> = serde_json::from_slice(&plaintext)?;
+        let keys = kp_strings
+            .iter()
+            .map(|kpstr| {
+                let key = SuiKeyPair::decode_base64(kpstr);
+                key.map(|k| (Into::<SuiAddress>::into(&k.public()), k))
+            })
+            .collect::<Result<BTreeMap<_, _>, _>>()
+            .map_err(|e| anyhow!("Invalid Keypair in encrypted keystore {:#?}", e))?;
+
+        self.unlocked = Some(UnlockedKeys {
+            keys,
+            kdf: file.kdf,
+            encryption_key,
+        });
+        Ok(())
+    }
+
+    /// Drop the decrypted keys from memory.
+    pub fn lock(&mut self) {
+        self.unlocked = None;
+    }
+
+    /// Re-encrypt the keystore with a key derived from `new_password`, after checking
+    /// `old_password`.
+    pub fn change_password(
+        &mut self,
+        old_password: &str,
+        new_password: &str,
+    ) -> Result<(), anyhow::Error> {
+        self.unlock(old_password)?;
+        let kdf = KdfParams::new_argon2id();
+        let encryption_key = kdf.derive_key(new_password)?;
+        let unlocked = self.unlocked_keys_mut()?;
+        unlocked.kdf = kdf;
+        unlocked.encryption_key = encryption_key;
+        self.save()
+    }
+
+    pub fn save(&self) -> Result<(), anyhow::Error> {
+        let unlocked = self
+            .unlocked
+            .as_ref()
+            .ok_or_else(|| anyhow!("Cannot save a locked keystore"))?;
+        let plaintext = serde_json::to_vec(
+            &unlocked
+                .keys
+                .values()
+                .map(EncodeDecodeBase64::encode_base64)
+                .collect::<Vec<_>>(),
+        )?;
+        let nonce: [u8; NONCE_LENGTH] = rand::random();
+        let ciphertext = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&unlocked.encryption_key))
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| anyhow!("Cannot encrypt keystore"))?;
+        let file = EncryptedKeystoreFile {
+            version: ENCRYPTED_KEYSTORE_VERSION,
+            kdf: unlocked.kdf.clone(),
+            nonce: Base64::encode(nonce),
+            ciphertext: Base64::encode(ciphertext),
+        };
+        self.write_atomically(serde_json::to_string_pretty(&file)?.as_bytes())
+    }
+
+    /// Replace the keystore file with `contents`, through a temporary file only readable by its
+    /// owner, so that the keystore is never left partially written nor world readable.
+    fn write_atomically(&self, contents: &[u8]) -> Result<(), anyhow::Error> {
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+
+        // The mode only applies to new files, so never reuse a leftover temporary file.
+        if tmp_path.exists() {
+            fs::remove_file(&tmp_path)?;
+        }
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+        let mut tmp_file = options.open(&tmp_path)?;
+        tmp_file.write_all(contents)?;
+        tmp_file.sync_all()?;
+        drop(tmp_file);
+        fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    fn unlocked_keys_mut(&mut self) -> Result<&mut UnlockedKeys, anyhow::Error> {
+        self.unlocked
+            .as_mut()
+            .ok_or_else(|| anyhow!("Keystore is locked"))
+    }
+}
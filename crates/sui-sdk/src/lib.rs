@@ -25,27 +25,34 @@ pub use sui_config::gateway;
 use sui_config::gateway::GatewayConfig;
 use sui_core::gateway_state::{GatewayClient, GatewayState, TxSeqNumber};
 pub use sui_json as json;
+use sui_json_rpc::api::EventReadApiClient;
 use sui_json_rpc::api::EventStreamingApiClient;
+use sui_json_rpc::api::GovernanceReadApiClient;
 use sui_json_rpc::api::RpcBcsApiClient;
 use sui_json_rpc::api::RpcFullNodeReadApiClient;
 use sui_json_rpc::api::RpcReadApiClient;
 use sui_json_rpc::api::TransactionExecutionApiClient;
 pub use sui_json_rpc_types as rpc_types;
 use sui_json_rpc_types::{
-    GetObjectDataResponse, GetRawObjectDataResponse, SuiEventEnvelope, SuiEventFilter,
-    SuiObjectInfo, SuiTransactionResponse, TransactionsPage,
+    CoinPage, EventPage, GetObjectDataResponse, GetRawObjectDataResponse, ObjectsPage, SuiBalance,
+    SuiEventEnvelope, SuiEventFilter, SuiObjectInfo, SuiTransactionResponse, TransactionsPage,
 };
 use sui_transaction_builder::{DataReader, TransactionBuilder};
 pub use sui_types as types;
 use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest};
+use sui_types::event::EventID;
+use sui_types::governance::{Delegation, StakedSui};
 use sui_types::messages::Transaction;
 use sui_types::query::{Ordering, TransactionQuery};
+use sui_types::sui_serde::Base64;
+use sui_types::sui_system_state::{SuiSystemState, Validator};
 use types::base_types::SequenceNumber;
 use types::error::TRANSACTION_NOT_FOUND_MSG_PREFIX;
 use types::messages::ExecuteTransactionRequestType;
 
 // re-export essential sui crates
 pub mod crypto;
+pub mod light_client;
 
 const WAIT_FOR_TX_TIMEOUT_SEC: u64 = 10;
 
@@ -65,6 +72,7 @@ pub struct SuiClient {
     read_api: Arc<ReadApi>,
     full_node_api: FullNodeApi,
     event_api: EventApi,
+    governance_api: GovernanceApi,
     quorum_driver: QuorumDriver,
     wallet_sync_api: WalletSyncApi,
 }
@@ -185,6 +193,7 @@ impl SuiClient {
 
         let full_node_api = FullNodeApi(api.clone());
         let event_api = EventApi(api.clone());
+        let governance_api = GovernanceApi(api.clone());
         let transaction_builder = TransactionBuilder(read_api.clone());
         let wallet_sync_api = WalletSyncApi(api.clone());
 
@@ -194,6 +203,7 @@ impl SuiClient {
             read_api,
             full_node_api,
             event_api,
+            governance_api,
             quorum_driver,
             wallet_sync_api,
         }
@@ -243,22 +253,72 @@ pub struct ReadApi {
 }
 
 impl ReadApi {
+    /// Returns all objects owned by `address`, fetching every page from the server.
     pub async fn get_objects_owned_by_address(
         &self,
         address: SuiAddress,
     ) -> anyhow::Result<Vec<SuiObjectInfo>> {
         Ok(match &*self.api {
-            SuiClientApi::Rpc(c) => c.http.get_objects_owned_by_address(address).await?,
+            SuiClientApi::Rpc(c) => {
+                let mut objects = Vec::new();
+                let mut cursor = None;
+                loop {
+                    let page = c
+                        .http
+                        .get_objects_owned_by_address(address, cursor, None)
+                        .await?;
+                    objects.extend(page.data);
+                    if !page.has_next_page {
+                        break objects;
+                    }
+                    cursor = page.next_cursor;
+                }
+            }
             SuiClientApi::Embedded(c) => c.get_objects_owned_by_address(address).await?,
         })
     }
 
+    /// Returns a page of at most `limit` objects owned by `address`, starting from `cursor`.
+    /// Not supported by the embedded gateway client.
+    pub async fn get_objects_owned_by_address_page(
+        &self,
+        address: SuiAddress,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> anyhow::Result<ObjectsPage> {
+        Ok(match &*self.api {
+            SuiClientApi::Rpc(c) => {
+                c.http
+                    .get_objects_owned_by_address(address, cursor, limit)
+                    .await?
+            }
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+
+    /// Returns all objects owned by the object `object_id`, fetching every page from the server.
     pub async fn get_objects_owned_by_object(
         &self,
         object_id: ObjectID,
     ) -> anyhow::Result<Vec<SuiObjectInfo>> {
         Ok(match &*self.api {
-            SuiClientApi::Rpc(c) => c.http.get_objects_owned_by_object(object_id).await?,
+            SuiClientApi::Rpc(c) => {
+                let mut objects = Vec::new();
+                let mut cursor = None;
+                loop {
+                    let page = c
+                        .http
+                        .get_objects_owned_by_object(object_id, cursor, None)
+                        .await?;
+                    objects.extend(page.data);
+                    if !page.has_next_page {
+                        break objects;
+                    }
+                    cursor = page.next_cursor;
+                }
+            }
             SuiClientApi::Embedded(c) => c.get_objects_owned_by_object(object_id).await?,
         })
     }
@@ -343,24 +403,141 @@ impl FullNodeApi {
             }
         })
     }
+
+    /// Return the total balance of `coin_type` owned by `owner`, defaults to SUI.
+    pub async fn get_balance(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+    ) -> anyhow::Result<SuiBalance> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_balance(owner, coin_type).await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+
+    /// Return the total balance of every coin type owned by `owner`.
+    pub async fn get_all_balances(&self, owner: SuiAddress) -> anyhow::Result<Vec<SuiBalance>> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_all_balances(owner).await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+
+    /// Return a page of the coins of `coin_type` owned by `owner`, defaults to SUI.
+    pub async fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: Option<String>,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> anyhow::Result<CoinPage> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_coins(owner, coin_type, cursor, limit).await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
 }
 pub struct EventApi(Arc<SuiClientApi>);
 
 impl EventApi {
+    /// Subscribe to events matching `filter`. When a `cursor` is provided, events stored by
+    /// the full node from that event ID onwards are replayed before live events are streamed.
     pub async fn subscribe_event(
         &self,
         filter: SuiEventFilter,
+        cursor: Option<EventID>,
     ) -> anyhow::Result<impl Stream<Item = Result<SuiEventEnvelope, anyhow::Error>>> {
         match &*self.0 {
             SuiClientApi::Rpc(RpcClient { ws: Some(c), .. }) => {
                 let subscription: Subscription<SuiEventEnvelope> =
-                    c.subscribe_event(filter).await?;
+                    c.subscribe_event(filter, cursor).await?;
                 Ok(subscription.map(|item| Ok(item?)))
             }
             _ => Err(anyhow!("Subscription only supported by WebSocket client.")),
         }
     }
+
+    /// Query events stored by the full node that match `query`, a page at a time.
+    pub async fn get_events(
+        &self,
+        query: SuiEventFilter,
+        cursor: Option<EventID>,
+        limit: Option<usize>,
+        descending_order: Option<bool>,
+    ) -> anyhow::Result<EventPage> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => {
+                c.http
+                    .get_events(query, cursor, limit, descending_order)
+                    .await?
+            }
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
 }
+pub struct GovernanceApi(Arc<SuiClientApi>);
+
+impl GovernanceApi {
+    /// Return the current Sui system state object.
+    pub async fn get_sui_system_state(&self) -> anyhow::Result<SuiSystemState> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_sui_system_state().await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+
+    /// Return the validators in the active validator set.
+    pub async fn get_validators(&self) -> anyhow::Result<Vec<Validator>> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_validators().await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+
+    /// Return the validators that will join the active set at the next epoch.
+    pub async fn get_pending_validators(&self) -> anyhow::Result<Vec<Validator>> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_pending_validators().await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+
+    /// Return the delegations owned by `owner`.
+    pub async fn get_delegations(&self, owner: SuiAddress) -> anyhow::Result<Vec<Delegation>> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_delegations(owner).await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+
+    /// Return the staked SUI objects owned by `owner`.
+    pub async fn get_staked_sui(&self, owner: SuiAddress) -> anyhow::Result<Vec<StakedSui>> {
+        Ok(match &*self.0 {
+            SuiClientApi::Rpc(c) => c.http.get_staked_sui(owner).await?,
+            SuiClientApi::Embedded(_) => {
+                return Err(anyhow!("Method not supported by embedded gateway client."))
+            }
+        })
+    }
+}
+
 pub struct QuorumDriver {
     api: Arc<SuiClientApi>,
 }
@@ -382,18 +559,29 @@ impl QuorumDriver {
     ) -> anyhow::Result<TransactionExecutionResult> {
         Ok(match &*self.api {
             SuiClientApi::Rpc(c) => {
-                let (tx_bytes, flag, signature, pub_key) = tx.to_network_data_for_execution();
                 let request_type =
                     request_type.unwrap_or(ExecuteTransactionRequestType::WaitForLocalExecution);
-                let resp = TransactionExecutionApiClient::execute_transaction(
-                    &c.http,
-                    tx_bytes,
-                    flag,
-                    signature,
-                    pub_key,
-                    request_type.clone(),
-                )
-                .await?;
+                let resp = if let Some(sponsor_signature) = &tx.signed_data.sponsor_signature {
+                    TransactionExecutionApiClient::execute_sponsored_transaction(
+                        &c.http,
+                        Base64::from_bytes(&tx.signed_data.data.to_bytes()),
+                        Base64::from_bytes(tx.signed_data.tx_signature.as_ref()),
+                        Base64::from_bytes(sponsor_signature.as_ref()),
+                        request_type.clone(),
+                    )
+                    .await?
+                } else {
+                    let (tx_bytes, flag, signature, pub_key) = tx.to_network_data_for_execution();
+                    TransactionExecutionApiClient::execute_transaction(
+                        &c.http,
+                        tx_bytes,
+                        flag,
+                        signature,
+                        pub_key,
+                        request_type.clone(),
+                    )
+                    .await?
+                };
 
                 match (request_type, resp) {
                     (
@@ -537,6 +725,9 @@ impl SuiClient {
     pub fn event_api(&self) -> &EventApi {
         &self.event_api
     }
+    pub fn governance_api(&self) -> &GovernanceApi {
+        &self.governance_api
+    }
     pub fn quorum_driver(&self) -> &QuorumDriver {
         &self.quorum_driver
     }
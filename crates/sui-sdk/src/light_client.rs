@@ -0,0 +1,152 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Verification of checkpoint certificates without trusting the node they are fetched from.
+//!
+//! Starting from a trusted committee (usually the genesis committee), the `CheckpointVerifier`
+//! follows the committees across epochs through the `next_epoch_committee` committed by the
+//! last checkpoint of each epoch. Any checkpoint certificate of the current epoch can then be
+//! verified, and used to prove that a transaction was executed with the given effects, since
+//! the checkpoint contents commit to the digests of both.
+//!
+//! The verifier does not fetch anything: checkpoints and their contents can come from any
+//! source, e.g. validators (as with `sui-tool fetch-checkpoint`) or several full nodes.
+
+use std::collections::BTreeMap;
+
+use anyhow::{bail, ensure};
+
+use sui_types::base_types::{ExecutionDigests, TransactionDigest, TransactionEffectsDigest};
+use sui_types::committee::{Committee, EpochId};
+use sui_types::messages::TransactionEffects;
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointContents, CheckpointSequenceNumber,
+};
+
+pub struct CheckpointVerifier {
+    committee: Committee,
+    latest_checkpoint: Option<CertifiedCheckpointSummary>,
+}
+
+impl CheckpointVerifier {
+    /// Create a verifier trusting `committee`, usually the genesis committee.
+    pub fn new(committee: Committee) -> Self {
+        Self {
+            committee,
+            latest_checkpoint: None,
+        }
+    }
+
+    /// The committee of the current epoch, which checkpoint certificates are verified against.
+    pub fn committee(&self) -> &Committee {
+        &self.committee
+    }
+
+    pub fn epoch(&self) -> EpochId {
+        self.committee.epoch
+    }
+
+    /// The checkpoint with the highest sequence number verified so far.
+    pub fn latest_checkpoint(&self) -> Option<&CertifiedCheckpointSummary> {
+        self.latest_checkpoint.as_ref()
+    }
+
+    /// Verify a checkpoint certificate of the current epoch, and its contents if provided.
+    ///
+    /// Checkpoints of later epochs can only be verified once the last checkpoint of every
+    /// epoch in between has been verified: the committee of the next epoch it commits to
+    /// then becomes the current committee. If the checkpoint directly follows the latest
+    /// verified checkpoint, it must also link to it.
+    pub fn verify_checkpoint(
+        &mut self,
+        checkpoint: &CertifiedCheckpointSummary,
+        contents: Option<&CheckpointContents>,
+    ) -> Result<(), anyhow::Error> {
+        let summary = &checkpoint.summary;
+        ensure!(
+            summary.epoch == self.committee.epoch,
+            "Checkpoint {} is in epoch {}, but the verifier is in epoch {}. The last checkpoint of each previous epoch must be verified first",
+            summary.sequence_number,
+            summary.epoch,
+            self.committee.epoch
+        );
+        checkpoint.verify(&self.committee, contents)?;
+
+        if let Some(latest) = &self.latest_checkpoint {
+            if summary.sequence_number <= latest.summary.sequence_number {
+                // An older checkpoint of the current epoch, nothing to update.
+                return Ok(());
+            }
+            if summary.sequence_number == latest.summary.sequence_number + 1 {
+                ensure!(
+                    summary.previous_digest == Some(latest.summary.digest()),
+                    "Checkpoint {} does not link to the previous checkpoint",
+                    summary.sequence_number
+                );
+            }
+        }
+
+        if let Some(next_epoch_committee) = &summary.next_epoch_committee {
+            let voting_rights: BTreeMap<_, _> = next_epoch_committee.iter().cloned().collect();
+            self.committee = Committee::new(summary.epoch + 1, voting_rights)?;
+        }
+        self.latest_checkpoint = Some(checkpoint.clone());
+        Ok(())
+    }
+
+    /// Verify a checkpoint certificate along with its contents, and check that the contents
+    /// include the given transaction with the given effects digest. This proves that the
+    /// transaction is final, and that its effects are the ones agreed by the committee.
+    pub fn verify_transaction_inclusion(
+        &mut self,
+        checkpoint: &CertifiedCheckpointSummary,
+        contents: &CheckpointContents,
+        transaction: &TransactionDigest,
+        effects: &TransactionEffectsDigest,
+    ) -> Result<(), anyhow::Error> {
+        self.verify_checkpoint(checkpoint, Some(contents))?;
+        check_inclusion(
+            checkpoint.summary.sequence_number,
+            contents,
+            &ExecutionDigests::new(*transaction, *effects),
+        )
+    }
+
+    /// Like `verify_transaction_inclusion`, for effects received from an untrusted node.
+    pub fn verify_effects_inclusion(
+        &mut self,
+        checkpoint: &CertifiedCheckpointSummary,
+        contents: &CheckpointContents,
+        effects: &TransactionEffects,
+    ) -> Result<(), anyhow::Error> {
+        self.verify_transaction_inclusion(
+            checkpoint,
+            contents,
+            &effects.transaction_digest,
+            &effects.digest(),
+        )
+    }
+}
+
+fn check_inclusion(
+    sequence_number: CheckpointSequenceNumber,
+    contents: &CheckpointContents,
+    digests: &ExecutionDigests,
+) -> Result<(), anyhow::Error> {
+    match contents
+        .iter()
+        .find(|included| included.transaction == digests.transaction)
+    {
+        Some(included) if included.effects == digests.effects => Ok(()),
+        Some(_) => bail!(
+            "Transaction {:?} is included in checkpoint {} with different effects",
+            digests.transaction,
+            sequence_number
+        ),
+        None => bail!(
+            "Transaction {:?} is not included in checkpoint {}",
+            digests.transaction,
+            sequence_number
+        ),
+    }
+}
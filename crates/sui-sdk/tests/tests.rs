@@ -5,8 +5,19 @@ use std::str::FromStr;
 use sha3::{Digest, Sha3_256};
 use tempfile::TempDir;
 
-use sui_sdk::crypto::{AccountKeystore, FileBasedKeystore, Keystore};
-use sui_types::crypto::{SignatureScheme, SuiSignatureInner};
+use rand::{rngs::StdRng, SeedableRng};
+use sui_sdk::crypto::{AccountKeystore, EncryptedFileKeystore, FileBasedKeystore, Keystore};
+use sui_sdk::light_client::CheckpointVerifier;
+use sui_types::base_types::{ExecutionDigests, TransactionEffectsDigest};
+use sui_types::committee::Committee;
+use sui_types::crypto::{
+    AuthorityKeyPair, EncodeDecodeBase64, KeypairTraits, SignatureScheme, SuiSignatureInner,
+};
+use sui_types::messages_checkpoint::{
+    CertifiedCheckpointSummary, CheckpointContents, CheckpointDigest, SignedCheckpointSummary,
+};
+use sui_types::utils::make_committee_key_num;
+use sui_types::waypoint::Accumulator;
 use sui_types::{
     base_types::{SuiAddress, SUI_ADDRESS_LENGTH},
     crypto::Ed25519SuiSignature,
@@ -68,3 +79,154 @@ fn keystore_display_test() -> Result<(), anyhow::Error> {
     assert!(!keystore.to_string().contains("keys:"));
     Ok(())
 }
+
+#[test]
+fn encrypted_keystore_test() -> Result<(), anyhow::Error> {
+    let temp_dir = TempDir::new().unwrap();
+    let keystore_path = temp_dir.path().join("sui.keystore");
+    let mut keystore = Keystore::from(EncryptedFileKeystore::create(
+        &keystore_path,
+        "password",
+        [],
+    )?);
+    let (address, _, _) = keystore.generate_new_key(SignatureScheme::ED25519, None)?;
+    keystore.sign(&address, b"hello")?;
+
+    // Keys are not stored in plaintext.
+    let contents = std::fs::read_to_string(&keystore_path)?;
+    let key = keystore.get_key(&address)?.encode_base64();
+    assert!(!contents.contains(&key));
+
+    // The keystore is locked when opened, and only unlocks with the right password.
+    let mut reopened = EncryptedFileKeystore::new(&keystore_path);
+    assert!(reopened.is_locked());
+    assert!(reopened.keys().is_empty());
+    assert!(reopened.sign(&address, b"hello").is_err());
+    assert!(reopened.unlock("wrong password").is_err());
+    reopened.unlock("password")?;
+    assert_eq!(reopened.addresses(), vec![address]);
+    reopened.sign(&address, b"hello")?;
+    reopened.lock();
+    assert!(reopened.get_key(&address).is_err());
+
+    // After a password change, only the new password unlocks the keystore.
+    reopened.change_password("password", "new password")?;
+    let mut reopened = EncryptedFileKeystore::new(&keystore_path);
+    assert!(reopened.unlock("password").is_err());
+    reopened.unlock("new password")?;
+    assert_eq!(reopened.get_key(&address)?.encode_base64(), key);
+    Ok(())
+}
+
+fn certify_checkpoint(
+    keys: &[AuthorityKeyPair],
+    committee: &Committee,
+    sequence_number: u64,
+    contents: &CheckpointContents,
+    previous_digest: Option<CheckpointDigest>,
+    next_epoch_committee: Option<Committee>,
+) -> CertifiedCheckpointSummary {
+    let signed = keys
+        .iter()
+        .map(|key| {
+            SignedCheckpointSummary::new(
+                committee.epoch,
+                sequence_number,
+                key.public().into(),
+                key,
+                contents,
+                previous_digest,
+                next_epoch_committee.clone(),
+                Accumulator::default(),
+            )
+        })
+        .collect();
+    CertifiedCheckpointSummary::aggregate(signed, committee).unwrap()
+}
+
+#[test]
+fn checkpoint_verifier_test() {
+    let mut rng = StdRng::from_seed([0; 32]);
+    let (keys0, committee0) = make_committee_key_num(4, &mut rng);
+    let (keys1, committee1) = make_committee_key_num(4, &mut rng);
+    let committee1 = Committee::new(1, committee1.voting_rights.into_iter().collect()).unwrap();
+
+    // The last checkpoint of epoch 0 hands over to the committee of epoch 1.
+    let contents0 = CheckpointContents::new_with_causally_ordered_transactions(
+        [ExecutionDigests::random()].into_iter(),
+    );
+    let checkpoint0 = certify_checkpoint(
+        &keys0,
+        &committee0,
+        0,
+        &contents0,
+        None,
+        Some(committee1.clone()),
+    );
+    let executed = ExecutionDigests::random();
+    let contents1 = CheckpointContents::new_with_causally_ordered_transactions(
+        [ExecutionDigests::random(), executed].into_iter(),
+    );
+    let checkpoint1 = certify_checkpoint(
+        &keys1,
+        &committee1,
+        1,
+        &contents1,
+        Some(checkpoint0.summary.digest()),
+        None,
+    );
+
+    let mut verifier = CheckpointVerifier::new(committee0.clone());
+    // Epoch 1 checkpoints cannot be verified before the end of epoch 0.
+    assert!(verifier.verify_checkpoint(&checkpoint1, None).is_err());
+    // Contents must match the checkpoint.
+    assert!(verifier
+        .verify_checkpoint(&checkpoint0, Some(&contents1))
+        .is_err());
+
+    verifier
+        .verify_checkpoint(&checkpoint0, Some(&contents0))
+        .unwrap();
+    assert_eq!(verifier.epoch(), 1);
+    assert_eq!(verifier.committee(), &committee1);
+
+    verifier
+        .verify_transaction_inclusion(
+            &checkpoint1,
+            &contents1,
+            &executed.transaction,
+            &executed.effects,
+        )
+        .unwrap();
+    assert_eq!(
+        verifier
+            .latest_checkpoint()
+            .unwrap()
+            .summary
+            .sequence_number,
+        1
+    );
+
+    // Other effects for the same transaction are rejected...
+    assert!(verifier
+        .verify_transaction_inclusion(
+            &checkpoint1,
+            &contents1,
+            &executed.transaction,
+            &TransactionEffectsDigest::random(),
+        )
+        .is_err());
+    // ...as well as transactions not in the checkpoint.
+    let other = ExecutionDigests::random();
+    assert!(verifier
+        .verify_transaction_inclusion(&checkpoint1, &contents1, &other.transaction, &other.effects)
+        .is_err());
+
+    // A checkpoint certified by the wrong committee is rejected.
+    let forged_committee =
+        Committee::new(1, committee0.voting_rights.into_iter().collect()).unwrap();
+    let forged = certify_checkpoint(&keys0, &forged_committee, 2, &contents1, None, None);
+    assert!(CheckpointVerifier::new(committee1)
+        .verify_checkpoint(&forged, None)
+        .is_err());
+}
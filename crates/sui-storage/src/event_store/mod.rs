@@ -16,7 +16,6 @@ use async_trait::async_trait;
 use enum_dispatch::enum_dispatch;
 use futures::prelude::stream::BoxStream;
 use move_core_types::identifier::Identifier;
-use move_core_types::language_storage::ModuleId;
 use move_core_types::value::MoveValue;
 use serde_json::Value;
 use std::collections::BTreeMap;
@@ -26,8 +25,10 @@ use sui_types::base_types::{ObjectID, SequenceNumber, SuiAddress, TransactionDig
 use sui_types::error::SuiError;
 use sui_types::error::SuiError::{StorageCorruptedFieldError, StorageMissingFieldError};
 use sui_types::event::{Event, TransferType};
-use sui_types::event::{EventEnvelope, EventType};
+use sui_types::event::{EventEnvelope, EventID, EventType};
+use sui_types::filter::EventFilter;
 use sui_types::object::Owner;
+use sui_types::parse_sui_struct_tag;
 use tokio_stream::StreamExt;
 
 pub mod sql;
@@ -39,6 +40,10 @@ use flexstr::SharedStr;
 /// Maximum number of events one can ask for right now
 pub const EVENT_STORE_QUERY_MAX_LIMIT: usize = 1000;
 
+/// Maximum number of events read from the store to answer one query that has to be filtered
+/// in-process
+pub const EVENT_STORE_QUERY_MAX_SCAN: usize = 10 * EVENT_STORE_QUERY_MAX_LIMIT;
+
 pub const TRANSFER_TYPE_KEY: &str = "xfer_type";
 pub const OBJECT_VERSION_KEY: &str = "obj_ver";
 pub const AMOUNT_KEY: &str = "amount";
@@ -47,6 +52,8 @@ pub const AMOUNT_KEY: &str = "amount";
 #[allow(unused)]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct StoredEvent {
+    /// Sequence number of the emitting transaction and index of the event within it
+    id: EventID,
     /// UTC timestamp in milliseconds
     timestamp: u64,
     /// Not present for non-transaction System events (eg EpochChange)
@@ -169,6 +176,89 @@ impl StoredEvent {
         })
     }
 
+    pub fn id(&self) -> EventID {
+        self.id
+    }
+
+    /// Rebuilds the [EventEnvelope] that was originally ingested, so that an [EventFilter]
+    /// can be evaluated against a stored event.
+    ///
+    /// [EventFilter]: sui_types::filter::EventFilter
+    pub fn into_event_envelope(self) -> Result<EventEnvelope, anyhow::Error> {
+        let event_type_str = self.event_type.as_str();
+        let event = match EventType::from_str(event_type_str) {
+            Ok(EventType::MoveEvent) => {
+                let type_ = self.move_event_name.as_ref().ok_or_else(|| {
+                    anyhow::anyhow!(StorageMissingFieldError(format!(
+                        "Missing move_event_name for event {:?}",
+                        self
+                    )))
+                })?;
+                Event::MoveEvent {
+                    package_id: self.package_id()?,
+                    transaction_module: Identifier::from_str(&self.transaction_module()?)?,
+                    sender: self.sender()?,
+                    type_: parse_sui_struct_tag(type_)?,
+                    contents: self.move_event_contents.clone().unwrap_or_default(),
+                }
+            }
+            Ok(EventType::Publish) => Event::Publish {
+                sender: self.sender()?,
+                package_id: self.package_id()?,
+            },
+            Ok(EventType::TransferObject) => Event::TransferObject {
+                package_id: self.package_id()?,
+                transaction_module: Identifier::from_str(&self.transaction_module()?)?,
+                sender: self.sender()?,
+                recipient: self.recipient()?,
+                object_id: self.object_id()?,
+                version: self.object_version()?.ok_or_else(|| {
+                    anyhow::anyhow!("Can't extract object version from StoredEvent: {self:?}")
+                })?,
+                type_: self.transfer_type()?.ok_or_else(|| {
+                    anyhow::anyhow!("Can't extract transfer type from StoredEvent: {self:?}")
+                })?,
+                amount: self.amount()?,
+            },
+            Ok(EventType::DeleteObject) => Event::DeleteObject {
+                package_id: self.package_id()?,
+                transaction_module: Identifier::from_str(&self.transaction_module()?)?,
+                sender: self.sender()?,
+                object_id: self.object_id()?,
+            },
+            Ok(EventType::NewObject) => Event::NewObject {
+                package_id: self.package_id()?,
+                transaction_module: Identifier::from_str(&self.transaction_module()?)?,
+                sender: self.sender()?,
+                recipient: self.recipient()?,
+                object_id: self.object_id()?,
+            },
+            // TODO support "EpochChange" and "Checkpoint"
+            Ok(other) => anyhow::bail!("Unsupported event type: {other:?}"),
+            Err(e) => anyhow::bail!("Invalid EventType {event_type_str}: {e:?}"),
+        };
+        // For move events the extra fields hold the JSON value of the event struct
+        let move_struct_json_value = matches!(event, Event::MoveEvent { .. }).then(|| {
+            Value::Object(
+                self.fields
+                    .iter()
+                    .filter_map(|(k, v)| match v {
+                        EventValue::Json(json) => Some((k.to_string(), json.clone())),
+                        _ => None,
+                    })
+                    .collect(),
+            )
+        });
+        Ok(EventEnvelope::new(
+            self.timestamp,
+            self.tx_digest,
+            self.id.tx_seq,
+            self.id.event_seq,
+            event,
+            move_struct_json_value,
+        ))
+    }
+
     /// Convert a vec of StoredEvents into a vec of SuiEventEnvelope.
     /// Returns Err when any conversion fails.
     pub fn into_event_envelopes(
@@ -276,6 +366,7 @@ impl TryInto<SuiEventEnvelope> for StoredEvent {
     fn try_into(self) -> Result<SuiEventEnvelope, Self::Error> {
         let timestamp = self.timestamp;
         let tx_digest = self.tx_digest;
+        let id = self.id;
         let event_type_str = self.event_type.as_str();
         let event = match EventType::from_str(event_type_str) {
             Ok(type_) => {
@@ -295,6 +386,7 @@ impl TryInto<SuiEventEnvelope> for StoredEvent {
         Ok(SuiEventEnvelope {
             timestamp,
             tx_digest,
+            id,
             event,
         })
     }
@@ -317,6 +409,9 @@ pub enum EventValue {
 /// One can think of events as logs.  They represent a log of what is happening to Sui.
 /// Thus, all different kinds of events fit on a timeline, and one should be able to query for
 /// different types of events that happen over that timeline.
+///
+/// Queries take an optional `cursor`: results start from (and include) the event with that ID,
+/// so callers can page through large result sets.
 #[async_trait]
 #[enum_dispatch]
 pub trait EventStore {
@@ -327,84 +422,26 @@ pub trait EventStore {
     /// Returns Ok(rows_affected).
     async fn add_events(&self, events: &[EventEnvelope]) -> Result<u64, SuiError>;
 
-    /// Returns at most `limit` events emitted by a given
-    /// transaction, sorted in order emitted.
-    async fn events_by_transaction(
+    /// Returns at most `limit` events starting from (and including) the event with ID `cursor`,
+    /// sorted in ascending event ID order, i.e. the order in which they were emitted.
+    async fn events_from_cursor(
         &self,
-        digest: TransactionDigest,
+        cursor: EventID,
         limit: usize,
     ) -> Result<Vec<StoredEvent>, SuiError>;
 
-    /// Returns at most `limit` events of a certain EventType
-    /// (e.g. `TransferObject`) within [start_time, end_time),
-    /// sorted in in ascending time.
-    async fn events_by_type(
+    /// Returns at most `limit` events matching `filter`, sorted in ascending event ID order, or
+    /// descending if `descending` is set, along with the cursor of the next page if there may be
+    /// more matching events.  The parts of the filter that map onto indexed columns are
+    /// evaluated by the store; the rest (e.g. `MoveEventField`) are evaluated in-process, reading
+    /// at most EVENT_STORE_QUERY_MAX_SCAN events, so a page can be short and still have a next one.
+    async fn query_events(
         &self,
-        start_time: u64,
-        end_time: u64,
-        event_type: EventType,
+        filter: &EventFilter,
+        cursor: Option<EventID>,
         limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError>;
-
-    /// Returns at most `limit` events emitted in a certain Module ID during
-    /// [start_time, end_time), sorted in ascending time.
-    async fn events_by_module_id(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        module: &ModuleId,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError>;
-
-    /// Returns at most `limit` events with the move event struct name
-    /// (e.g. `0x2::devnet_nft::MintNFTEvent`) emitted
-    /// during [start_time, end_time), sorted in ascending time.
-    async fn events_by_move_event_struct_name(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        move_event_struct_name: &str,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError>;
-
-    /// Returns at most `limit` events associated with a certain sender
-    /// emitted during [start_time, end_time), sorted in ascending time.
-    async fn events_by_sender(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        sender: &SuiAddress,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError>;
-
-    /// Returns at most `limit` events associated with a certain recipient
-    /// emitted during [start_time, end_time), sorted in ascending time.
-    async fn events_by_recipient(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        recipient: &Owner,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError>;
-
-    /// Returns at most `limit` events associated with a certain object id
-    /// emitted during [start_time, end_time), sorted in ascending time.
-    async fn events_by_object(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        object: &ObjectID,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError>;
-
-    /// Generic event iterator that returns events emitted between
-    /// [start_time, end_time), sorted in ascending time.
-    async fn event_iterator(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError>;
+        descending: bool,
+    ) -> Result<(Vec<StoredEvent>, Option<EventID>), SuiError>;
 }
 
 /// EventStoreType contains different implementations of EventStores, but implements the EventStore trait.
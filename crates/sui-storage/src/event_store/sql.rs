@@ -16,11 +16,13 @@ use sui_types::base_types::SuiAddress;
 use sui_types::object::Owner;
 
 use sqlx::{
-    sqlite::{SqliteConnectOptions, SqliteRow},
-    Executor, Row, SqlitePool,
+    query::Query,
+    sqlite::{Sqlite, SqliteArguments, SqliteConnectOptions, SqliteRow},
+    Executor, QueryBuilder, Row, SqlitePool,
 };
 use sui_types::error::SuiError;
 use sui_types::event::{Event, TransferTypeVariants};
+use sui_types::filter::{EventFilter, Filter};
 use tracing::{debug, info, instrument, log, warn};
 
 /// Sqlite-based Event Store
@@ -72,14 +74,17 @@ enum EventsTableColumns {
     Sender,
     /// recipient TEXT
     Recipient,
+    /// event_num INTEGER
+    EventNum,
 }
 
 const SQL_INSERT_TX: &str = "INSERT INTO events (timestamp, seq_num, tx_digest, event_type, \
     package_id, module_name, object_id, fields, move_event_name, contents, sender,  \
-    recipient) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)";
+    recipient, event_num) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)";
 
 const INDEXED_COLUMNS: &[&str] = &[
     "timestamp",
+    "seq_num",
     "tx_digest",
     "event_type",
     "package_id",
@@ -138,6 +143,26 @@ impl SqlEventStore {
             .map_err(convert_sqlx_err)?;
         info!("SQLite events table is initialized with query {create_sql:?}");
 
+        // Tables created by older versions lack the columns appended to the enum since, add them
+        let existing_columns: Vec<String> = sqlx::query("PRAGMA table_info(events)")
+            .map(|row: SqliteRow| row.get("name"))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(convert_sqlx_err)?;
+        let mut backfill_event_num = false;
+        for column in &table_columns {
+            let name = column.split_whitespace().next().unwrap();
+            if existing_columns.iter().any(|c| c == name) {
+                continue;
+            }
+            self.pool
+                .execute(format!("ALTER TABLE events ADD COLUMN {column}").as_str())
+                .await
+                .map_err(convert_sqlx_err)?;
+            info!(column, "Added missing column to SQLite events table");
+            backfill_event_num |= name == "event_num";
+        }
+
         // Then, create indexes
         for column in INDEXED_COLUMNS {
             // NOTE: Cannot prepare CREATE INDEX statements.
@@ -155,6 +180,21 @@ impl SqlEventStore {
             info!(column, "Index is ready");
         }
 
+        if backfill_event_num {
+            // Events of a transaction were inserted in the order they were emitted, so their
+            // index within the transaction can be recovered from the row ids. This runs after the
+            // indexes are created, so that the smallest row id of each transaction is looked up
+            // in the seq_num index rather than by scanning the table for every row.
+            self.pool
+                .execute(
+                    "UPDATE events SET event_num = rowid - (SELECT MIN(e.rowid) FROM events e \
+                    WHERE e.seq_num = events.seq_num)",
+                )
+                .await
+                .map_err(convert_sqlx_err)?;
+            info!("Backfilled event_num column of SQLite events table");
+        }
+
         // Setting last sequence number
         let last_seq_num = self.last_seq_num().await?;
         self.seq_num.store(last_seq_num, Ordering::Relaxed);
@@ -255,6 +295,83 @@ impl SqlEventStore {
         }
     }
 
+    /// Implements `EventStore::query_events`, reading at most `max_scan` events from the table.
+    async fn query_events_with_max_scan(
+        &self,
+        filter: &EventFilter,
+        cursor: Option<EventID>,
+        limit: usize,
+        descending: bool,
+        max_scan: usize,
+    ) -> Result<(Vec<StoredEvent>, Option<EventID>), SuiError> {
+        let limit = Self::cap_limit(limit);
+        let order = if descending { "DESC" } else { "ASC" };
+        // One extra row is read to find where the next page starts
+        let batch_size = limit + 1;
+        let mut cursor = cursor.map(|cursor| (cursor, true));
+        let mut scanned = 0;
+        let mut events = Vec::new();
+        loop {
+            let mut builder = QueryBuilder::new("SELECT * FROM events WHERE ");
+            let exact = Self::push_filter(&mut builder, filter)?;
+            if let Some((cursor, inclusive)) = cursor {
+                Self::push_cursor(&mut builder, cursor, inclusive, descending);
+            }
+            builder
+                .push(format!(
+                    " ORDER BY seq_num {order}, event_num {order} LIMIT "
+                ))
+                .push_bind(batch_size as i64);
+            let rows = builder
+                .build()
+                .map(StoredEvent::from)
+                .fetch_all(&self.pool)
+                .await
+                .map_err(convert_sqlx_err)?;
+
+            // When the SQL condition is only a superset of the filter, keep scanning until the
+            // page is full or the scan budget is spent, and let the caller resume from there.
+            let exhausted = rows.len() < batch_size;
+            cursor = rows.last().map(|event| (event.id(), false));
+            for event in rows {
+                if events.len() == limit || scanned == max_scan {
+                    return Ok((events, Some(event.id())));
+                }
+                scanned += 1;
+                if exact || Self::matches(filter, &event) {
+                    events.push(event);
+                }
+            }
+            if exhausted {
+                return Ok((events, None));
+            }
+        }
+    }
+
+    /// Like `Filter::matches`, events the filter cannot be evaluated against don't match.
+    fn matches(filter: &EventFilter, event: &StoredEvent) -> bool {
+        match event.clone().into_event_envelope() {
+            Ok(envelope) => filter.matches(&envelope),
+            Err(e) => {
+                warn!(event_id = ?event.id(), error = ?e, "Cannot rebuild stored event");
+                false
+            }
+        }
+    }
+
+    /// Binds the `(seq_num > ? OR (seq_num = ? AND event_num >= ?))` cursor condition shared by
+    /// all queries.  A missing cursor starts from the very first event.
+    fn bind_cursor<'q>(
+        query: Query<'q, Sqlite, SqliteArguments<'q>>,
+        cursor: Option<EventID>,
+    ) -> Query<'q, Sqlite, SqliteArguments<'q>> {
+        let cursor = cursor.unwrap_or_default();
+        query
+            .bind(cursor.tx_seq as i64)
+            .bind(cursor.tx_seq as i64)
+            .bind(cursor.event_seq as i64)
+    }
+
     fn cap_limit(limit: usize) -> usize {
         if limit > EVENT_STORE_QUERY_MAX_LIMIT {
             EVENT_STORE_QUERY_MAX_LIMIT
@@ -262,6 +379,123 @@ impl SqlEventStore {
             limit
         }
     }
+
+    /// Appends a SQL condition equivalent to `filter` to `builder`.
+    /// Returns false if the condition is only a superset of the filter, as is the case when
+    /// `MoveEventField` is involved, in which case the rows must be filtered again in-process.
+    fn push_filter(
+        builder: &mut QueryBuilder<'_, Sqlite>,
+        filter: &EventFilter,
+    ) -> Result<bool, SuiError> {
+        Ok(match filter {
+            EventFilter::Package(package_id) => {
+                builder.push("package_id = ").push_bind(package_id.to_vec());
+                true
+            }
+            EventFilter::Module(module) => {
+                builder.push("module_name = ").push_bind(module.to_string());
+                true
+            }
+            EventFilter::MoveEventType(struct_tag) => {
+                builder
+                    .push("move_event_name = ")
+                    .push_bind(struct_tag.to_string());
+                true
+            }
+            EventFilter::EventType(event_type) => {
+                builder.push("event_type = ").push_bind(*event_type as u16);
+                true
+            }
+            EventFilter::SenderAddress(sender) => {
+                builder.push("sender = ").push_bind(sender.to_vec());
+                true
+            }
+            EventFilter::Recipient(recipient) => {
+                let recipient_str = serde_json::to_string(recipient).map_err(|e| {
+                    SuiError::OwnerFailedToSerialize {
+                        error: (e.to_string()),
+                    }
+                })?;
+                builder.push("recipient = ").push_bind(recipient_str);
+                true
+            }
+            EventFilter::ObjectId(object_id) => {
+                builder.push("object_id = ").push_bind(object_id.to_vec());
+                true
+            }
+            EventFilter::Transaction(digest) => {
+                builder.push("tx_digest = ").push_bind(digest.to_bytes());
+                true
+            }
+            EventFilter::TimeRange {
+                start_time,
+                end_time,
+            } => {
+                builder
+                    .push("(timestamp >= ")
+                    .push_bind(*start_time as i64)
+                    .push(" AND timestamp < ")
+                    .push_bind(*end_time as i64)
+                    .push(")");
+                true
+            }
+            // The JSON fields are not indexed, match everything and filter in-process
+            EventFilter::MoveEventField { .. } => {
+                builder.push("1");
+                false
+            }
+            EventFilter::MatchAll(filters) => Self::push_filters(builder, filters, " AND ", "1")?,
+            EventFilter::MatchAny(filters) => Self::push_filters(builder, filters, " OR ", "0")?,
+        })
+    }
+
+    fn push_filters(
+        builder: &mut QueryBuilder<'_, Sqlite>,
+        filters: &[EventFilter],
+        separator: &str,
+        empty: &str,
+    ) -> Result<bool, SuiError> {
+        if filters.is_empty() {
+            builder.push(empty);
+            return Ok(true);
+        }
+        let mut exact = true;
+        builder.push("(");
+        for (i, filter) in filters.iter().enumerate() {
+            if i > 0 {
+                builder.push(separator);
+            }
+            exact &= Self::push_filter(builder, filter)?;
+        }
+        builder.push(")");
+        Ok(exact)
+    }
+
+    /// Appends the condition selecting events at or after `cursor` in the requested order, or
+    /// strictly after it if `inclusive` is false.
+    fn push_cursor(
+        builder: &mut QueryBuilder<'_, Sqlite>,
+        cursor: EventID,
+        inclusive: bool,
+        descending: bool,
+    ) {
+        let (seq_cmp, event_cmp) = match (descending, inclusive) {
+            (false, true) => (" > ", " >= "),
+            (false, false) => (" > ", " > "),
+            (true, true) => (" < ", " <= "),
+            (true, false) => (" < ", " < "),
+        };
+        builder
+            .push(" AND (seq_num")
+            .push(seq_cmp)
+            .push_bind(cursor.tx_seq as i64)
+            .push(" OR (seq_num = ")
+            .push_bind(cursor.tx_seq as i64)
+            .push(" AND event_num")
+            .push(event_cmp)
+            .push_bind(cursor.event_seq as i64)
+            .push("))");
+    }
 }
 
 impl From<SqliteRow> for StoredEvent {
@@ -270,6 +504,9 @@ impl From<SqliteRow> for StoredEvent {
     // TODO: gracefully handle data corruption/incompatibility without panicking
     fn from(row: SqliteRow) -> Self {
         let timestamp: i64 = row.get(EventsTableColumns::Timestamp as usize);
+        let seq_num: i64 = row.get(EventsTableColumns::SeqNum as usize);
+        // The event_num column is nullable, as `initialize` appends it to existing tables
+        let event_num: Option<i64> = row.get(EventsTableColumns::EventNum as usize);
         let digest_raw: Option<Vec<u8>> = row.get(EventsTableColumns::TxDigest as usize);
         let tx_digest = digest_raw.map(|bytes| {
             TransactionDigest::new(
@@ -313,6 +550,10 @@ impl From<SqliteRow> for StoredEvent {
             .expect("Error converting stored recipient address to Owner");
 
         StoredEvent {
+            id: EventID {
+                tx_seq: seq_num as u64,
+                event_seq: event_num.unwrap_or_default() as u64,
+            },
             timestamp: timestamp as u64,
             tx_digest,
             event_type: SharedStr::from(Event::name_from_ordinal(event_type as usize)),
@@ -329,29 +570,10 @@ impl From<SqliteRow> for StoredEvent {
     }
 }
 
-const TS_QUERY: &str =
-    "SELECT * FROM events WHERE timestamp >= ? AND timestamp < ? ORDER BY timestamp ASC LIMIT ?";
-
-const TX_QUERY: &str = "SELECT * FROM events WHERE tx_digest = ? LIMIT ?";
-
-// ASC is used so there's a way to fetch from last one to NOW
-const QUERY_BY_TYPE: &str = "SELECT * FROM events WHERE timestamp >= ? AND \
-    timestamp < ? AND event_type = ? ORDER BY timestamp ASC LIMIT ?";
-
-const QUERY_BY_MODULE: &str = "SELECT * FROM events WHERE timestamp >= ? AND \
-    timestamp < ? AND package_id = ? AND module_name = ? ORDER BY timestamp ASC LIMIT ?";
-
-const QUERY_BY_MOVE_EVENT_STRUCT_NAME: &str = "SELECT * FROM events WHERE timestamp >= ? AND \
-    timestamp < ? AND move_event_name = ? ORDER BY timestamp ASC LIMIT ?";
-
-const QUERY_BY_SENDER: &str = "SELECT * FROM events WHERE timestamp >= ? AND \
-    timestamp < ? AND sender = ? ORDER BY timestamp ASC LIMIT ?";
-
-const QUERY_BY_RECIPIENT: &str = "SELECT * FROM events WHERE timestamp >= ? AND \
-    timestamp < ? AND recipient = ? ORDER BY timestamp ASC LIMIT ?";
-
-const QUERY_BY_OBJECT_ID: &str = "SELECT * FROM events WHERE timestamp >= ? AND \
-    timestamp < ? AND object_id = ? ORDER BY timestamp ASC LIMIT ?";
+// Events are ordered by (seq_num, event_num), which is the order they were emitted in.
+// The cursor condition is bound through `SqlEventStore::bind_cursor`.
+const QUERY_FROM_CURSOR: &str = "SELECT * FROM events WHERE seq_num > ? OR \
+    (seq_num = ? AND event_num >= ?) ORDER BY seq_num ASC, event_num ASC LIMIT ?";
 
 #[async_trait]
 impl EventStore for SqlEventStore {
@@ -395,6 +617,7 @@ impl EventStore for SqlEventStore {
                 .bind(event.event.move_event_contents())
                 .bind(sender)
                 .bind(event.event.recipient_serialized()?)
+                .bind(event.event_num as i64)
                 .execute(&self.pool)
                 .await
                 .map_err(convert_sqlx_err)?;
@@ -414,15 +637,14 @@ impl EventStore for SqlEventStore {
     }
 
     #[instrument(level = "debug", skip_all, err)]
-    async fn events_by_transaction(
+    async fn events_from_cursor(
         &self,
-        digest: TransactionDigest,
+        cursor: EventID,
         limit: usize,
     ) -> Result<Vec<StoredEvent>, SuiError> {
         let limit = Self::cap_limit(limit);
-        let rows = sqlx::query(TX_QUERY)
-            .persistent(true)
-            .bind(digest.to_bytes())
+        let query = sqlx::query(QUERY_FROM_CURSOR).persistent(true);
+        let rows = Self::bind_cursor(query, Some(cursor))
             .bind(limit as i64)
             .map(StoredEvent::from)
             .fetch_all(&self.pool)
@@ -432,163 +654,21 @@ impl EventStore for SqlEventStore {
     }
 
     #[instrument(level = "debug", skip_all, err)]
-    async fn events_by_type(
+    async fn query_events(
         &self,
-        start_time: u64,
-        end_time: u64,
-        event_type: EventType,
+        filter: &EventFilter,
+        cursor: Option<EventID>,
         limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError> {
-        let limit = Self::cap_limit(limit);
-        let rows = sqlx::query(QUERY_BY_TYPE)
-            .persistent(true)
-            .bind(start_time as i64)
-            .bind(end_time as i64)
-            .bind(event_type as u16)
-            .bind(limit as i64)
-            .map(StoredEvent::from)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(convert_sqlx_err)?;
-        Ok(rows)
-    }
-
-    #[instrument(level = "debug", skip_all, err)]
-    async fn event_iterator(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError> {
-        let limit = Self::cap_limit(limit);
-        let rows = sqlx::query(TS_QUERY)
-            .bind(start_time as i64)
-            .bind(end_time as i64)
-            .bind(limit as i64)
-            .map(StoredEvent::from)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(convert_sqlx_err)?;
-        Ok(rows)
-    }
-
-    #[instrument(level = "debug", skip_all, err)]
-    async fn events_by_module_id(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        module: &ModuleId,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError> {
-        let limit = Self::cap_limit(limit);
-        let rows = sqlx::query(QUERY_BY_MODULE)
-            .persistent(true)
-            .bind(start_time as i64)
-            .bind(end_time as i64)
-            .bind(module.address().to_vec())
-            .bind(module.name().to_string())
-            .bind(limit as i64)
-            .map(StoredEvent::from)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(convert_sqlx_err)?;
-        Ok(rows)
-    }
-
-    #[instrument(level = "debug", skip_all, err)]
-    async fn events_by_move_event_struct_name(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        move_event_struct_name: &str,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError> {
-        let limit = Self::cap_limit(limit);
-        // TODO: duplication: these 10 lines are repetitive (4 times) in this file.
-        let rows = sqlx::query(QUERY_BY_MOVE_EVENT_STRUCT_NAME)
-            .persistent(true)
-            .bind(start_time as i64)
-            .bind(end_time as i64)
-            .bind(move_event_struct_name)
-            .bind(limit as i64)
-            .map(StoredEvent::from)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(convert_sqlx_err)?;
-        Ok(rows)
-    }
-
-    #[instrument(level = "debug", skip_all, err)]
-    async fn events_by_sender(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        sender: &SuiAddress,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError> {
-        let limit = Self::cap_limit(limit);
-        let sender_vec = sender.to_vec();
-        let rows = sqlx::query(QUERY_BY_SENDER)
-            .persistent(true)
-            .bind(start_time as i64)
-            .bind(end_time as i64)
-            .bind(sender_vec)
-            .bind(limit as i64)
-            .map(StoredEvent::from)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(convert_sqlx_err)?;
-        Ok(rows)
-    }
-
-    #[instrument(level = "debug", skip_all, err)]
-    async fn events_by_recipient(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        recipient: &Owner,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError> {
-        let limit = Self::cap_limit(limit);
-        let recipient_str =
-            serde_json::to_string(recipient).map_err(|e| SuiError::OwnerFailedToSerialize {
-                error: (e.to_string()),
-            })?;
-        let rows = sqlx::query(QUERY_BY_RECIPIENT)
-            .persistent(true)
-            .bind(start_time as i64)
-            .bind(end_time as i64)
-            .bind(recipient_str)
-            .bind(limit as i64)
-            .map(StoredEvent::from)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(convert_sqlx_err)?;
-        Ok(rows)
-    }
-
-    #[instrument(level = "debug", skip_all, err)]
-    async fn events_by_object(
-        &self,
-        start_time: u64,
-        end_time: u64,
-        object: &ObjectID,
-        limit: usize,
-    ) -> Result<Vec<StoredEvent>, SuiError> {
-        let limit = Self::cap_limit(limit);
-        let object_vec = object.to_vec();
-
-        let rows = sqlx::query(QUERY_BY_OBJECT_ID)
-            .persistent(true)
-            .bind(start_time as i64)
-            .bind(end_time as i64)
-            .bind(object_vec)
-            .bind(limit as i64)
-            .map(StoredEvent::from)
-            .fetch_all(&self.pool)
-            .await
-            .map_err(convert_sqlx_err)?;
-        Ok(rows)
+        descending: bool,
+    ) -> Result<(Vec<StoredEvent>, Option<EventID>), SuiError> {
+        self.query_events_with_max_scan(
+            filter,
+            cursor,
+            limit,
+            descending,
+            EVENT_STORE_QUERY_MAX_SCAN,
+        )
+        .await
     }
 }
 
@@ -601,7 +681,7 @@ mod tests {
     use super::test_utils;
     use super::*;
     use flexstr::shared_str;
-    use move_core_types::{account_address::AccountAddress, identifier::Identifier};
+    use move_core_types::identifier::Identifier;
 
     use sui_types::event::{EventEnvelope, TransferType};
 
@@ -634,6 +714,23 @@ mod tests {
         assert_eq!(queried.move_event_name.as_ref(), move_event_name.as_ref());
     }
 
+    /// Returns at most `limit` events matching `filter` emitted during [start_time, end_time),
+    /// in ascending event ID order.
+    async fn query_time_range(
+        db: &SqlEventStore,
+        start_time: u64,
+        end_time: u64,
+        filter: EventFilter,
+        limit: usize,
+    ) -> Result<Vec<StoredEvent>, SuiError> {
+        let filter = EventFilter::TimeRange {
+            start_time,
+            end_time,
+        }
+        .and(filter);
+        Ok(db.query_events(&filter, None, limit, false).await?.0)
+    }
+
     #[tokio::test]
     async fn test_eventstore_basic_insert_read() -> Result<(), SuiError> {
         telemetry_subscribers::init_for_testing();
@@ -680,7 +777,8 @@ mod tests {
         assert_eq!(db.total_event_count().await?, 6);
 
         // Query for records in time range, end should be exclusive - should get 2
-        let queried_events = db.event_iterator(1_000_000, 1_002_000, 20).await?;
+        let queried_events =
+            query_time_range(&db, 1_000_000, 1_002_000, EventFilter::MatchAll(vec![]), 20).await?;
         assert_eq!(queried_events.len(), 2);
         for i in 0..2 {
             // ASCENDING order
@@ -736,8 +834,14 @@ mod tests {
 
         // Query for transfer event
         let mut events = db
-            .events_by_transaction(target_event.tx_digest.unwrap(), 10)
-            .await?;
+            .query_events(
+                &EventFilter::Transaction(target_event.tx_digest.unwrap()),
+                None,
+                10,
+                false,
+            )
+            .await?
+            .0;
         assert_eq!(events.len(), 1); // Should be no more events, just that one
         let transfer_event = events.pop().unwrap();
 
@@ -792,9 +896,14 @@ mod tests {
         db.add_events(&to_insert).await?;
         info!("Done inserting");
 
-        let queried_events = db
-            .events_by_type(1_000_000, 1_005_000, EventType::TransferObject, 2)
-            .await?;
+        let queried_events = query_time_range(
+            &db,
+            1_000_000,
+            1_005_000,
+            EventFilter::EventType(EventType::TransferObject),
+            2,
+        )
+        .await?;
         assert_eq!(queried_events.len(), 2);
 
         // Desc timestamp order, so the last transfer event should be first
@@ -802,47 +911,77 @@ mod tests {
         test_queried_event_vs_test_envelope(&queried_events[1], &to_insert[4]);
 
         // Query again with limit of 1, it should return only the last transfer event
-        let queried_events = db
-            .events_by_type(1_000_000, 1_005_000, EventType::TransferObject, 1)
-            .await?;
+        let queried_events = query_time_range(
+            &db,
+            1_000_000,
+            1_005_000,
+            EventFilter::EventType(EventType::TransferObject),
+            1,
+        )
+        .await?;
         assert_eq!(queried_events.len(), 1);
         test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[2]);
         assert_eq!(queried_events[0].fields.len(), 3);
 
         // Query with wrong time range, return 0 events
-        let queried_events = db
-            .events_by_type(1_006_000, 1_009_000, EventType::TransferObject, 1)
-            .await?;
+        let queried_events = query_time_range(
+            &db,
+            1_006_000,
+            1_009_000,
+            EventFilter::EventType(EventType::TransferObject),
+            1,
+        )
+        .await?;
         assert_eq!(queried_events.len(), 0);
 
         // Query Publish Event
-        let queried_events = db
-            .events_by_type(1_001_000, 1_002_000, EventType::Publish, 1)
-            .await?;
+        let queried_events = query_time_range(
+            &db,
+            1_001_000,
+            1_002_000,
+            EventFilter::EventType(EventType::Publish),
+            1,
+        )
+        .await?;
         assert_eq!(queried_events.len(), 1);
         test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[1]);
         assert_eq!(queried_events[0].fields.len(), 0);
 
         // Query NewObject Event
-        let queried_events = db
-            .events_by_type(1_000_000, 1_002_000, EventType::NewObject, 1)
-            .await?;
+        let queried_events = query_time_range(
+            &db,
+            1_000_000,
+            1_002_000,
+            EventFilter::EventType(EventType::NewObject),
+            1,
+        )
+        .await?;
         assert_eq!(queried_events.len(), 1);
         test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[0]);
         assert_eq!(queried_events[0].fields.len(), 0);
 
         // Query DeleteObject Event
-        let queried_events = db
-            .events_by_type(1_003_000, 1_004_000, EventType::DeleteObject, 1)
-            .await?;
+        let queried_events = query_time_range(
+            &db,
+            1_003_000,
+            1_004_000,
+            EventFilter::EventType(EventType::DeleteObject),
+            1,
+        )
+        .await?;
         assert_eq!(queried_events.len(), 1);
         test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[3]);
         assert_eq!(queried_events[0].fields.len(), 0);
 
         // Query Move Event
-        let queried_events = db
-            .events_by_type(1_004_000, 1_006_000, EventType::MoveEvent, 1)
-            .await?;
+        let queried_events = query_time_range(
+            &db,
+            1_004_000,
+            1_006_000,
+            EventFilter::EventType(EventType::MoveEvent),
+            1,
+        )
+        .await?;
         assert_eq!(queried_events.len(), 1);
         test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[5]);
         assert_ne!(queried_events[0].fields.len(), 0);
@@ -903,8 +1042,14 @@ mod tests {
 
         // Query for the Move event and validate basic fields
         let events = db
-            .events_by_transaction(to_insert[5].tx_digest.unwrap(), 10)
-            .await?;
+            .query_events(
+                &EventFilter::Transaction(to_insert[5].tx_digest.unwrap()),
+                None,
+                10,
+                false,
+            )
+            .await?
+            .0;
         let move_event = &events[0];
         assert_eq!(events.len(), 1); // Should be no more events, just that one
 
@@ -912,13 +1057,10 @@ mod tests {
         assert_eq!(move_event.fields.len(), 2);
 
         // Query by module ID
-        let mod_id = ModuleId::new(
-            AccountAddress::from(ObjectID::from_hex_literal("0x3").unwrap()),
-            Identifier::from_str("test_module").unwrap(),
+        let module_filter = EventFilter::Package(ObjectID::from_hex_literal("0x3").unwrap()).and(
+            EventFilter::Module(Identifier::from_str("test_module").unwrap()),
         );
-        let queried_events = db
-            .events_by_module_id(1_000_000, 1_006_001, &mod_id, 3)
-            .await?;
+        let queried_events = query_time_range(&db, 1_000_000, 1_006_001, module_filter, 3).await?;
         assert_eq!(queried_events.len(), 2);
 
         // results are sorted in DESC order
@@ -967,14 +1109,16 @@ mod tests {
         assert_eq!(db.add_events(&to_insert).await?, 3);
         info!("Done inserting");
 
-        let events = db
-            .events_by_move_event_struct_name(
-                1_000_000,
-                1_002_000,
-                "0x2::SUI::test_foo<address, vector<u8>>",
-                10,
-            )
-            .await?;
+        let events = query_time_range(
+            &db,
+            1_000_000,
+            1_002_000,
+            EventFilter::MoveEventType(
+                parse_sui_struct_tag("0x2::SUI::test_foo<address, vector<u8>>").unwrap(),
+            ),
+            10,
+        )
+        .await?;
         assert_eq!(events.len(), 2);
 
         test_queried_event_vs_test_envelope(&events[0], &to_insert[0]);
@@ -1065,9 +1209,14 @@ mod tests {
         info!("Done inserting");
 
         // Query by sender
-        let events = db
-            .events_by_sender(1_000_000, 1_990_000, &sender, 10)
-            .await?;
+        let events = query_time_range(
+            &db,
+            1_000_000,
+            1_990_000,
+            EventFilter::SenderAddress(sender),
+            10,
+        )
+        .await?;
         assert_eq!(events.len(), 5);
 
         test_queried_event_vs_test_envelope(&events[0], &to_insert[0]);
@@ -1077,9 +1226,14 @@ mod tests {
         test_queried_event_vs_test_envelope(&events[4], &to_insert[7]);
 
         // Query by recipient
-        let events = db
-            .events_by_recipient(1_000_000, 1_990_000, &recipient, 10)
-            .await?;
+        let events = query_time_range(
+            &db,
+            1_000_000,
+            1_990_000,
+            EventFilter::Recipient(recipient),
+            10,
+        )
+        .await?;
         assert_eq!(events.len(), 3);
 
         test_queried_event_vs_test_envelope(&events[0], &to_insert[0]);
@@ -1087,9 +1241,14 @@ mod tests {
         test_queried_event_vs_test_envelope(&events[2], &to_insert[3]);
 
         // Query by object
-        let events = db
-            .events_by_object(1_000_000, 1_990_000, &object_id, 10)
-            .await?;
+        let events = query_time_range(
+            &db,
+            1_000_000,
+            1_990_000,
+            EventFilter::ObjectId(object_id),
+            10,
+        )
+        .await?;
         assert_eq!(events.len(), 4);
 
         test_queried_event_vs_test_envelope(&events[0], &to_insert[0]);
@@ -1121,8 +1280,14 @@ mod tests {
         db.add_events(&to_insert).await?;
 
         let events = db
-            .events_by_transaction(to_insert[0].tx_digest.unwrap(), 10)
-            .await?;
+            .query_events(
+                &EventFilter::Transaction(to_insert[0].tx_digest.unwrap()),
+                None,
+                10,
+                false,
+            )
+            .await?
+            .0;
         assert_eq!(events.len(), 1);
         info!("events[0]: {:?}", events[0]);
         assert_eq!(
@@ -1199,4 +1364,294 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_eventstore_events_from_cursor() -> Result<(), SuiError> {
+        telemetry_subscribers::init_for_testing();
+
+        // Initialize store
+        let db = SqlEventStore::new_memory_only_not_prod().await?;
+        db.initialize().await?;
+
+        // Two events in transaction 1, one in transaction 2 and two in transaction 3
+        let mut to_insert = vec![
+            test_utils::new_test_newobj_event(1_000_000, 1, None, None, None),
+            test_utils::new_test_deleteobj_event(1_000_000, 1, None, None),
+            test_utils::new_test_publish_event(1_001_000, 2, None),
+            test_utils::new_test_transfer_event(
+                1_002_000,
+                3,
+                1,
+                TransferType::Coin,
+                None,
+                None,
+                None,
+            ),
+            test_utils::new_test_move_event(
+                1_002_000,
+                3,
+                ObjectID::from_hex_literal("0x3").unwrap(),
+                "test_module",
+                "test_foo",
+            ),
+        ];
+        to_insert[1].event_num = 1;
+        to_insert[4].event_num = 1;
+        assert_eq!(db.add_events(&to_insert).await?, 5);
+
+        // Read everything from the start
+        let queried_events = db.events_from_cursor(EventID::default(), 20).await?;
+        assert_eq!(queried_events.len(), 5);
+        for (queried, orig) in queried_events.iter().zip(&to_insert) {
+            assert_eq!(queried.id(), orig.id());
+            test_queried_event_vs_test_envelope(queried, orig);
+        }
+
+        // Resume from the middle of transaction 1, the cursor is inclusive
+        let cursor = EventID {
+            tx_seq: 1,
+            event_seq: 1,
+        };
+        let queried_events = db.events_from_cursor(cursor, 2).await?;
+        assert_eq!(queried_events.len(), 2);
+        assert_eq!(queried_events[0].id(), to_insert[1].id());
+        assert_eq!(queried_events[1].id(), to_insert[2].id());
+
+        // Stored events can be turned back into the envelopes that were ingested
+        let cursor = EventID {
+            tx_seq: 3,
+            event_seq: 0,
+        };
+        let queried_events = db.events_from_cursor(cursor, 20).await?;
+        assert_eq!(queried_events.len(), 2);
+        for (queried, orig) in queried_events.into_iter().zip(&to_insert[3..]) {
+            let envelope = queried.into_event_envelope().unwrap();
+            assert_eq!(envelope.id(), orig.id());
+            assert_eq!(envelope.event, orig.event);
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_eventstore_initialize_migrates_old_table() -> Result<(), SuiError> {
+        telemetry_subscribers::init_for_testing();
+
+        // An events table created before the event_num column was introduced
+        let db = SqlEventStore::new_memory_only_not_prod().await?;
+        db.pool
+            .execute(
+                "CREATE TABLE events(timestamp INTEGER NOT NULL, seq_num INTEGER, tx_digest BLOB, \
+                event_type INTEGER, package_id BLOB, module_name TEXT, function TEXT, \
+                object_id BLOB, fields TEXT, move_event_name TEXT, contents BLOB, sender BLOB, \
+                recipient TEXT);",
+            )
+            .await
+            .map_err(convert_sqlx_err)?;
+        for (timestamp, seq_num) in [(1_000_000, 1), (1_000_000, 1), (1_001_000, 2)] {
+            sqlx::query(
+                "INSERT INTO events (timestamp, seq_num, event_type, fields) VALUES (?, ?, ?, ?)",
+            )
+            .bind(timestamp as i64)
+            .bind(seq_num as i64)
+            .bind(EventType::NewObject as u16)
+            .bind("")
+            .execute(&db.pool)
+            .await
+            .map_err(convert_sqlx_err)?;
+        }
+
+        db.initialize().await?;
+
+        // Existing events are numbered in the order they were inserted within their transaction
+        let ids: Vec<_> = db
+            .events_from_cursor(EventID::default(), 20)
+            .await?
+            .iter()
+            .map(|event| event.id())
+            .collect();
+        let expected =
+            [(1, 0), (1, 1), (2, 0)].map(|(tx_seq, event_seq)| EventID { tx_seq, event_seq });
+        assert_eq!(ids, expected);
+
+        // New events can be written and read back
+        let to_insert = vec![test_utils::new_test_publish_event(1_002_000, 3, None)];
+        assert_eq!(db.add_events(&to_insert).await?, 1);
+        let cursor = EventID {
+            tx_seq: 3,
+            event_seq: 0,
+        };
+        let queried_events = db.events_from_cursor(cursor, 20).await?;
+        assert_eq!(queried_events.len(), 1);
+        test_queried_event_vs_test_envelope(&queried_events[0], &to_insert[0]);
+
+        // Initializing again leaves the table as is
+        db.initialize().await?;
+        assert_eq!(db.total_event_count().await?, 4);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_eventstore_query_events() -> Result<(), SuiError> {
+        telemetry_subscribers::init_for_testing();
+
+        // Initialize store
+        let db = SqlEventStore::new_memory_only_not_prod().await?;
+        db.initialize().await?;
+
+        let package_id = ObjectID::from_hex_literal("0x3").unwrap();
+        let mut to_insert = vec![
+            test_utils::new_test_newobj_event(1_000_000, 1, None, None, None),
+            test_utils::new_test_publish_event(1_001_000, 2, None),
+            test_utils::new_test_transfer_event(
+                1_002_000,
+                3,
+                1,
+                TransferType::Coin,
+                None,
+                None,
+                None,
+            ),
+            test_utils::new_test_move_event(1_003_000, 4, package_id, "test_module", "test_foo"),
+            test_utils::new_test_move_event(1_004_000, 5, package_id, "test_module", "test_foo"),
+            test_utils::new_test_move_event(1_005_000, 6, package_id, "test_module", "test_foo"),
+        ];
+        to_insert[3].move_struct_json_value = Some(json!({"name": "a"}));
+        to_insert[4].move_struct_json_value = Some(json!({"name": "b"}));
+        to_insert[5].move_struct_json_value = Some(json!({"name": "a"}));
+        assert_eq!(db.add_events(&to_insert).await?, 6);
+
+        let ids = |(events, _): (Vec<StoredEvent>, Option<EventID>)| {
+            events.iter().map(|e| e.id().tx_seq).collect::<Vec<_>>()
+        };
+
+        // Indexed filters, in both orders
+        let filter = EventFilter::EventType(EventType::MoveEvent);
+        let events = db.query_events(&filter, None, 10, false).await?;
+        assert_eq!(ids(events), vec![4, 5, 6]);
+        let events = db.query_events(&filter, None, 10, true).await?;
+        assert_eq!(ids(events), vec![6, 5, 4]);
+
+        // A full page returns the cursor of the next one
+        let (events, next_cursor) = db.query_events(&filter, None, 2, false).await?;
+        assert_eq!(events.len(), 2);
+        assert_eq!(next_cursor, Some(to_insert[5].id()));
+
+        let filter = EventFilter::MatchAny(vec![
+            EventFilter::EventType(EventType::Publish),
+            EventFilter::EventType(EventType::NewObject),
+        ]);
+        let events = db.query_events(&filter, None, 10, false).await?;
+        assert_eq!(ids(events), vec![1, 2]);
+
+        let filter = EventFilter::TimeRange {
+            start_time: 1_001_000,
+            end_time: 1_004_000,
+        }
+        .and(EventFilter::Package(package_id));
+        let events = db.query_events(&filter, None, 10, false).await?;
+        assert_eq!(ids(events), vec![4]);
+
+        // The cursor is inclusive, and follows the order of the query
+        let cursor = EventID {
+            tx_seq: 5,
+            event_seq: 0,
+        };
+        let filter = EventFilter::MatchAll(vec![]);
+        let events = db.query_events(&filter, Some(cursor), 2, false).await?;
+        assert_eq!(ids(events), vec![5, 6]);
+        let events = db.query_events(&filter, Some(cursor), 2, true).await?;
+        assert_eq!(ids(events), vec![5, 4]);
+
+        // MoveEventField is evaluated in-process, scanning past events that don't match
+        let filter = EventFilter::MoveEventField {
+            path: "/name".to_string(),
+            value: json!("a"),
+        };
+        let events = db.query_events(&filter, None, 1, false).await?;
+        assert_eq!(ids(events), vec![4]);
+        let events = db.query_events(&filter, Some(cursor), 1, false).await?;
+        assert_eq!(ids(events), vec![6]);
+        let events = db.query_events(&filter, None, 10, true).await?;
+        assert_eq!(ids(events), vec![6, 4]);
+
+        let filter =
+            EventFilter::MatchAny(vec![filter, EventFilter::EventType(EventType::Publish)]);
+        let events = db.query_events(&filter, None, 10, false).await?;
+        assert_eq!(ids(events), vec![2, 4, 6]);
+
+        // The in-process filtering stops once the scan budget is spent, returning a cursor to
+        // resume the scan from
+        let filter = EventFilter::MoveEventField {
+            path: "/name".to_string(),
+            value: json!("b"),
+        };
+        let (events, next_cursor) = db
+            .query_events_with_max_scan(&filter, None, 10, false, 3)
+            .await?;
+        assert!(events.is_empty());
+        assert_eq!(next_cursor, Some(to_insert[3].id()));
+        let events = db
+            .query_events_with_max_scan(&filter, next_cursor, 10, false, 3)
+            .await?;
+        assert_eq!(events.1, None);
+        assert_eq!(ids(events), vec![5]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_eventstore_paged_queries() -> Result<(), SuiError> {
+        telemetry_subscribers::init_for_testing();
+
+        // Initialize store
+        let db = SqlEventStore::new_memory_only_not_prod().await?;
+        db.initialize().await?;
+
+        let mut to_insert = vec![
+            test_utils::new_test_newobj_event(1_000_000, 1, None, None, None),
+            test_utils::new_test_deleteobj_event(1_000_000, 1, None, None),
+            test_utils::new_test_publish_event(1_001_000, 2, None),
+            test_utils::new_test_newobj_event(1_002_000, 3, None, None, None),
+            test_utils::new_test_newobj_event(1_003_000, 4, None, None, None),
+        ];
+        to_insert[1].event_num = 1;
+        assert_eq!(db.add_events(&to_insert).await?, 5);
+
+        // Page through the whole time range two events at a time
+        let time_range = EventFilter::TimeRange {
+            start_time: 1_000_000,
+            end_time: 1_004_000,
+        };
+        let mut cursor = None;
+        let mut pages = vec![];
+        loop {
+            let (events, next_cursor) = db.query_events(&time_range, cursor, 2, false).await?;
+            pages.push(events);
+            cursor = next_cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+        assert_eq!(pages.len(), 3);
+        let queried_events = pages.into_iter().flatten().collect::<Vec<_>>();
+        assert_eq!(queried_events.len(), 5);
+        for (queried, orig) in queried_events.iter().zip(&to_insert) {
+            assert_eq!(queried.id(), orig.id());
+        }
+
+        // The cursor also applies to filtered queries
+        let cursor = EventID {
+            tx_seq: 1,
+            event_seq: 1,
+        };
+        let filter = time_range.and(EventFilter::EventType(EventType::NewObject));
+        let (queried_events, _) = db.query_events(&filter, Some(cursor), 10, false).await?;
+        assert_eq!(queried_events.len(), 2);
+        assert_eq!(queried_events[0].id(), to_insert[3].id());
+        assert_eq!(queried_events[1].id(), to_insert[4].id());
+
+        Ok(())
+    }
 }
@@ -6,13 +6,14 @@
 
 use move_core_types::identifier::Identifier;
 use rocksdb::Options;
-use serde::{de::DeserializeOwned, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
 use typed_store::rocks::DBMap;
 use typed_store::traits::Map;
 use typed_store::traits::TypedStoreDebug;
 use typed_store_derive::DBMapUtils;
 
-use sui_types::base_types::ObjectRef;
+use sui_types::base_types::{ObjectDigest, ObjectRef, SequenceNumber};
 use sui_types::base_types::{ObjectID, SuiAddress, TransactionDigest};
 use sui_types::batch::TxSequenceNumber;
 use sui_types::error::SuiResult;
@@ -20,6 +21,19 @@ use sui_types::object::Owner;
 
 use crate::default_db_options;
 
+/// Key of the coin index: the owner address, the coin type (e.g. `0x2::sui::SUI`) and the coin
+/// object id.
+pub type CoinIndexKey = (SuiAddress, String, ObjectID);
+
+/// Latest known state of a coin object, as recorded in the coin index.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CoinInfo {
+    pub version: SequenceNumber,
+    pub digest: ObjectDigest,
+    pub balance: u64,
+    pub previous_transaction: TransactionDigest,
+}
+
 #[derive(DBMapUtils)]
 pub struct IndexStore {
     /// Index from sui address to transactions initiated by that address.
@@ -53,8 +67,24 @@ pub struct IndexStore {
     /// Index from transaction digest to sequence number.
     #[default_options_override_fn = "transactions_seq_table_default_config"]
     transactions_seq: DBMap<TransactionDigest, TxSequenceNumber>,
+
+    /// Index from owner address and coin type to the coins of that type owned by the address.
+    /// Only coins directly owned by an address are indexed.
+    #[default_options_override_fn = "coin_index_table_default_config"]
+    coin_index: DBMap<CoinIndexKey, CoinInfo>,
+
+    /// The highest version of each coin object seen by the coin index, whether the coin was
+    /// written or consumed at that version. Indexing a transaction again, or out of order, then
+    /// never brings back a stale or deleted coin.
+    #[default_options_override_fn = "coin_index_table_default_config"]
+    coin_versions: DBMap<ObjectID, SequenceNumber>,
+
+    /// The sequence number of the next transaction to index, under `NEXT_SEQUENCE_TO_INDEX_KEY`.
+    next_sequence_to_index: DBMap<u64, TxSequenceNumber>,
 }
 
+const NEXT_SEQUENCE_TO_INDEX_KEY: u64 = 0;
+
 // These functions are used to initialize the DB tables
 fn transactions_seq_table_default_config() -> Options {
     default_db_options(None, Some(1_000_000)).0
@@ -77,6 +107,9 @@ fn transactions_by_move_function_table_default_config() -> Options {
 fn timestamps_table_default_config() -> Options {
     default_db_options(None, Some(1_000_000)).1
 }
+fn coin_index_table_default_config() -> Options {
+    default_db_options(None, Some(1_000_000)).0
+}
 
 impl IndexStore {
     pub fn index_tx(
@@ -85,6 +118,8 @@ impl IndexStore {
         active_inputs: impl Iterator<Item = ObjectID>,
         mutated_objects: impl Iterator<Item = (ObjectRef, Owner)> + Clone,
         move_functions: impl Iterator<Item = (ObjectID, Identifier, Identifier)> + Clone,
+        removed_coins: impl Iterator<Item = (CoinIndexKey, SequenceNumber)>,
+        added_coins: impl Iterator<Item = (CoinIndexKey, CoinInfo)>,
         sequence: TxSequenceNumber,
         digest: &TransactionDigest,
         timestamp_ms: u64,
@@ -134,11 +169,91 @@ impl IndexStore {
         let batch =
             batch.insert_batch(&self.timestamps, std::iter::once((*digest, timestamp_ms)))?;
 
+        // Remove the input versions of coins before adding their new versions, a coin that
+        // stays with the same owner is then simply updated in place.
+        let mut coin_versions = HashMap::new();
+        let mut stale_coins = Vec::new();
+        for (key, version) in removed_coins {
+            // Only remove the coin if the index does not already hold a later version of it.
+            if self
+                .coin_index
+                .get(&key)?
+                .map_or(false, |info| info.version <= version)
+            {
+                stale_coins.push(key.clone());
+            }
+            self.record_coin_version(&mut coin_versions, key.2, version)?;
+        }
+        let mut new_coins = Vec::new();
+        for (key, info) in added_coins {
+            if self.latest_coin_version(&coin_versions, &key.2)? < Some(info.version) {
+                self.record_coin_version(&mut coin_versions, key.2, info.version)?;
+                new_coins.push((key, info));
+            }
+        }
+        let batch = batch.delete_batch(&self.coin_index, stale_coins)?;
+        let batch = batch.insert_batch(&self.coin_index, new_coins)?;
+        let batch = batch.insert_batch(&self.coin_versions, coin_versions)?;
+
+        let next_sequence = self.next_sequence_to_index()?.max(sequence + 1);
+        let batch = batch.insert_batch(
+            &self.next_sequence_to_index,
+            std::iter::once((NEXT_SEQUENCE_TO_INDEX_KEY, next_sequence)),
+        )?;
+
         batch.write()?;
 
         Ok(())
     }
 
+    fn latest_coin_version(
+        &self,
+        pending: &HashMap<ObjectID, SequenceNumber>,
+        object_id: &ObjectID,
+    ) -> SuiResult<Option<SequenceNumber>> {
+        match pending.get(object_id) {
+            Some(version) => Ok(Some(*version)),
+            None => Ok(self.coin_versions.get(object_id)?),
+        }
+    }
+
+    fn record_coin_version(
+        &self,
+        pending: &mut HashMap<ObjectID, SequenceNumber>,
+        object_id: ObjectID,
+        version: SequenceNumber,
+    ) -> SuiResult {
+        if self.latest_coin_version(pending, &object_id)? < Some(version) {
+            pending.insert(object_id, version);
+        }
+        Ok(())
+    }
+
+    /// Adds coins that were not created by a transaction, e.g. the genesis objects, to the
+    /// coin index.
+    pub fn index_coins(&self, coins: impl Iterator<Item = (CoinIndexKey, CoinInfo)>) -> SuiResult {
+        let coins: Vec<_> = coins.collect();
+        let batch = self
+            .coin_index
+            .batch()
+            .insert_batch(&self.coin_index, coins.iter().cloned())?
+            .insert_batch(
+                &self.coin_versions,
+                coins.iter().map(|(key, info)| (key.2, info.version)),
+            )?;
+        batch.write()?;
+        Ok(())
+    }
+
+    /// Returns the sequence number of the next transaction to index. Transactions are indexed
+    /// in order, so all transactions before it are indexed.
+    pub fn next_sequence_to_index(&self) -> SuiResult<TxSequenceNumber> {
+        Ok(self
+            .next_sequence_to_index
+            .get(&NEXT_SEQUENCE_TO_INDEX_KEY)?
+            .unwrap_or_default())
+    }
+
     /// Returns unix timestamp for a transaction if it exists
     pub fn get_timestamp_ms(
         &self,
@@ -293,4 +408,183 @@ impl IndexStore {
     ) -> SuiResult<Option<TxSequenceNumber>> {
         Ok(self.transactions_seq.get(digest)?)
     }
+
+    /// Returns at most `limit` coins of type `coin_type` owned by `owner`, starting from (and
+    /// including) the coin with id `cursor`, sorted by object id.
+    pub fn get_coins(
+        &self,
+        owner: SuiAddress,
+        coin_type: String,
+        cursor: ObjectID,
+        limit: Option<usize>,
+    ) -> SuiResult<Vec<(ObjectID, CoinInfo)>> {
+        let iter = self
+            .coin_index
+            .iter()
+            .skip_to(&(owner, coin_type.clone(), cursor))?
+            .take_while(|((coin_owner, type_, _), _)| coin_owner == &owner && type_ == &coin_type)
+            .map(|((_, _, object_id), info)| (object_id, info));
+        Ok(if let Some(limit) = limit {
+            iter.take(limit).collect()
+        } else {
+            iter.collect()
+        })
+    }
+
+    /// Returns the total balance and number of coins of type `coin_type` owned by `owner`.
+    pub fn get_balance(&self, owner: SuiAddress, coin_type: String) -> SuiResult<(u128, usize)> {
+        Ok(self
+            .get_coins(owner, coin_type, ObjectID::ZERO, None)?
+            .iter()
+            .fold((0, 0), |(total, count), (_, info)| {
+                (total + info.balance as u128, count + 1)
+            }))
+    }
+
+    /// Returns the total balance and number of coins owned by `owner` for each coin type, sorted
+    /// by coin type.
+    pub fn get_all_balances(&self, owner: SuiAddress) -> SuiResult<Vec<(String, u128, usize)>> {
+        let mut balances: Vec<(String, u128, usize)> = Vec::new();
+        // The empty string sorts before all coin types. The coins of a type are next to each
+        // other in the index, but the types are ordered by length first as keys are BCS encoded.
+        for ((_, coin_type, _), info) in self
+            .coin_index
+            .iter()
+            .skip_to(&(owner, String::new(), ObjectID::ZERO))?
+            .take_while(|((coin_owner, _, _), _)| coin_owner == &owner)
+        {
+            match balances.last_mut() {
+                Some((last_type, total, count)) if last_type == &coin_type => {
+                    *total += info.balance as u128;
+                    *count += 1;
+                }
+                _ => balances.push((coin_type, info.balance as u128, 1)),
+            }
+        }
+        balances.sort_by(|(a, _, _), (b, _, _)| a.cmp(b));
+        Ok(balances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(
+        owner: SuiAddress,
+        coin_type: &str,
+        object_id: ObjectID,
+        balance: u64,
+    ) -> (CoinIndexKey, CoinInfo) {
+        (
+            (owner, coin_type.to_string(), object_id),
+            CoinInfo {
+                version: SequenceNumber::from_u64(1),
+                digest: ObjectDigest::random(),
+                balance,
+                previous_transaction: TransactionDigest::genesis(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_coin_index() {
+        let working_dir = tempfile::tempdir().unwrap();
+        let store =
+            IndexStore::open_tables_read_write(working_dir.path().to_path_buf(), None, None);
+
+        let owner = SuiAddress::random_for_testing_only();
+        let other_owner = SuiAddress::random_for_testing_only();
+        let sui = "0x2::sui::SUI";
+        let usdc = "0x1234::usdc::USDC";
+        let mut sui_coins: Vec<_> = (0..3).map(|_| ObjectID::random()).collect();
+        sui_coins.sort();
+        let usdc_coin = ObjectID::random();
+
+        store
+            .index_coins(
+                vec![
+                    coin(owner, sui, sui_coins[0], 100),
+                    coin(owner, sui, sui_coins[1], 200),
+                    coin(owner, sui, sui_coins[2], 300),
+                    coin(owner, usdc, usdc_coin, 5),
+                    coin(other_owner, sui, ObjectID::random(), 1000),
+                ]
+                .into_iter(),
+            )
+            .unwrap();
+
+        assert_eq!(store.get_balance(owner, sui.to_string()).unwrap(), (600, 3));
+        assert_eq!(store.get_balance(owner, usdc.to_string()).unwrap(), (5, 1));
+        assert_eq!(
+            store.get_all_balances(owner).unwrap(),
+            vec![(usdc.to_string(), 5, 1), (sui.to_string(), 600, 3)]
+        );
+
+        // Paging through coins starts from the cursor, inclusive
+        let page = store
+            .get_coins(owner, sui.to_string(), sui_coins[1], Some(1))
+            .unwrap();
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].0, sui_coins[1]);
+
+        // Spending a coin removes it from the index of its previous owner
+        let (spent_key, spent_info) = coin(owner, sui, sui_coins[0], 100);
+        let (_, mut received) = coin(other_owner, sui, sui_coins[0], 100);
+        received.version = spent_info.version.increment();
+        let index_spend = |sequence| {
+            store.index_tx(
+                owner,
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::once((spent_key.clone(), spent_info.version)),
+                std::iter::once((
+                    (other_owner, sui.to_string(), sui_coins[0]),
+                    received.clone(),
+                )),
+                sequence,
+                &TransactionDigest::random(),
+                0,
+            )
+        };
+        index_spend(1).unwrap();
+        assert_eq!(store.get_balance(owner, sui.to_string()).unwrap(), (500, 2));
+        assert_eq!(
+            store.get_balance(other_owner, sui.to_string()).unwrap(),
+            (1100, 2)
+        );
+        assert_eq!(store.next_sequence_to_index().unwrap(), 2);
+
+        // The coin is then sent back to its previous owner
+        let mut returned = received.clone();
+        returned.version = received.version.increment();
+        store
+            .index_tx(
+                other_owner,
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::empty(),
+                std::iter::once((
+                    (other_owner, sui.to_string(), sui_coins[0]),
+                    received.version,
+                )),
+                std::iter::once((spent_key.clone(), returned)),
+                2,
+                &TransactionDigest::random(),
+                0,
+            )
+            .unwrap();
+        assert_eq!(store.get_balance(owner, sui.to_string()).unwrap(), (600, 3));
+
+        // Indexing the older transaction again neither removes the coin from its owner nor
+        // brings back the stale version of the other owner
+        index_spend(1).unwrap();
+        assert_eq!(store.get_balance(owner, sui.to_string()).unwrap(), (600, 3));
+        assert_eq!(
+            store.get_balance(other_owner, sui.to_string()).unwrap(),
+            (1000, 1)
+        );
+        assert_eq!(store.next_sequence_to_index().unwrap(), 3);
+    }
 }
@@ -6,6 +6,7 @@ use sui_storage::event_store::{sql::SqlEventStore, test_utils, EventStore};
 use sui_types::{
     base_types::ObjectID,
     event::{EventEnvelope, EventType, TransferType},
+    filter::EventFilter,
 };
 #[tokio::test]
 async fn test_stored_event_to_sui_event() -> Result<(), anyhow::Error> {
@@ -42,9 +43,12 @@ async fn test_stored_event_to_sui_event() -> Result<(), anyhow::Error> {
 
     let publish = test_utils::new_test_publish_event(1_001_000, 5, None);
     assert_eq!(db.add_events(&vec![publish.clone()]).await?, 1);
-    let mut queried_events = db
-        .events_by_type(1_001_000, 1_002_000, EventType::Publish, 1)
-        .await?;
+    let filter = EventFilter::TimeRange {
+        start_time: 1_001_000,
+        end_time: 1_002_000,
+    }
+    .and(EventFilter::EventType(EventType::Publish));
+    let (mut queried_events, _) = db.query_events(&filter, None, 1, false).await?;
     assert_eq!(queried_events.len(), 1);
     let sui_event: SuiEventEnvelope = queried_events.swap_remove(0).try_into()?;
     assert!(
@@ -61,7 +65,9 @@ async fn insert_and_fetch_by_tx_digest_then_compare(
     let tx_digest = event_envelope.tx_digest.unwrap();
     assert_eq!(db.add_events(&vec![event_envelope.clone()]).await?, 1);
 
-    let mut events = db.events_by_transaction(tx_digest, 10).await?;
+    let (mut events, _) = db
+        .query_events(&EventFilter::Transaction(tx_digest), None, 10, false)
+        .await?;
     assert_eq!(events.len(), 1);
     let stored_event = events.pop().unwrap();
     let sui_event: SuiEventEnvelope = stored_event.try_into()?;
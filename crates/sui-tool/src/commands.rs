@@ -5,6 +5,8 @@ use anyhow::Result;
 use futures::future::join_all;
 use std::cmp::min;
 use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
 use std::path::PathBuf;
 use std::sync::Arc;
 use sui_config::genesis::Genesis;
@@ -20,7 +22,13 @@ use anyhow::anyhow;
 use futures::stream::StreamExt;
 
 use clap::*;
+use sui_core::authority::authority_store_tables::AuthorityPerpetualTables;
 use sui_core::authority::MAX_ITEMS_LIMIT;
+use sui_core::checkpoints::CheckpointStoreTables;
+use sui_core::state_snapshot::{
+    export_state_snapshot, verify_state_snapshot, DEFAULT_OBJECTS_PER_CHUNK,
+};
+use sui_types::crypto::AuthoritySignInfo;
 use sui_types::messages_checkpoint::{
     CheckpointRequest, CheckpointResponse, CheckpointSequenceNumber,
 };
@@ -137,6 +145,37 @@ pub enum ToolCommand {
         )]
         sequence_number: Option<CheckpointSequenceNumber>,
     },
+
+    /// Export the live object set at a certified checkpoint to a state snapshot file, which a
+    /// new node can be bootstrapped from. The node owning the DB must be stopped.
+    #[clap(name = "export-snapshot")]
+    ExportSnapshot {
+        /// Path of the node DB, i.e. the `db-path` of its config
+        #[clap(long = "db-path")]
+        db_path: PathBuf,
+        #[clap(
+            long,
+            help = "Export the state at this certified checkpoint, defaults to the latest one"
+        )]
+        sequence_number: Option<CheckpointSequenceNumber>,
+        #[clap(long, help = "Path of the snapshot file to write")]
+        output: PathBuf,
+        #[clap(long, help = "Number of objects per chunk", default_value_t = DEFAULT_OBJECTS_PER_CHUNK)]
+        objects_per_chunk: usize,
+    },
+
+    /// Check the integrity of a state snapshot file and the certificate of its checkpoint.
+    #[clap(name = "verify-snapshot")]
+    VerifySnapshot {
+        #[clap(long, help = "Path of the snapshot file to verify")]
+        path: PathBuf,
+
+        #[clap(
+            long = "genesis",
+            help = "Genesis of the network, whose committee the checkpoint certificate is verified from"
+        )]
+        genesis: PathBuf,
+    },
 }
 
 fn make_clients(genesis: &Genesis) -> Result<BTreeMap<AuthorityName, NetworkAuthorityClient>> {
@@ -549,6 +588,42 @@ impl ToolCommand {
                     }
                 }
             }
+            ToolCommand::ExportSnapshot {
+                db_path,
+                sequence_number,
+                output,
+                objects_per_chunk,
+            } => {
+                let perpetual_tables = AuthorityPerpetualTables::<AuthoritySignInfo>::open(
+                    &db_path.join("store"),
+                    None,
+                );
+                let checkpoint_tables = CheckpointStoreTables::open_tables_read_write(
+                    db_path.join("checkpoints"),
+                    None,
+                    None,
+                );
+                let summary = export_state_snapshot(
+                    &perpetual_tables,
+                    &checkpoint_tables,
+                    sequence_number,
+                    objects_per_chunk,
+                    BufWriter::new(File::create(&output)?),
+                )?;
+                println!(
+                    "Exported {} objects in {} chunks at checkpoint {} to {:?}",
+                    summary.num_objects, summary.num_chunks, summary.sequence_number, output
+                );
+            }
+            ToolCommand::VerifySnapshot { path, genesis } => {
+                let committee = Genesis::load(genesis)?.committee()?;
+                let summary =
+                    verify_state_snapshot(BufReader::new(File::open(&path)?), &committee)?;
+                println!(
+                    "Snapshot at checkpoint {} is valid: {} objects in {} chunks",
+                    summary.sequence_number, summary.num_objects, summary.num_chunks
+                );
+            }
         };
         Ok(())
     }
@@ -21,8 +21,8 @@ use sui_types::base_types::{ObjectID, ObjectRef, SuiAddress};
 use sui_types::error::SuiError;
 use sui_types::gas_coin::GasCoin;
 use sui_types::messages::{
-    CallArg, InputObjectKind, MoveCall, ObjectArg, SingleTransactionKind, TransactionData,
-    TransactionKind, TransferObject,
+    BatchResult, CallArg, InputObjectKind, MoveCall, ObjectArg, SingleTransactionKind,
+    TransactionData, TransactionExpiration, TransactionKind, TransferObject,
 };
 use sui_types::move_package::MovePackage;
 use sui_types::object::{Object, Owner};
@@ -41,6 +41,14 @@ pub trait DataReader {
     ) -> Result<GetRawObjectDataResponse, anyhow::Error>;
 }
 
+/// An argument of a Move call step of a batch transaction
+pub enum BatchCallArg {
+    /// An argument given as for `TransactionBuilder::move_call`
+    Json(SuiJsonValue),
+    /// An object created by an earlier step of the batch
+    Result(BatchResult),
+}
+
 pub struct TransactionBuilder(pub Arc<dyn DataReader + Sync + Send>);
 
 impl TransactionBuilder {
@@ -78,6 +86,7 @@ impl TransactionBuilder {
         gas: Option<ObjectID>,
         gas_budget: u64,
         recipient: SuiAddress,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         let single_transfer = self.single_transfer_object(object_id, recipient).await?;
         let gas = self
@@ -88,7 +97,8 @@ impl TransactionBuilder {
             signer,
             gas,
             gas_budget,
-        ))
+        )
+        .with_expiration(expiration))
     }
 
     async fn single_transfer_object(
@@ -109,11 +119,13 @@ impl TransactionBuilder {
         gas_budget: u64,
         recipient: SuiAddress,
         amount: Option<u64>,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         let object = self.get_object_ref(sui_object_id).await?;
-        Ok(TransactionData::new_transfer_sui(
-            recipient, signer, amount, object, gas_budget,
-        ))
+        Ok(
+            TransactionData::new_transfer_sui(recipient, signer, amount, object, gas_budget)
+                .with_expiration(expiration),
+        )
     }
 
     pub async fn pay(
@@ -124,6 +136,7 @@ impl TransactionBuilder {
         amounts: Vec<u64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         if let Some(gas) = gas {
             if input_coins.contains(&gas) {
@@ -144,7 +157,7 @@ impl TransactionBuilder {
             .select_gas(signer, gas, gas_budget, input_coins)
             .await?;
         let data = TransactionData::new_pay(signer, coins, recipients, amounts, gas, gas_budget);
-        Ok(data)
+        Ok(data.with_expiration(expiration))
     }
 
     pub async fn move_call(
@@ -157,6 +170,7 @@ impl TransactionBuilder {
         call_args: Vec<SuiJsonValue>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         let single_move_call = self
             .single_move_call(package_object_id, module, function, type_args, call_args)
@@ -179,7 +193,8 @@ impl TransactionBuilder {
             signer,
             gas,
             gas_budget,
-        ))
+        )
+        .with_expiration(expiration))
     }
 
     async fn single_move_call(
@@ -189,6 +204,26 @@ impl TransactionBuilder {
         function: &str,
         type_args: Vec<SuiTypeTag>,
         call_args: Vec<SuiJsonValue>,
+    ) -> anyhow::Result<SingleTransactionKind> {
+        self.batch_move_call(
+            package_object_id,
+            module,
+            function,
+            type_args,
+            call_args.into_iter().map(BatchCallArg::Json).collect(),
+        )
+        .await
+    }
+
+    /// Build a Move call step of a batch transaction, which may use objects created by earlier
+    /// steps of the batch.
+    pub async fn batch_move_call(
+        &self,
+        package_object_id: ObjectID,
+        module: &str,
+        function: &str,
+        type_args: Vec<SuiTypeTag>,
+        call_args: Vec<BatchCallArg>,
     ) -> anyhow::Result<SingleTransactionKind> {
         let package_ref = self.get_object_ref(package_object_id).await?;
         let module = Identifier::from_str(module)?;
@@ -247,7 +282,7 @@ impl TransactionBuilder {
         module: &Identifier,
         function: &Identifier,
         type_args: &[TypeTag],
-        json_args: Vec<SuiJsonValue>,
+        call_args: Vec<BatchCallArg>,
     ) -> Result<Vec<CallArg>, anyhow::Error> {
         let package = self.0.get_object(package_id).await?.into_object()?;
         let package = package
@@ -255,8 +290,20 @@ impl TransactionBuilder {
             .try_as_package()
             .cloned()
             .ok_or_else(|| anyhow!("Object [{}] is not a move package.", package_id))?;
-        let package: MovePackage = MovePackage::new(package.id, &package.module_map);
+        let package: MovePackage =
+            MovePackage::new(package.id, &package.module_map).with_lineage(package.lineage);
 
+        // Objects created by earlier steps are resolved as placeholder object IDs, to check that
+        // they are passed in place of objects.
+        let (json_args, results): (Vec<_>, Vec<_>) = call_args
+            .into_iter()
+            .map(|arg| match arg {
+                BatchCallArg::Json(value) => (value, None),
+                BatchCallArg::Result(result) => {
+                    (SuiJsonValue::from_object_id(ObjectID::ZERO), Some(result))
+                }
+            })
+            .unzip();
         let json_args = resolve_move_function_args(
             &package,
             module.clone(),
@@ -267,13 +314,19 @@ impl TransactionBuilder {
         let mut args = Vec::new();
         let mut objects = BTreeMap::new();
         // TODO: duplicated code with gateway_state.rs
-        for arg in json_args {
-            args.push(match arg {
-                SuiJsonCallArg::Object(id) => {
+        for (arg, result) in json_args.into_iter().zip(results) {
+            args.push(match (arg, result) {
+                (SuiJsonCallArg::Object(_), Some(result)) => CallArg::Result(result),
+                (_, Some(result)) => {
+                    return Err(anyhow!(
+                        "{result} is passed in place of an argument that is not an object"
+                    ))
+                }
+                (SuiJsonCallArg::Object(id), None) => {
                     CallArg::Object(self.get_object_arg(id, &mut objects).await?)
                 }
-                SuiJsonCallArg::Pure(p) => CallArg::Pure(p),
-                SuiJsonCallArg::ObjVec(v) => {
+                (SuiJsonCallArg::Pure(p), None) => CallArg::Pure(p),
+                (SuiJsonCallArg::ObjVec(v), None) => {
                     let mut object_ids = vec![];
                     for id in v {
                         object_ids.push(self.get_object_arg(id, &mut objects).await?);
@@ -282,11 +335,17 @@ impl TransactionBuilder {
                 }
             })
         }
+        // Objects created by earlier steps do not exist yet, so the call can only be type
+        // checked when the batch is executed.
+        if args.iter().any(|arg| matches!(arg, CallArg::Result(_))) {
+            return Ok(args);
+        }
         let compiled_module = package.deserialize_module(module)?;
 
         resolve_and_type_check(
             &objects,
             &compiled_module,
+            package.lineage(),
             function,
             type_args,
             args.clone(),
@@ -302,14 +361,13 @@ impl TransactionBuilder {
         compiled_modules: Vec<Vec<u8>>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         let gas = self.select_gas(sender, gas, gas_budget, vec![]).await?;
-        Ok(TransactionData::new_module(
-            sender,
-            gas,
-            compiled_modules,
-            gas_budget,
-        ))
+        Ok(
+            TransactionData::new_module(sender, gas, compiled_modules, gas_budget)
+                .with_expiration(expiration),
+        )
     }
 
     pub async fn split_coin(
@@ -319,6 +377,7 @@ impl TransactionBuilder {
         split_amounts: Vec<u64>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         let coin = self.0.get_object(coin_object_id).await?.into_object()?;
         let coin_object_ref = coin.reference.to_object_ref();
@@ -340,7 +399,8 @@ impl TransactionBuilder {
                 CallArg::Pure(bcs::to_bytes(&split_amounts)?),
             ],
             gas_budget,
-        ))
+        )
+        .with_expiration(expiration))
     }
 
     pub async fn split_coin_equal(
@@ -350,6 +410,7 @@ impl TransactionBuilder {
         split_count: u64,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         let coin = self.0.get_object(coin_object_id).await?.into_object()?;
         let coin_object_ref = coin.reference.to_object_ref();
@@ -371,7 +432,8 @@ impl TransactionBuilder {
                 CallArg::Pure(bcs::to_bytes(&split_count)?),
             ],
             gas_budget,
-        ))
+        )
+        .with_expiration(expiration))
     }
 
     pub async fn merge_coins(
@@ -381,6 +443,7 @@ impl TransactionBuilder {
         coin_to_merge: ObjectID,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         let coin = self.0.get_object(primary_coin).await?.into_object()?;
         let primary_coin_ref = coin.reference.to_object_ref();
@@ -403,7 +466,8 @@ impl TransactionBuilder {
                 CallArg::Object(ObjectArg::ImmOrOwnedObject(coin_to_merge_ref)),
             ],
             gas_budget,
-        ))
+        )
+        .with_expiration(expiration))
     }
 
     pub async fn batch_transaction(
@@ -412,6 +476,7 @@ impl TransactionBuilder {
         single_transaction_params: Vec<RPCTransactionRequestParams>,
         gas: Option<ObjectID>,
         gas_budget: u64,
+        expiration: TransactionExpiration,
     ) -> anyhow::Result<TransactionData> {
         fp_ensure!(
             !single_transaction_params.is_empty(),
@@ -440,14 +505,26 @@ impl TransactionBuilder {
             };
             tx_kinds.push(single_tx);
         }
+        self.batch_transaction_with_results(signer, tx_kinds, gas, gas_budget, expiration)
+            .await
+    }
 
-        let all_inputs = tx_kinds
-            .iter()
-            .map(|tx| tx.input_objects())
-            .collect::<Result<Vec<_>, _>>()?;
-        let inputs = all_inputs
+    /// Build a batch transaction from steps that may use objects created by earlier steps,
+    /// through `CallArg::Result` arguments of Move calls (see `batch_move_call`),
+    /// `SingleTransactionKind::TransferBatchResult` and `SingleTransactionKind::PayBatchResults`.
+    pub async fn batch_transaction_with_results(
+        &self,
+        signer: SuiAddress,
+        steps: Vec<SingleTransactionKind>,
+        gas: Option<ObjectID>,
+        gas_budget: u64,
+        expiration: TransactionExpiration,
+    ) -> anyhow::Result<TransactionData> {
+        let kind = TransactionKind::Batch(steps);
+        kind.validity_check()?;
+        let inputs = kind
+            .input_objects()?
             .iter()
-            .flatten()
             .flat_map(|obj| match obj {
                 InputObjectKind::ImmOrOwnedMoveObject((id, _, _)) => Some(*id),
                 _ => None,
@@ -456,12 +533,23 @@ impl TransactionBuilder {
 
         let gas = self.select_gas(signer, gas, gas_budget, inputs).await?;
 
-        Ok(TransactionData::new(
-            TransactionKind::Batch(tx_kinds),
-            signer,
-            gas,
-            gas_budget,
-        ))
+        Ok(TransactionData::new(kind, signer, gas, gas_budget).with_expiration(expiration))
+    }
+
+    /// Have `sponsor` pay for the gas of `data`, with `gas` or one of its gas coins that covers
+    /// the budget of `data`. The result must be signed by both the sender and the sponsor.
+    pub async fn sponsor(
+        &self,
+        data: TransactionData,
+        sponsor: SuiAddress,
+        gas: Option<ObjectID>,
+    ) -> anyhow::Result<TransactionData> {
+        let gas = self
+            .select_gas(sponsor, gas, data.gas_budget, vec![])
+            .await?;
+        let data = data.with_sponsor(sponsor, gas);
+        data.validity_check()?;
+        Ok(data)
     }
 
     async fn get_object_ref(&self, object_id: ObjectID) -> anyhow::Result<ObjectRef> {
@@ -13,12 +13,14 @@ use serde::Serialize;
 pub const BALANCE_MODULE_NAME: &IdentStr = ident_str!("balance");
 pub const BALANCE_STRUCT_NAME: &IdentStr = ident_str!("Balance");
 
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct Supply {
     pub value: u64,
 }
 
+// `Balance` is already the name of the coin balance summary in the JSON-RPC schema.
 #[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
+#[schemars(rename = "MoveBalance")]
 pub struct Balance {
     value: u64,
 }
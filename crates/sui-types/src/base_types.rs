@@ -26,11 +26,13 @@ use sha3::Sha3_256;
 
 pub use crate::committee::EpochId;
 use crate::crypto::{
-    AuthorityPublicKey, AuthorityPublicKeyBytes, KeypairTraits, PublicKey, SuiPublicKey,
+    AuthorityPublicKey, AuthorityPublicKeyBytes, KeypairTraits, PublicKey, SignatureScheme,
+    SuiPublicKey,
 };
 use crate::error::ExecutionError;
 use crate::error::ExecutionErrorKind;
 use crate::error::SuiError;
+use crate::multisig::MultiPublicKey;
 use crate::object::{Object, Owner};
 use crate::sui_serde::Hex;
 use crate::sui_serde::Readable;
@@ -231,6 +233,25 @@ impl From<&PublicKey> for SuiAddress {
     }
 }
 
+/// The address of a multisig commits to the threshold and to every public key with its weight.
+impl From<&MultiPublicKey> for SuiAddress {
+    fn from(multi_pk: &MultiPublicKey) -> Self {
+        let mut hasher = Sha3_256::default();
+        hasher.update([SignatureScheme::MultiSig.flag()]);
+        hasher.update(multi_pk.threshold().to_le_bytes());
+        for (pk, weight) in multi_pk.pubkeys() {
+            hasher.update([pk.flag()]);
+            hasher.update(pk);
+            hasher.update([*weight]);
+        }
+        let g_arr = hasher.finalize();
+
+        let mut res = [0u8; SUI_ADDRESS_LENGTH];
+        res.copy_from_slice(&AsRef::<[u8]>::as_ref(&g_arr)[..SUI_ADDRESS_LENGTH]);
+        SuiAddress(res)
+    }
+}
+
 impl TryFrom<&[u8]> for SuiAddress {
     type Error = SuiError;
 
@@ -317,6 +338,17 @@ impl ExecutionDigests {
     }
 }
 
+/// Object references are accumulated into the live object set root committed by checkpoints.
+impl IntoPoint for ObjectRef {
+    fn into_point(&self) -> RistrettoPoint {
+        let mut data = Vec::with_capacity(ObjectID::LENGTH + 8 + OBJECT_DIGEST_LENGTH);
+        data.extend_from_slice(self.0.as_ref());
+        data.extend_from_slice(&self.1.value().to_le_bytes());
+        data.extend_from_slice(&self.2 .0);
+        RistrettoPoint::hash_from_bytes::<Sha512>(&data)
+    }
+}
+
 impl IntoPoint for ExecutionDigests {
     fn into_point(&self) -> RistrettoPoint {
         let mut data = [0; 64];
@@ -372,6 +404,11 @@ impl TxContext {
         id
     }
 
+    /// Return the number of object IDs derived so far
+    pub fn ids_created(&self) -> u64 {
+        self.ids_created
+    }
+
     /// Return the transaction digest, to include in new objects
     pub fn digest(&self) -> TransactionDigest {
         TransactionDigest::new(self.digest.clone().try_into().unwrap())
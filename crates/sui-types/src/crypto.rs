@@ -34,6 +34,7 @@ use crate::base_types::{AuthorityName, SuiAddress};
 use crate::committee::{Committee, EpochId};
 use crate::error::{SuiError, SuiResult};
 use crate::intent::{Intent, IntentMessage};
+use crate::multisig::MultiSig;
 use crate::sui_serde::{AggrAuthSignature, Base64, Encoding, Readable, SuiBitmap};
 pub use enum_dispatch::enum_dispatch;
 
@@ -259,6 +260,14 @@ impl EncodeDecodeBase64 for PublicKey {
     }
 }
 
+impl FromStr for PublicKey {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::decode_base64(s)
+    }
+}
+
 impl Serialize for PublicKey {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -300,7 +309,7 @@ impl PublicKey {
             SignatureScheme::Secp256k1 => {
                 PublicKey::Secp256k1KeyPair(Secp256k1PublicKey::from_bytes(key_bytes)?)
             }
-            SignatureScheme::BLS12381 => {
+            SignatureScheme::BLS12381 | SignatureScheme::MultiSig => {
                 return Err(eyre::Report::msg(format!("Unsupported scheme {curve:?}.")))
             }
         })
@@ -604,6 +613,9 @@ pub fn derive_key_pair_from_path(
         SignatureScheme::BLS12381 => Err(SuiError::UnsupportedFeatureError {
             error: "BLS is not supported for user key derivation".to_string(),
         }),
+        SignatureScheme::MultiSig => Err(SuiError::UnsupportedFeatureError {
+            error: "Multisig is not supported for user key derivation".to_string(),
+        }),
     }
 }
 
@@ -671,6 +683,9 @@ pub fn validate_path(
         SignatureScheme::BLS12381 => Err(SuiError::UnsupportedFeatureError {
             error: "BLS is not supported for user key derivation".to_string(),
         }),
+        SignatureScheme::MultiSig => Err(SuiError::UnsupportedFeatureError {
+            error: "Multisig is not supported for user key derivation".to_string(),
+        }),
     }
 }
 
@@ -720,6 +735,7 @@ where
 pub enum Signature {
     Ed25519SuiSignature,
     Secp256k1SuiSignature,
+    MultiSig,
 }
 
 impl Serialize for Signature {
@@ -790,6 +806,7 @@ impl AsRef<[u8]> for Signature {
         match self {
             Signature::Ed25519SuiSignature(sig) => sig.as_ref(),
             Signature::Secp256k1SuiSignature(sig) => sig.as_ref(),
+            Signature::MultiSig(sig) => sig.as_ref(),
         }
     }
 }
@@ -806,6 +823,10 @@ impl signature::Signature for Signature {
                     Ok(<Secp256k1SuiSignature as ToFromBytes>::from_bytes(bytes)
                         .map_err(|_| signature::Error::new())?
                         .into())
+                } else if x == &SignatureScheme::MultiSig.flag() {
+                    Ok(<MultiSig as ToFromBytes>::from_bytes(bytes)
+                        .map_err(|_| signature::Error::new())?
+                        .into())
                 } else {
                     Err(signature::Error::new())
                 }
@@ -1574,6 +1595,7 @@ pub enum SignatureScheme {
     ED25519,
     Secp256k1,
     BLS12381,
+    MultiSig,
 }
 
 impl SignatureScheme {
@@ -1581,6 +1603,7 @@ impl SignatureScheme {
         match self {
             SignatureScheme::ED25519 => 0x00,
             SignatureScheme::Secp256k1 => 0x01,
+            SignatureScheme::MultiSig => 0x03,
             SignatureScheme::BLS12381 => 0xff,
         }
     }
@@ -1606,6 +1629,7 @@ impl FromStr for SignatureScheme {
             "ed25519" => Ok(SignatureScheme::ED25519),
             "secp256k1" => Ok(SignatureScheme::Secp256k1),
             "bls12381" => Ok(SignatureScheme::BLS12381),
+            "multisig" => Ok(SignatureScheme::MultiSig),
             _ => Err(SuiError::KeyConversionError(
                 "Invalid key scheme".to_string(),
             )),
@@ -1619,6 +1643,7 @@ impl ToString for SignatureScheme {
             SignatureScheme::ED25519 => "ed25519".to_string(),
             SignatureScheme::Secp256k1 => "secp256k1".to_string(),
             SignatureScheme::BLS12381 => "bls12381".to_string(),
+            SignatureScheme::MultiSig => "multisig".to_string(),
         }
     }
 }
@@ -0,0 +1,124 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::base_types::{ObjectDigest, ObjectID, SequenceNumber};
+use crate::error::{SuiError, SuiResult};
+use crate::SUI_FRAMEWORK_ADDRESS;
+use move_core_types::ident_str;
+use move_core_types::identifier::IdentStr;
+use move_core_types::language_storage::{StructTag, TypeTag};
+use move_core_types::value::{MoveStruct, MoveValue};
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub const DYNAMIC_FIELD_MODULE_NAME: &IdentStr = ident_str!("dynamic_field");
+pub const DYNAMIC_FIELD_FIELD_STRUCT_NAME: &IdentStr = ident_str!("Field");
+
+pub const DYNAMIC_OBJECT_FIELD_MODULE_NAME: &IdentStr = ident_str!("dynamic_object_field");
+pub const DYNAMIC_OBJECT_FIELD_WRAPPER_STRUCT_NAME: &IdentStr = ident_str!("Wrapper");
+
+#[derive(Clone, Serialize, Deserialize, JsonSchema, Ord, PartialOrd, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamicFieldInfo {
+    /// The name of the field, rendered from its Move value
+    pub name: String,
+    #[serde(rename = "type")]
+    pub type_: DynamicFieldType,
+    /// The type of the value bound to the field, or of the object for a dynamic object field
+    pub object_type: String,
+    /// For a dynamic field, the ID of the object holding the field. For a dynamic object field,
+    /// the ID of the object bound to the field.
+    pub object_id: ObjectID,
+    pub version: SequenceNumber,
+    pub digest: ObjectDigest,
+}
+
+#[derive(
+    Clone, Copy, Serialize, Deserialize, JsonSchema, Ord, PartialOrd, Eq, PartialEq, Debug,
+)]
+pub enum DynamicFieldType {
+    DynamicField,
+    DynamicObject,
+}
+
+impl DynamicFieldInfo {
+    pub fn is_dynamic_field(tag: &StructTag) -> bool {
+        tag.address == SUI_FRAMEWORK_ADDRESS
+            && tag.module.as_ident_str() == DYNAMIC_FIELD_MODULE_NAME
+            && tag.name.as_ident_str() == DYNAMIC_FIELD_FIELD_STRUCT_NAME
+    }
+
+    pub fn is_dynamic_object_field_wrapper(tag: &StructTag) -> bool {
+        tag.address == SUI_FRAMEWORK_ADDRESS
+            && tag.module.as_ident_str() == DYNAMIC_OBJECT_FIELD_MODULE_NAME
+            && tag.name.as_ident_str() == DYNAMIC_OBJECT_FIELD_WRAPPER_STRUCT_NAME
+    }
+
+    /// Extract the name of the field, and the type of the field, from the type and contents of a
+    /// `sui::dynamic_field::Field` object. For dynamic object fields, the ID of the object bound
+    /// to the field is returned as well.
+    pub fn parse_move_object(
+        tag: &StructTag,
+        move_struct: MoveStruct,
+    ) -> SuiResult<(MoveValue, DynamicFieldType, Option<ObjectID>)> {
+        let mut fields = match move_struct {
+            MoveStruct::WithFields(fields) | MoveStruct::WithTypes { fields, .. } => fields,
+            MoveStruct::Runtime(_) => {
+                return Err(SuiError::ObjectSerializationError {
+                    error: "Dynamic field layout is missing field names".to_string(),
+                })
+            }
+        };
+        let mut take_field = |field: &str| {
+            fields
+                .iter()
+                .position(|(name, _)| name.as_str() == field)
+                .map(|i| fields.swap_remove(i).1)
+                .ok_or_else(|| SuiError::ObjectSerializationError {
+                    error: format!("Dynamic field is missing field `{field}`"),
+                })
+        };
+        let name = take_field("name")?;
+        let value = take_field("value")?;
+
+        let is_object_wrapper = matches!(
+            tag.type_params.first(),
+            Some(TypeTag::Struct(name_tag)) if Self::is_dynamic_object_field_wrapper(name_tag)
+        );
+        if !is_object_wrapper {
+            return Ok((name, DynamicFieldType::DynamicField, None));
+        }
+
+        // Wrapper { name }
+        let name = match name {
+            MoveValue::Struct(
+                MoveStruct::WithFields(mut fields) | MoveStruct::WithTypes { mut fields, .. },
+            ) if fields.len() == 1 => fields.pop().unwrap().1,
+            _ => {
+                return Err(SuiError::ObjectSerializationError {
+                    error: "Unexpected layout for dynamic object field name".to_string(),
+                })
+            }
+        };
+        // ID { bytes }
+        let object_id = match value {
+            MoveValue::Struct(
+                MoveStruct::WithFields(fields) | MoveStruct::WithTypes { fields, .. },
+            ) => match fields.as_slice() {
+                [(_, MoveValue::Address(bytes))] => ObjectID::from(*bytes),
+                _ => {
+                    return Err(SuiError::ObjectSerializationError {
+                        error: "Unexpected layout for dynamic object field value".to_string(),
+                    })
+                }
+            },
+            _ => {
+                return Err(SuiError::ObjectSerializationError {
+                    error: "Unexpected layout for dynamic object field value".to_string(),
+                })
+            }
+        };
+        Ok((name, DynamicFieldType::DynamicObject, Some(object_id)))
+    }
+}
@@ -2,7 +2,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{base_types::*, committee::EpochId, messages::ExecutionFailureStatus};
+use crate::{base_types::*, committee::EpochId, messages::ExecutionFailureStatus, object::Owner};
 use move_binary_format::errors::{Location, PartialVMError, VMError};
 use move_core_types::vm_status::{StatusCode, StatusType};
 use narwhal_executor::SubscriberError;
@@ -76,11 +76,24 @@ pub enum SuiError {
     SharedObjectLockNotSetError,
     #[error("Invalid Batch Transaction: {}", error)]
     InvalidBatchTransaction { error: String },
+    #[error("Invalid sponsored transaction: {error}")]
+    InvalidSponsoredTransaction { error: String },
+    #[error("Transaction expired at the end of epoch {expiration_epoch}, current epoch is {current_epoch}")]
+    TransactionExpired {
+        expiration_epoch: EpochId,
+        current_epoch: EpochId,
+    },
     #[error("Object {child_id:?} is owned by object {parent_id:?}, which is not in the input")]
     MissingObjectOwner {
         child_id: ObjectID,
         parent_id: ObjectID,
     },
+    #[error("Object {object:?} is owned by {actual_owner}, not by object {given_parent:?}")]
+    InvalidChildObjectAccess {
+        object: ObjectID,
+        given_parent: ObjectID,
+        actual_owner: Owner,
+    },
 
     // Signature verification
     #[error("Signature is not valid: {}", error)]
@@ -208,6 +221,8 @@ pub enum SuiError {
     SubscriptionServiceClosed,
     #[error("Checkpointing error: {}", error)]
     CheckpointingError { error: String },
+    #[error("Local state diverged from the state root of checkpoint {checkpoint}")]
+    StateDivergence { checkpoint: u64 },
     #[error(
         "ExecutionDriver error for {:?}: {} - Caused by : {}",
         digest,
@@ -37,6 +37,8 @@ pub struct EventEnvelope {
     pub tx_digest: Option<TransactionDigest>,
     /// Sequence number, must be nondecreasing for event ingestion idempotency
     pub seq_num: u64,
+    /// Index of the event within the transaction that emitted it
+    pub event_num: u64,
     /// Specific event type
     pub event: Event,
     /// json value for MoveStruct (for MoveEvent only)
@@ -48,6 +50,7 @@ impl EventEnvelope {
         timestamp: u64,
         tx_digest: Option<TransactionDigest>,
         seq_num: u64,
+        event_num: u64,
         event: Event,
         move_struct_json_value: Option<Value>,
     ) -> Self {
@@ -55,6 +58,7 @@ impl EventEnvelope {
             timestamp,
             tx_digest,
             seq_num,
+            event_num,
             event,
             move_struct_json_value,
         }
@@ -63,6 +67,36 @@ impl EventEnvelope {
     pub fn event_type(&self) -> &'static str {
         self.event.variant_name()
     }
+
+    pub fn id(&self) -> EventID {
+        EventID {
+            tx_seq: self.seq_num,
+            event_seq: self.event_num,
+        }
+    }
+}
+
+/// Unique ID of a Sui event, made of the sequence number of the emitting transaction and the
+/// index of the event within that transaction. The ID is local to the full node that indexed
+/// the event and can be used as a cursor to resume reading events from that node.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct EventID {
+    pub tx_seq: u64,
+    pub event_seq: u64,
 }
 
 #[derive(
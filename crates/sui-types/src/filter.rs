@@ -5,7 +5,7 @@ use move_core_types::identifier::Identifier;
 use move_core_types::language_storage::StructTag;
 use serde_json::Value;
 
-use crate::base_types::SuiAddress;
+use crate::base_types::{SuiAddress, TransactionDigest};
 use crate::event::EventType;
 use crate::event::{Event, EventEnvelope};
 use crate::messages::TxCertAndSignedEffects;
@@ -22,10 +22,19 @@ pub enum EventFilter {
     Module(Identifier),
     MoveEventType(StructTag),
     EventType(EventType),
-    MoveEventField { path: String, value: Value },
+    MoveEventField {
+        path: String,
+        value: Value,
+    },
     SenderAddress(SuiAddress),
     Recipient(Owner),
     ObjectId(ObjectID),
+    Transaction(TransactionDigest),
+    /// Events emitted within [start_time, end_time), in milliseconds since the UNIX epoch
+    TimeRange {
+        start_time: u64,
+        end_time: u64,
+    },
     MatchAll(Vec<EventFilter>),
     MatchAny(Vec<EventFilter>),
 }
@@ -56,6 +65,13 @@ impl EventFilter {
                 matches!(item.event.object_id(), Some(id) if &id == object_id)
             }
             EventFilter::EventType(type_) => &item.event.event_type() == type_,
+            EventFilter::Transaction(digest) => {
+                matches!(&item.tx_digest, Some(tx_digest) if tx_digest == digest)
+            }
+            EventFilter::TimeRange {
+                start_time,
+                end_time,
+            } => (*start_time..*end_time).contains(&item.timestamp),
             EventFilter::MatchAll(filters) => filters.iter().all(|f| f.matches(item)),
             EventFilter::MatchAny(filters) => filters.iter().any(|f| f.matches(item)),
             EventFilter::Recipient(recipient) => {
@@ -79,13 +95,64 @@ impl Filter<EventEnvelope> for EventFilter {
 pub enum TransactionFilter {
     // subscribe to all transactions
     Any,
+    MoveFunction {
+        package: ObjectID,
+        module: Option<Identifier>,
+        function: Option<Identifier>,
+    },
+    InputObject(ObjectID),
+    MutatedObject(ObjectID),
+    FromAddress(SuiAddress),
+    ToAddress(SuiAddress),
+    // match on whether the transaction executed successfully
+    ExecutionSucceeded(bool),
+    MatchAll(Vec<TransactionFilter>),
+    MatchAny(Vec<TransactionFilter>),
 }
 
-impl Filter<TxCertAndSignedEffects> for TransactionFilter {
-    fn matches(&self, _item: &TxCertAndSignedEffects) -> bool {
-        match self {
+impl TransactionFilter {
+    fn try_matches(&self, item: &TxCertAndSignedEffects) -> Result<bool, anyhow::Error> {
+        let (cert, effects) = item;
+        let data = &cert.signed_data.data;
+        Ok(match self {
             TransactionFilter::Any => true,
-        }
+            TransactionFilter::MoveFunction {
+                package,
+                module,
+                function,
+            } => data.move_calls().iter().any(|call| {
+                &call.package.0 == package
+                    && module.as_ref().map_or(true, |m| m == &call.module)
+                    && function.as_ref().map_or(true, |f| f == &call.function)
+            }),
+            TransactionFilter::InputObject(object_id) => data
+                .input_objects()?
+                .iter()
+                .any(|o| &o.object_id() == object_id),
+            TransactionFilter::MutatedObject(object_id) => effects
+                .effects
+                .all_mutated()
+                .any(|((id, _, _), _, _)| id == object_id),
+            TransactionFilter::FromAddress(sender) => &cert.sender_address() == sender,
+            TransactionFilter::ToAddress(recipient) => effects.effects.all_mutated().any(
+                |(_, owner, _)| matches!(owner.get_owner_address(), Ok(addr) if &addr == recipient),
+            ),
+            TransactionFilter::ExecutionSucceeded(success) => {
+                effects.effects.status.is_ok() == *success
+            }
+            TransactionFilter::MatchAll(filters) => filters.iter().all(|f| f.matches(item)),
+            TransactionFilter::MatchAny(filters) => filters.iter().any(|f| f.matches(item)),
+        })
+    }
+
+    pub fn and(self, other_filter: TransactionFilter) -> Self {
+        Self::MatchAll(vec![self, other_filter])
+    }
+}
+
+impl Filter<TxCertAndSignedEffects> for TransactionFilter {
+    fn matches(&self, item: &TxCertAndSignedEffects) -> bool {
+        self.try_matches(item).unwrap_or_default()
     }
 }
 
@@ -6,6 +6,7 @@ use crate::{
     error::{ExecutionError, ExecutionErrorKind},
     error::{SuiError, SuiResult},
     gas_coin::GasCoin,
+    messages::TransactionEffects,
     object::{Object, Owner},
 };
 use move_core_types::{
@@ -63,6 +64,35 @@ impl GasCostSummary {
     pub fn net_gas_usage(&self) -> i64 {
         self.gas_used() as i64 - self.storage_rebate as i64
     }
+
+    /// Sum the gas costs of a set of executed transactions, whose effects are read one at a
+    /// time. Fails on the first effects that cannot be read.
+    pub fn new_from_txn_effects(
+        transactions: impl Iterator<Item = SuiResult<TransactionEffects>>,
+    ) -> SuiResult<GasCostSummary> {
+        transactions.try_fold(
+            GasCostSummary {
+                computation_cost: 0,
+                storage_cost: 0,
+                storage_rebate: 0,
+            },
+            |total, effects| {
+                let effects = effects?;
+                Ok(GasCostSummary {
+                    computation_cost: total.computation_cost + effects.gas_used.computation_cost,
+                    storage_cost: total.storage_cost + effects.gas_used.storage_cost,
+                    storage_rebate: total.storage_rebate + effects.gas_used.storage_rebate,
+                })
+            },
+        )
+    }
+
+    /// The storage charge to move into the storage fund: the storage paid for minus the
+    /// rebates paid back. It is zero if the rebates exceed the storage paid for, as the charge
+    /// of a `ChangeEpoch` cannot be negative.
+    pub fn net_storage_charge(&self) -> u64 {
+        self.storage_cost.saturating_sub(self.storage_rebate)
+    }
 }
 
 // Fixed cost type
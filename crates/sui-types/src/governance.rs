@@ -0,0 +1,77 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use move_core_types::{ident_str, identifier::IdentStr, language_storage::StructTag};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    balance::Balance,
+    base_types::{ObjectID, SuiAddress},
+    committee::EpochId,
+    id::UID,
+    SUI_FRAMEWORK_ADDRESS,
+};
+
+pub const STAKING_POOL_MODULE_NAME: &IdentStr = ident_str!("staking_pool");
+pub const STAKED_SUI_STRUCT_NAME: &IdentStr = ident_str!("StakedSui");
+pub const DELEGATION_STRUCT_NAME: &IdentStr = ident_str!("Delegation");
+
+/// Rust version of the Move sui::staking_pool::StakedSui type
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
+pub struct StakedSui {
+    pub id: UID,
+    pub principal: Balance,
+    /// The Move `Option<EpochTimeLock>` holding the epoch until which the staked SUI is locked.
+    /// An `EpochTimeLock` only holds a `u64`, and a Move option serializes as a vector of at most
+    /// one element, which has the same BCS encoding as a Rust option.
+    pub sui_token_lock: Option<EpochId>,
+}
+
+impl StakedSui {
+    pub fn type_() -> StructTag {
+        StructTag {
+            address: SUI_FRAMEWORK_ADDRESS,
+            module: STAKING_POOL_MODULE_NAME.to_owned(),
+            name: STAKED_SUI_STRUCT_NAME.to_owned(),
+            type_params: vec![],
+        }
+    }
+
+    pub fn id(&self) -> &ObjectID {
+        self.id.object_id()
+    }
+
+    pub fn principal(&self) -> u64 {
+        self.principal.value()
+    }
+}
+
+/// Rust version of the Move sui::staking_pool::Delegation type
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
+pub struct Delegation {
+    pub id: UID,
+    pub validator_address: SuiAddress,
+    pub pool_starting_epoch: u64,
+    pub pool_tokens: Balance,
+    pub principal_sui_amount: u64,
+}
+
+impl Delegation {
+    pub fn type_() -> StructTag {
+        StructTag {
+            address: SUI_FRAMEWORK_ADDRESS,
+            module: STAKING_POOL_MODULE_NAME.to_owned(),
+            name: DELEGATION_STRUCT_NAME.to_owned(),
+            type_params: vec![],
+        }
+    }
+
+    pub fn id(&self) -> &ObjectID {
+        self.id.object_id()
+    }
+
+    pub fn pool_tokens(&self) -> u64 {
+        self.pool_tokens.value()
+    }
+}
@@ -4,8 +4,8 @@
 use crate::{
     base_types::{ObjectID, ObjectRef, SequenceNumber},
     error::{SuiError, SuiResult},
-    object::Object,
-    storage::{BackingPackageStore, DeleteKind, ParentSync, WriteKind},
+    object::{Object, Owner},
+    storage::{BackingPackageStore, ChildObjectResolver, DeleteKind, ParentSync, WriteKind},
 };
 use move_core_types::{language_storage::ModuleId, resolver::ModuleResolver};
 use std::collections::BTreeMap;
@@ -33,6 +33,23 @@ impl ParentSync for InMemoryStorage {
     }
 }
 
+impl ChildObjectResolver for InMemoryStorage {
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>> {
+        let child_object = match self.persistent.get(child) {
+            None => return Ok(None),
+            Some(object) => object,
+        };
+        if child_object.owner != Owner::ObjectOwner((*parent).into()) {
+            return Err(SuiError::InvalidChildObjectAccess {
+                object: *child,
+                given_parent: *parent,
+                actual_owner: child_object.owner,
+            });
+        }
+        Ok(Some(child_object.clone()))
+    }
+}
+
 impl ModuleResolver for InMemoryStorage {
     type Error = SuiError;
 
@@ -25,9 +25,11 @@ pub mod batch;
 pub mod coin;
 pub mod committee;
 pub mod crypto;
+pub mod dynamic_field;
 pub mod event;
 pub mod gas;
 pub mod gas_coin;
+pub mod governance;
 pub mod id;
 pub mod in_memory_storage;
 pub mod intent;
@@ -35,6 +37,7 @@ pub mod message_envelope;
 pub mod messages;
 pub mod messages_checkpoint;
 pub mod move_package;
+pub mod multisig;
 pub mod object;
 pub mod query;
 pub mod signature_seed;
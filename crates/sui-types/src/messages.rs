@@ -54,6 +54,8 @@ pub enum CallArg {
     Object(ObjectArg),
     // a vector of objects
     ObjVec(Vec<ObjectArg>),
+    // an object created by an earlier step of a batch transaction
+    Result(BatchResult),
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
@@ -67,6 +69,23 @@ pub enum ObjectArg {
     },
 }
 
+/// An object created by an earlier step of a batch transaction, which a later step can use in
+/// place of an input object.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub struct BatchResult {
+    /// The index of the step that created the object
+    pub step: u16,
+    /// The index of the object among the objects created by the step that still exist after it,
+    /// in creation order
+    pub index: u16,
+}
+
+impl Display for BatchResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Result({}, {})", self.step, self.index)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct TransferObject {
     pub recipient: SuiAddress,
@@ -94,6 +113,18 @@ pub struct MoveModulePublish {
     pub modules: Vec<Vec<u8>>,
 }
 
+#[serde_as]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct MoveModuleUpgrade {
+    /// The package to upgrade. The upgraded package is published under a new ID, and records
+    /// this one in its lineage.
+    pub package: ObjectRef,
+    /// The `sui::package::UpgradeCap` of the package, which is updated to the new version.
+    pub upgrade_cap: ObjectRef,
+    #[serde_as(as = "Vec<Bytes>")]
+    pub modules: Vec<Vec<u8>>,
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct TransferSui {
     pub recipient: SuiAddress,
@@ -112,6 +143,26 @@ pub struct Pay {
     pub amounts: Vec<u64>,
 }
 
+/// Transfer an object created by an earlier step of a batch transaction
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct TransferBatchResult {
+    pub recipient: SuiAddress,
+    pub object: BatchResult,
+}
+
+/// Pay each recipient the corresponding amount using coins created by earlier steps of a batch
+/// transaction
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
+pub struct PayBatchResults {
+    /// The coins to be used for payment
+    pub coins: Vec<BatchResult>,
+    /// The addresses that will receive payment
+    pub recipients: Vec<SuiAddress>,
+    /// The amounts each recipient will receive.
+    /// Must be the same length as recipients
+    pub amounts: Vec<u64>,
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct ChangeEpoch {
     /// The next (to become) epoch ID.
@@ -143,6 +194,12 @@ pub enum SingleTransactionKind {
     /// A validator will not sign a transaction of this kind from outside. It only
     /// signs internally during epoch changes.
     ChangeEpoch(ChangeEpoch),
+    /// Publish a new version of a Move package, compatible with the previous one
+    Upgrade(MoveModuleUpgrade),
+    /// Transfer an object created by an earlier step of the batch
+    TransferBatchResult(TransferBatchResult),
+    /// Pay multiple recipients using coins created by earlier steps of the batch
+    PayBatchResults(PayBatchResults),
     // .. more transaction types go here
 }
 
@@ -159,7 +216,9 @@ impl SingleTransactionKind {
                 arguments
                     .iter()
                     .filter_map(|arg| match arg {
-                        CallArg::Pure(_) | CallArg::Object(ObjectArg::ImmOrOwnedObject(_)) => None,
+                        CallArg::Pure(_)
+                        | CallArg::Object(ObjectArg::ImmOrOwnedObject(_))
+                        | CallArg::Result(_) => None,
                         CallArg::Object(ObjectArg::SharedObject {
                             id,
                             initial_shared_version,
@@ -186,6 +245,22 @@ impl SingleTransactionKind {
         }
     }
 
+    /// Return the objects created by earlier steps of a batch transaction that this step uses.
+    pub fn batch_results(&self) -> Vec<BatchResult> {
+        match &self {
+            Self::Call(MoveCall { arguments, .. }) => arguments
+                .iter()
+                .filter_map(|arg| match arg {
+                    CallArg::Result(result) => Some(*result),
+                    _ => None,
+                })
+                .collect(),
+            Self::TransferBatchResult(TransferBatchResult { object, .. }) => vec![*object],
+            Self::PayBatchResults(PayBatchResults { coins, .. }) => coins.clone(),
+            _ => vec![],
+        }
+    }
+
     pub fn move_call(&self) -> Option<&MoveCall> {
         match &self {
             Self::Call(call @ MoveCall { .. }) => Some(call),
@@ -207,7 +282,7 @@ impl SingleTransactionKind {
             }) => arguments
                 .iter()
                 .filter_map(|arg| match arg {
-                    CallArg::Pure(_) => None,
+                    CallArg::Pure(_) | CallArg::Result(_) => None,
                     CallArg::Object(ObjectArg::ImmOrOwnedObject(object_ref)) => {
                         Some(vec![InputObjectKind::ImmOrOwnedMoveObject(*object_ref)])
                     }
@@ -264,7 +339,28 @@ impl SingleTransactionKind {
                     .collect::<Vec<_>>();
                 Transaction::input_objects_in_compiled_modules(&compiled_modules)
             }
-            Self::TransferSui(_) => {
+            Self::Upgrade(MoveModuleUpgrade {
+                package,
+                upgrade_cap,
+                modules,
+            }) => {
+                // As for publishing, the dependencies of the new modules are implicit inputs.
+                // The previous version of the package is needed to check compatibility, and
+                // may already be one of them.
+                let compiled_modules = modules
+                    .iter()
+                    .filter_map(|bytes| CompiledModule::deserialize(bytes).ok())
+                    .collect::<Vec<_>>();
+                let mut input_objects =
+                    Transaction::input_objects_in_compiled_modules(&compiled_modules);
+                if !input_objects.contains(&InputObjectKind::MovePackage(package.0)) {
+                    input_objects.push(InputObjectKind::MovePackage(package.0));
+                }
+                input_objects.push(InputObjectKind::ImmOrOwnedMoveObject(*upgrade_cap));
+                input_objects
+            }
+            // Objects created by earlier steps are not inputs of the transaction.
+            Self::TransferSui(_) | Self::TransferBatchResult(_) | Self::PayBatchResults(_) => {
                 vec![]
             }
             Self::Pay(Pay { coins, .. }) => coins
@@ -331,9 +427,34 @@ impl Display for SingleTransactionKind {
                     writeln!(writer, "{}", amount)?
                 }
             }
+            Self::TransferBatchResult(t) => {
+                writeln!(writer, "Transaction Kind : Transfer Batch Result")?;
+                writeln!(writer, "Recipient : {}", t.recipient)?;
+                writeln!(writer, "Object : {}", t.object)?;
+            }
+            Self::PayBatchResults(p) => {
+                writeln!(writer, "Transaction Kind : Pay Batch Results")?;
+                writeln!(writer, "Coins:")?;
+                for coin in &p.coins {
+                    writeln!(writer, "{}", coin)?;
+                }
+                writeln!(writer, "Recipients:")?;
+                for recipient in &p.recipients {
+                    writeln!(writer, "{}", recipient)?;
+                }
+                writeln!(writer, "Amounts:")?;
+                for amount in &p.amounts {
+                    writeln!(writer, "{}", amount)?
+                }
+            }
             Self::Publish(_p) => {
                 writeln!(writer, "Transaction Kind : Publish")?;
             }
+            Self::Upgrade(u) => {
+                writeln!(writer, "Transaction Kind : Upgrade")?;
+                writeln!(writer, "Package ID : {}", u.package.0.to_hex_literal())?;
+                writeln!(writer, "Upgrade Capability : {}", u.upgrade_cap.0)?;
+            }
             Self::Call(c) => {
                 writeln!(writer, "Transaction Kind : Call")?;
                 writeln!(writer, "Package ID : {}", c.package.0.to_hex_literal())?;
@@ -435,15 +556,18 @@ impl TransactionKind {
                 let valid = self.single_transactions().all(|s| match s {
                     SingleTransactionKind::Call(_)
                     | SingleTransactionKind::TransferObject(_)
-                    | SingleTransactionKind::Pay(_) => true,
+                    | SingleTransactionKind::Pay(_)
+                    | SingleTransactionKind::TransferBatchResult(_)
+                    | SingleTransactionKind::PayBatchResults(_) => true,
                     SingleTransactionKind::TransferSui(_)
                     | SingleTransactionKind::ChangeEpoch(_)
-                    | SingleTransactionKind::Publish(_) => false,
+                    | SingleTransactionKind::Publish(_)
+                    | SingleTransactionKind::Upgrade(_) => false,
                 });
                 fp_ensure!(
                     valid,
                     SuiError::InvalidBatchTransaction {
-                        error: "Batch transaction contains non-batchable transactions. Only Call and TransferObject are allowed".to_string()
+                        error: "Batch transaction contains non-batchable transactions. Only Call, TransferObject, Pay and their batch result forms are allowed".to_string()
                     }
                 );
             }
@@ -451,11 +575,34 @@ impl TransactionKind {
                 SingleTransactionKind::Pay(_)
                 | SingleTransactionKind::Call(_)
                 | SingleTransactionKind::Publish(_)
+                | SingleTransactionKind::Upgrade(_)
                 | SingleTransactionKind::TransferObject(_)
                 | SingleTransactionKind::TransferSui(_)
-                | SingleTransactionKind::ChangeEpoch(_) => (),
+                | SingleTransactionKind::ChangeEpoch(_)
+                | SingleTransactionKind::TransferBatchResult(_)
+                | SingleTransactionKind::PayBatchResults(_) => (),
             },
         }
+        // Each step can only use objects created by the steps before it, at most once.
+        for (step, s) in self.single_transactions().enumerate() {
+            let mut used = HashSet::new();
+            for result in s.batch_results() {
+                fp_ensure!(
+                    (result.step as usize) < step,
+                    SuiError::InvalidBatchTransaction {
+                        error: format!(
+                            "Step {step} uses {result}, which is not created by an earlier step"
+                        ),
+                    }
+                );
+                fp_ensure!(
+                    used.insert(result),
+                    SuiError::InvalidBatchTransaction {
+                        error: format!("Step {step} uses {result} more than once"),
+                    }
+                );
+            }
+        }
         Ok(())
     }
 }
@@ -479,6 +626,20 @@ impl Display for TransactionKind {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+pub enum TransactionExpiration {
+    /// The transaction has no expiration.
+    None,
+    /// Validators won't sign or execute the transaction after the given epoch.
+    Epoch(EpochId),
+}
+
+impl From<Option<EpochId>> for TransactionExpiration {
+    fn from(epoch: Option<EpochId>) -> Self {
+        epoch.map_or(Self::None, Self::Epoch)
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Serialize, Deserialize)]
 pub struct TransactionData {
     pub kind: TransactionKind,
@@ -486,6 +647,10 @@ pub struct TransactionData {
     gas_payment: ObjectRef,
     pub gas_price: u64,
     pub gas_budget: u64,
+    /// The address paying for gas, if it is not the sender. The gas object must then be owned
+    /// by the sponsor, which must sign the transaction data as well.
+    sponsor: Option<SuiAddress>,
+    expiration: TransactionExpiration,
 }
 
 impl TransactionData {
@@ -502,6 +667,8 @@ impl TransactionData {
             gas_price: 1,
             gas_payment,
             gas_budget,
+            sponsor: None,
+            expiration: TransactionExpiration::None,
         }
     }
 
@@ -518,6 +685,18 @@ impl TransactionData {
             gas_price,
             gas_payment,
             gas_budget,
+            sponsor: None,
+            expiration: TransactionExpiration::None,
+        }
+    }
+
+    /// Have `sponsor` pay for the gas of this transaction with `gas_payment`, one of its own
+    /// gas objects. The sender and the sponsor must both sign the resulting data.
+    pub fn with_sponsor(self, sponsor: SuiAddress, gas_payment: ObjectRef) -> Self {
+        TransactionData {
+            gas_payment,
+            sponsor: Some(sponsor),
+            ..self
         }
     }
 
@@ -597,6 +776,22 @@ impl TransactionData {
         Self::new(kind, sender, gas_payment, gas_budget)
     }
 
+    pub fn new_upgrade(
+        sender: SuiAddress,
+        gas_payment: ObjectRef,
+        package: ObjectRef,
+        upgrade_cap: ObjectRef,
+        modules: Vec<Vec<u8>>,
+        gas_budget: u64,
+    ) -> Self {
+        let kind = TransactionKind::Single(SingleTransactionKind::Upgrade(MoveModuleUpgrade {
+            package,
+            upgrade_cap,
+            modules,
+        }));
+        Self::new(kind, sender, gas_payment, gas_budget)
+    }
+
     /// Returns the transaction kind as a &str (variant name, no fields)
     pub fn kind_as_str(&self) -> &'static str {
         self.kind.variant_name()
@@ -610,6 +805,67 @@ impl TransactionData {
         self.sender
     }
 
+    pub fn sponsor(&self) -> Option<SuiAddress> {
+        self.sponsor
+    }
+
+    pub fn with_expiration(self, expiration: TransactionExpiration) -> Self {
+        TransactionData { expiration, ..self }
+    }
+
+    pub fn expiration(&self) -> TransactionExpiration {
+        self.expiration
+    }
+
+    /// Check that the transaction has not expired in `epoch`.
+    pub fn check_expiration(&self, epoch: EpochId) -> SuiResult {
+        match self.expiration {
+            TransactionExpiration::None => Ok(()),
+            TransactionExpiration::Epoch(expiration_epoch) => {
+                fp_ensure!(
+                    epoch <= expiration_epoch,
+                    SuiError::TransactionExpired {
+                        expiration_epoch,
+                        current_epoch: epoch,
+                    }
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// The owner of the gas object: the sponsor if there is one, the sender otherwise.
+    pub fn gas_owner(&self) -> SuiAddress {
+        self.sponsor.unwrap_or(self.sender)
+    }
+
+    pub fn validity_check(&self) -> SuiResult {
+        self.kind.validity_check()?;
+        if let Some(sponsor) = self.sponsor {
+            fp_ensure!(
+                sponsor != self.sender,
+                SuiError::InvalidSponsoredTransaction {
+                    error: "Sponsor must be different from the sender".to_string(),
+                }
+            );
+            // TransferSui moves funds out of the gas object, which only the sender may spend.
+            let valid = self.kind.single_transactions().all(|s| {
+                !matches!(
+                    s,
+                    SingleTransactionKind::TransferSui(_) | SingleTransactionKind::ChangeEpoch(_)
+                )
+            });
+            fp_ensure!(
+                valid,
+                SuiError::InvalidSponsoredTransaction {
+                    error: "TransferSui and ChangeEpoch transactions cannot be sponsored"
+                        .to_string(),
+                }
+            );
+        }
+        Ok(())
+    }
+
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut writer = Vec::new();
         self.write(&mut writer);
@@ -677,6 +933,9 @@ pub struct SenderSignedData {
     pub data: TransactionData,
     /// tx_signature is signed by the transaction sender, applied on `data`.
     pub tx_signature: Signature,
+    /// sponsor_signature is signed by the gas sponsor, applied on `data`. It must be set if
+    /// and only if `data` has a sponsor.
+    pub sponsor_signature: Option<Signature>,
 }
 
 impl<S> TransactionEnvelope<S> {
@@ -696,16 +955,45 @@ impl<S> TransactionEnvelope<S> {
 
         self.signed_data
             .tx_signature
-            .add_to_verification_obligation_or_verify(self.signed_data.data.sender, obligation, idx)
+            .add_to_verification_obligation_or_verify(
+                self.signed_data.data.sender,
+                obligation,
+                idx,
+            )?;
+        if let Some((sponsor, signature)) = self.sponsor_and_signature()? {
+            signature.add_to_verification_obligation_or_verify(sponsor, obligation, idx)?;
+        }
+        Ok(())
     }
 
+    /// Verify the signature of the sender, and the one of the gas sponsor if there is one.
     pub fn verify_sender_signature(&self) -> SuiResult<()> {
         if self.is_verified || self.signed_data.data.kind.is_system_tx() {
             return Ok(());
         }
         self.signed_data
             .tx_signature
-            .verify(&self.signed_data.data, self.signed_data.data.sender)
+            .verify(&self.signed_data.data, self.signed_data.data.sender)?;
+        if let Some((sponsor, signature)) = self.sponsor_and_signature()? {
+            signature.verify(&self.signed_data.data, sponsor)?;
+        }
+        Ok(())
+    }
+
+    fn sponsor_and_signature(&self) -> SuiResult<Option<(SuiAddress, &Signature)>> {
+        match (
+            self.signed_data.data.sponsor,
+            &self.signed_data.sponsor_signature,
+        ) {
+            (None, None) => Ok(None),
+            (Some(sponsor), Some(signature)) => Ok(Some((sponsor, signature))),
+            (Some(sponsor), None) => Err(SuiError::IncorrectSigner {
+                error: format!("Missing signature of the gas sponsor {:?}", sponsor),
+            }),
+            (None, Some(_)) => Err(SuiError::IncorrectSigner {
+                error: "Transaction has a sponsor signature but no sponsor".to_string(),
+            }),
+        }
     }
 
     pub fn sender_address(&self) -> SuiAddress {
@@ -807,13 +1095,32 @@ impl Transaction {
     }
 
     pub fn new(data: TransactionData, signature: Signature) -> Self {
+        Self::from_signed_data(SenderSignedData {
+            data,
+            tx_signature: signature,
+            sponsor_signature: None,
+        })
+    }
+
+    /// Create a transaction whose gas is paid by the sponsor of `data`, from the signatures
+    /// of both the sender and the sponsor.
+    pub fn new_sponsored(
+        data: TransactionData,
+        signature: Signature,
+        sponsor_signature: Signature,
+    ) -> Self {
+        Self::from_signed_data(SenderSignedData {
+            data,
+            tx_signature: signature,
+            sponsor_signature: Some(sponsor_signature),
+        })
+    }
+
+    pub fn from_signed_data(signed_data: SenderSignedData) -> Self {
         Self {
             transaction_digest: OnceCell::new(),
             is_verified: false,
-            signed_data: SenderSignedData {
-                data,
-                tx_signature: signature,
-            },
+            signed_data,
             auth_sign_info: EmptySignInfo {},
         }
     }
@@ -894,6 +1201,7 @@ impl SignedTransaction {
             tx_signature: Ed25519SuiSignature::from_bytes(&[0; Ed25519SuiSignature::LENGTH])
                 .unwrap()
                 .into(),
+            sponsor_signature: None,
         };
         let signature = AuthoritySignature::new(&signed_data, secret);
         Self {
@@ -925,7 +1233,7 @@ impl SignedTransaction {
     // forming a CertifiedTransaction, where each transaction's authority signature
     // is taking out to form an aggregated signature.
     pub fn to_transaction(self) -> Transaction {
-        Transaction::new(self.signed_data.data, self.signed_data.tx_signature)
+        Transaction::from_signed_data(self.signed_data)
     }
 }
 
@@ -1212,6 +1520,21 @@ pub enum ExecutionFailureStatus {
     MoveAbort(ModuleId, u64), // TODO func def + offset?
     VMVerificationOrDeserializationError,
     VMInvariantViolation,
+
+    //
+    // Package upgrade errors
+    //
+    /// The upgrade capability is not a `sui::package::UpgradeCap` of the upgraded package
+    InvalidUpgradeCap,
+    /// A module of the previous version of the package is missing or changed incompatibly
+    IncompatiblePackageUpgrade,
+
+    //
+    // Batch transaction errors
+    //
+    /// A step used an object that was not created by the referenced earlier step, or that the
+    /// step cannot use
+    InvalidBatchResult,
 }
 
 #[derive(Eq, PartialEq, Clone, Copy, Debug, Serialize, Deserialize, Hash)]
@@ -1404,6 +1727,21 @@ impl Display for ExecutionFailureStatus {
             ExecutionFailureStatus::VMInvariantViolation => {
                 write!(f, "MOVE VM INVARIANT VIOLATION.")
             }
+            ExecutionFailureStatus::InvalidUpgradeCap => write!(
+                f,
+                "Invalid Upgrade Capability. \
+                The object is not the upgrade capability of the package."
+            ),
+            ExecutionFailureStatus::IncompatiblePackageUpgrade => write!(
+                f,
+                "Incompatible Package Upgrade. \
+                The upgrade must keep all modules, struct layouts and public function signatures."
+            ),
+            ExecutionFailureStatus::InvalidBatchResult => write!(
+                f,
+                "Invalid Batch Result. \
+                The object was not created by the earlier step, or is not owned by the sender."
+            ),
         }
     }
 }
@@ -1980,7 +2318,7 @@ impl CertifiedTransaction {
     }
 
     pub fn to_transaction(self) -> Transaction {
-        Transaction::new(self.signed_data.data, self.signed_data.tx_signature)
+        Transaction::from_signed_data(self.signed_data)
     }
 
     /// Verify the certificate.
@@ -2087,6 +2425,16 @@ impl ConsensusTransaction {
             .unwrap_or_default()
     }
 
+    /// The epoch in which the content of this transaction was signed.
+    pub fn epoch(&self) -> EpochId {
+        match &self.kind {
+            ConsensusTransactionKind::UserTransaction(certificate) => certificate.epoch(),
+            ConsensusTransactionKind::Checkpoint(fragment) => {
+                fragment.proposer.auth_signature.epoch
+            }
+        }
+    }
+
     pub fn verify(&self, committee: &Committee) -> SuiResult<()> {
         match &self.kind {
             ConsensusTransactionKind::UserTransaction(certificate) => certificate.verify(committee),
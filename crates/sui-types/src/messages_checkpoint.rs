@@ -10,7 +10,7 @@ use crate::committee::{EpochId, StakeUnit};
 use crate::crypto::{AuthoritySignInfo, AuthoritySignInfoTrait, AuthorityWeakQuorumSignInfo};
 use crate::error::SuiResult;
 use crate::messages::CertifiedTransaction;
-use crate::waypoint::{Waypoint, WaypointDiff};
+use crate::waypoint::{Accumulator, Waypoint, WaypointDiff};
 use crate::{
     base_types::AuthorityName,
     committee::Committee,
@@ -188,6 +188,11 @@ pub struct CheckpointSummary {
     /// TODO: If desired, we could also commit to the previous last checkpoint cert so that
     /// they form a hash chain.
     pub next_epoch_committee: Option<Vec<(AuthorityName, StakeUnit)>>,
+    /// Accumulator over the references of all live objects (i.e. not deleted or wrapped)
+    /// once all transactions of this and previous checkpoints are executed, starting from
+    /// the genesis objects. Nodes compare it with their own accumulator to detect state
+    /// divergence, and it authenticates the objects of a state snapshot.
+    pub state_root: Accumulator,
 }
 
 impl CheckpointSummary {
@@ -197,12 +202,8 @@ impl CheckpointSummary {
         transactions: &CheckpointContents,
         previous_digest: Option<CheckpointDigest>,
         next_epoch_committee: Option<Committee>,
+        state_root: Accumulator,
     ) -> CheckpointSummary {
-        let mut waypoint = Box::new(Waypoint::default());
-        transactions.iter().for_each(|tx| {
-            waypoint.insert(tx);
-        });
-
         let content_digest = transactions.digest();
 
         Self {
@@ -211,6 +212,7 @@ impl CheckpointSummary {
             content_digest,
             previous_digest,
             next_epoch_committee: next_epoch_committee.map(|c| c.voting_rights),
+            state_root,
         }
     }
 
@@ -261,6 +263,7 @@ impl SignedCheckpointSummary {
         transactions: &CheckpointContents,
         previous_digest: Option<CheckpointDigest>,
         next_epoch_committee: Option<Committee>,
+        state_root: Accumulator,
     ) -> SignedCheckpointSummary {
         let checkpoint = CheckpointSummary::new(
             epoch,
@@ -268,6 +271,7 @@ impl SignedCheckpointSummary {
             transactions,
             previous_digest,
             next_epoch_committee,
+            state_root,
         );
         SignedCheckpointSummary::new_from_summary(checkpoint, authority, signer)
     }
@@ -729,7 +733,16 @@ mod tests {
             .map(|k| {
                 let name = k.public().into();
 
-                SignedCheckpointSummary::new(committee.epoch, 1, name, k, &set, None, None)
+                SignedCheckpointSummary::new(
+                    committee.epoch,
+                    1,
+                    name,
+                    k,
+                    &set,
+                    None,
+                    None,
+                    Accumulator::default(),
+                )
             })
             .collect();
 
@@ -757,7 +770,16 @@ mod tests {
             .map(|k| {
                 let name = k.public().into();
 
-                SignedCheckpointSummary::new(committee.epoch, 1, name, k, &set, None, None)
+                SignedCheckpointSummary::new(
+                    committee.epoch,
+                    1,
+                    name,
+                    k,
+                    &set,
+                    None,
+                    None,
+                    Accumulator::default(),
+                )
             })
             .collect();
 
@@ -776,7 +798,16 @@ mod tests {
                     [ExecutionDigests::random()].into_iter(),
                 );
 
-                SignedCheckpointSummary::new(committee.epoch, 1, name, k, &set, None, None)
+                SignedCheckpointSummary::new(
+                    committee.epoch,
+                    1,
+                    name,
+                    k,
+                    &set,
+                    None,
+                    None,
+                    Accumulator::default(),
+                )
             })
             .collect();
 
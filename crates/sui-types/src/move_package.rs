@@ -3,13 +3,17 @@
 
 use crate::{
     base_types::ObjectID,
-    error::{SuiError, SuiResult},
+    error::{ExecutionError, ExecutionErrorKind, SuiError, SuiResult},
+    id::{ID, UID},
+    SUI_FRAMEWORK_ADDRESS,
 };
 use move_binary_format::access::ModuleAccess;
 use move_binary_format::binary_views::BinaryIndexedView;
 use move_binary_format::file_format::CompiledModule;
 use move_binary_format::normalized;
-use move_core_types::identifier::Identifier;
+use move_core_types::ident_str;
+use move_core_types::identifier::{IdentStr, Identifier};
+use move_core_types::language_storage::StructTag;
 use move_disassembler::disassembler::Disassembler;
 use move_ir_types::location::Spanned;
 use serde::{Deserialize, Serialize};
@@ -23,6 +27,9 @@ use std::collections::BTreeMap;
 // #[path = "unit_tests/move_package.rs"]
 // mod base_types_tests;
 
+pub const PACKAGE_MODULE_NAME: &IdentStr = ident_str!("package");
+pub const UPGRADE_CAP_STRUCT_NAME: &IdentStr = ident_str!("UpgradeCap");
+
 // serde_bytes::ByteBuf is an analog of Vec<u8> with built-in fast serialization.
 #[serde_as]
 #[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize, Hash)]
@@ -31,6 +38,11 @@ pub struct MovePackage {
     // TODO use session cache
     #[serde_as(as = "BTreeMap<_, Bytes>")]
     module_map: BTreeMap<String, Vec<u8>>,
+    /// The IDs of the previous versions of this package, from the originally published one.
+    /// Not serialized with the package, so that packages published without a lineage keep their
+    /// encoding: `Data` serializes the lineage of upgraded packages separately.
+    #[serde(skip)]
+    lineage: Vec<ObjectID>,
 }
 
 impl MovePackage {
@@ -38,13 +50,36 @@ impl MovePackage {
         Self {
             id,
             module_map: module_map.clone(),
+            lineage: Vec::new(),
         }
     }
 
+    /// Make this package the next version of `previous` in its lineage.
+    pub fn upgrade_of(self, previous: &MovePackage) -> Self {
+        let mut lineage = previous.lineage.clone();
+        lineage.push(previous.id);
+        self.with_lineage(lineage)
+    }
+
+    pub fn with_lineage(mut self, lineage: Vec<ObjectID>) -> Self {
+        self.lineage = lineage;
+        self
+    }
+
     pub fn id(&self) -> ObjectID {
         self.id
     }
 
+    /// The IDs of the previous versions of this package, from the originally published one.
+    pub fn lineage(&self) -> &[ObjectID] {
+        &self.lineage
+    }
+
+    /// The version of this package in its lineage, starting at 1 when first published.
+    pub fn version(&self) -> u64 {
+        self.lineage.len() as u64 + 1
+    }
+
     pub fn serialized_module_map(&self) -> &BTreeMap<String, Vec<u8>> {
         &self.module_map
     }
@@ -137,3 +172,45 @@ impl FromIterator<CompiledModule> for MovePackage {
         )
     }
 }
+
+// Rust version of the Move sui::package::UpgradeCap type
+#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+pub struct UpgradeCap {
+    pub id: UID,
+    /// The latest version of the package.
+    pub package: ID,
+    /// The version of `package` in its lineage.
+    pub version: u64,
+}
+
+impl UpgradeCap {
+    pub fn type_() -> StructTag {
+        StructTag {
+            address: SUI_FRAMEWORK_ADDRESS,
+            module: PACKAGE_MODULE_NAME.to_owned(),
+            name: UPGRADE_CAP_STRUCT_NAME.to_owned(),
+            type_params: vec![],
+        }
+    }
+
+    /// Is this other StructTag representing an UpgradeCap?
+    pub fn is_upgrade_cap(other: &StructTag) -> bool {
+        other.address == SUI_FRAMEWORK_ADDRESS
+            && other.module.as_ident_str() == PACKAGE_MODULE_NAME
+            && other.name.as_ident_str() == UPGRADE_CAP_STRUCT_NAME
+    }
+
+    /// Create an upgrade capability from BCS bytes
+    pub fn from_bcs_bytes(content: &[u8]) -> Result<Self, ExecutionError> {
+        bcs::from_bytes(content).map_err(|err| {
+            ExecutionError::new_with_source(
+                ExecutionErrorKind::InvalidUpgradeCap,
+                format!("Unable to deserialize upgrade capability: {:?}", err),
+            )
+        })
+    }
+
+    pub fn to_bcs_bytes(&self) -> Vec<u8> {
+        bcs::to_bytes(&self).unwrap()
+    }
+}
@@ -0,0 +1,317 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! k-of-n multi-signatures.
+//!
+//! A multisig address is derived from a weighted set of public keys (Ed25519 and Secp256k1 can
+//! be mixed) and a threshold. A `MultiSig` carries the signatures of some of the members, a
+//! bitmap telling which members signed, and the `MultiPublicKey` itself, so that it can be
+//! checked against the address. It is valid if all member signatures are valid and the sum of
+//! the weights of the signers reaches the threshold.
+
+use std::collections::HashSet;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_with::{serde_as, Bytes};
+
+use crate::base_types::SuiAddress;
+use crate::crypto::{
+    PublicKey, Signable, Signature, SignatureScheme, SuiSignature, VerificationObligation,
+};
+use crate::error::{SuiError, SuiResult};
+use crate::intent::Intent;
+use crate::sui_serde::{Base64, Readable};
+
+#[cfg(test)]
+#[path = "unit_tests/multisig_tests.rs"]
+mod multisig_tests;
+
+pub type WeightUnit = u8;
+pub type ThresholdUnit = u16;
+pub type BitmapUnit = u16;
+
+pub const MAX_SIGNER_IN_MULTISIG: usize = 10;
+
+/// A weighted set of public keys and the threshold that the weights of the signers must reach.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MultiPublicKey {
+    pk_map: Vec<(PublicKey, WeightUnit)>,
+    threshold: ThresholdUnit,
+}
+
+impl MultiPublicKey {
+    pub fn new(
+        pks: Vec<PublicKey>,
+        weights: Vec<WeightUnit>,
+        threshold: ThresholdUnit,
+    ) -> SuiResult<Self> {
+        if pks.len() != weights.len() {
+            return Err(SuiError::InvalidSignature {
+                error: "Each public key of a multisig must have a weight".to_string(),
+            });
+        }
+        let multi_pk = MultiPublicKey {
+            pk_map: pks.into_iter().zip(weights.into_iter()).collect(),
+            threshold,
+        };
+        multi_pk.validate()?;
+        Ok(multi_pk)
+    }
+
+    pub fn pubkeys(&self) -> &[(PublicKey, WeightUnit)] {
+        &self.pk_map
+    }
+
+    pub fn threshold(&self) -> ThresholdUnit {
+        self.threshold
+    }
+
+    /// Check that the threshold can be reached, and that every public key appears once.
+    pub fn validate(&self) -> SuiResult<()> {
+        let error = |error: &str| {
+            Err(SuiError::InvalidSignature {
+                error: error.to_string(),
+            })
+        };
+        if self.pk_map.is_empty() || self.pk_map.len() > MAX_SIGNER_IN_MULTISIG {
+            return error("A multisig must have between 1 and 10 public keys");
+        }
+        if self.pk_map.iter().any(|(_, weight)| *weight == 0) {
+            return error("The weights of a multisig must be positive");
+        }
+        let total_weight: ThresholdUnit = self
+            .pk_map
+            .iter()
+            .map(|(_, weight)| *weight as ThresholdUnit)
+            .sum();
+        if self.threshold == 0 || self.threshold > total_weight {
+            return error("The threshold of a multisig must be positive and reachable");
+        }
+        let mut keys = HashSet::new();
+        if !self
+            .pk_map
+            .iter()
+            .all(|(pk, _)| keys.insert((pk.flag(), pk.as_ref().to_vec())))
+        {
+            return error("A multisig cannot contain the same public key twice");
+        }
+        Ok(())
+    }
+}
+
+/// The signature of a member of a multisig. Unlike `Signature`, it cannot be a multisig, so
+/// that decoding a multisig never recurses into nested multisigs.
+struct MemberSignature(Signature);
+
+impl MemberSignature {
+    fn new(sig: Signature) -> SuiResult<Self> {
+        if sig.scheme().flag() == SignatureScheme::MultiSig.flag() {
+            return Err(SuiError::InvalidSignature {
+                error: "A multisig cannot contain another multisig".to_string(),
+            });
+        }
+        Ok(Self(sig))
+    }
+}
+
+impl Serialize for MemberSignature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MemberSignature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        use serde::de::Error;
+
+        // Check the flag before decoding the signature itself.
+        let bytes: Vec<u8> = Vec::deserialize(deserializer)?;
+        if bytes.first() == Some(&SignatureScheme::MultiSig.flag()) {
+            return Err(Error::custom("A multisig cannot contain another multisig"));
+        }
+        <Signature as signature::Signature>::from_bytes(&bytes)
+            .map(Self)
+            .map_err(|e| Error::custom(e.to_string()))
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct MultiSigParts {
+    sigs: Vec<MemberSignature>,
+    bitmap: BitmapUnit,
+    multi_pk: MultiPublicKey,
+}
+
+/// A multisig, serialized as the multisig flag followed by the BCS bytes of the member
+/// signatures, the bitmap of their indices in the `MultiPublicKey`, and the `MultiPublicKey`.
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Hash)]
+pub struct MultiSig(
+    #[schemars(with = "Base64")]
+    #[serde_as(as = "Readable<Base64, Bytes>")]
+    Vec<u8>,
+);
+
+impl MultiSig {
+    /// Combine the signatures of some members of `multi_pk` into a multisig. The signatures
+    /// can be given in any order, and are matched to the members by their public key.
+    pub fn combine(sigs: Vec<Signature>, multi_pk: MultiPublicKey) -> SuiResult<Self> {
+        multi_pk.validate()?;
+        let mut indexed_sigs = Vec::with_capacity(sigs.len());
+        let mut bitmap: BitmapUnit = 0;
+        for sig in sigs {
+            let sig = MemberSignature::new(sig)?;
+            let index = multi_pk
+                .pk_map
+                .iter()
+                .position(|(pk, _)| {
+                    pk.flag() == sig.0.scheme().flag() && pk.as_ref() == sig.0.public_key_bytes()
+                })
+                .ok_or_else(|| SuiError::IncorrectSigner {
+                    error: "The signature is not from a member of the multisig".to_string(),
+                })?;
+            if bitmap & (1 << index) != 0 {
+                return Err(SuiError::InvalidSignature {
+                    error: "Duplicate signature from a member of the multisig".to_string(),
+                });
+            }
+            bitmap |= 1 << index;
+            indexed_sigs.push((index, sig));
+        }
+        indexed_sigs.sort_by_key(|(index, _)| *index);
+
+        let parts = MultiSigParts {
+            sigs: indexed_sigs.into_iter().map(|(_, sig)| sig).collect(),
+            bitmap,
+            multi_pk,
+        };
+        let mut bytes = vec![SignatureScheme::MultiSig.flag()];
+        bytes.extend(bcs::to_bytes(&parts).expect("Serialization should not fail"));
+        Ok(Self(bytes))
+    }
+
+    pub fn multi_pk(&self) -> SuiResult<MultiPublicKey> {
+        Ok(self.parts()?.multi_pk)
+    }
+
+    fn parts(&self) -> SuiResult<MultiSigParts> {
+        let parts: MultiSigParts =
+            bcs::from_bytes(self.signature_bytes()).map_err(|e| SuiError::InvalidSignature {
+                error: format!("Invalid multisig: {e}"),
+            })?;
+        parts.multi_pk.validate()?;
+        Ok(parts)
+    }
+
+    /// Check the multisig against `author`, verifying each member signature with
+    /// `verify_member`, called with the address of the member.
+    fn verify_with<F>(&self, author: SuiAddress, mut verify_member: F) -> SuiResult<()>
+    where
+        F: FnMut(&Signature, SuiAddress) -> SuiResult<()>,
+    {
+        let MultiSigParts {
+            sigs,
+            bitmap,
+            multi_pk,
+        } = self.parts()?;
+
+        let received_addr = SuiAddress::from(&multi_pk);
+        if received_addr != author {
+            return Err(SuiError::IncorrectSigner {
+                error: format!("Multisig verification failure. Author is {author}, received address is {received_addr}")
+            });
+        }
+
+        let indices: Vec<usize> = (0..multi_pk.pk_map.len())
+            .filter(|index| bitmap & (1 << index) != 0)
+            .collect();
+        if bitmap >> multi_pk.pk_map.len() != 0 || indices.len() != sigs.len() {
+            return Err(SuiError::InvalidSignature {
+                error: "The multisig bitmap does not match its signatures".to_string(),
+            });
+        }
+
+        let mut weight: ThresholdUnit = 0;
+        for (sig, index) in sigs.iter().zip(indices) {
+            let (pk, member_weight) = &multi_pk.pk_map[index];
+            verify_member(&sig.0, SuiAddress::from(pk))?;
+            weight += *member_weight as ThresholdUnit;
+        }
+
+        if weight < multi_pk.threshold {
+            return Err(SuiError::InvalidSignature {
+                error: format!(
+                    "Insufficient weight for the multisig: {weight} < {}",
+                    multi_pk.threshold
+                ),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl AsRef<[u8]> for MultiSig {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl signature::Signature for MultiSig {
+    fn from_bytes(bytes: &[u8]) -> Result<Self, signature::Error> {
+        if bytes.first() != Some(&SignatureScheme::MultiSig.flag()) {
+            return Err(signature::Error::new());
+        }
+        let multisig = Self(bytes.to_vec());
+        multisig.parts().map_err(|_| signature::Error::new())?;
+        Ok(multisig)
+    }
+}
+
+impl SuiSignature for MultiSig {
+    fn signature_bytes(&self) -> &[u8] {
+        self.0.get(1..).unwrap_or_default()
+    }
+
+    /// The public keys are part of the signature bytes.
+    fn public_key_bytes(&self) -> &[u8] {
+        &[]
+    }
+
+    fn scheme(&self) -> SignatureScheme {
+        SignatureScheme::MultiSig
+    }
+
+    fn verify<T>(&self, value: &T, author: SuiAddress) -> SuiResult<()>
+    where
+        T: Signable<Vec<u8>>,
+    {
+        self.verify_with(author, |sig, member| sig.verify(value, member))
+    }
+
+    fn verify_secure<T>(&self, value: &T, intent: Intent, author: SuiAddress) -> SuiResult<()>
+    where
+        T: Serialize,
+    {
+        self.verify_with(author, |sig, member| {
+            sig.verify_secure(value, intent, member)
+        })
+    }
+
+    fn add_to_verification_obligation_or_verify(
+        &self,
+        author: SuiAddress,
+        obligation: &mut VerificationObligation,
+        idx: usize,
+    ) -> SuiResult<()> {
+        self.verify_with(author, |sig, member| {
+            sig.add_to_verification_obligation_or_verify(member, obligation, idx)
+        })
+    }
+}
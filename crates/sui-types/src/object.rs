@@ -12,7 +12,7 @@ use move_core_types::language_storage::StructTag;
 use move_core_types::language_storage::TypeTag;
 use move_core_types::value::{MoveStruct, MoveStructLayout, MoveTypeLayout};
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use serde_with::serde_as;
 use serde_with::Bytes;
 
@@ -215,7 +215,7 @@ impl MoveObject {
     }
 }
 
-#[derive(Eq, PartialEq, Debug, Clone, Deserialize, Serialize, Hash)]
+#[derive(Eq, PartialEq, Debug, Clone, Hash)]
 #[allow(clippy::large_enum_variant)]
 pub enum Data {
     /// An object whose governing logic lives in a published Move module
@@ -225,6 +225,47 @@ pub enum Data {
     // ... Sui "native" types go here
 }
 
+/// The serialized form of `Data`. Packages with a lineage are serialized as a distinct variant
+/// holding the lineage next to the package, so that packages published before upgrades existed
+/// keep their encoding.
+#[derive(Serialize)]
+#[serde(rename = "Data")]
+enum SerializedDataRef<'a> {
+    Move(&'a MoveObject),
+    Package(&'a MovePackage),
+    UpgradedPackage(&'a MovePackage, &'a [ObjectID]),
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Data")]
+#[allow(clippy::large_enum_variant)]
+enum SerializedData {
+    Move(MoveObject),
+    Package(MovePackage),
+    UpgradedPackage(MovePackage, Vec<ObjectID>),
+}
+
+impl Serialize for Data {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Data::Move(m) => SerializedDataRef::Move(m),
+            Data::Package(p) if p.lineage().is_empty() => SerializedDataRef::Package(p),
+            Data::Package(p) => SerializedDataRef::UpgradedPackage(p, p.lineage()),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Data {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match SerializedData::deserialize(deserializer)? {
+            SerializedData::Move(m) => Data::Move(m),
+            SerializedData::Package(p) => Data::Package(p),
+            SerializedData::UpgradedPackage(p, lineage) => Data::Package(p.with_lineage(lineage)),
+        })
+    }
+}
+
 impl Data {
     pub fn try_as_move(&self) -> Option<&MoveObject> {
         use Data::*;
@@ -379,6 +420,20 @@ impl Object {
         }
     }
 
+    // Note: this will panic if `modules` is empty
+    pub fn new_upgraded_package(
+        modules: Vec<CompiledModule>,
+        previous: &MovePackage,
+        previous_transaction: TransactionDigest,
+    ) -> Self {
+        Object {
+            data: Data::Package(MovePackage::from_iter(modules).upgrade_of(previous)),
+            owner: Owner::Immutable,
+            previous_transaction,
+            storage_rebate: 0,
+        }
+    }
+
     pub fn is_immutable(&self) -> bool {
         self.owner.is_immutable()
     }
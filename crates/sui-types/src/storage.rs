@@ -50,6 +50,11 @@ pub trait Storage: ObjectResolver {
     fn log_event(&mut self, event: Event);
 
     fn apply_object_changes(&mut self, changes: BTreeMap<ObjectID, ObjectChange>);
+
+    /// Record the child objects that were loaded from storage during execution, and then
+    /// modified, removed or deleted. They are not inputs of the transaction, but their previous
+    /// versions are needed to charge for storage changes.
+    fn save_loaded_child_objects(&mut self, loaded_child_objects: BTreeMap<ObjectID, Object>);
 }
 
 pub trait BackingPackageStore {
@@ -96,6 +101,31 @@ impl<S: ParentSync> ParentSync for &mut S {
     }
 }
 
+/// Resolves the child objects of an object, i.e. the objects it owns, such as its dynamic fields.
+/// These are loaded on demand during execution rather than passed as inputs to the transaction.
+pub trait ChildObjectResolver {
+    /// Read the latest version of `child`. Fails if `child` exists but is not owned by `parent`.
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>>;
+}
+
+impl<S: ChildObjectResolver> ChildObjectResolver for std::sync::Arc<S> {
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>> {
+        ChildObjectResolver::read_child_object(self.as_ref(), parent, child)
+    }
+}
+
+impl<S: ChildObjectResolver> ChildObjectResolver for &S {
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>> {
+        ChildObjectResolver::read_child_object(*self, parent, child)
+    }
+}
+
+impl<S: ChildObjectResolver> ChildObjectResolver for &mut S {
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>> {
+        ChildObjectResolver::read_child_object(*self, parent, child)
+    }
+}
+
 impl<S: ObjectResolver> ObjectResolver for &S {
     fn read_object(&self, id: &ObjectID) -> Option<&Object> {
         ObjectResolver::read_object(*self, id)
@@ -4,12 +4,11 @@
 use std::collections::BTreeMap;
 
 use fastcrypto::traits::ToFromBytes;
-use move_core_types::{
-    account_address::AccountAddress, ident_str, identifier::IdentStr, language_storage::StructTag,
-};
+use move_core_types::{ident_str, identifier::IdentStr, language_storage::StructTag};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::base_types::AuthorityName;
+use crate::base_types::{AuthorityName, SuiAddress};
 use crate::committee::{Committee, CommitteeWithNetAddresses, StakeUnit};
 use crate::crypto::AuthorityPublicKeyBytes;
 use crate::{
@@ -23,7 +22,7 @@ pub const SUI_SYSTEM_MODULE_NAME: &IdentStr = ident_str!("sui_system");
 pub const ADVANCE_EPOCH_FUNCTION_NAME: &IdentStr = ident_str!("advance_epoch");
 
 /// Rust version of the Move sui::sui_system::SystemParameters type
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct SystemParameters {
     pub min_validator_stake: u64,
     pub max_validator_candidate_count: u64,
@@ -32,14 +31,14 @@ pub struct SystemParameters {
 
 /// Rust version of the Move std::option::Option type.
 /// Putting it in this file because it's only used here.
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct MoveOption<T> {
     pub vec: Vec<T>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct ValidatorMetadata {
-    pub sui_address: AccountAddress,
+    pub sui_address: SuiAddress,
     pub pubkey_bytes: Vec<u8>,
     pub network_pubkey_bytes: Vec<u8>,
     pub proof_of_possession_bytes: Vec<u8>,
@@ -62,7 +61,7 @@ impl ValidatorMetadata {
 }
 
 /// Rust version of the Move sui::validator::Validator type
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct Validator {
     pub metadata: ValidatorMetadata,
     pub stake_amount: u64,
@@ -87,16 +86,16 @@ impl Validator {
 }
 
 /// Rust version of the Move sui::staking_pool::PendingDelegationEntry type.
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct PendingDelegationEntry {
-    pub delegator: AccountAddress,
+    pub delegator: SuiAddress,
     pub sui_amount: u64,
 }
 
 /// Rust version of the Move sui::staking_pool::StakingPool type
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct StakingPool {
-    pub validator_address: AccountAddress,
+    pub validator_address: SuiAddress,
     pub starting_epoch: u64,
     pub epoch_starting_sui_balance: u64,
     pub epoch_starting_delegation_token_supply: u64,
@@ -107,7 +106,7 @@ pub struct StakingPool {
 }
 
 /// Rust version of the Move sui::validator_set::ValidatorSet type
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct ValidatorSet {
     pub validator_stake: u64,
     pub delegation_stake: u64,
@@ -119,7 +118,7 @@ pub struct ValidatorSet {
 }
 
 /// Rust version of the Move sui::sui_system::SuiSystemState type
-#[derive(Debug, Serialize, Deserialize, Clone, Eq, PartialEq)]
+#[derive(Debug, Serialize, Deserialize, Clone, JsonSchema, Eq, PartialEq)]
 pub struct SuiSystemState {
     pub info: UID,
     pub epoch: u64,
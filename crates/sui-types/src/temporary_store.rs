@@ -13,8 +13,8 @@ use crate::{
     object::Owner,
     object::{Data, Object},
     storage::{
-        BackingPackageStore, DeleteKind, ObjectChange, ObjectResolver, ParentSync, Storage,
-        WriteKind,
+        BackingPackageStore, ChildObjectResolver, DeleteKind, ObjectChange, ObjectResolver,
+        ParentSync, Storage, WriteKind,
     },
 };
 use move_core_types::account_address::AccountAddress;
@@ -76,6 +76,9 @@ pub struct TemporaryStore<S> {
     tx_digest: TransactionDigest,
     input_objects: BTreeMap<ObjectID, Object>,
     mutable_input_refs: Vec<ObjectRef>, // Inputs that are mutable
+    /// Child objects loaded from the store during execution that were then changed. They are
+    /// not inputs, but their previous versions are needed to charge for storage.
+    loaded_child_objects: BTreeMap<ObjectID, Object>,
     // When an object is being written, we need to ensure that a few invariants hold.
     // It's critical that we always call write_object to update `_written`, instead of writing
     // into _written directly.
@@ -97,6 +100,7 @@ impl<S> TemporaryStore<S> {
             tx_digest,
             input_objects: objects,
             mutable_input_refs: mutable_inputs,
+            loaded_child_objects: BTreeMap::new(),
             _written: BTreeMap::new(),
             deleted: BTreeMap::new(),
             events: Vec::new(),
@@ -118,6 +122,15 @@ impl<S> TemporaryStore<S> {
         {
             self.check_invariants();
         }
+        // Changed child objects are reported along with the inputs, so that their previous
+        // versions are cleaned up like those of the inputs, including their locks.
+        let mut mutable_inputs = self.mutable_input_refs;
+        mutable_inputs.extend(
+            self.loaded_child_objects
+                .iter()
+                .filter(|(id, _)| self._written.contains_key(id) || self.deleted.contains_key(id))
+                .map(|(_, object)| object.compute_object_reference()),
+        );
         let written = self
             ._written
             .into_iter()
@@ -128,10 +141,12 @@ impl<S> TemporaryStore<S> {
             .into_iter()
             .map(|(id, (seq, kind))| (id, (seq, kind)))
             .collect();
+        let mut objects = self.input_objects;
+        objects.extend(self.loaded_child_objects);
         (
             InnerTemporaryStore {
-                objects: self.input_objects,
-                mutable_inputs: self.mutable_input_refs,
+                objects,
+                mutable_inputs,
                 written,
                 deleted,
             },
@@ -187,6 +202,7 @@ impl<S> TemporaryStore<S> {
             let (old_object_size, storage_rebate) = self
                 .input_objects
                 .get(object_id)
+                .or_else(|| self.loaded_child_objects.get(object_id))
                 .map(|old| (old.object_size_for_gas_metering(), old.storage_rebate))
                 .unwrap_or((0, 0));
             let new_storage_rebate = gas_status.charge_storage_mutation(
@@ -207,7 +223,11 @@ impl<S> TemporaryStore<S> {
             // Otherwise if an object is in `self.deleted` but not in `self.objects`, it means this
             // object was unwrapped and then deleted. The rebate would have been provided already when
             // mutating the object that wrapped this object.
-            if let Some(old_object) = self.input_objects.get(object_id) {
+            if let Some(old_object) = self
+                .input_objects
+                .get(object_id)
+                .or_else(|| self.loaded_child_objects.get(object_id))
+            {
                 gas_status.charge_storage_mutation(
                     old_object.object_size_for_gas_metering(),
                     0,
@@ -335,7 +355,7 @@ impl<S> TemporaryStore<S> {
     // is that an entry is not both added and deleted by the
     // caller.
 
-    pub fn write_object(&mut self, mut object: Object, kind: WriteKind) {
+    pub fn write_object(&mut self, mut object: Object, mut kind: WriteKind) {
         // there should be no write after delete
         debug_assert!(self.deleted.get(&object.id()).is_none());
         // Check it is not read-only
@@ -351,10 +371,21 @@ impl<S> TemporaryStore<S> {
         // The adapter is not very disciplined at filling in the correct
         // previous transaction digest, so we ensure it is correct here.
         object.previous_transaction = self.tx_digest;
+        // An object created by an earlier step of a batch transaction is still created by the
+        // transaction when a later step mutates it.
+        if let Some((_, WriteKind::Create)) = self._written.get(&object.id()) {
+            kind = WriteKind::Create;
+        }
         self._written.insert(object.id(), (object, kind));
     }
 
     pub fn delete_object(&mut self, id: &ObjectID, version: SequenceNumber, kind: DeleteKind) {
+        // An object created by an earlier step of a batch transaction never existed outside of
+        // the transaction, so deleting (or wrapping) it only drops its creation.
+        if let Some((_, WriteKind::Create)) = self._written.get(id) {
+            self._written.remove(id);
+            return;
+        }
         // there should be no deletion after write
         debug_assert!(self._written.get(id).is_none());
         // Check it is not read-only
@@ -390,6 +421,7 @@ impl<S> Storage for TemporaryStore<S> {
         self._written.clear();
         self.deleted.clear();
         self.events.clear();
+        self.loaded_child_objects.clear();
     }
 
     fn log_event(&mut self, event: Event) {
@@ -404,6 +436,34 @@ impl<S> Storage for TemporaryStore<S> {
             }
         }
     }
+
+    fn save_loaded_child_objects(&mut self, loaded_child_objects: BTreeMap<ObjectID, Object>) {
+        for (id, object) in loaded_child_objects {
+            // Keep the version from before the transaction if the child was already loaded by a
+            // previous command of the same transaction.
+            self.loaded_child_objects.entry(id).or_insert(object);
+        }
+    }
+}
+
+impl<S: ChildObjectResolver> ChildObjectResolver for TemporaryStore<S> {
+    fn read_child_object(&self, parent: &ObjectID, child: &ObjectID) -> SuiResult<Option<Object>> {
+        if self.deleted.contains_key(child) {
+            return Ok(None);
+        }
+        let child_object = match self._written.get(child) {
+            Some((object, _kind)) => object,
+            None => return self.store.read_child_object(parent, child),
+        };
+        if child_object.owner != Owner::ObjectOwner((*parent).into()) {
+            return Err(SuiError::InvalidChildObjectAccess {
+                object: *child,
+                given_parent: *parent,
+                actual_owner: child_object.owner,
+            });
+        }
+        Ok(Some(child_object.clone()))
+    }
 }
 
 impl<S: BackingPackageStore> ModuleResolver for TemporaryStore<S> {
@@ -32,6 +32,7 @@ fn test_move_event_filter() {
         timestamp: 0,
         tx_digest: Some(TransactionDigest::random()),
         seq_num: 0,
+        event_num: 0,
         event: move_event,
         move_struct_json_value: Some(json!(BTreeMap::from([("balance", 10000)]))),
     };
@@ -84,16 +85,23 @@ fn test_transfer_filter() {
         type_: TransferType::Coin,
         amount: None,
     };
+    let tx_digest = TransactionDigest::random();
     let envelope = EventEnvelope {
-        timestamp: 0,
-        tx_digest: Some(TransactionDigest::random()),
+        timestamp: 1_000,
+        tx_digest: Some(tx_digest),
         seq_num: 1,
+        event_num: 0,
         event: move_event,
         move_struct_json_value: None,
     };
 
     let filters = vec![
         EventFilter::EventType(EventType::TransferObject),
+        EventFilter::Transaction(tx_digest),
+        EventFilter::TimeRange {
+            start_time: 1_000,
+            end_time: 2_000,
+        },
         EventFilter::Package(ObjectID::from(SUI_FRAMEWORK_ADDRESS)),
         EventFilter::Module(Identifier::from(ident_str!("test_module"))),
         EventFilter::ObjectId(object_id),
@@ -110,6 +118,23 @@ fn test_transfer_filter() {
             filter
         )
     }
+
+    // The time range excludes its end
+    let false_filters = vec![
+        EventFilter::Transaction(TransactionDigest::random()),
+        EventFilter::TimeRange {
+            start_time: 0,
+            end_time: 1_000,
+        },
+    ];
+    for filter in &false_filters {
+        assert!(
+            !filter.matches(&envelope),
+            "event = {:?}, filter = {:?}",
+            envelope,
+            filter
+        )
+    }
 }
 
 #[test]
@@ -122,6 +147,7 @@ fn test_publish_filter() {
         timestamp: 0,
         tx_digest: Some(TransactionDigest::random()),
         seq_num: 0,
+        event_num: 0,
         event: move_event,
         move_struct_json_value: None,
     };
@@ -159,6 +185,7 @@ fn test_delete_object_filter() {
         timestamp: 0,
         tx_digest: Some(TransactionDigest::random()),
         seq_num: 0,
+        event_num: 0,
         event: move_event,
         move_struct_json_value: None,
     };
@@ -200,6 +227,7 @@ fn test_new_object_filter() {
         timestamp: 0,
         tx_digest: Some(TransactionDigest::random()),
         seq_num: 0,
+        event_num: 0,
         event: move_event,
         move_struct_json_value: None,
     };
@@ -232,6 +260,7 @@ fn test_epoch_change_filter() {
         timestamp: 0,
         tx_digest: Some(TransactionDigest::random()),
         seq_num: 1,
+        event_num: 0,
         event: move_event,
         move_struct_json_value: None,
     };
@@ -247,6 +276,7 @@ fn test_checkpoint_filter() {
         timestamp: 0,
         tx_digest: Some(TransactionDigest::random()),
         seq_num: 1,
+        event_num: 0,
         event: move_event,
         move_struct_json_value: None,
     };
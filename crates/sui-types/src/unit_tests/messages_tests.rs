@@ -16,6 +16,7 @@ use crate::crypto::{get_key_pair, AccountKeyPair, AuthorityKeyPair, AuthorityPub
 use crate::messages_checkpoint::CheckpointContents;
 use crate::messages_checkpoint::CheckpointSummary;
 use crate::object::Owner;
+use crate::waypoint::Accumulator;
 
 use super::*;
 fn random_object_ref() -> ObjectRef {
@@ -595,6 +596,7 @@ fn test_user_signature_committed_in_checkpoints() {
         ),
         None,
         None,
+        Accumulator::default(),
     );
     let checkpoint_summary_b = CheckpointSummary::new(
         0,
@@ -604,6 +606,7 @@ fn test_user_signature_committed_in_checkpoints() {
         ),
         None,
         None,
+        Accumulator::default(),
     );
 
     assert_ne!(checkpoint_summary_a.digest(), checkpoint_summary_b.digest());
@@ -0,0 +1,219 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+use super::*;
+
+use fastcrypto::ed25519::Ed25519KeyPair;
+use fastcrypto::secp256k1::Secp256k1KeyPair;
+use fastcrypto::traits::KeyPair;
+
+use crate::crypto::bcs_signable_test::{get_obligation_input, Foo};
+use crate::crypto::{get_key_pair, SuiKeyPair};
+
+fn keys() -> Vec<SuiKeyPair> {
+    let (_, kp1): (_, Ed25519KeyPair) = get_key_pair();
+    let (_, kp2): (_, Secp256k1KeyPair) = get_key_pair();
+    let (_, kp3): (_, Ed25519KeyPair) = get_key_pair();
+    vec![kp1.into(), kp2.into(), kp3.into()]
+}
+
+fn two_of_three(keys: &[SuiKeyPair]) -> MultiPublicKey {
+    MultiPublicKey::new(
+        keys.iter().map(|kp| kp.public()).collect(),
+        vec![1, 1, 1],
+        2,
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_multisig_verify() {
+    let keys = keys();
+    let multi_pk = two_of_three(&keys);
+    let address = SuiAddress::from(&multi_pk);
+    let message = Foo("some data".to_string());
+    let sigs: Vec<Signature> = keys.iter().map(|kp| Signature::new(&message, kp)).collect();
+
+    // Any two members can sign, in any order.
+    for pair in [[0, 1], [2, 0], [1, 2]] {
+        let multisig = MultiSig::combine(
+            pair.iter().map(|i| sigs[*i].clone()).collect(),
+            multi_pk.clone(),
+        )
+        .unwrap();
+        let signature = Signature::from(multisig);
+        assert_eq!(signature.scheme().flag(), SignatureScheme::MultiSig.flag());
+        assert!(signature.verify(&message, address).is_ok());
+
+        let (mut obligation, idx) = get_obligation_input(&message);
+        assert!(signature
+            .add_to_verification_obligation_or_verify(address, &mut obligation, idx)
+            .is_ok());
+        assert!(obligation.verify_all().is_ok());
+    }
+
+    // One signature does not reach the threshold.
+    let multisig = MultiSig::combine(vec![sigs[0].clone()], multi_pk.clone()).unwrap();
+    assert!(multisig.verify(&message, address).is_err());
+
+    // The signatures must be valid for the message.
+    let multisig = MultiSig::combine(
+        vec![
+            sigs[0].clone(),
+            Signature::new(&Foo("other data".to_string()), &keys[1]),
+        ],
+        multi_pk.clone(),
+    )
+    .unwrap();
+    assert!(multisig.verify(&message, address).is_err());
+
+    // A multisig is only valid for the address of its public keys.
+    let multisig = MultiSig::combine(sigs[..2].to_vec(), multi_pk.clone()).unwrap();
+    assert!(multisig
+        .verify(&message, (&keys[0].public()).into())
+        .is_err());
+    let other_multi_pk = MultiPublicKey::new(
+        keys.iter().map(|kp| kp.public()).collect(),
+        vec![1, 1, 1],
+        1,
+    )
+    .unwrap();
+    assert_ne!(SuiAddress::from(&other_multi_pk), address);
+    assert!(multisig
+        .verify(&message, SuiAddress::from(&other_multi_pk))
+        .is_err());
+}
+
+#[test]
+fn test_multisig_weights() {
+    let keys = keys();
+    let multi_pk = MultiPublicKey::new(
+        keys.iter().map(|kp| kp.public()).collect(),
+        vec![3, 1, 1],
+        3,
+    )
+    .unwrap();
+    let address = SuiAddress::from(&multi_pk);
+    let message = Foo("some data".to_string());
+    let sigs: Vec<Signature> = keys.iter().map(|kp| Signature::new(&message, kp)).collect();
+
+    let multisig = MultiSig::combine(vec![sigs[0].clone()], multi_pk.clone()).unwrap();
+    assert!(multisig.verify(&message, address).is_ok());
+    let multisig = MultiSig::combine(sigs[1..].to_vec(), multi_pk).unwrap();
+    assert!(multisig.verify(&message, address).is_err());
+}
+
+#[test]
+fn test_multisig_combine() {
+    let keys = keys();
+    let multi_pk = two_of_three(&keys);
+    let message = Foo("some data".to_string());
+    let sig = Signature::new(&message, &keys[0]);
+
+    // Duplicate signatures are rejected.
+    assert!(MultiSig::combine(vec![sig.clone(), sig.clone()], multi_pk.clone()).is_err());
+
+    // So are signatures of non members.
+    let (_, outsider): (_, Ed25519KeyPair) = get_key_pair();
+    assert!(MultiSig::combine(
+        vec![sig, Signature::new(&message, &SuiKeyPair::from(outsider))],
+        multi_pk
+    )
+    .is_err());
+}
+
+#[test]
+fn test_multisig_serde() {
+    let keys = keys();
+    let multi_pk = two_of_three(&keys);
+    let message = Foo("some data".to_string());
+    let multisig = MultiSig::combine(
+        keys[..2]
+            .iter()
+            .map(|kp| Signature::new(&message, kp))
+            .collect(),
+        multi_pk.clone(),
+    )
+    .unwrap();
+    let sig = Signature::from(multisig.clone());
+
+    let bytes = bcs::to_bytes(&sig).unwrap();
+    let decoded: Signature = bcs::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, sig);
+    let json = serde_json::to_string(&sig).unwrap();
+    let decoded: Signature = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded, sig);
+    assert_eq!(multisig.multi_pk().unwrap(), multi_pk);
+
+    // The flag, signature and public key bytes, as sent to `executeTransaction`.
+    let flag = [sig.scheme().flag()];
+    let concatenated = [&flag[..], sig.signature_bytes(), sig.public_key_bytes()].concat();
+    assert_eq!(
+        <Signature as signature::Signature>::from_bytes(&concatenated).unwrap(),
+        sig
+    );
+
+    // Truncated bytes are rejected.
+    assert!(<Signature as signature::Signature>::from_bytes(&concatenated[..10]).is_err());
+}
+
+#[test]
+fn test_nested_multisig_rejected() {
+    let keys = keys();
+    let multi_pk = two_of_three(&keys);
+    let message = Foo("some data".to_string());
+    let inner = Signature::from(
+        MultiSig::combine(
+            keys[..2]
+                .iter()
+                .map(|kp| Signature::new(&message, kp))
+                .collect(),
+            multi_pk.clone(),
+        )
+        .unwrap(),
+    );
+
+    // A multisig cannot be combined into another multisig.
+    assert!(MultiSig::combine(vec![inner.clone()], multi_pk.clone()).is_err());
+
+    // Nor decoded from bytes that nest one.
+    let parts = MultiSigParts {
+        sigs: vec![MemberSignature(inner)],
+        bitmap: 1,
+        multi_pk,
+    };
+    let mut bytes = vec![SignatureScheme::MultiSig.flag()];
+    bytes.extend(bcs::to_bytes(&parts).unwrap());
+    assert!(<Signature as signature::Signature>::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn test_multi_public_key_validation() {
+    let keys = keys();
+    let pks: Vec<_> = keys.iter().map(|kp| kp.public()).collect();
+
+    // The threshold must be positive and reachable.
+    assert!(MultiPublicKey::new(pks.clone(), vec![1, 1, 1], 0).is_err());
+    assert!(MultiPublicKey::new(pks.clone(), vec![1, 1, 1], 4).is_err());
+    assert!(MultiPublicKey::new(pks.clone(), vec![1, 1, 1], 3).is_ok());
+    // Every key has a positive weight.
+    assert!(MultiPublicKey::new(pks.clone(), vec![1, 1], 1).is_err());
+    assert!(MultiPublicKey::new(pks.clone(), vec![1, 0, 1], 1).is_err());
+    // Keys appear once.
+    assert!(MultiPublicKey::new(vec![pks[0].clone(), pks[0].clone()], vec![1, 1], 1).is_err());
+    // At most MAX_SIGNER_IN_MULTISIG keys.
+    assert!(MultiPublicKey::new(vec![], vec![], 1).is_err());
+    let many_pks: Vec<PublicKey> = (0..MAX_SIGNER_IN_MULTISIG + 1)
+        .map(|_| {
+            let (_, kp): (_, Ed25519KeyPair) = get_key_pair();
+            kp.public().clone().into()
+        })
+        .collect();
+    assert!(MultiPublicKey::new(many_pks.clone(), vec![1; MAX_SIGNER_IN_MULTISIG + 1], 1).is_err());
+    assert!(MultiPublicKey::new(
+        many_pks[..MAX_SIGNER_IN_MULTISIG].to_vec(),
+        vec![1; MAX_SIGNER_IN_MULTISIG],
+        1
+    )
+    .is_ok());
+}
@@ -127,3 +127,26 @@ fn test_checkpoint() {
 
     assert!(ck.catch_up_items(diff3).is_ok());
 }
+
+#[test]
+fn test_remove_and_union() {
+    let v1 = make_item();
+    let v2 = make_item();
+    let v3 = make_item();
+
+    let mut state = Waypoint::default();
+    state.insert_all([v1.clone(), v2.clone()].iter());
+
+    // A delta replacing v2 by v3.
+    let mut delta = Waypoint::default();
+    delta.remove(&v2);
+    delta.insert(&v3);
+    state.union(&delta);
+
+    let mut expected = Waypoint::default();
+    expected.insert_all([v3.clone(), v1.clone()].iter());
+    assert_eq!(state, expected);
+
+    state.remove_all([v1, v3].iter());
+    assert_eq!(state, Waypoint::default());
+}
@@ -82,6 +82,33 @@ impl Accumulator {
             self.insert(i);
         }
     }
+
+    /// Remove one item from the accumulator. The item is assumed to have been
+    /// inserted before, otherwise the accumulator no longer represents a set.
+    pub fn remove<I>(&mut self, item: &I)
+    where
+        I: IntoPoint,
+    {
+        let point: RistrettoPoint = item.into_point();
+        self.accumulator -= point;
+    }
+
+    // Remove all items from an iterator from the accumulator
+    pub fn remove_all<'a, I, It>(&'a mut self, items: It)
+    where
+        It: 'a + IntoIterator<Item = &'a I>,
+        I: 'a + IntoPoint,
+    {
+        for i in items {
+            self.remove(i);
+        }
+    }
+
+    /// Add all items accumulated in `other` (which may also contain removals)
+    /// to this accumulator.
+    pub fn union(&mut self, other: &Accumulator) {
+        self.accumulator += other.accumulator;
+    }
 }
 
 impl Debug for Accumulator {
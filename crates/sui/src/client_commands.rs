@@ -21,6 +21,7 @@ use serde_json::json;
 use tracing::info;
 
 use crate::config::{Config, PersistedConfig, SuiClientConfig};
+use crate::keytool::read_keystore_password;
 use sui_framework::build_move_package_to_bytes;
 use sui_json::SuiJsonValue;
 use sui_json_rpc_types::{
@@ -28,13 +29,14 @@ use sui_json_rpc_types::{
 };
 use sui_json_rpc_types::{GetRawObjectDataResponse, SuiData};
 use sui_json_rpc_types::{SuiCertifiedTransaction, SuiExecutionStatus, SuiTransactionEffects};
-use sui_sdk::crypto::AccountKeystore;
+use sui_sdk::crypto::{AccountKeystore, Keystore};
 use sui_sdk::TransactionExecutionResult;
 use sui_sdk::{ClientType, SuiClient};
 use sui_types::{
     base_types::{ObjectID, SuiAddress},
+    committee::EpochId,
     gas_coin::GasCoin,
-    messages::Transaction,
+    messages::{Transaction, TransactionExpiration},
     object::Owner,
     parse_sui_type_tag, SUI_FRAMEWORK_ADDRESS,
 };
@@ -108,6 +110,11 @@ pub enum SuiClientCommands {
         /// Gas budget for running module initializers
         #[clap(long)]
         gas_budget: u64,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
 
     /// Call Move function
@@ -142,6 +149,11 @@ pub enum SuiClientCommands {
         /// Gas budget for this call
         #[clap(long)]
         gas_budget: u64,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
 
     /// Transfer object
@@ -163,6 +175,11 @@ pub enum SuiClientCommands {
         /// Gas budget for this transfer
         #[clap(long)]
         gas_budget: u64,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
     /// Transfer SUI, and pay gas with the same SUI coin object.
     /// If amount is specified, only the amount is transferred; otherwise the entire object
@@ -184,6 +201,11 @@ pub enum SuiClientCommands {
         /// The amount to transfer, if not specified, the entire coin object will be transferred.
         #[clap(long)]
         amount: Option<u64>,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
     /// Pay SUI to recipients following specified amounts, with input coins.
     /// Length of recipients must be the same as that of amounts.
@@ -209,6 +231,11 @@ pub enum SuiClientCommands {
         /// Gas budget for this transfer
         #[clap(long)]
         gas_budget: u64,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
     /// Synchronize client state with authorities.
     #[clap(name = "sync")]
@@ -264,6 +291,11 @@ pub enum SuiClientCommands {
         /// Gas budget for this call
         #[clap(long)]
         gas_budget: u64,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
 
     /// Merge two coin objects into one coin
@@ -281,6 +313,11 @@ pub enum SuiClientCommands {
         /// Gas budget for this call
         #[clap(long)]
         gas_budget: u64,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
 
     /// Create an example NFT
@@ -326,6 +363,11 @@ pub enum SuiClientCommands {
         /// The amount to transfer, if not specified, the entire coin object will be transferred.
         #[clap(long)]
         amount: Option<u64>,
+
+        /// The last epoch in which the transaction can be executed. If not specified, the
+        /// transaction never expires.
+        #[clap(long)]
+        expiration_epoch: Option<EpochId>,
     },
 
     /// Execute a Signed Transaction. This is useful when the user prefers to sign elsewhere and use this command to execute.
@@ -359,6 +401,7 @@ impl SuiClientCommands {
                 gas,
                 build_config,
                 gas_budget,
+                expiration_epoch,
             } => {
                 let sender = context.try_get_object_owner(&gas).await?;
                 let sender = sender.unwrap_or(context.active_address()?);
@@ -367,7 +410,13 @@ impl SuiClientCommands {
                 let data = context
                     .client
                     .transaction_builder()
-                    .publish(sender, compiled_modules, gas, gas_budget)
+                    .publish(
+                        sender,
+                        compiled_modules,
+                        gas,
+                        gas_budget,
+                        expiration_epoch.into(),
+                    )
                     .await?;
                 let signature = context.config.keystore.sign(&sender, &data.to_bytes())?;
                 let response = context
@@ -390,9 +439,18 @@ impl SuiClientCommands {
                 gas,
                 gas_budget,
                 args,
+                expiration_epoch,
             } => {
                 let (cert, effects) = call_move(
-                    package, &module, &function, type_args, gas, gas_budget, args, context,
+                    package,
+                    &module,
+                    &function,
+                    type_args,
+                    gas,
+                    gas_budget,
+                    args,
+                    expiration_epoch.into(),
+                    context,
                 )
                 .await?;
                 SuiClientCommandResult::Call(cert, effects)
@@ -403,6 +461,7 @@ impl SuiClientCommands {
                 object_id,
                 gas,
                 gas_budget,
+                expiration_epoch,
             } => {
                 let from = context.get_object_owner(&object_id).await?;
                 let time_start = Instant::now();
@@ -410,7 +469,14 @@ impl SuiClientCommands {
                 let data = context
                     .client
                     .transaction_builder()
-                    .transfer_object(from, object_id, gas, gas_budget, to)
+                    .transfer_object(
+                        from,
+                        object_id,
+                        gas,
+                        gas_budget,
+                        to,
+                        expiration_epoch.into(),
+                    )
                     .await?;
                 let signature = context.config.keystore.sign(&from, &data.to_bytes())?;
                 let response = context
@@ -431,13 +497,21 @@ impl SuiClientCommands {
                 sui_coin_object_id: object_id,
                 gas_budget,
                 amount,
+                expiration_epoch,
             } => {
                 let from = context.get_object_owner(&object_id).await?;
 
                 let data = context
                     .client
                     .transaction_builder()
-                    .transfer_sui(from, object_id, gas_budget, to, amount)
+                    .transfer_sui(
+                        from,
+                        object_id,
+                        gas_budget,
+                        to,
+                        amount,
+                        expiration_epoch.into(),
+                    )
                     .await?;
                 let signature = context.config.keystore.sign(&from, &data.to_bytes())?;
                 let response = context
@@ -458,6 +532,7 @@ impl SuiClientCommands {
                 amounts,
                 gas,
                 gas_budget,
+                expiration_epoch,
             } => {
                 ensure!(
                     !input_coins.is_empty(),
@@ -479,7 +554,15 @@ impl SuiClientCommands {
                 let data = context
                     .client
                     .transaction_builder()
-                    .pay(from, input_coins, recipients, amounts, gas, gas_budget)
+                    .pay(
+                        from,
+                        input_coins,
+                        recipients,
+                        amounts,
+                        gas,
+                        gas_budget,
+                        expiration_epoch.into(),
+                    )
                     .await?;
                 let signature = context.config.keystore.sign(&from, &data.to_bytes())?;
                 let response = context
@@ -554,6 +637,7 @@ impl SuiClientCommands {
                 count,
                 gas,
                 gas_budget,
+                expiration_epoch,
             } => {
                 let signer = context.get_object_owner(&coin_id).await?;
                 let data = match (amounts, count) {
@@ -561,7 +645,14 @@ impl SuiClientCommands {
                         context
                             .client
                             .transaction_builder()
-                            .split_coin(signer, coin_id, amounts, gas, gas_budget)
+                            .split_coin(
+                                signer,
+                                coin_id,
+                                amounts,
+                                gas,
+                                gas_budget,
+                                expiration_epoch.into(),
+                            )
                             .await?
                     }
                     (None, Some(count)) => {
@@ -571,7 +662,14 @@ impl SuiClientCommands {
                         context
                             .client
                             .transaction_builder()
-                            .split_coin_equal(signer, coin_id, count, gas, gas_budget)
+                            .split_coin_equal(
+                                signer,
+                                coin_id,
+                                count,
+                                gas,
+                                gas_budget,
+                                expiration_epoch.into(),
+                            )
                             .await?
                     }
                     _ => {
@@ -589,12 +687,20 @@ impl SuiClientCommands {
                 coin_to_merge,
                 gas,
                 gas_budget,
+                expiration_epoch,
             } => {
                 let signer = context.get_object_owner(&primary_coin).await?;
                 let data = context
                     .client
                     .transaction_builder()
-                    .merge_coins(signer, primary_coin, coin_to_merge, gas, gas_budget)
+                    .merge_coins(
+                        signer,
+                        primary_coin,
+                        coin_to_merge,
+                        gas,
+                        gas_budget,
+                        expiration_epoch.into(),
+                    )
                     .await?;
                 let signature = context.config.keystore.sign(&signer, &data.to_bytes())?;
                 let response = context
@@ -648,6 +754,7 @@ impl SuiClientCommands {
                     gas,
                     gas_budget.unwrap_or(100_000),
                     args,
+                    TransactionExpiration::None,
                     context,
                 )
                 .await?;
@@ -666,13 +773,21 @@ impl SuiClientCommands {
                 sui_coin_object_id: object_id,
                 gas_budget,
                 amount,
+                expiration_epoch,
             } => {
                 let from = context.get_object_owner(&object_id).await?;
 
                 let data = context
                     .client
                     .transaction_builder()
-                    .transfer_sui(from, object_id, gas_budget, to, amount)
+                    .transfer_sui(
+                        from,
+                        object_id,
+                        gas_budget,
+                        to,
+                        amount,
+                        expiration_epoch.into(),
+                    )
                     .await?;
                 SuiClientCommandResult::SerializeTransferSui(data.to_base64())
             }
@@ -736,12 +851,15 @@ pub struct WalletContext {
 
 impl WalletContext {
     pub async fn new(config_path: &Path) -> Result<Self, anyhow::Error> {
-        let config: SuiClientConfig = PersistedConfig::read(config_path).map_err(|err| {
+        let mut config: SuiClientConfig = PersistedConfig::read(config_path).map_err(|err| {
             err.context(format!(
                 "Cannot open wallet config file at {:?}",
                 config_path
             ))
         })?;
+        if let Keystore::Encrypted(keystore) = &mut config.keystore {
+            keystore.unlock(&read_keystore_password()?)?;
+        }
 
         let client = config.client_type.init().await?;
         let config = config.persisted(config_path);
@@ -1033,6 +1151,7 @@ pub async fn call_move(
     gas: Option<ObjectID>,
     gas_budget: u64,
     args: Vec<SuiJsonValue>,
+    expiration: TransactionExpiration,
     context: &mut WalletContext,
 ) -> Result<(SuiCertifiedTransaction, SuiTransactionEffects), anyhow::Error> {
     let gas_owner = context.try_get_object_owner(&gas).await?;
@@ -1053,6 +1172,7 @@ pub async fn call_move(
             args,
             gas,
             gas_budget,
+            expiration,
         )
         .await?;
     let signature = context.config.keystore.sign(&sender, &data.to_bytes())?;
@@ -4,7 +4,7 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use anyhow::anyhow;
+use anyhow::{anyhow, ensure};
 use bip32::{DerivationPath, Mnemonic};
 use clap::*;
 use fastcrypto::traits::{ToFromBytes, VerifyingKey};
@@ -12,19 +12,24 @@ use signature::rand_core::OsRng;
 use tracing::info;
 
 use fastcrypto::ed25519::{Ed25519KeyPair, Ed25519PrivateKey, Ed25519PublicKey};
-use sui_sdk::crypto::{AccountKeystore, Keystore};
+use sui_sdk::crypto::{AccountKeystore, EncryptedFileKeystore, FileBasedKeystore, Keystore};
 use sui_types::base_types::SuiAddress;
 use sui_types::base_types::{decode_bytes_hex, encode_bytes_hex};
 use sui_types::crypto::{
     derive_key_pair_from_path, get_key_pair, AuthorityKeyPair, Ed25519SuiSignature,
-    EncodeDecodeBase64, NetworkKeyPair, SignatureScheme, SuiKeyPair, SuiSignatureInner,
+    EncodeDecodeBase64, NetworkKeyPair, PublicKey, Signature, SignatureScheme, SuiKeyPair,
+    SuiSignatureInner,
 };
+use sui_types::multisig::{MultiPublicKey, MultiSig, ThresholdUnit, WeightUnit};
 use sui_types::sui_serde::{Base64, Encoding};
 
 #[cfg(test)]
 #[path = "unit_tests/keytool_tests.rs"]
 mod keytool_tests;
 
+/// Environment variable holding the password of an encrypted keystore, for non-interactive use.
+pub const SUI_KEYSTORE_PASSWORD_ENV: &str = "SUI_KEYSTORE_PASSWORD";
+
 #[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 #[clap(rename_all = "kebab-case")]
@@ -64,6 +69,49 @@ pub enum KeyToolCommand {
     LoadKeypair {
         file: PathBuf,
     },
+    /// Encrypt all keys of a plaintext keystore with a password into a new encrypted keystore
+    /// file. Set `keystore` to `Encrypted: <output>` in client.yaml to use it.
+    /// The password is read from the SUI_KEYSTORE_PASSWORD environment variable if set,
+    /// otherwise it is prompted for.
+    MigrateToEncrypted {
+        /// Path of the encrypted keystore to create.
+        #[clap(long)]
+        output: PathBuf,
+        /// Delete the plaintext keystore once its keys are encrypted.
+        #[clap(long)]
+        remove_plaintext: bool,
+    },
+    /// Change the password of an encrypted keystore.
+    ChangePassword,
+    /// Derive the address of a k-of-n multisig from the Base64 encoded public keys of its
+    /// members (with their key scheme flag), their weights, and the threshold that the sum of
+    /// the weights of the signers must reach.
+    MultiSigAddress {
+        #[clap(long)]
+        threshold: ThresholdUnit,
+        #[clap(long, multiple_occurrences = false, multiple_values = true)]
+        pks: Vec<PublicKey>,
+        #[clap(long, multiple_occurrences = false, multiple_values = true)]
+        weights: Vec<WeightUnit>,
+    },
+    /// Combine the signatures of some members of a multisig into a multisig signature. The
+    /// signatures are Base64 encoded `flag || signature || public key`, as output by `sign`,
+    /// and the multisig is defined as in `multi-sig-address`.
+    MultiSigCombinePartialSig {
+        #[clap(
+            long,
+            multiple_occurrences = false,
+            multiple_values = true,
+            parse(try_from_str = decode_signature)
+        )]
+        sigs: Vec<Signature>,
+        #[clap(long, multiple_occurrences = false, multiple_values = true)]
+        pks: Vec<PublicKey>,
+        #[clap(long, multiple_occurrences = false, multiple_values = true)]
+        weights: Vec<WeightUnit>,
+        #[clap(long)]
+        threshold: ThresholdUnit,
+    },
 }
 
 impl KeyToolCommand {
@@ -127,6 +175,10 @@ impl KeyToolCommand {
                 info!("Address : {}", address);
                 let message = Base64::decode(&data).map_err(|e| anyhow!(e))?;
                 let signature = keystore.sign(&address, &message)?;
+                info!(
+                    "Serialized signature Base64: {}",
+                    Base64::encode(&signature)
+                );
                 // Separate pub key and signature string, signature and pub key are concatenated with an '@' symbol.
                 let signature_string = format!("{:?}", signature);
                 let sig_split = signature_string.split('@').collect::<Vec<_>>();
@@ -175,12 +227,152 @@ impl KeyToolCommand {
                     }
                 }
             }
+
+            KeyToolCommand::MigrateToEncrypted {
+                output,
+                remove_plaintext,
+            } => {
+                let plaintext = match keystore {
+                    Keystore::File(plaintext) => plaintext,
+                    _ => return Err(anyhow!("Only a plaintext file keystore can be migrated")),
+                };
+                migrate_to_encrypted(
+                    plaintext,
+                    &output,
+                    &read_new_keystore_password()?,
+                    remove_plaintext,
+                )?;
+            }
+
+            KeyToolCommand::ChangePassword => {
+                let encrypted = match keystore {
+                    Keystore::Encrypted(encrypted) => encrypted,
+                    _ => return Err(anyhow!("The keystore is not encrypted")),
+                };
+                let old_password = read_keystore_password()?;
+                let new_password = prompt_new_keystore_password()?;
+                encrypted.change_password(&old_password, &new_password)?;
+                println!("Password of keystore {:?} changed", encrypted.path());
+            }
+
+            KeyToolCommand::MultiSigAddress {
+                threshold,
+                pks,
+                weights,
+            } => {
+                let multi_pk = MultiPublicKey::new(pks, weights, threshold)?;
+                println!("MultiSig address: {}", SuiAddress::from(&multi_pk));
+                println!("Participating parties:");
+                println!(
+                    " {0: ^42} | {1: ^50} | {2: ^6}",
+                    "Sui Address", "Public Key (Base64)", "Weight"
+                );
+                println!("{}", ["-"; 100].join(""));
+                for (pk, weight) in multi_pk.pubkeys() {
+                    println!(
+                        " {0: ^42} | {1: ^50} | {2: ^6}",
+                        SuiAddress::from(pk),
+                        pk.encode_base64(),
+                        weight
+                    );
+                }
+            }
+
+            KeyToolCommand::MultiSigCombinePartialSig {
+                sigs,
+                pks,
+                weights,
+                threshold,
+            } => {
+                let multi_pk = MultiPublicKey::new(pks, weights, threshold)?;
+                let address = SuiAddress::from(&multi_pk);
+                let multisig = MultiSig::combine(sigs, multi_pk)?;
+                println!("MultiSig address: {address}");
+                println!("MultiSig serialized Base64: {}", Base64::encode(&multisig));
+            }
         }
 
         Ok(())
     }
 }
 
+/// Encrypt all keys of the `plaintext` keystore with `password` into a new encrypted keystore
+/// at `output`, optionally deleting the plaintext keystore once the keys are encrypted.
+pub fn migrate_to_encrypted(
+    plaintext: &FileBasedKeystore,
+    output: &Path,
+    password: &str,
+    remove_plaintext: bool,
+) -> Result<(), anyhow::Error> {
+    let encrypted = EncryptedFileKeystore::create(
+        output,
+        password,
+        plaintext
+            .key_pairs()
+            .into_iter()
+            .map(|kp| SuiKeyPair::decode_base64(&kp.encode_base64()))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| anyhow!(e))?,
+    )?;
+
+    // Make sure the keys can be decrypted before deleting them.
+    let mut check = EncryptedFileKeystore::new(output);
+    check.unlock(password)?;
+    ensure!(
+        check.addresses() == plaintext.addresses(),
+        "Encrypted keystore does not contain all keys"
+    );
+    println!(
+        "{} keys encrypted to {:?}",
+        encrypted.addresses().len(),
+        output
+    );
+
+    if remove_plaintext {
+        if let Some(path) = plaintext.path() {
+            fs::remove_file(path)?;
+            println!("Removed plaintext keystore {:?}", path);
+        }
+    }
+    Ok(())
+}
+
+/// Read the password of an encrypted keystore from the SUI_KEYSTORE_PASSWORD environment
+/// variable, or prompt for it.
+pub fn read_keystore_password() -> Result<String, anyhow::Error> {
+    match std::env::var(SUI_KEYSTORE_PASSWORD_ENV) {
+        Ok(password) => Ok(password),
+        Err(_) => Ok(rpassword::prompt_password("Keystore password: ")?),
+    }
+}
+
+/// Like `read_keystore_password`, but asks for a confirmation when prompting.
+fn read_new_keystore_password() -> Result<String, anyhow::Error> {
+    match std::env::var(SUI_KEYSTORE_PASSWORD_ENV) {
+        Ok(password) => {
+            ensure!(!password.is_empty(), "Keystore password cannot be empty");
+            Ok(password)
+        }
+        Err(_) => prompt_new_keystore_password(),
+    }
+}
+
+fn prompt_new_keystore_password() -> Result<String, anyhow::Error> {
+    let password = rpassword::prompt_password("New keystore password: ")?;
+    ensure!(!password.is_empty(), "Keystore password cannot be empty");
+    ensure!(
+        password == rpassword::prompt_password("Confirm keystore password: ")?,
+        "Passwords do not match"
+    );
+    Ok(password)
+}
+
+/// Decode a Base64 encoded `flag || signature || public key`.
+fn decode_signature(s: &str) -> Result<Signature, anyhow::Error> {
+    let bytes = Base64::decode(s).map_err(|e| anyhow!(e))?;
+    <Signature as signature::Signature>::from_bytes(&bytes).map_err(|e| anyhow!(e))
+}
+
 fn store_and_print_keypair(address: SuiAddress, keypair: SuiKeyPair) {
     let path_str = format!("{}.key", address).to_lowercase();
     let path = Path::new(&path_str);
@@ -20,7 +20,7 @@ use sui_config::{
     sui_config_dir, Config, PersistedConfig, SUI_CLIENT_CONFIG, SUI_FULLNODE_CONFIG,
     SUI_GATEWAY_CONFIG, SUI_NETWORK_CONFIG,
 };
-use sui_sdk::crypto::{AccountKeystore, FileBasedKeystore, Keystore};
+use sui_sdk::crypto::{AccountKeystore, EncryptedFileKeystore, FileBasedKeystore, Keystore};
 use sui_sdk::ClientType;
 use sui_swarm::memory::Swarm;
 use sui_types::crypto::{SignatureScheme, SuiKeyPair};
@@ -29,7 +29,7 @@ use crate::client_commands::{SuiClientCommands, WalletContext};
 use crate::config::SuiClientConfig;
 use crate::console::start_console;
 use crate::genesis_ceremony::{run, Ceremony};
-use crate::keytool::KeyToolCommand;
+use crate::keytool::{read_keystore_password, KeyToolCommand};
 use crate::sui_move::{self, execute_move_command};
 
 #[allow(clippy::large_enum_variant)]
@@ -312,7 +312,16 @@ impl SuiCommand {
             SuiCommand::KeyTool { keystore_path, cmd } => {
                 let keystore_path =
                     keystore_path.unwrap_or(sui_config_dir()?.join(SUI_KEYSTORE_FILENAME));
-                let mut keystore = Keystore::from(FileBasedKeystore::new(&keystore_path)?);
+                let mut keystore = if EncryptedFileKeystore::is_encrypted_keystore(&keystore_path) {
+                    let mut keystore = EncryptedFileKeystore::new(&keystore_path);
+                    // Changing the password asks for the current one by itself.
+                    if !matches!(cmd, KeyToolCommand::ChangePassword) {
+                        keystore.unlock(&read_keystore_password()?)?;
+                    }
+                    Keystore::from(keystore)
+                } else {
+                    Keystore::from(FileBasedKeystore::new(&keystore_path)?)
+                };
                 cmd.execute(&mut keystore)
             }
             SuiCommand::Console { config } => {
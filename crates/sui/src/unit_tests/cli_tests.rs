@@ -312,6 +312,7 @@ async fn test_gas_command() -> Result<(), anyhow::Error> {
         object_id: object_to_send,
         gas: Some(object_id),
         gas_budget: 50000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -351,6 +352,7 @@ async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
         build_config,
         gas: Some(gas_obj_id),
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -403,6 +405,7 @@ async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
         args,
         gas: None,
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -443,6 +446,7 @@ async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
         args: args.to_vec(),
         gas: Some(gas),
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await;
@@ -467,6 +471,7 @@ async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
         args: args.to_vec(),
         gas: Some(gas),
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await;
@@ -492,6 +497,7 @@ async fn test_move_call_args_linter_command() -> Result<(), anyhow::Error> {
         args: args.to_vec(),
         gas: Some(gas),
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -524,6 +530,7 @@ async fn test_package_publish_command() -> Result<(), anyhow::Error> {
         build_config,
         gas: Some(gas_obj_id),
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -582,6 +589,7 @@ async fn test_native_transfer() -> Result<(), anyhow::Error> {
         to: recipient,
         object_id: obj_id,
         gas_budget: 50000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -650,6 +658,7 @@ async fn test_native_transfer() -> Result<(), anyhow::Error> {
         to: recipient,
         object_id: obj_id,
         gas_budget: 50000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -911,6 +920,7 @@ async fn test_merge_coin() -> Result<(), anyhow::Error> {
         coin_to_merge,
         gas: Some(gas),
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -959,6 +969,7 @@ async fn test_merge_coin() -> Result<(), anyhow::Error> {
         coin_to_merge,
         gas: None,
         gas_budget: 10_000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -1019,6 +1030,7 @@ async fn test_split_coin() -> Result<(), anyhow::Error> {
         coin_id: coin,
         amounts: Some(vec![1000, 10]),
         count: None,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -1075,6 +1087,7 @@ async fn test_split_coin() -> Result<(), anyhow::Error> {
         coin_id: coin,
         amounts: None,
         count: Some(3),
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -1134,6 +1147,7 @@ async fn test_split_coin() -> Result<(), anyhow::Error> {
         coin_id: coin,
         amounts: Some(vec![1000, 10]),
         count: None,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -1227,6 +1241,7 @@ async fn test_serialize_tx() -> Result<(), anyhow::Error> {
         sui_coin_object_id: coin,
         gas_budget: 1000,
         amount: Some(1),
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -4,11 +4,13 @@
 use crate::keytool::read_authority_keypair_from_file;
 use crate::keytool::read_keypair_from_file;
 
+use super::migrate_to_encrypted;
 use super::write_keypair_to_file;
 use super::KeyToolCommand;
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 use sui_sdk::crypto::AccountKeystore;
+use sui_sdk::crypto::EncryptedFileKeystore;
 use sui_sdk::crypto::FileBasedKeystore;
 use sui_sdk::crypto::InMemKeystore;
 use sui_sdk::crypto::Keystore;
@@ -69,11 +71,58 @@ fn test_flag_in_signature_and_keypair() -> Result<(), anyhow::Error> {
                 );
                 assert!(pk1.flag() == Secp256k1SuiSignature::SCHEME.flag())
             }
+            Signature::MultiSig(_) => unreachable!("The keystore only holds single keys"),
         }
     }
     Ok(())
 }
 
+#[test]
+fn test_multisig_commands() -> Result<(), anyhow::Error> {
+    let mut keystore = Keystore::from(InMemKeystore::new(2));
+    keystore.add_key(SuiKeyPair::Secp256k1SuiKeyPair(get_key_pair().1))?;
+    let pks = keystore.keys();
+
+    KeyToolCommand::MultiSigAddress {
+        threshold: 2,
+        pks: pks.clone(),
+        weights: vec![1, 1, 1],
+    }
+    .execute(&mut keystore)?;
+
+    let sigs = pks
+        .iter()
+        .skip(1)
+        .map(|pk| keystore.sign(&pk.into(), b"hello"))
+        .collect::<Result<Vec<_>, _>>()?;
+    KeyToolCommand::MultiSigCombinePartialSig {
+        sigs: sigs.clone(),
+        pks: pks.clone(),
+        weights: vec![1, 1, 1],
+        threshold: 2,
+    }
+    .execute(&mut keystore)?;
+
+    // The threshold cannot be reached.
+    assert!(KeyToolCommand::MultiSigAddress {
+        threshold: 4,
+        pks: pks.clone(),
+        weights: vec![1, 1, 1],
+    }
+    .execute(&mut keystore)
+    .is_err());
+    // A signature cannot be used twice.
+    assert!(KeyToolCommand::MultiSigCombinePartialSig {
+        sigs: vec![sigs[0].clone(), sigs[0].clone()],
+        pks,
+        weights: vec![1, 1, 1],
+        threshold: 2,
+    }
+    .execute(&mut keystore)
+    .is_err());
+    Ok(())
+}
+
 #[test]
 fn test_read_write_keystore_with_flag() {
     let dir = tempfile::TempDir::new().unwrap();
@@ -283,3 +332,21 @@ fn test_valid_derivation_path() -> Result<(), anyhow::Error> {
     .is_ok());
     Ok(())
 }
+
+#[test]
+fn test_migrate_to_encrypted() -> Result<(), anyhow::Error> {
+    let temp_dir = TempDir::new().unwrap();
+    let plaintext_path = temp_dir.path().join("sui.keystore");
+    let encrypted_path = temp_dir.path().join("sui.keystore.enc");
+    let mut keystore = FileBasedKeystore::new(&plaintext_path)?;
+    keystore.add_key(SuiKeyPair::Ed25519SuiKeyPair(get_key_pair().1))?;
+    keystore.add_key(SuiKeyPair::Secp256k1SuiKeyPair(get_key_pair().1))?;
+
+    migrate_to_encrypted(&keystore, &encrypted_path, "password", true)?;
+    assert!(!plaintext_path.exists());
+
+    let mut encrypted = EncryptedFileKeystore::new(&encrypted_path);
+    encrypted.unlock("password")?;
+    assert_eq!(encrypted.addresses(), keystore.addresses());
+    Ok(())
+}
@@ -7,9 +7,7 @@ use std::{collections::BTreeMap, sync::Arc};
 use futures::future;
 use jsonrpsee::core::client::{ClientT, Subscription, SubscriptionClientT};
 use jsonrpsee::rpc_params;
-use move_core_types::account_address::AccountAddress;
 use move_core_types::identifier::Identifier;
-use move_core_types::language_storage::ModuleId;
 use move_core_types::value::MoveStructLayout;
 use prometheus::Registry;
 use tokio::sync::Mutex;
@@ -18,20 +16,24 @@ use tokio::time::{sleep, Duration};
 
 use sui::client_commands::{SuiClientCommandResult, SuiClientCommands};
 use sui_json_rpc_types::{
-    SuiEvent, SuiEventEnvelope, SuiEventFilter, SuiExecuteTransactionResponse, SuiExecutionStatus,
-    SuiMoveStruct, SuiMoveValue, SuiTransactionFilter, SuiTransactionResponse,
+    EventPage, SuiEvent, SuiEventEnvelope, SuiEventFilter, SuiExecuteTransactionResponse,
+    SuiExecutionStatus, SuiMoveStruct, SuiMoveValue, SuiTransactionFilter, SuiTransactionResponse,
 };
 use sui_macros::*;
 use sui_node::SuiNode;
 use sui_sdk::crypto::AccountKeystore;
 use sui_types::base_types::{ObjectRef, SequenceNumber};
-use sui_types::event::TransferType;
+use sui_types::event::{EventID, TransferType};
+use sui_types::filter::EventFilter;
+use sui_types::governance::{Delegation, StakedSui};
 use sui_types::messages::{
     ExecuteTransactionRequest, ExecuteTransactionRequestType, ExecuteTransactionResponse,
 };
 use sui_types::object::{Object, ObjectRead, Owner, PastObjectRead};
+use sui_types::parse_sui_struct_tag;
 use sui_types::query::TransactionQuery;
 use sui_types::sui_framework_address_concat_string;
+use sui_types::sui_system_state::{SuiSystemState, Validator};
 use sui_types::{
     base_types::{ObjectID, SuiAddress, TransactionDigest},
     messages::TransactionInfoRequest,
@@ -253,37 +255,51 @@ async fn test_full_node_indexes() -> Result<(), anyhow::Error> {
     };
 
     // query all events
-    let all_events = node
+    let time_range = EventFilter::TimeRange {
+        start_time: ts.unwrap() - HOUR_MS,
+        end_time: ts.unwrap() + HOUR_MS,
+    };
+    let (all_events, _) = node
         .state()
-        .get_events_by_timerange(ts.unwrap() - HOUR_MS, ts.unwrap() + HOUR_MS, 10)
+        .get_events(&time_range, None, 10, false)
         .await?;
     assert_eq!(all_events.len(), 1);
     assert_eq!(all_events[0].event, expected_event);
     assert_eq!(all_events[0].tx_digest.unwrap(), digest);
 
     // query by sender
-    let events_by_sender = node
+    let (events_by_sender, _) = node
         .state()
-        .get_events_by_sender(&sender, ts.unwrap() - HOUR_MS, ts.unwrap() + HOUR_MS, 10)
+        .get_events(
+            &time_range.clone().and(EventFilter::SenderAddress(sender)),
+            None,
+            10,
+            false,
+        )
         .await?;
     assert_eq!(events_by_sender.len(), 1);
     assert_eq!(events_by_sender[0].event, expected_event);
     assert_eq!(events_by_sender[0].tx_digest.unwrap(), digest);
 
     // query by tx digest
-    let events_by_tx = node.state().get_events_by_transaction(digest, 10).await?;
+    let (events_by_tx, _) = node
+        .state()
+        .get_events(&EventFilter::Transaction(digest), None, 10, false)
+        .await?;
     assert_eq!(events_by_tx.len(), 1);
     assert_eq!(events_by_tx[0].event, expected_event);
     assert_eq!(events_by_tx[0].tx_digest.unwrap(), digest);
 
     // query by recipient
-    let events_by_recipient = node
+    let (events_by_recipient, _) = node
         .state()
-        .get_events_by_recipient(
-            &Owner::AddressOwner(receiver),
-            ts.unwrap() - HOUR_MS,
-            ts.unwrap() + HOUR_MS,
+        .get_events(
+            &time_range
+                .clone()
+                .and(EventFilter::Recipient(Owner::AddressOwner(receiver))),
+            None,
             10,
+            false,
         )
         .await?;
     assert_eq!(events_by_recipient.len(), 1);
@@ -291,13 +307,15 @@ async fn test_full_node_indexes() -> Result<(), anyhow::Error> {
     assert_eq!(events_by_recipient[0].tx_digest.unwrap(), digest);
 
     // query by object
-    let events_by_object = node
+    let (events_by_object, _) = node
         .state()
-        .get_events_by_object(
-            &transferred_object,
-            ts.unwrap() - HOUR_MS,
-            ts.unwrap() + HOUR_MS,
+        .get_events(
+            &time_range
+                .clone()
+                .and(EventFilter::ObjectId(transferred_object)),
+            None,
             10,
+            false,
         )
         .await?;
     assert_eq!(events_by_object.len(), 1);
@@ -305,14 +323,14 @@ async fn test_full_node_indexes() -> Result<(), anyhow::Error> {
     assert_eq!(events_by_object[0].tx_digest.unwrap(), digest);
 
     // query by transaction module
-    // Query by module ID
-    let mod_id = ModuleId::new(
-        AccountAddress::from(ObjectID::from_hex_literal("0x2").unwrap()),
-        Identifier::from_str("native").unwrap(),
-    );
-    let events_by_module = node
+    let module_filter = EventFilter::MatchAll(vec![
+        time_range,
+        EventFilter::Package(ObjectID::from_hex_literal("0x2").unwrap()),
+        EventFilter::Module(Identifier::from_str("native").unwrap()),
+    ]);
+    let (events_by_module, _) = node
         .state()
-        .get_events_by_transaction_module(&mod_id, ts.unwrap() - HOUR_MS, ts.unwrap() + HOUR_MS, 10)
+        .get_events(&module_filter, None, 10, false)
         .await?;
     assert_eq!(events_by_module.len(), 1);
     assert_eq!(events_by_module[0].event, expected_event);
@@ -395,6 +413,7 @@ async fn test_full_node_sync_flood() -> Result<(), anyhow::Error> {
                         coin_id: object_to_split.0,
                         gas: Some(gas_object_id),
                         gas_budget: 50000,
+                        expiration_epoch: None,
                     }
                     .execute(context)
                     .await
@@ -563,15 +582,13 @@ async fn test_full_node_sub_and_query_move_event_ok() -> Result<(), anyhow::Erro
     };
 
     // Query by move event struct name
-    let events_by_sender = node
-        .state()
-        .get_events_by_move_event_struct_name(
-            &struct_tag_str,
-            ts.unwrap() - HOUR_MS,
-            ts.unwrap() + HOUR_MS,
-            10,
-        )
-        .await?;
+    let filter = EventFilter::MoveEventType(parse_sui_struct_tag(&struct_tag_str)?).and(
+        EventFilter::TimeRange {
+            start_time: ts.unwrap() - HOUR_MS,
+            end_time: ts.unwrap() + HOUR_MS,
+        },
+    );
+    let (events_by_sender, _) = node.state().get_events(&filter, None, 10, false).await?;
     assert_eq!(events_by_sender.len(), 1);
     assert_eq!(events_by_sender[0].event, expected_event);
     assert_eq!(events_by_sender[0].tx_digest.unwrap(), digest);
@@ -631,67 +648,88 @@ async fn test_full_node_event_read_api_ok() -> Result<(), anyhow::Error> {
     };
 
     // query by sender
-    let params = rpc_params![sender, 10, ts.unwrap() - HOUR_MS, ts.unwrap() + HOUR_MS];
-    let events_by_sender: Vec<SuiEventEnvelope> = jsonrpc_client
-        .request("sui_getEventsBySender", params)
+    let params = rpc_params![
+        SuiEventFilter::SenderAddress(sender),
+        None::<EventID>,
+        10,
+        false
+    ];
+    let events_by_sender: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
         .await
         .unwrap();
+    let events_by_sender = events_by_sender.data;
     assert_eq!(events_by_sender.len(), 1);
     assert_eq!(events_by_sender[0].event, expected_event);
     assert_eq!(events_by_sender[0].tx_digest.unwrap(), digest);
 
     // query by tx digest
-    let params = rpc_params![digest, 10];
-    let events_by_tx: Vec<SuiEventEnvelope> = jsonrpc_client
-        .request("sui_getEventsByTransaction", params)
+    let params = rpc_params![
+        SuiEventFilter::Transaction(digest),
+        None::<EventID>,
+        10,
+        false
+    ];
+    let events_by_tx: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
         .await
         .unwrap();
+    let events_by_tx = events_by_tx.data;
     assert_eq!(events_by_tx.len(), 1);
     assert_eq!(events_by_tx[0].event, expected_event);
     assert_eq!(events_by_tx[0].tx_digest.unwrap(), digest);
 
     // query by recipient
     let params = rpc_params![
-        Owner::AddressOwner(receiver),
+        SuiEventFilter::Recipient(Owner::AddressOwner(receiver)),
+        None::<EventID>,
         10,
-        ts.unwrap() - HOUR_MS,
-        ts.unwrap() + HOUR_MS
+        false
     ];
-    let events_by_recipient: Vec<SuiEventEnvelope> = jsonrpc_client
-        .request("sui_getEventsByRecipient", params)
+    let events_by_recipient: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
         .await
         .unwrap();
+    let events_by_recipient = events_by_recipient.data;
     assert_eq!(events_by_recipient.len(), 1);
     assert_eq!(events_by_recipient[0].event, expected_event);
     assert_eq!(events_by_recipient[0].tx_digest.unwrap(), digest);
 
     // query by object
     let params = rpc_params![
-        transferred_object,
+        SuiEventFilter::ObjectId(transferred_object),
+        None::<EventID>,
         10,
-        ts.unwrap() - HOUR_MS,
-        ts.unwrap() + HOUR_MS
+        false
     ];
-    let events_by_object: Vec<SuiEventEnvelope> = jsonrpc_client
-        .request("sui_getEventsByObject", params)
+    let events_by_object: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
         .await
         .unwrap();
+    let events_by_object = events_by_object.data;
     assert_eq!(events_by_object.len(), 1);
     assert_eq!(events_by_object[0].event, expected_event);
     assert_eq!(events_by_object[0].tx_digest.unwrap(), digest);
 
-    // query by transaction module
+    // query by transaction module, within a time range
     let params = rpc_params![
-        ObjectID::from_hex_literal("0x2").unwrap(),
-        "native",
+        SuiEventFilter::All(vec![
+            SuiEventFilter::Package(ObjectID::from_hex_literal("0x2").unwrap()),
+            SuiEventFilter::Module("native".into()),
+            SuiEventFilter::TimeRange {
+                start_time: ts.unwrap() - HOUR_MS,
+                end_time: ts.unwrap() + HOUR_MS,
+            },
+        ]),
+        None::<EventID>,
         10,
-        ts.unwrap() - HOUR_MS,
-        ts.unwrap() + HOUR_MS
+        false
     ];
-    let events_by_module: Vec<SuiEventEnvelope> = jsonrpc_client
-        .request("sui_getEventsByModule", params)
+    let events_by_module: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
         .await
         .unwrap();
+    let events_by_module = events_by_module.data;
     assert_eq!(events_by_module.len(), 1);
     assert_eq!(events_by_module[0].event, expected_event);
     assert_eq!(events_by_module[0].tx_digest.unwrap(), digest);
@@ -704,24 +742,31 @@ async fn test_full_node_event_read_api_ok() -> Result<(), anyhow::Error> {
 
     // query by move event struct name
     let params = rpc_params![
-        struct_tag_str,
+        SuiEventFilter::MoveEventType(struct_tag_str),
+        None::<EventID>,
         10,
-        ts2.unwrap() - HOUR_MS,
-        ts2.unwrap() + HOUR_MS
+        false
     ];
-    let events_by_sender: Vec<SuiEventEnvelope> = jsonrpc_client
-        .request("sui_getEventsByMoveEventStructName", params)
+    let events_by_sender: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
         .await
         .unwrap();
+    let events_by_sender = events_by_sender.data;
     assert_eq!(events_by_sender.len(), 1);
     assert_eq!(events_by_sender[0].tx_digest.unwrap(), digest2);
 
     // query all transactions
-    let params = rpc_params![10, ts.unwrap() - HOUR_MS, ts2.unwrap() + HOUR_MS];
-    let all_events: Vec<SuiEventEnvelope> = jsonrpc_client
-        .request("sui_getEventsByTimeRange", params)
+    let all_transactions = || SuiEventFilter::TimeRange {
+        start_time: ts.unwrap() - HOUR_MS,
+        end_time: ts2.unwrap() + HOUR_MS,
+    };
+    let params = rpc_params![all_transactions(), None::<EventID>, 10, false];
+    let all_events: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
         .await
         .unwrap();
+    assert!(!all_events.has_next_page);
+    let all_events = all_events.data;
     // The first txn emits TransferObject
     // The second txn emits MoveEvent and NewObject
     assert_eq!(all_events.len(), 3);
@@ -732,6 +777,34 @@ async fn test_full_node_event_read_api_ok() -> Result<(), anyhow::Error> {
     // Sorted in ascending time
     assert_eq!(tx_digests, vec![digest, digest2, digest2]);
 
+    // page through the same events
+    let params = rpc_params![all_transactions(), None::<EventID>, 2, false];
+    let first_page: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
+        .await
+        .unwrap();
+    assert_eq!(first_page.data, all_events[..2]);
+    assert!(first_page.has_next_page);
+    assert_eq!(first_page.next_cursor, Some(all_events[2].id));
+
+    let params = rpc_params![all_transactions(), first_page.next_cursor, 2, false];
+    let second_page: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
+        .await
+        .unwrap();
+    assert_eq!(second_page.data, all_events[2..]);
+    assert!(!second_page.has_next_page);
+
+    // and in descending order
+    let params = rpc_params![all_transactions(), None::<EventID>, 10, true];
+    let descending: EventPage = jsonrpc_client
+        .request("sui_getEvents", params)
+        .await
+        .unwrap();
+    let mut ascending = all_events;
+    ascending.reverse();
+    assert_eq!(descending.data, ascending);
+
     Ok(())
 }
 
@@ -1018,6 +1091,52 @@ async fn test_full_node_transaction_orchestrator_rpc_ok() -> Result<(), anyhow::
     Ok(())
 }
 
+#[tokio::test]
+async fn test_full_node_governance_read_api_ok() -> Result<(), anyhow::Error> {
+    let test_cluster = init_cluster_builder_env_aware().build().await?;
+    let fullnode = test_cluster.fullnode_handle.as_ref().unwrap();
+    let jsonrpc_client = &fullnode.rpc_client;
+    let expected = fullnode
+        .sui_node
+        .state()
+        .get_sui_system_state_object()
+        .await?;
+
+    let system_state: SuiSystemState = jsonrpc_client
+        .request("sui_getSuiSystemState", rpc_params![])
+        .await
+        .unwrap();
+    assert_eq!(system_state, expected);
+
+    let validators: Vec<Validator> = jsonrpc_client
+        .request("sui_getValidators", rpc_params![])
+        .await
+        .unwrap();
+    assert!(!validators.is_empty());
+    assert_eq!(validators, expected.validators.active_validators);
+
+    let pending_validators: Vec<Validator> = jsonrpc_client
+        .request("sui_getPendingValidators", rpc_params![])
+        .await
+        .unwrap();
+    assert_eq!(pending_validators, expected.validators.pending_validators);
+
+    // Nothing has been staked or delegated since genesis.
+    let owner = test_cluster.get_address_0();
+    let delegations: Vec<Delegation> = jsonrpc_client
+        .request("sui_getDelegations", rpc_params![owner])
+        .await
+        .unwrap();
+    assert!(delegations.is_empty());
+    let staked_sui: Vec<StakedSui> = jsonrpc_client
+        .request("sui_getStakedSui", rpc_params![owner])
+        .await
+        .unwrap();
+    assert!(staked_sui.is_empty());
+
+    Ok(())
+}
+
 async fn get_obj_read_from_node(
     node: &SuiNode,
     object_id: ObjectID,
@@ -22,7 +22,7 @@ pub fn test_validatdor_metadata(
 ) -> ValidatorMetadata {
     let network_keypair: NetworkKeyPair = get_key_pair().1;
     ValidatorMetadata {
-        sui_address: sui_address.into(),
+        sui_address,
         pubkey_bytes: pubkey_bytes.as_bytes().to_vec(),
         network_pubkey_bytes: network_keypair.public().as_bytes().to_vec(),
         proof_of_possession_bytes: vec![],
@@ -36,7 +36,7 @@ pub fn test_validatdor_metadata(
 
 pub fn test_staking_pool(sui_address: SuiAddress, epoch_starting_sui_balance: u64) -> StakingPool {
     StakingPool {
-        validator_address: sui_address.into(),
+        validator_address: sui_address,
         starting_epoch: 0,
         epoch_starting_sui_balance,
         epoch_starting_delegation_token_supply: 0,
@@ -31,7 +31,8 @@ use sui_types::error::SuiResult;
 use sui_types::messages::ExecuteTransactionRequestType;
 use sui_types::messages::{
     BatchInfoRequest, BatchInfoResponseItem, CallArg, ObjectArg, ObjectInfoRequest,
-    ObjectInfoResponse, Transaction, TransactionData, TransactionEffects, TransactionInfoResponse,
+    ObjectInfoResponse, Transaction, TransactionData, TransactionEffects, TransactionExpiration,
+    TransactionInfoResponse,
 };
 use sui_types::object::{Object, Owner};
 use sui_types::SUI_FRAMEWORK_OBJECT_ID;
@@ -98,7 +99,13 @@ pub async fn publish_basics_package(context: &WalletContext, sender: SuiAddress)
         let data = context
             .client
             .transaction_builder()
-            .publish(sender, all_module_bytes, None, 50000)
+            .publish(
+                sender,
+                all_module_bytes,
+                None,
+                50000,
+                TransactionExpiration::None,
+            )
             .await
             .unwrap();
 
@@ -155,6 +162,7 @@ pub async fn submit_move_transaction(
             arguments,
             gas_object,
             50000,
+            TransactionExpiration::None,
         )
         .await
         .unwrap();
@@ -281,6 +289,7 @@ pub async fn transfer_sui(
         amount: None,
         sui_coin_object_id: gas_ref.0,
         gas_budget: 50000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -317,6 +326,7 @@ pub async fn transfer_coin(
         object_id: object_to_send,
         gas: None,
         gas_budget: 50000,
+        expiration_epoch: None,
     }
     .execute(context)
     .await?;
@@ -337,6 +347,7 @@ pub async fn split_coin_with_wallet_context(context: &mut WalletContext, coin_id
         count: Some(2),
         gas: None,
         gas_budget: MAX_GAS,
+        expiration_epoch: None,
     }
     .execute(context)
     .await
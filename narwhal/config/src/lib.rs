@@ -120,6 +120,11 @@ pub struct Parameters {
     pub max_header_delay: Duration,
     /// The depth of the garbage collection (Denominated in number of rounds).
     pub gc_depth: u64,
+    /// The number of rounds for which certificates, headers and batches are kept in storage once
+    /// they have been committed and executed. Older ones are pruned in the background. Values
+    /// below `gc_depth` are raised to `gc_depth`.
+    #[serde(default = "Parameters::default_gc_retention_rounds")]
+    pub gc_retention_rounds: u64,
     /// The delay after which the synchronizer retries to send sync requests. Denominated in ms.
     #[serde(with = "duration_format")]
     pub sync_retry_delay: Duration,
@@ -133,6 +138,10 @@ pub struct Parameters {
     /// is not reached.
     #[serde(with = "duration_format")]
     pub max_batch_delay: Duration,
+    /// The compression of the batches that the workers send to each other. It is also the
+    /// compression that the workers request when fetching batches from other workers.
+    #[serde(default)]
+    pub batch_compression: BatchCompression,
     /// The parameters for the block synchronizer
     pub block_synchronizer: BlockSynchronizerParameters,
     /// The parameters for the Consensus API gRPC server
@@ -153,6 +162,31 @@ impl Parameters {
     fn default_max_header_num_of_batches() -> usize {
         1_000
     }
+
+    fn default_gc_retention_rounds() -> u64 {
+        50_000
+    }
+}
+
+/// The compression applied to the batches sent over the network. The digest of a batch is always
+/// computed over its uncompressed contents.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchCompression {
+    /// The batches are sent uncompressed.
+    None,
+    /// The batches are compressed with zstd.
+    #[default]
+    Zstd,
+}
+
+impl std::fmt::Display for BatchCompression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchCompression::None => write!(f, "none"),
+            BatchCompression::Zstd => write!(f, "zstd"),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -299,10 +333,12 @@ impl Default for Parameters {
             max_header_num_of_batches: 1000,
             max_header_delay: Duration::from_millis(100),
             gc_depth: 50,
+            gc_retention_rounds: Parameters::default_gc_retention_rounds(),
             sync_retry_delay: Duration::from_millis(5_000),
             sync_retry_nodes: 3,
             batch_size: 500_000,
             max_batch_delay: Duration::from_millis(100),
+            batch_compression: BatchCompression::default(),
             block_synchronizer: BlockSynchronizerParameters::default(),
             consensus_api_grpc: ConsensusAPIGrpcParameters::default(),
             max_concurrent_requests: 500_000,
@@ -327,6 +363,10 @@ impl Parameters {
             self.max_header_delay.as_millis()
         );
         info!("Garbage collection depth set to {} rounds", self.gc_depth);
+        info!(
+            "Garbage collection retention set to {} rounds",
+            self.gc_retention_rounds
+        );
         info!(
             "Sync retry delay set to {} ms",
             self.sync_retry_delay.as_millis()
@@ -337,6 +377,7 @@ impl Parameters {
             "Max batch delay set to {} ms",
             self.max_batch_delay.as_millis()
         );
+        info!("Batch compression set to {}", self.batch_compression);
         info!(
             "Synchronize range timeout set to {} s",
             self.block_synchronizer.range_synchronize_timeout.as_secs()
@@ -774,10 +815,14 @@ mod tests {
         assert!(logs_contain("Header max number of batches set to 1000"));
         assert!(logs_contain("Max header delay set to 100 ms"));
         assert!(logs_contain("Garbage collection depth set to 50 rounds"));
+        assert!(logs_contain(
+            "Garbage collection retention set to 50000 rounds"
+        ));
         assert!(logs_contain("Sync retry delay set to 5000 ms"));
         assert!(logs_contain("Sync retry nodes set to 3 nodes"));
         assert!(logs_contain("Batch size set to 500000 B"));
         assert!(logs_contain("Max batch delay set to 100 ms"));
+        assert!(logs_contain("Batch compression set to zstd"));
         assert!(logs_contain("Synchronize certificates timeout set to 30 s"));
         assert!(logs_contain(
             "Payload (batches) availability timeout set to 30 s"
@@ -51,11 +51,7 @@ pub fn process_certificates(c: &mut Criterion) {
             .sum();
         consensus_group.throughput(Throughput::Bytes(data_size as u64));
 
-        let mut ordering_engine = Bullshark {
-            committee: committee.clone(),
-            store,
-            gc_depth,
-        };
+        let mut ordering_engine = Bullshark::new(committee.clone(), store, gc_depth);
         consensus_group.bench_with_input(
             BenchmarkId::new("batched", certificates.len()),
             &certificates,
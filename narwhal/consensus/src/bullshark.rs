@@ -8,8 +8,11 @@ use crate::{
 use config::{Committee, Stake};
 use fastcrypto::{hash::Hash, traits::EncodeDecodeBase64};
 use std::{collections::BTreeSet, sync::Arc};
-use tracing::{debug, error};
-use types::{Certificate, CertificateDigest, ConsensusStore, Round, SequenceNumber, StoreResult};
+use tracing::{debug, error, info};
+use types::{
+    Certificate, CertificateDigest, ConsensusStore, LeaderSchedule, ReputationScores, Round,
+    SequenceNumber, StoreResult,
+};
 
 #[cfg(test)]
 #[path = "tests/bullshark_tests.rs"]
@@ -22,6 +25,13 @@ pub struct Bullshark {
     pub store: Arc<ConsensusStore>,
     /// The depth of the garbage collector.
     pub gc_depth: Round,
+    /// The number of committed sub-dags over which reputation scores are accumulated before a new
+    /// leader schedule is derived from them.
+    pub num_sub_dags_per_schedule: u64,
+    /// The reputation scores of the current scoring window.
+    pub reputation_scores: ReputationScores,
+    /// The leader schedule in effect.
+    pub leader_schedule: LeaderSchedule,
 }
 
 impl ConsensusProtocol for Bullshark {
@@ -80,22 +90,86 @@ impl ConsensusProtocol for Bullshark {
             return Ok(Vec::new());
         }
 
+        // Committing a leader may complete a scoring window and change the leader schedule. The
+        // leaders that are not committed yet must then be elected again with the new schedule, so
+        // that all nodes elect the same leaders regardless of how their commits were batched.
+        let mut sequence = Vec::new();
+        while self.try_commit(state, r, &mut consensus_index, &mut sequence)? {}
+
+        // Log the latest committed round of every authority (for debug).
+        // Performance note: if tracing at the debug log level is disabled, this is cheap, see
+        // https://github.com/tokio-rs/tracing/pull/326
+        for (name, round) in &state.last_committed {
+            debug!("Latest commit of {}: Round {}", name.encode_base64(), round);
+        }
+
+        Ok(sequence)
+    }
+
+    fn update_committee(&mut self, new_committee: Committee) -> StoreResult<()> {
+        self.committee = new_committee;
+        self.reputation_scores = ReputationScores::default();
+        self.leader_schedule = LeaderSchedule::default();
+        self.store.clear()
+    }
+}
+
+impl Bullshark {
+    /// The default number of committed sub-dags per scoring window of the leader schedule.
+    pub const DEFAULT_NUM_SUB_DAGS_PER_SCHEDULE: u64 = 100;
+
+    /// Create a new Bullshark consensus instance.
+    pub fn new(committee: Committee, store: Arc<ConsensusStore>, gc_depth: Round) -> Self {
+        // Recover the reputation of the authorities, so that we derive the same leader schedules
+        // as the other nodes.
+        let reputation_scores = store
+            .read_reputation_scores()
+            .expect("Failed to load reputation scores from store");
+        let leader_schedule = store
+            .read_latest_leader_schedule()
+            .expect("Failed to load leader schedule from store")
+            .map(|(_, leader_schedule)| leader_schedule)
+            .unwrap_or_default();
+
+        Self {
+            committee,
+            store,
+            gc_depth,
+            num_sub_dags_per_schedule: Self::DEFAULT_NUM_SUB_DAGS_PER_SCHEDULE,
+            reputation_scores,
+            leader_schedule,
+        }
+    }
+
+    /// Try to commit the leader of `leader_round`, along with the preceding leaders linked to it.
+    /// Returns true if the leader schedule changed on the way, in which case the leaders that
+    /// remain to be committed must be elected again.
+    fn try_commit(
+        &mut self,
+        state: &mut ConsensusState,
+        leader_round: Round,
+        consensus_index: &mut SequenceNumber,
+        sequence: &mut Vec<ConsensusOutput>,
+    ) -> StoreResult<bool> {
         // Get the certificate's digest of the leader. If we already ordered this leader,
         // there is nothing to do.
-        let leader_round = r;
         if leader_round <= state.last_committed_round {
-            return Ok(Vec::new());
+            return Ok(false);
         }
-        let (leader_digest, leader) = match Self::leader(&self.committee, leader_round, &state.dag)
-        {
+        let (leader_digest, leader) = match Self::leader(
+            &self.committee,
+            &self.leader_schedule,
+            leader_round,
+            &state.dag,
+        ) {
             Some(x) => x,
-            None => return Ok(Vec::new()),
+            None => return Ok(false),
         };
 
-        // Check if the leader has f+1 support from its children (ie. round r-1).
+        // Check if the leader has f+1 support from its children (ie. round r+1).
         let stake: Stake = state
             .dag
-            .get(&round)
+            .get(&(leader_round + 1))
             .expect("We should have the whole history by now")
             .values()
             .filter(|(_, x)| x.header.parents.contains(leader_digest))
@@ -107,70 +181,99 @@ impl ConsensusProtocol for Bullshark {
         // a leader block means committing all its dependencies.
         if stake < self.committee.validity_threshold() {
             debug!("Leader {:?} does not have enough support", leader);
-            return Ok(Vec::new());
+            return Ok(false);
         }
 
         // Get an ordered list of past leaders that are linked to the current leader.
         debug!("Leader {:?} has enough support", leader);
-        let mut sequence = Vec::new();
-
+        let leader_schedule = &self.leader_schedule;
         // TODO: duplicated in tusk.rs
-        for leader in utils::order_leaders(&self.committee, leader, state, Self::leader)
-            .iter()
-            .rev()
-        {
+        let leaders =
+            utils::order_leaders(&self.committee, leader, state, |committee, round, dag| {
+                Self::leader(committee, leader_schedule, round, dag)
+            });
+
+        for leader in leaders.iter().rev() {
             debug!("Previous Leader {:?} has enough support", leader);
 
             // Starting from the oldest leader, flatten the sub-dag referenced by the leader.
-            for x in utils::order_dag(self.gc_depth, leader, state) {
+            let sub_dag = utils::order_dag(self.gc_depth, leader, state);
+            let last = sub_dag.len().saturating_sub(1);
+            let mut new_schedule = None;
+            for (i, x) in sub_dag.into_iter().enumerate() {
                 let digest = x.digest();
 
                 // Update and clean up internal state.
                 state.update(&x, self.gc_depth);
 
+                // Credit the origin of the certificate. The sub-dag counts towards the scoring
+                // window with its last certificate, so that the scores and the new leader schedule
+                // are persisted atomically with the consensus state.
+                self.reputation_scores.add_score(&x.origin(), 1);
+                if i == last {
+                    new_schedule = self.complete_sub_dag();
+                }
+
                 // Add the certificate to the sequence.
                 sequence.push(ConsensusOutput {
                     certificate: x,
-                    consensus_index,
+                    consensus_index: *consensus_index,
                 });
 
                 // Increase the global consensus index.
-                consensus_index += 1;
+                *consensus_index += 1;
 
                 // Persist the update.
                 // TODO [issue #116]: Ensure this is not a performance bottleneck.
-                self.store.write_consensus_state(
+                self.store.write_consensus_state_with_reputation(
                     &state.last_committed,
-                    &consensus_index,
+                    consensus_index,
                     &digest,
+                    &self.reputation_scores,
+                    new_schedule
+                        .as_ref()
+                        .map(|schedule| (leader.round(), schedule)),
                 )?;
             }
-        }
 
-        // Log the latest committed round of every authority (for debug).
-        // Performance note: if tracing at the debug log level is disabled, this is cheap, see
-        // https://github.com/tokio-rs/tracing/pull/326
-        for (name, round) in &state.last_committed {
-            debug!("Latest commit of {}: Round {}", name.encode_base64(), round);
+            self.report_reputation(state);
+            if new_schedule.is_some() {
+                return Ok(true);
+            }
         }
-
-        Ok(sequence)
+        Ok(false)
     }
 
-    fn update_committee(&mut self, new_committee: Committee) -> StoreResult<()> {
-        self.committee = new_committee;
-        self.store.clear()
+    /// Account for a committed sub-dag in the current scoring window. If this completes the window,
+    /// a new leader schedule is derived from its scores and returned, and the scores are reset.
+    fn complete_sub_dag(&mut self) -> Option<LeaderSchedule> {
+        self.reputation_scores.num_sub_dags += 1;
+        if self.reputation_scores.num_sub_dags < self.num_sub_dags_per_schedule {
+            return None;
+        }
+
+        let leader_schedule = LeaderSchedule::new(&self.committee, &self.reputation_scores);
+        info!(
+            "New leader schedule, swapping out {} authorities",
+            leader_schedule.bad_nodes.len()
+        );
+        self.leader_schedule = leader_schedule.clone();
+        self.reputation_scores = ReputationScores::default();
+        Some(leader_schedule)
     }
-}
 
-impl Bullshark {
-    /// Create a new Bullshark consensus instance.
-    pub fn new(committee: Committee, store: Arc<ConsensusStore>, gc_depth: Round) -> Self {
-        Self {
-            committee,
-            store,
-            gc_depth,
+    fn report_reputation(&self, state: &ConsensusState) {
+        for name in self.committee.authorities.keys() {
+            state
+                .metrics
+                .leader_reputation_scores
+                .with_label_values(&[&name.encode_base64()])
+                .set(self.reputation_scores.score(name) as i64);
         }
+        state
+            .metrics
+            .leader_schedule_bad_nodes
+            .set(self.leader_schedule.bad_nodes.len() as i64);
     }
 
     // TODO: duplicated in tusk.rs
@@ -178,6 +281,7 @@ impl Bullshark {
     /// specified round (if any).
     fn leader<'a>(
         committee: &Committee,
+        leader_schedule: &LeaderSchedule,
         round: Round,
         dag: &'a Dag,
     ) -> Option<&'a (CertificateDigest, Certificate)> {
@@ -187,14 +291,17 @@ impl Bullshark {
         cfg_if::cfg_if! {
             if #[cfg(test)] {
                 // consensus tests rely on returning the same leader.
-                let leader = committee.authorities.iter().next().expect("Empty authorities table!").0;
+                let leader = committee.authorities.iter().next().expect("Empty authorities table!").0.clone();
             } else {
                 // Elect the leader in a stake-weighted choice seeded by the round
-                let leader = &committee.leader(round);
+                let leader = committee.leader(round);
             }
         }
 
+        // Authorities with a bad reputation are swapped for ones with a good reputation.
+        let leader = leader_schedule.leader(leader, round);
+
         // Return its certificate and the certificate's digest.
-        dag.get(&round).and_then(|x| x.get(leader))
+        dag.get(&round).and_then(|x| x.get(&leader))
     }
 }
@@ -23,6 +23,10 @@ pub struct ConsensusMetrics {
     pub recovered_consensus_output: IntCounter,
     /// The approximate size in memory (including heap allocations) of the Dag.
     pub dag_size_bytes: IntGauge,
+    /// The reputation score of every authority in the current scoring window of the leader schedule
+    pub leader_reputation_scores: IntGaugeVec,
+    /// The number of authorities swapped out as leaders by the current leader schedule
+    pub leader_schedule_bad_nodes: IntGauge,
 }
 
 impl ConsensusMetrics {
@@ -67,6 +71,17 @@ impl ConsensusMetrics {
                 "The approximate size in memory (including heap allocations) of the dag",
                 registry
             ).unwrap(),
+            leader_reputation_scores: register_int_gauge_vec_with_registry!(
+                "leader_reputation_scores",
+                "The number of certificates of every authority committed in the current scoring window of the leader schedule",
+                &["authority"],
+                registry
+            ).unwrap(),
+            leader_schedule_bad_nodes: register_int_gauge_with_registry!(
+                "leader_schedule_bad_nodes",
+                "The number of authorities swapped out as leaders by the current leader schedule",
+                registry
+            ).unwrap(),
         }
     }
 }
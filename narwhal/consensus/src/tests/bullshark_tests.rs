@@ -16,22 +16,40 @@ use test_utils::CommitteeFixture;
 #[allow(unused_imports)]
 use tokio::sync::mpsc::channel;
 use tokio::sync::watch;
-use types::{CertificateDigest, ReconfigureNotification};
+use types::{CertificateDigest, LeaderSchedule, ReconfigureNotification, ReputationScores};
 
 // TODO: duplicated in tusk_tests.rs
 pub fn make_consensus_store(store_path: &std::path::Path) -> Arc<ConsensusStore> {
     const LAST_COMMITTED_CF: &str = "last_committed";
     const SEQUENCE_CF: &str = "sequence";
+    const REPUTATION_SCORES_CF: &str = "reputation_scores";
+    const LEADER_SCHEDULES_CF: &str = "leader_schedules";
 
-    let rocksdb = rocks::open_cf(store_path, None, &[LAST_COMMITTED_CF, SEQUENCE_CF])
-        .expect("Failed to create database");
+    let rocksdb = rocks::open_cf(
+        store_path,
+        None,
+        &[
+            LAST_COMMITTED_CF,
+            SEQUENCE_CF,
+            REPUTATION_SCORES_CF,
+            LEADER_SCHEDULES_CF,
+        ],
+    )
+    .expect("Failed to create database");
 
-    let (last_committed_map, sequence_map) = reopen!(&rocksdb,
+    let (last_committed_map, sequence_map, reputation_scores_map, leader_schedules_map) = reopen!(&rocksdb,
         LAST_COMMITTED_CF;<PublicKey, Round>,
-        SEQUENCE_CF;<SequenceNumber, CertificateDigest>
+        SEQUENCE_CF;<SequenceNumber, CertificateDigest>,
+        REPUTATION_SCORES_CF;<u64, ReputationScores>,
+        LEADER_SCHEDULES_CF;<Round, LeaderSchedule>
     );
 
-    Arc::new(ConsensusStore::new(last_committed_map, sequence_map))
+    Arc::new(ConsensusStore::new(
+        last_committed_map,
+        sequence_map,
+        reputation_scores_map,
+        leader_schedules_map,
+    ))
 }
 
 // TODO: dulpicated in tusk_tests.rs
@@ -573,3 +591,124 @@ async fn restart_with_new_committee() {
         handle.await.unwrap();
     }
 }
+
+// The leader schedule only swaps out authorities that scored less than half of the best score,
+// and never more than f of the stake.
+#[test]
+fn leader_schedule_from_scores() {
+    let fixture = CommitteeFixture::builder().build();
+    let committee = fixture.committee();
+    let mut keys: Vec<_> = fixture.authorities().map(|a| a.public_key()).collect();
+    keys.sort();
+
+    // Equal scores do not swap anyone out.
+    let mut scores = ReputationScores::default();
+    for key in &keys {
+        scores.add_score(key, 10);
+    }
+    assert_eq!(
+        LeaderSchedule::new(&committee, &scores),
+        LeaderSchedule::default()
+    );
+
+    // Only one of the three authorities without any score can be swapped out.
+    let mut scores = ReputationScores::default();
+    scores.add_score(&keys[3], 10);
+    let schedule = LeaderSchedule::new(&committee, &scores);
+    assert_eq!(schedule.bad_nodes, BTreeSet::from([keys[0].clone()]));
+    assert_eq!(schedule.good_nodes, vec![keys[3].clone()]);
+
+    // The leader of a bad node is replaced, the others are kept.
+    assert_eq!(schedule.leader(keys[0].clone(), 2), keys[3]);
+    assert_eq!(schedule.leader(keys[1].clone(), 2), keys[1]);
+}
+
+// Run for 5 dag rounds with one dead node, and a scoring window of 2 sub-dags. The commits of the
+// leaders of rounds 2 and 4 complete the window, and the dead node is swapped out as leader.
+#[test]
+fn leader_schedule_swaps_dead_node() {
+    let fixture = CommitteeFixture::builder().build();
+    let committee = fixture.committee();
+    let mut keys: Vec<_> = fixture.authorities().map(|a| a.public_key()).collect();
+    keys.sort(); // Ensure we don't remove the leader.
+    let dead = keys.pop().unwrap();
+
+    let genesis = Certificate::genesis(&committee)
+        .iter()
+        .map(|x| x.digest())
+        .collect::<BTreeSet<_>>();
+    let (certificates, _) =
+        test_utils::make_optimal_certificates(&committee, 1..=5, &genesis, &keys);
+
+    let store = make_consensus_store(&test_utils::temp_dir());
+    let gc_depth = 50;
+    let mut bullshark = Bullshark::new(committee.clone(), store.clone(), gc_depth);
+    bullshark.num_sub_dags_per_schedule = 2;
+    let metrics = Arc::new(ConsensusMetrics::new(&Registry::new()));
+    let mut state = ConsensusState::new(Certificate::genesis(&committee), metrics);
+
+    let mut consensus_index = 0;
+    for certificate in certificates {
+        let sequence = bullshark
+            .process_certificate(&mut state, consensus_index, certificate)
+            .unwrap();
+        consensus_index += sequence.len() as SequenceNumber;
+    }
+    assert_eq!(state.last_committed_round, 4);
+
+    let expected = LeaderSchedule {
+        bad_nodes: BTreeSet::from([dead]),
+        good_nodes: vec![keys[0].clone()],
+    };
+    assert_eq!(bullshark.leader_schedule, expected);
+    assert_eq!(bullshark.reputation_scores, ReputationScores::default());
+
+    // The schedule is persisted, and recovered on restart.
+    assert_eq!(
+        store.read_latest_leader_schedule().unwrap(),
+        Some((4, expected.clone()))
+    );
+    let bullshark = Bullshark::new(committee, store, gc_depth);
+    assert_eq!(bullshark.leader_schedule, expected);
+}
+
+// Run for 3 dag rounds in ideal conditions, with a leader schedule swapping out the leader. The
+// commit of round 2 should be led by its replacement.
+#[test]
+fn leader_schedule_elects_good_node() {
+    let fixture = CommitteeFixture::builder().build();
+    let committee = fixture.committee();
+    let mut keys: Vec<_> = fixture.authorities().map(|a| a.public_key()).collect();
+    keys.sort();
+
+    let genesis = Certificate::genesis(&committee)
+        .iter()
+        .map(|x| x.digest())
+        .collect::<BTreeSet<_>>();
+    let (certificates, _) =
+        test_utils::make_optimal_certificates(&committee, 1..=3, &genesis, &keys);
+
+    let store = make_consensus_store(&test_utils::temp_dir());
+    let mut bullshark = Bullshark::new(committee.clone(), store, 50);
+    bullshark.leader_schedule = LeaderSchedule {
+        bad_nodes: BTreeSet::from([keys[0].clone()]),
+        good_nodes: vec![keys[1].clone()],
+    };
+    let metrics = Arc::new(ConsensusMetrics::new(&Registry::new()));
+    let mut state = ConsensusState::new(Certificate::genesis(&committee), metrics);
+
+    let mut sequence = Vec::new();
+    for certificate in certificates {
+        sequence.extend(
+            bullshark
+                .process_certificate(&mut state, sequence.len() as SequenceNumber, certificate)
+                .unwrap(),
+        );
+    }
+
+    // The round 1 certificates, then the leader of round 2.
+    assert_eq!(sequence.len(), 5);
+    let leader = &sequence.last().unwrap().certificate;
+    assert_eq!(leader.round(), 2);
+    assert_eq!(leader.origin(), keys[1]);
+}
@@ -16,21 +16,39 @@ use test_utils::CommitteeFixture;
 #[allow(unused_imports)]
 use tokio::sync::mpsc::channel;
 use tokio::sync::watch;
-use types::{CertificateDigest, ReconfigureNotification};
+use types::{CertificateDigest, LeaderSchedule, ReconfigureNotification, ReputationScores};
 
 pub fn make_consensus_store(store_path: &std::path::Path) -> Arc<ConsensusStore> {
     const LAST_COMMITTED_CF: &str = "last_committed";
     const SEQUENCE_CF: &str = "sequence";
+    const REPUTATION_SCORES_CF: &str = "reputation_scores";
+    const LEADER_SCHEDULES_CF: &str = "leader_schedules";
 
-    let rocksdb = rocks::open_cf(store_path, None, &[LAST_COMMITTED_CF, SEQUENCE_CF])
-        .expect("Failed to create database");
+    let rocksdb = rocks::open_cf(
+        store_path,
+        None,
+        &[
+            LAST_COMMITTED_CF,
+            SEQUENCE_CF,
+            REPUTATION_SCORES_CF,
+            LEADER_SCHEDULES_CF,
+        ],
+    )
+    .expect("Failed to create database");
 
-    let (last_committed_map, sequence_map) = reopen!(&rocksdb,
+    let (last_committed_map, sequence_map, reputation_scores_map, leader_schedules_map) = reopen!(&rocksdb,
         LAST_COMMITTED_CF;<PublicKey, Round>,
-        SEQUENCE_CF;<SequenceNumber, CertificateDigest>
+        SEQUENCE_CF;<SequenceNumber, CertificateDigest>,
+        REPUTATION_SCORES_CF;<u64, ReputationScores>,
+        LEADER_SCHEDULES_CF;<Round, LeaderSchedule>
     );
 
-    Arc::new(ConsensusStore::new(last_committed_map, sequence_map))
+    Arc::new(ConsensusStore::new(
+        last_committed_map,
+        sequence_map,
+        reputation_scores_map,
+        leader_schedules_map,
+    ))
 }
 
 pub fn make_certificate_store(store_path: &std::path::Path) -> CertificateStore {
@@ -14,7 +14,7 @@ use tracing::info;
 use crate::metrics::ExecutorMetrics;
 use crate::notifier::Notifier;
 use async_trait::async_trait;
-use config::{Committee, SharedWorkerCache};
+use config::{Committee, Epoch, SharedWorkerCache};
 use consensus::ConsensusOutput;
 use crypto::PublicKey;
 use network::P2pNetwork;
@@ -28,7 +28,8 @@ use crate::subscriber::spawn_subscriber;
 use tokio::sync::oneshot;
 use tokio::{sync::watch, task::JoinHandle};
 use types::{
-    metered_channel, CertificateDigest, ConsensusStore, ReconfigureNotification, SequenceNumber,
+    metered_channel, CertificateDigest, ConsensusStore, ReconfigureNotification, Round,
+    SequenceNumber,
 };
 
 /// Convenience type representing a serialized transaction.
@@ -67,6 +68,7 @@ impl Executor {
         execution_state: State,
         tx_reconfigure: &watch::Sender<ReconfigureNotification>,
         rx_sequence: metered_channel::Receiver<ConsensusOutput>,
+        tx_executed_round: watch::Sender<(Epoch, Round)>,
         registry: &Registry,
         restored_consensus_output: Vec<ConsensusOutput>,
     ) -> SubscriberResult<Vec<JoinHandle<()>>>
@@ -94,7 +96,8 @@ impl Executor {
             restored_consensus_output,
         );
 
-        let notifier_handler = Notifier::spawn(rx_notifier, execution_state, arc_metrics);
+        let notifier_handler =
+            Notifier::spawn(rx_notifier, execution_state, tx_executed_round, arc_metrics);
 
         // Return the handle.
         info!("Consensus subscriber successfully started");
@@ -1,13 +1,14 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 use crate::{ExecutionIndices, ExecutionState, ExecutorMetrics};
+use config::Epoch;
 use consensus::ConsensusOutput;
 use fastcrypto::hash::Hash;
 use std::sync::Arc;
-use tokio::task::JoinHandle;
+use tokio::{sync::watch, task::JoinHandle};
 use tracing::debug;
 
-use types::{metered_channel, Batch};
+use types::{metered_channel, Batch, Round};
 
 #[derive(Clone, Debug)]
 pub struct BatchIndex {
@@ -19,6 +20,9 @@ pub struct BatchIndex {
 pub struct Notifier<State: ExecutionState> {
     rx_notifier: metered_channel::Receiver<(BatchIndex, Batch)>,
     callback: State,
+    /// Outputs the epoch and the highest round of the certificates whose batches have been
+    /// executed. Rounds start over with each epoch.
+    tx_executed_round: watch::Sender<(Epoch, Round)>,
     metrics: Arc<ExecutorMetrics>,
 }
 
@@ -26,11 +30,13 @@ impl<State: ExecutionState + Send + Sync + 'static> Notifier<State> {
     pub fn spawn(
         rx_notifier: metered_channel::Receiver<(BatchIndex, Batch)>,
         callback: State,
+        tx_executed_round: watch::Sender<(Epoch, Round)>,
         metrics: Arc<ExecutorMetrics>,
     ) -> JoinHandle<()> {
         let notifier = Notifier {
             rx_notifier,
             callback,
+            tx_executed_round,
             metrics,
         };
         tokio::spawn(notifier.run())
@@ -57,6 +63,15 @@ impl<State: ExecutionState + Send + Sync + 'static> Notifier<State> {
                     .await;
             }
             self.metrics.notifier_processed_bytes.inc_by(bytes as u64);
+
+            // The execution indices of the batch are persisted by the execution state, it is
+            // now safe to prune it from storage.
+            let certificate = &index.consensus_output.certificate;
+            let executed = (certificate.epoch(), certificate.round());
+            if executed > *self.tx_executed_round.borrow() {
+                // Ignore the error when the receivers dropped.
+                let _ = self.tx_executed_round.send(executed);
+            }
         }
     }
 }
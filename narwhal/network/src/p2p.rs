@@ -10,6 +10,7 @@ use anemo::PeerId;
 use anyhow::format_err;
 use anyhow::Result;
 use async_trait::async_trait;
+use config::BatchCompression;
 use crypto::{traits::KeyPair, NetworkPublicKey};
 use rand::{rngs::SmallRng, SeedableRng as _};
 use std::collections::HashMap;
@@ -34,6 +35,8 @@ pub struct P2pNetwork {
     rng: SmallRng,
     // One bounded executor per address
     executors: HashMap<PeerId, BoundedExecutor>,
+    // The compression requested for the batches fetched from workers.
+    batch_compression: BatchCompression,
 }
 
 impl P2pNetwork {
@@ -49,9 +52,16 @@ impl P2pNetwork {
             retry_config,
             rng: SmallRng::from_entropy(),
             executors: HashMap::new(),
+            batch_compression: BatchCompression::default(),
         }
     }
 
+    /// Set the compression requested for the batches fetched from workers.
+    pub fn with_batch_compression(mut self, batch_compression: BatchCompression) -> Self {
+        self.batch_compression = batch_compression;
+        self
+    }
+
     // Creates a new single-use anemo::Network to connect outbound to a single
     // address. This is for tests and should not be used from worker code.
     pub async fn new_for_single_address(
@@ -354,8 +364,11 @@ impl UnreliableNetwork<WorkerBatchMessage> for P2pNetwork {
         message: &WorkerBatchMessage,
     ) -> Result<JoinHandle<Result<anemo::Response<()>>>> {
         let message = message.to_owned();
-        let f =
-            move |peer| async move { WorkerToWorkerClient::new(peer).report_batch(message).await };
+        let f = move |peer| async move {
+            WorkerToWorkerClient::new(peer)
+                .report_batch_or_legacy(message, None)
+                .await
+        };
         self.unreliable_send(peer, f)
     }
 }
@@ -371,7 +384,11 @@ impl ReliableNetwork<WorkerBatchMessage> for P2pNetwork {
         let message = message.to_owned();
         let f = move |peer| {
             let message = message.clone();
-            async move { WorkerToWorkerClient::new(peer).report_batch(message).await }
+            async move {
+                WorkerToWorkerClient::new(peer)
+                    .report_batch_or_legacy(message, None)
+                    .await
+            }
         };
 
         self.send(peer, f).await
@@ -388,7 +405,7 @@ impl UnreliableNetwork<WorkerBatchRequest> for P2pNetwork {
         let message = message.to_owned();
         let f = move |peer| async move {
             WorkerToWorkerClient::new(peer)
-                .request_batches(message)
+                .request_batches_or_legacy(message, None)
                 .await
         };
         self.unreliable_send(peer, f)
@@ -433,13 +450,20 @@ impl WorkerRpc for P2pNetwork {
             .network
             .peer(peer_id)
             .ok_or_else(|| format_err!("Network has no connection with peer {peer_id}"))?;
-        let request =
-            anemo::Request::new(RequestBatchRequest { batch }).with_timeout(BATCH_REQUEST_TIMEOUT);
+        let request = RequestBatchRequest {
+            batch,
+            compression: self.batch_compression,
+        };
         let response = WorkerToWorkerClient::new(peer)
-            .request_batch(request)
+            .request_batch_or_legacy(request, Some(BATCH_REQUEST_TIMEOUT))
             .await
             .map_err(|e| format_err!("Network error {:?}", e))?;
-        Ok(response.into_body().batch)
+        response
+            .into_body()
+            .batch
+            .map(|batch| batch.decompress())
+            .transpose()
+            .map_err(|e| format_err!("Invalid batch {batch}: {e}"))
     }
 }
 
@@ -456,7 +480,7 @@ impl ReliableNetwork<WorkerBatchRequest> for P2pNetwork {
             let message = message.clone();
             async move {
                 WorkerToWorkerClient::new(peer)
-                    .request_batches(message)
+                    .request_batches_or_legacy(message, None)
                     .await
             }
         };
@@ -1,6 +1,6 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
-use config::{Parameters, SharedCommittee, SharedWorkerCache, WorkerId};
+use config::{Epoch, Parameters, SharedCommittee, SharedWorkerCache, WorkerId};
 use consensus::{
     bullshark::Bullshark,
     dag::Dag,
@@ -19,7 +19,7 @@ use std::sync::Arc;
 use storage::{CertificateStore, ProposerKey, ProposerStore};
 use store::{
     reopen,
-    rocks::{open_cf, DBMap},
+    rocks::{open_cf_opts, open_cf_opts_secondary, DBMap},
     Store,
 };
 use tokio::sync::oneshot;
@@ -27,12 +27,14 @@ use tokio::{sync::watch, task::JoinHandle};
 use tracing::{debug, info};
 use types::{
     metered_channel, Batch, BatchDigest, Certificate, CertificateDigest, ConsensusStore, Header,
-    HeaderDigest, ReconfigureNotification, Round, RoundVoteDigestPair, SequenceNumber,
+    HeaderDigest, LeaderSchedule, ReconfigureNotification, ReputationScores, Round,
+    RoundVoteDigestPair, SequenceNumber,
 };
-use worker::{metrics::initialise_metrics, Worker};
+use worker::{metrics::initialise_metrics, TransactionValidator, Worker};
 
 pub mod execution_state;
 pub mod metrics;
+pub mod replay;
 pub mod restarter;
 
 /// All the data stores of the node.
@@ -59,29 +61,59 @@ impl NodeStorage {
     const BATCHES_CF: &'static str = "batches";
     const LAST_COMMITTED_CF: &'static str = "last_committed";
     const SEQUENCE_CF: &'static str = "sequence";
+    const REPUTATION_SCORES_CF: &'static str = "reputation_scores";
+    const LEADER_SCHEDULES_CF: &'static str = "leader_schedules";
     const TEMP_BATCH_CF: &'static str = "temp_batches";
 
+    /// All the datastore column families.
+    const COLUMN_FAMILIES: [&'static str; 13] = [
+        Self::LAST_PROPOSED_CF,
+        Self::VOTES_CF,
+        Self::HEADERS_CF,
+        Self::CERTIFICATES_CF,
+        Self::CERTIFICATE_ID_BY_ROUND_CF,
+        Self::CERTIFICATE_ID_BY_ORIGIN_CF,
+        Self::PAYLOAD_CF,
+        Self::BATCHES_CF,
+        Self::LAST_COMMITTED_CF,
+        Self::SEQUENCE_CF,
+        Self::REPUTATION_SCORES_CF,
+        Self::LEADER_SCHEDULES_CF,
+        Self::TEMP_BATCH_CF,
+    ];
+
     /// Open or reopen all the storage of the node.
     pub fn reopen<Path: AsRef<std::path::Path>>(store_path: Path) -> Self {
-        let rocksdb = open_cf(
-            store_path,
-            None,
-            &[
-                Self::LAST_PROPOSED_CF,
-                Self::VOTES_CF,
-                Self::HEADERS_CF,
-                Self::CERTIFICATES_CF,
-                Self::CERTIFICATE_ID_BY_ROUND_CF,
-                Self::CERTIFICATE_ID_BY_ORIGIN_CF,
-                Self::PAYLOAD_CF,
-                Self::BATCHES_CF,
-                Self::LAST_COMMITTED_CF,
-                Self::SEQUENCE_CF,
-                Self::TEMP_BATCH_CF,
-            ],
-        )
-        .expect("Cannot open database");
+        // Batches hold many repeated addresses and type tags, they are stored compressed.
+        let options = rocksdb::Options::default();
+        let mut batch_options = rocksdb::Options::default();
+        batch_options.set_compression_type(rocksdb::DBCompressionType::Zstd);
+        let column_families: Vec<_> = Self::COLUMN_FAMILIES
+            .iter()
+            .map(|cf| match *cf {
+                Self::BATCHES_CF | Self::TEMP_BATCH_CF => (*cf, &batch_options),
+                _ => (*cf, &options),
+            })
+            .collect();
+        let rocksdb =
+            open_cf_opts(store_path, None, &column_families).expect("Cannot open database");
+        Self::from_rocksdb(&rocksdb)
+    }
+
+    /// Open the storage of a node as a read-only secondary instance, which may be done while the
+    /// node is running. The stores reflect the state of the node when they are opened.
+    pub fn reopen_read_only<Path: AsRef<std::path::Path>>(store_path: Path) -> Self {
+        let options = rocksdb::Options::default();
+        let column_families: Vec<_> = Self::COLUMN_FAMILIES
+            .iter()
+            .map(|cf| (*cf, &options))
+            .collect();
+        let rocksdb = open_cf_opts_secondary(store_path, None, None, &column_families)
+            .expect("Cannot open database");
+        Self::from_rocksdb(&rocksdb)
+    }
 
+    fn from_rocksdb(rocksdb: &Arc<rocksdb::DBWithThreadMode<rocksdb::MultiThreaded>>) -> Self {
         let (
             last_proposed_map,
             votes_map,
@@ -93,8 +125,10 @@ impl NodeStorage {
             batch_map,
             last_committed_map,
             sequence_map,
+            reputation_scores_map,
+            leader_schedules_map,
             temp_batch_map,
-        ) = reopen!(&rocksdb,
+        ) = reopen!(rocksdb,
             Self::LAST_PROPOSED_CF;<ProposerKey, Header>,
             Self::VOTES_CF;<PublicKey, RoundVoteDigestPair>,
             Self::HEADERS_CF;<HeaderDigest, Header>,
@@ -105,6 +139,8 @@ impl NodeStorage {
             Self::BATCHES_CF;<BatchDigest, Batch>,
             Self::LAST_COMMITTED_CF;<PublicKey, Round>,
             Self::SEQUENCE_CF;<SequenceNumber, CertificateDigest>,
+            Self::REPUTATION_SCORES_CF;<u64, ReputationScores>,
+            Self::LEADER_SCHEDULES_CF;<Round, LeaderSchedule>,
             Self::TEMP_BATCH_CF;<(CertificateDigest, BatchDigest), Batch>
         );
 
@@ -118,7 +154,12 @@ impl NodeStorage {
         );
         let payload_store = Store::new(payload_map);
         let batch_store = Store::new(batch_map);
-        let consensus_store = Arc::new(ConsensusStore::new(last_committed_map, sequence_map));
+        let consensus_store = Arc::new(ConsensusStore::new(
+            last_committed_map,
+            sequence_map,
+            reputation_scores_map,
+            leader_schedules_map,
+        ));
         let temp_batch_store = Store::new(temp_batch_map);
 
         Self {
@@ -194,15 +235,17 @@ impl Node {
         let name = keypair.public().clone();
         let mut handles = Vec::new();
         let (rx_executor_network, tx_executor_network) = oneshot::channel();
-        let (dag, network_model) = if !internal_consensus {
+        let (dag, network_model, rx_executed_round) = if !internal_consensus {
             debug!("Consensus is disabled: the primary will run w/o Bullshark");
             let consensus_metrics = Arc::new(ConsensusMetrics::new(registry));
             let (handle, dag) = Dag::new(&committee.load(), rx_new_certificates, consensus_metrics);
 
             handles.push(handle);
 
-            (Some(Arc::new(dag)), NetworkModel::Asynchronous)
+            (Some(Arc::new(dag)), NetworkModel::Asynchronous, None)
         } else {
+            let (tx_executed_round, rx_executed_round) = watch::channel((0, 0));
+
             let consensus_handles = Self::spawn_consensus(
                 name.clone(),
                 tx_executor_network,
@@ -214,12 +257,17 @@ impl Node {
                 &tx_reconfigure,
                 rx_new_certificates,
                 tx_committed_certificates.clone(),
+                tx_executed_round,
                 registry,
             )
             .await?;
 
             handles.extend(consensus_handles);
-            (None, NetworkModel::PartiallySynchronous)
+            (
+                None,
+                NetworkModel::PartiallySynchronous,
+                Some(rx_executed_round),
+            )
         };
 
         // Inject memory profiling here if we build with dhat-heap feature flag
@@ -259,6 +307,7 @@ impl Node {
             tx_committed_certificates,
             registry,
             Some(rx_executor_network),
+            rx_executed_round,
         );
         handles.extend(primary_handles);
 
@@ -294,6 +343,7 @@ impl Node {
         tx_reconfigure: &watch::Sender<ReconfigureNotification>,
         rx_new_certificates: metered_channel::Receiver<Certificate>,
         tx_committed_certificates: metered_channel::Sender<Certificate>,
+        tx_executed_round: watch::Sender<(Epoch, Round)>,
         registry: &Registry,
     ) -> SubscriberResult<Vec<JoinHandle<()>>>
     where
@@ -357,6 +407,7 @@ impl Node {
             execution_state,
             tx_reconfigure,
             rx_sequence,
+            tx_executed_round,
             registry,
             restored_consensus_output,
         )?;
@@ -381,6 +432,8 @@ impl Node {
         store: &NodeStorage,
         // The configuration parameters.
         parameters: Parameters,
+        // The transaction validator defining Tx acceptance,
+        tx_validator: impl TransactionValidator,
         // The prometheus metrics Registry
         registry: &Registry,
     ) -> Vec<JoinHandle<()>> {
@@ -397,6 +450,7 @@ impl Node {
                 worker_cache.clone(),
                 parameters.clone(),
                 store.batch_store.clone(),
+                tx_validator.clone(),
                 metrics.clone(),
             );
             handles.extend(worker_handles);
@@ -20,10 +20,11 @@ use narwhal_node as node;
 use node::{
     execution_state::SimpleExecutionState,
     metrics::{primary_metrics_registry, start_prometheus_server, worker_metrics_registry},
+    replay::{replay_consensus_output, verify_consensus_output},
     Node, NodeStorage,
 };
 use prometheus::Registry;
-use std::sync::Arc;
+use std::{io::Write, sync::Arc};
 use telemetry_subscribers::TelemetryGuards;
 use tokio::sync::mpsc::{channel, Receiver};
 use tracing::info;
@@ -31,6 +32,8 @@ use tracing::info;
 use tracing::subscriber::set_global_default;
 #[cfg(feature = "benchmark")]
 use tracing_subscriber::filter::{EnvFilter, LevelFilter};
+use types::SequenceNumber;
+use worker::TrivialTransactionValidator;
 
 #[cfg(feature = "dhat-heap")]
 #[global_allocator]
@@ -73,6 +76,18 @@ async fn main() -> Result<(), eyre::Report> {
                 )
                 .setting(AppSettings::SubcommandRequiredElseHelp),
         )
+        .subcommand(
+            SubCommand::with_name("replay")
+                .about("Print the consensus output recorded in the store of a primary")
+                .args_from_usage("--committee=<FILE> 'The file containing committee information'")
+                .args_from_usage("--parameters=[FILE] 'The file containing the node parameters'")
+                .args_from_usage("--store=<PATH> 'The path of the data store of the primary'")
+                .args_from_usage("--worker-store=[PATH]... 'The path of the data store of a worker, to resolve the batches'")
+                .args_from_usage("--from=[INDEX] 'The first consensus index to print'")
+                .args_from_usage("--to=[INDEX] 'The last consensus index to print'")
+                .args_from_usage("--format=[FORMAT] 'The output format, either json (default, one line per index) or bcs (one value per index)'")
+                .args_from_usage("--verify 'Check that Bullshark sequences the stored DAG the same way again'"),
+        )
         .setting(AppSettings::SubcommandRequiredElseHelp)
         .get_matches();
 
@@ -148,6 +163,10 @@ async fn main() -> Result<(), eyre::Report> {
             )
             .await?
         }
+        ("replay", Some(sub_matches)) => {
+            let _guard = setup_telemetry(tracing_level, network_tracing_level, None);
+            replay(sub_matches).await?
+        }
         _ => unreachable!(),
     }
     Ok(())
@@ -271,6 +290,7 @@ async fn run(
                 worker_cache,
                 &store,
                 parameters.clone(),
+                TrivialTransactionValidator::default(),
                 &registry,
             )
         }
@@ -295,6 +315,69 @@ async fn run(
     Ok(())
 }
 
+// Prints the consensus output recorded in the store of a primary, and optionally checks it.
+async fn replay(matches: &ArgMatches<'_>) -> Result<(), eyre::Report> {
+    let committee_file = matches.value_of("committee").unwrap();
+    let parameters_file = matches.value_of("parameters");
+    let store_path = matches.value_of("store").unwrap();
+
+    let committee =
+        Committee::import(committee_file).context("Failed to load the committee information")?;
+    let parameters = match parameters_file {
+        Some(filename) => {
+            Parameters::import(filename).context("Failed to load the node's parameters")?
+        }
+        None => Parameters::default(),
+    };
+    let from = matches
+        .value_of("from")
+        .map(str::parse::<SequenceNumber>)
+        .transpose()
+        .context("The consensus index must be a positive integer")?
+        .unwrap_or(0);
+    let to = matches
+        .value_of("to")
+        .map(str::parse::<SequenceNumber>)
+        .transpose()
+        .context("The consensus index must be a positive integer")?
+        .unwrap_or(SequenceNumber::MAX);
+
+    // Open the stores as secondary instances, so that the nodes may keep running.
+    let store = NodeStorage::reopen_read_only(store_path);
+    let worker_stores: Vec<_> = matches
+        .values_of("worker-store")
+        .map(|paths| paths.map(NodeStorage::reopen_read_only).collect())
+        .unwrap_or_default();
+
+    let as_bcs = match matches.value_of("format").unwrap_or("json") {
+        "json" => false,
+        "bcs" => true,
+        format => eyre::bail!("Unknown output format: {format}"),
+    };
+    // Every output is written as soon as it is read, one JSON document per line or one BCS value
+    // after the other.
+    let mut stdout = std::io::stdout().lock();
+    replay_consensus_output(&store, &worker_stores, from..=to, |output| {
+        if as_bcs {
+            stdout.write_all(&bcs::to_bytes(&output)?)?;
+        } else {
+            serde_json::to_writer(&mut stdout, &output)?;
+            writeln!(stdout)?;
+        }
+        Ok(())
+    })
+    .await?;
+    stdout.flush()?;
+
+    if matches.is_present("verify") {
+        if let Some(mismatch) = verify_consensus_output(committee, &store, parameters.gc_depth)? {
+            eyre::bail!("{mismatch}");
+        }
+        eprintln!("Bullshark sequences the stored DAG as recorded by consensus");
+    }
+    Ok(())
+}
+
 /// Receives an ordered list of certificates and apply any application-specific logic.
 async fn analyze(mut rx_output: Receiver<SerializedTransaction>) {
     while let Some(_message) = rx_output.recv().await {
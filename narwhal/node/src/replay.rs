@@ -0,0 +1,184 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tools to read back the output of consensus recorded in the storage of a primary, and to check
+//! that Bullshark sequences the stored DAG the same way again.
+use crate::NodeStorage;
+use config::{Committee, WorkerId};
+use consensus::{
+    bullshark::Bullshark,
+    consensus::{ConsensusProtocol, ConsensusState},
+    metrics::ConsensusMetrics,
+};
+use eyre::{bail, eyre, Context};
+use fastcrypto::hash::Hash;
+use prometheus::Registry;
+use serde::{Deserialize, Serialize};
+use std::{fmt, ops::RangeInclusive, sync::Arc};
+use types::{Batch, BatchDigest, Certificate, CertificateDigest, Round, SequenceNumber};
+
+/// A certificate sequenced by consensus, along with the batches of its payload.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplayedOutput {
+    /// The consensus index of the certificate.
+    pub consensus_index: SequenceNumber,
+    /// The sequenced certificate.
+    pub certificate: Certificate,
+    /// The batches of the payload of the certificate and the worker that created them. A batch is
+    /// `None` if it could not be found in any of the stores.
+    pub batches: Vec<(BatchDigest, WorkerId, Option<Batch>)>,
+}
+
+/// The first consensus index at which the stored consensus sequence differs from the one obtained
+/// by running Bullshark again over the stored DAG.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SequenceMismatch {
+    /// The consensus index of the mismatch.
+    pub consensus_index: SequenceNumber,
+    /// The certificate stored at this index.
+    pub stored: CertificateDigest,
+    /// The certificate sequenced at this index by the replay, if any.
+    pub replayed: Option<CertificateDigest>,
+}
+
+impl fmt::Display for SequenceMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.replayed {
+            Some(replayed) => write!(
+                f,
+                "Consensus index {} holds certificate {} but Bullshark sequenced {}",
+                self.consensus_index, self.stored, replayed
+            ),
+            None => write!(
+                f,
+                "Consensus index {} holds certificate {} but Bullshark did not sequence that far",
+                self.consensus_index, self.stored
+            ),
+        }
+    }
+}
+
+/// Read back the certificates sequenced by consensus at the consensus indices of `range`, in
+/// order, and resolve their batches from the store of the primary (which its workers share when
+/// they run in the same process) or else from the stores of the workers. Every output is handed
+/// to `sink` as soon as it is read, so that the whole sequence is never held in memory.
+pub async fn replay_consensus_output<F>(
+    primary_store: &NodeStorage,
+    worker_stores: &[NodeStorage],
+    range: RangeInclusive<SequenceNumber>,
+    mut sink: F,
+) -> eyre::Result<()>
+where
+    F: FnMut(ReplayedOutput) -> eyre::Result<()>,
+{
+    let mut next = *range.start();
+    while next <= *range.end() {
+        // Look up one consensus index at a time, as the store is not read across the awaits below.
+        let entry = primary_store
+            .consensus_store
+            .read_sequence(&(next..=*range.end()))
+            .context("Failed to read the consensus sequence")?
+            .next();
+        let (consensus_index, digest) = match entry {
+            Some(entry) => entry,
+            None => break,
+        };
+
+        let certificate = primary_store
+            .certificate_store
+            .read(digest)
+            .context("Failed to read a sequenced certificate")?
+            .ok_or_else(|| {
+                eyre!(
+                    "Certificate {digest} sequenced at index {consensus_index} is missing from the store (pruned?)"
+                )
+            })?;
+
+        let mut batches = Vec::with_capacity(certificate.header.payload.len());
+        for (batch_digest, worker_id) in &certificate.header.payload {
+            let mut batch = None;
+            for store in std::iter::once(primary_store).chain(worker_stores) {
+                batch = store
+                    .batch_store
+                    .read(*batch_digest)
+                    .await
+                    .context("Failed to read a batch")?;
+                if batch.is_some() {
+                    break;
+                }
+            }
+            batches.push((*batch_digest, *worker_id, batch));
+        }
+
+        sink(ReplayedOutput {
+            consensus_index,
+            certificate,
+            batches,
+        })?;
+
+        next = match consensus_index.checked_add(1) {
+            Some(next) => next,
+            None => break,
+        };
+    }
+    Ok(())
+}
+
+/// Run Bullshark again over the certificates of the current epoch held by the store, and compare
+/// the resulting sequence with the one recorded by consensus. Returns the first mismatch, if any.
+/// This requires the DAG to be stored in full since genesis.
+pub fn verify_consensus_output(
+    committee: Committee,
+    store: &NodeStorage,
+    gc_depth: Round,
+) -> eyre::Result<Option<SequenceMismatch>> {
+    // The certificates are returned by increasing round, so that the parents of every certificate
+    // are processed before it.
+    let certificates: Vec<_> = store
+        .certificate_store
+        .after_round(1)
+        .context("Failed to read the stored certificates")?
+        .into_iter()
+        .filter(|certificate| certificate.epoch() == committee.epoch())
+        .collect();
+    if let Some(certificate) = certificates.first() {
+        if certificate.round() > 1 {
+            bail!(
+                "The certificates below round {} are missing from the store (pruned?), consensus cannot be replayed from genesis",
+                certificate.round()
+            );
+        }
+    }
+
+    // Bullshark persists its state as it commits, give it a scratch store.
+    let scratch = tempfile::tempdir().context("Failed to create a scratch store")?;
+    let scratch_store = NodeStorage::reopen(scratch.path());
+    let mut bullshark = Bullshark::new(
+        committee.clone(),
+        scratch_store.consensus_store.clone(),
+        gc_depth,
+    );
+    let metrics = Arc::new(ConsensusMetrics::new(&Registry::new()));
+    let mut state = ConsensusState::new(Certificate::genesis(&committee), metrics);
+
+    let mut replayed = Vec::new();
+    for certificate in certificates {
+        let sequence = bullshark
+            .process_certificate(&mut state, replayed.len() as SequenceNumber, certificate)
+            .context("Failed to run consensus")?;
+        replayed.extend(sequence.iter().map(|output| output.certificate.digest()));
+    }
+
+    let mut stored = store
+        .consensus_store
+        .read_sequence(&(0..=SequenceNumber::MAX))
+        .context("Failed to read the consensus sequence")?;
+    Ok(stored.find_map(|(consensus_index, digest)| {
+        let replayed = replayed.get(consensus_index as usize).copied();
+        (replayed != Some(digest)).then_some(SequenceMismatch {
+            consensus_index,
+            stored: digest,
+            replayed,
+        })
+    }))
+}
@@ -11,13 +11,14 @@ use prometheus::Registry;
 use std::{path::PathBuf, sync::Arc};
 use tokio::sync::mpsc::Receiver;
 use types::ReconfigureNotification;
+use worker::TransactionValidator;
 
 // Module to start a node (primary, workers and default consensus), keep it running, and restarting it
 /// every time the committee changes.
 pub struct NodeRestarter;
 
 impl NodeRestarter {
-    pub async fn watch<State>(
+    pub async fn watch<State, TxValidator>(
         primary_keypair: KeyPair,
         primary_network_keypair: NetworkKeyPair,
         worker_ids_and_keypairs: Vec<(WorkerId, NetworkKeyPair)>,
@@ -26,6 +27,7 @@ impl NodeRestarter {
         storage_base_path: PathBuf,
         execution_state: Arc<State>,
         parameters: Parameters,
+        tx_validator: TxValidator,
         mut rx_reconfigure: Receiver<(
             KeyPair,
             NetworkKeyPair,
@@ -36,6 +38,7 @@ impl NodeRestarter {
         registry: &Registry,
     ) where
         State: ExecutionState + Send + Sync + 'static,
+        TxValidator: TransactionValidator,
     {
         let mut primary_keypair = primary_keypair;
         let mut primary_network_keypair = primary_network_keypair;
@@ -76,6 +79,7 @@ impl NodeRestarter {
                 worker_cache.clone(),
                 &store,
                 parameters.clone(),
+                tx_validator.clone(),
                 registry,
             );
 
@@ -0,0 +1,109 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use consensus::{
+    bullshark::Bullshark,
+    consensus::{ConsensusProtocol, ConsensusState},
+    metrics::ConsensusMetrics,
+};
+use fastcrypto::hash::Hash;
+use narwhal_node::{
+    replay::{replay_consensus_output, verify_consensus_output, SequenceMismatch},
+    NodeStorage,
+};
+use prometheus::Registry;
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
+use test_utils::{fixture_batch_with_transactions, temp_dir, CommitteeFixture};
+use types::{Certificate, CertificateDigest, SequenceNumber};
+
+#[tokio::test]
+async fn replay_and_verify_consensus_output() {
+    let fixture = CommitteeFixture::builder().build();
+    let committee = fixture.committee();
+    let keys: Vec<_> = fixture.authorities().map(|a| a.public_key()).collect();
+    let store = NodeStorage::reopen(temp_dir());
+    let worker_store = NodeStorage::reopen(temp_dir());
+    let gc_depth = 50;
+
+    // Sequence 5 rounds of certificates, storing them as the primary and consensus do.
+    let genesis = Certificate::genesis(&committee)
+        .iter()
+        .map(|x| x.digest())
+        .collect::<BTreeSet<_>>();
+    let (certificates, _) =
+        test_utils::make_optimal_certificates(&committee, 1..=5, &genesis, &keys);
+    let mut bullshark = Bullshark::new(committee.clone(), store.consensus_store.clone(), gc_depth);
+    let metrics = Arc::new(ConsensusMetrics::new(&Registry::new()));
+    let mut state = ConsensusState::new(Certificate::genesis(&committee), metrics);
+    let mut sequence = Vec::new();
+    for certificate in certificates {
+        store.certificate_store.write(certificate.clone()).unwrap();
+        let output = bullshark
+            .process_certificate(&mut state, sequence.len() as SequenceNumber, certificate)
+            .unwrap();
+        sequence.extend(output);
+    }
+    assert!(sequence.len() > 2);
+
+    // Only the batch of the first sequenced certificate is held by the worker.
+    let first = &sequence[0].certificate;
+    let (batch_digest, _) = first.header.payload.iter().next().unwrap();
+    let batch = fixture_batch_with_transactions(10);
+    worker_store
+        .batch_store
+        .write(*batch_digest, batch.clone())
+        .await;
+
+    // Read back the whole sequence.
+    let mut outputs = Vec::new();
+    replay_consensus_output(&store, &[worker_store], 0..=SequenceNumber::MAX, |output| {
+        outputs.push(output);
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(outputs.len(), sequence.len());
+    for (output, expected) in outputs.iter().zip(&sequence) {
+        assert_eq!(output.consensus_index, expected.consensus_index);
+        assert_eq!(output.certificate.digest(), expected.certificate.digest());
+        assert_eq!(
+            output.batches.len(),
+            expected.certificate.header.payload.len()
+        );
+    }
+    assert_eq!(outputs[0].batches[0].2, Some(batch));
+    assert_eq!(outputs[1].batches[0].2, None);
+
+    // Read back a part of the sequence.
+    let mut indices = Vec::new();
+    replay_consensus_output(&store, &[], 1..=2, |output| {
+        indices.push(output.consensus_index);
+        Ok(())
+    })
+    .await
+    .unwrap();
+    assert_eq!(indices, vec![1, 2]);
+
+    // Bullshark sequences the stored DAG the same way again.
+    assert_eq!(
+        verify_consensus_output(committee.clone(), &store, gc_depth).unwrap(),
+        None
+    );
+
+    // Record a different certificate at index 1, as a forked validator would.
+    let forked = CertificateDigest::default();
+    store
+        .consensus_store
+        .write_consensus_state(&HashMap::new(), &2, &forked)
+        .unwrap();
+    assert_eq!(
+        verify_consensus_output(committee, &store, gc_depth).unwrap(),
+        Some(SequenceMismatch {
+            consensus_index: 1,
+            stored: forked,
+            replayed: Some(sequence[1].certificate.digest()),
+        })
+    );
+}
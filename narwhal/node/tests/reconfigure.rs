@@ -24,6 +24,7 @@ use tokio::{
     time::{interval, sleep, Duration, MissedTickBehavior},
 };
 use types::{ReconfigureNotification, TransactionProto, TransactionsClient};
+use worker::TrivialTransactionValidator;
 
 /// A simple/dumb execution engine.
 struct SimpleExecutionState {
@@ -208,6 +209,7 @@ async fn restart() {
                 /* base_store_path */ test_utils::temp_dir(),
                 execution_state,
                 parameters,
+                TrivialTransactionValidator::default(),
                 rx_node_reconfigure,
                 &Registry::new(),
             )
@@ -333,6 +335,7 @@ async fn epoch_change() {
             worker_cache.clone(),
             &store,
             p,
+            TrivialTransactionValidator::default(),
             &Registry::new(),
         );
 
@@ -19,6 +19,7 @@ mod header_waiter;
 mod helper;
 mod primary;
 mod proposer;
+mod pruner;
 mod state_handler;
 mod synchronizer;
 mod utils;
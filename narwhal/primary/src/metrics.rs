@@ -395,6 +395,12 @@ pub struct PrimaryMetrics {
     pub votes_dropped_equivocation_protection: IntCounterVec,
     /// Number of pending batches in proposer
     pub num_of_pending_batches_in_proposer: IntGaugeVec,
+    /// All the rounds below this one have been pruned from storage
+    pub pruned_round: IntGaugeVec,
+    /// Number of certificates pruned from storage
+    pub pruned_certificates: IntCounterVec,
+    /// Number of batches pruned from storage
+    pub pruned_batches: IntCounterVec,
 }
 
 impl PrimaryMetrics {
@@ -553,7 +559,28 @@ impl PrimaryMetrics {
                 "Number of batch digests pending in proposer for next header proposal",
                 &["epoch"],
                 registry
-            ).unwrap()
+            ).unwrap(),
+            pruned_round: register_int_gauge_vec_with_registry!(
+                "pruned_round",
+                "All the rounds below this one have been pruned from storage",
+                &["epoch"],
+                registry
+            )
+            .unwrap(),
+            pruned_certificates: register_int_counter_vec_with_registry!(
+                "pruned_certificates",
+                "Number of certificates pruned from storage, along with their headers",
+                &["epoch"],
+                registry
+            )
+            .unwrap(),
+            pruned_batches: register_int_counter_vec_with_registry!(
+                "pruned_batches",
+                "Number of batches pruned from storage",
+                &["epoch"],
+                registry
+            )
+            .unwrap(),
         }
     }
 }
@@ -13,6 +13,7 @@ use crate::{
     helper::Helper,
     metrics::initialise_metrics,
     proposer::Proposer,
+    pruner::Pruner,
     state_handler::StateHandler,
     synchronizer::Synchronizer,
     BlockRemover, CertificatesResponse, PayloadAvailabilityResponse,
@@ -25,7 +26,7 @@ use anemo_tower::{
     trace::TraceLayer,
 };
 use async_trait::async_trait;
-use config::{Parameters, SharedCommittee, SharedWorkerCache, WorkerId, WorkerInfo};
+use config::{Epoch, Parameters, SharedCommittee, SharedWorkerCache, WorkerId, WorkerInfo};
 use consensus::dag::Dag;
 use crypto::{KeyPair, NetworkKeyPair, PublicKey};
 use fastcrypto::{
@@ -53,7 +54,7 @@ use types::{
     error::DagError,
     metered_channel::{channel_with_total, Receiver, Sender},
     BatchDigest, Certificate, ConsensusStore, FetchCertificatesRequest, FetchCertificatesResponse,
-    Header, HeaderDigest, PrimaryToPrimary, PrimaryToPrimaryServer, ReconfigureNotification,
+    Header, HeaderDigest, PrimaryToPrimary, PrimaryToPrimaryServer, ReconfigureNotification, Round,
     RoundVoteDigestPair, WorkerInfoResponse, WorkerOthersBatchMessage, WorkerOurBatchMessage,
     WorkerToPrimary, WorkerToPrimaryServer,
 };
@@ -103,6 +104,9 @@ impl Primary {
         registry: &Registry,
         // See comments in Subscriber::spawn
         rx_executor_network: Option<oneshot::Sender<P2pNetwork>>,
+        // The epoch and highest round executed by the executor, if any. Storage is pruned as it
+        // advances.
+        rx_executed_round: Option<watch::Receiver<(Epoch, Round)>>,
     ) -> Vec<JoinHandle<()>> {
         // Write the parameters to the logs.
         parameters.tracing();
@@ -331,7 +335,8 @@ impl Primary {
         let signature_service = SignatureService::new(signer);
 
         if let Some(rx_executor_network) = rx_executor_network {
-            let executor_network = P2pNetwork::new(network.clone());
+            let executor_network = P2pNetwork::new(network.clone())
+                .with_batch_compression(parameters.batch_compression);
             if rx_executor_network.send(executor_network).is_err() {
                 panic!("Executor shut down before primary has a chance to start");
             }
@@ -445,7 +450,7 @@ impl Primary {
             rx_parents,
             rx_our_digests,
             tx_headers,
-            node_metrics,
+            node_metrics.clone(),
         );
 
         // The `Helper` is dedicated to reply to certificates & payload availability requests
@@ -461,6 +466,24 @@ impl Primary {
             helper_primary_network,
         );
 
+        // Prunes from storage the rounds that have been executed long enough ago.
+        let pruner_handle = rx_executed_round.map(|rx_executed_round| {
+            Pruner::spawn(
+                name.clone(),
+                (**committee.load()).clone(),
+                worker_cache.clone(),
+                certificate_store.clone(),
+                header_store.clone(),
+                payload_store.clone(),
+                parameters.gc_depth,
+                parameters.gc_retention_rounds,
+                rx_executed_round,
+                tx_reconfigure.subscribe(),
+                P2pNetwork::new(network.clone()),
+                node_metrics.clone(),
+            )
+        });
+
         // Keeps track of the latest consensus round and allows other tasks to clean up their their internal state
         let state_handler_handle = StateHandler::spawn(
             name.clone(),
@@ -476,7 +499,8 @@ impl Primary {
         let consensus_api_handle = if !internal_consensus {
             // Retrieves a block's data by contacting the worker nodes that contain the
             // underlying batches and their transactions.
-            let block_waiter_primary_network = P2pNetwork::new(network.clone());
+            let block_waiter_primary_network = P2pNetwork::new(network.clone())
+                .with_batch_compression(parameters.batch_compression);
             let block_waiter = BlockWaiter::new(
                 name.clone(),
                 worker_cache.clone(),
@@ -539,6 +563,10 @@ impl Primary {
             handles.push(h);
         }
 
+        if let Some(h) = pruner_handle {
+            handles.push(h);
+        }
+
         handles
     }
 }
@@ -0,0 +1,224 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use crate::{metrics::PrimaryMetrics, utils, PayloadToken};
+use anyhow::Result;
+use config::{Committee, Epoch, SharedWorkerCache, WorkerId};
+use crypto::PublicKey;
+use fastcrypto::hash::Hash;
+use futures::future::try_join_all;
+use network::{P2pNetwork, PrimaryToWorkerRpc};
+use std::{cmp::min, sync::Arc};
+use storage::CertificateStore;
+use store::Store;
+use tokio::{sync::watch, task::JoinHandle};
+use tracing::{debug, info, warn};
+use types::{BatchDigest, CertificateDigest, Header, HeaderDigest, ReconfigureNotification, Round};
+
+#[cfg(test)]
+#[path = "tests/pruner_tests.rs"]
+pub mod pruner_tests;
+
+/// The maximum number of rounds pruned at once, to bound the size of each deletion.
+const MAX_PRUNED_ROUNDS: Round = 100;
+
+/// Prunes from storage the certificates, headers, payload markers and batches of the rounds that
+/// have been committed and executed more than `retention_rounds` ago.
+pub struct Pruner {
+    /// The public key of this primary.
+    name: PublicKey,
+    /// The committee information.
+    committee: Committee,
+    /// The worker information cache.
+    worker_cache: SharedWorkerCache,
+    /// Storage that keeps the Certificates by their digest id.
+    certificate_store: CertificateStore,
+    /// Storage that keeps the headers by their digest id.
+    header_store: Store<HeaderDigest, Header>,
+    /// The persistent storage for payload markers from workers.
+    payload_store: Store<(BatchDigest, WorkerId), PayloadToken>,
+    /// The number of executed rounds to keep in storage.
+    retention_rounds: Round,
+    /// Receives the epoch and the highest round of the certificates executed by the executor.
+    rx_executed_round: watch::Receiver<(Epoch, Round)>,
+    /// Watch channel to reconfigure the committee.
+    rx_reconfigure: watch::Receiver<ReconfigureNotification>,
+    /// Network driver allowing to send messages to our workers.
+    worker_network: P2pNetwork,
+    /// The metrics handler.
+    metrics: Arc<PrimaryMetrics>,
+    /// All the rounds below this one have been pruned.
+    pruned_round: Round,
+}
+
+impl Pruner {
+    #[must_use]
+    pub fn spawn(
+        name: PublicKey,
+        committee: Committee,
+        worker_cache: SharedWorkerCache,
+        certificate_store: CertificateStore,
+        header_store: Store<HeaderDigest, Header>,
+        payload_store: Store<(BatchDigest, WorkerId), PayloadToken>,
+        gc_depth: Round,
+        retention_rounds: Round,
+        rx_executed_round: watch::Receiver<(Epoch, Round)>,
+        rx_reconfigure: watch::Receiver<ReconfigureNotification>,
+        worker_network: P2pNetwork,
+        metrics: Arc<PrimaryMetrics>,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            Self {
+                name,
+                committee,
+                worker_cache,
+                certificate_store,
+                header_store,
+                payload_store,
+                // Consensus and the other primary tasks may still need the certificates of the
+                // last `gc_depth` rounds.
+                retention_rounds: retention_rounds.max(gc_depth),
+                rx_executed_round,
+                rx_reconfigure,
+                worker_network,
+                metrics,
+                pruned_round: 0,
+            }
+            .run()
+            .await;
+        })
+    }
+
+    async fn run(&mut self) {
+        info!(
+            "Pruner on node {} has started successfully, keeping {} rounds.",
+            self.name, self.retention_rounds
+        );
+        loop {
+            tokio::select! {
+                result = self.rx_executed_round.changed() => {
+                    if result.is_err() {
+                        debug!("Executor dropped, stop pruning");
+                        return;
+                    }
+                    let (epoch, executed_round) = *self.rx_executed_round.borrow();
+                    self.handle_executed_round(epoch, executed_round).await;
+                },
+
+                result = self.rx_reconfigure.changed() => {
+                    result.expect("Committee channel dropped");
+                    let message = self.rx_reconfigure.borrow().clone();
+                    if let ReconfigureNotification::Shutdown = message {
+                        return;
+                    }
+                    self.reconfigure(message);
+                    tracing::debug!("Committee updated to {}", self.committee);
+                }
+            }
+        }
+    }
+
+    /// Prunes the rounds executed more than `retention_rounds` before `executed_round`. The
+    /// executor may still report rounds of the previous epoch after a reconfiguration, and these
+    /// say nothing about the rounds of the current epoch, so they are ignored.
+    async fn handle_executed_round(&mut self, epoch: Epoch, executed_round: Round) {
+        if epoch != self.committee.epoch {
+            debug!("Ignoring round {executed_round} executed in epoch {epoch}");
+            return;
+        }
+        self.prune_up_to(executed_round.saturating_sub(self.retention_rounds))
+            .await;
+    }
+
+    fn reconfigure(&mut self, message: ReconfigureNotification) {
+        match message {
+            ReconfigureNotification::NewEpoch(new_committee) => {
+                // Rounds start over with the new epoch.
+                self.committee = new_committee;
+                self.pruned_round = 0;
+            }
+            ReconfigureNotification::UpdateCommittee(new_committee) => {
+                self.committee = new_committee;
+            }
+            ReconfigureNotification::Shutdown => (),
+        }
+    }
+
+    /// Prunes all the rounds below `target_round`, a few rounds at a time. Rounds that fail to be
+    /// pruned are retried the next time the executor makes progress.
+    async fn prune_up_to(&mut self, target_round: Round) {
+        while self.pruned_round < target_round {
+            let round = min(target_round, self.pruned_round + MAX_PRUNED_ROUNDS);
+            if let Err(e) = self.prune(round).await {
+                warn!("Failed to prune the rounds below {round}: {e}");
+                return;
+            }
+            self.pruned_round = round;
+            self.metrics
+                .pruned_round
+                .with_label_values(&[&self.committee.epoch.to_string()])
+                .set(round as i64);
+        }
+    }
+
+    /// Deletes all the certificates below `round`, along with their headers, payload markers and
+    /// the batches held by our workers.
+    async fn prune(&self, round: Round) -> Result<()> {
+        let certificates = self.certificate_store.before_round(round)?;
+        if certificates.is_empty() {
+            return Ok(());
+        }
+
+        // Send delete requests to individual workers.
+        let mut worker_requests = Vec::new();
+        let batches_by_worker = utils::map_certificate_batches_by_worker(&certificates);
+        for (worker_id, batch_ids) in batches_by_worker.iter() {
+            let worker_name = self
+                .worker_cache
+                .load()
+                .worker(&self.name, worker_id)
+                .expect("Worker id not found")
+                .name;
+            worker_requests.push(
+                self.worker_network
+                    .delete_batches(worker_name, batch_ids.clone()),
+            );
+        }
+        try_join_all(worker_requests).await?;
+
+        let header_ids: Vec<HeaderDigest> = certificates.iter().map(|c| c.header.id).collect();
+        self.header_store.remove_all(header_ids).await?;
+
+        let batches: Vec<(BatchDigest, WorkerId)> = batches_by_worker
+            .into_iter()
+            .flat_map(|(worker_id, batch_ids)| {
+                batch_ids
+                    .into_iter()
+                    .map(move |batch_id| (batch_id, worker_id))
+            })
+            .collect();
+        let num_batches = batches.len();
+        self.payload_store.remove_all(batches).await?;
+
+        // NOTE: delete certificates in the end since if we need to repeat the request
+        // we want to be able to find them in storage.
+        let certificate_ids: Vec<CertificateDigest> =
+            certificates.iter().map(|c| c.digest()).collect();
+        let num_certificates = certificate_ids.len();
+        self.certificate_store.delete_all(certificate_ids)?;
+
+        let epoch = self.committee.epoch.to_string();
+        self.metrics
+            .pruned_certificates
+            .with_label_values(&[&epoch])
+            .inc_by(num_certificates as u64);
+        self.metrics
+            .pruned_batches
+            .with_label_values(&[&epoch])
+            .inc_by(num_batches as u64);
+        debug!(
+            "Pruned {num_certificates} certificates and {num_batches} batches below round {round}"
+        );
+
+        Ok(())
+    }
+}
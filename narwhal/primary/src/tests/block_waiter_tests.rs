@@ -6,6 +6,7 @@ use crate::{
     BlockWaiter,
 };
 use anemo::PeerId;
+use config::BatchCompression;
 use crypto::traits::KeyPair as _;
 use fastcrypto::hash::Hash;
 use mockall::*;
@@ -15,8 +16,8 @@ use test_utils::{
     fixture_batch_with_transactions, fixture_payload, test_network, CommitteeFixture,
 };
 use types::{
-    Batch, BatchMessage, Certificate, CertificateDigest, MockWorkerToWorker, RequestBatchResponse,
-    WorkerToWorkerServer,
+    Batch, BatchMessage, Certificate, CertificateDigest, CompressedBatch, MockWorkerToWorker,
+    RequestBatchResponse, WorkerToWorkerServer,
 };
 
 #[tokio::test]
@@ -56,7 +57,13 @@ async fn test_successfully_retrieve_block() {
             .withf(move |request| request.body().batch == batch_id)
             .returning(|_| {
                 Ok(anemo::Response::new(RequestBatchResponse {
-                    batch: Some(Batch(vec![vec![10u8, 5u8, 2u8], vec![8u8, 2u8, 3u8]])),
+                    batch: Some(
+                        CompressedBatch::new(
+                            &Batch(vec![vec![10u8, 5u8, 2u8], vec![8u8, 2u8, 3u8]]),
+                            BatchCompression::default(),
+                        )
+                        .unwrap(),
+                    ),
                 }))
             });
     }
@@ -142,7 +149,7 @@ async fn test_successfully_retrieve_multiple_blocks() {
                 .withf(move |request| request.body().batch == digest)
                 .returning(move |_| {
                     Ok(anemo::Response::new(RequestBatchResponse {
-                        batch: Some(b.clone()),
+                        batch: Some(CompressedBatch::new(&b, BatchCompression::default()).unwrap()),
                     }))
                 });
         }
@@ -173,7 +180,9 @@ async fn test_successfully_retrieve_multiple_blocks() {
                     .withf(move |request| request.body().batch == digest)
                     .returning(move |_| {
                         Ok(anemo::Response::new(RequestBatchResponse {
-                            batch: Some(b.clone()),
+                            batch: Some(
+                                CompressedBatch::new(&b, BatchCompression::default()).unwrap(),
+                            ),
                         }))
                     });
             }
@@ -14,7 +14,7 @@ use std::{collections::BTreeSet, num::NonZeroUsize, sync::Arc, time::Duration};
 use test_utils::{temp_dir, CommitteeFixture};
 use tokio::sync::watch;
 use types::{Certificate, FetchCertificatesRequest, PrimaryToPrimary, ReconfigureNotification};
-use worker::{metrics::initialise_metrics, Worker};
+use worker::{metrics::initialise_metrics, TrivialTransactionValidator, Worker};
 
 #[tokio::test]
 async fn get_network_peers_from_admin_server() {
@@ -81,6 +81,7 @@ async fn get_network_peers_from_admin_server() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
@@ -103,6 +104,7 @@ async fn get_network_peers_from_admin_server() {
         worker_cache.clone(),
         worker_1_parameters.clone(),
         store.batch_store,
+        TrivialTransactionValidator::default(),
         metrics_1,
     );
 
@@ -193,6 +195,7 @@ async fn get_network_peers_from_admin_server() {
         tx_feedback_2,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
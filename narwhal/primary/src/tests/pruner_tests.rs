@@ -0,0 +1,188 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use super::*;
+use crate::common::create_db_stores;
+use anemo::PeerId;
+use crypto::traits::KeyPair;
+use prometheus::Registry;
+use test_utils::CommitteeFixture;
+use types::{MockPrimaryToWorker, PrimaryToWorkerServer, WorkerDeleteBatchesMessage};
+
+#[tokio::test]
+async fn prune_rounds() {
+    // GIVEN
+    let (header_store, certificate_store, payload_store) = create_db_stores();
+    let (_tx_executed_round, rx_executed_round) = watch::channel((0, 0));
+
+    // AND the necessary keys
+    let fixture = CommitteeFixture::builder().randomize_ports(true).build();
+    let committee = fixture.committee();
+    let worker_cache = fixture.shared_worker_cache();
+    let author = fixture.authorities().next().unwrap();
+    let primary = fixture.authorities().nth(1).unwrap();
+    let name = primary.public_key();
+    let (_tx_reconfigure, rx_reconfigure) =
+        watch::channel(ReconfigureNotification::NewEpoch(committee.clone()));
+
+    let network = test_utils::test_network(primary.network_keypair(), primary.address());
+    let mut pruner = Pruner {
+        name: name.clone(),
+        committee: committee.clone(),
+        worker_cache: worker_cache.clone(),
+        certificate_store: certificate_store.clone(),
+        header_store: header_store.clone(),
+        payload_store: payload_store.clone(),
+        retention_rounds: 2,
+        rx_executed_round,
+        rx_reconfigure,
+        worker_network: P2pNetwork::new(network.clone()),
+        metrics: Arc::new(PrimaryMetrics::new(&Registry::new())),
+        pruned_round: 0,
+    };
+
+    // AND one certificate with one batch per round, for rounds 1 to 4
+    let worker_id = 0;
+    let mut certificates = Vec::new();
+    let mut batches = Vec::new();
+    for round in 1..=4 {
+        let batch = test_utils::fixture_batch_with_transactions(10);
+        let header = author
+            .header_builder(&committee)
+            .round(round)
+            .with_payload_batch(batch.clone(), worker_id)
+            .build(author.keypair())
+            .unwrap();
+        let certificate = fixture.certificate(&header);
+
+        certificate_store.write(certificate.clone()).unwrap();
+        header_store.write(header.id, header).await;
+        payload_store.write((batch.digest(), worker_id), 0).await;
+
+        certificates.push(certificate);
+        batches.push(batch.digest());
+    }
+
+    // AND a worker expecting to delete the batches of rounds 1 and 2
+    let worker = primary.worker(worker_id);
+    let expected = batches[..2].to_vec();
+    let mut mock_server = MockPrimaryToWorker::new();
+    mock_server
+        .expect_delete_batches()
+        .withf(move |request| {
+            request.body()
+                == &WorkerDeleteBatchesMessage {
+                    digests: expected.clone(),
+                }
+        })
+        .times(1)
+        .returning(|_| Ok(anemo::Response::new(())));
+    let routes = anemo::Router::new().add_rpc_service(PrimaryToWorkerServer::new(mock_server));
+    let _worker_network = worker.new_network(routes);
+
+    let address = network::multiaddr_to_address(&worker.info().worker_address).unwrap();
+    let peer_id = PeerId(worker.keypair().public().0.to_bytes());
+    network
+        .connect_with_peer_id(address, peer_id)
+        .await
+        .unwrap();
+
+    // WHEN the executor reaches round 5
+    pruner.prune_up_to(5 - pruner.retention_rounds).await;
+
+    // THEN the rounds below 3 are pruned
+    assert_eq!(pruner.pruned_round, 3);
+    for (i, certificate) in certificates.iter().enumerate() {
+        let pruned = certificate.round() < 3;
+        assert_eq!(
+            certificate_store
+                .read(certificate.digest())
+                .unwrap()
+                .is_none(),
+            pruned
+        );
+        assert_eq!(
+            header_store
+                .read(certificate.header.id)
+                .await
+                .unwrap()
+                .is_none(),
+            pruned
+        );
+        assert_eq!(
+            payload_store
+                .read((batches[i], worker_id))
+                .await
+                .unwrap()
+                .is_none(),
+            pruned
+        );
+    }
+    assert_eq!(
+        pruner
+            .metrics
+            .pruned_certificates
+            .with_label_values(&[&committee.epoch.to_string()])
+            .get(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn prune_rounds_across_epochs() {
+    // GIVEN a pruner with empty stores
+    let (header_store, certificate_store, payload_store) = create_db_stores();
+    let (tx_executed_round, rx_executed_round) = watch::channel((0, 0));
+
+    let fixture = CommitteeFixture::builder().randomize_ports(true).build();
+    let committee = fixture.committee();
+    let worker_cache = fixture.shared_worker_cache();
+    let primary = fixture.authorities().next().unwrap();
+    let (_tx_reconfigure, rx_reconfigure) =
+        watch::channel(ReconfigureNotification::NewEpoch(committee.clone()));
+
+    let network = test_utils::test_network(primary.network_keypair(), primary.address());
+    let mut pruner = Pruner {
+        name: primary.public_key(),
+        committee: committee.clone(),
+        worker_cache,
+        certificate_store,
+        header_store,
+        payload_store,
+        retention_rounds: 2,
+        rx_executed_round,
+        rx_reconfigure,
+        worker_network: P2pNetwork::new(network),
+        metrics: Arc::new(PrimaryMetrics::new(&Registry::new())),
+        pruned_round: 0,
+    };
+
+    // WHEN the executor reaches round 10 of the current epoch
+    tx_executed_round.send((committee.epoch, 10)).unwrap();
+    let (epoch, round) = *pruner.rx_executed_round.borrow();
+    pruner.handle_executed_round(epoch, round).await;
+
+    // THEN the rounds below 8 are pruned
+    assert_eq!(pruner.pruned_round, 8);
+
+    // WHEN the epoch changes
+    let mut new_committee = committee.clone();
+    new_committee.epoch = committee.epoch + 1;
+    pruner.reconfigure(ReconfigureNotification::NewEpoch(new_committee.clone()));
+
+    // THEN pruning starts over from the first round
+    assert_eq!(pruner.pruned_round, 0);
+
+    // WHEN the executor still reports a round of the previous epoch
+    pruner.handle_executed_round(committee.epoch, 12).await;
+
+    // THEN it is ignored
+    assert_eq!(pruner.pruned_round, 0);
+
+    // WHEN the executor reaches round 4 of the new epoch, below the rounds of the previous epoch
+    tx_executed_round.send((new_committee.epoch, 4)).unwrap();
+    let (epoch, round) = *pruner.rx_executed_round.borrow();
+    pruner.handle_executed_round(epoch, round).await;
+
+    // THEN the rounds below 2 of the new epoch are pruned
+    assert_eq!(pruner.pruned_round, 2);
+}
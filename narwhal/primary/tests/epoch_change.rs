@@ -79,6 +79,7 @@ async fn test_simple_epoch_change() {
             /* tx_committed_certificates */ tx_feedback,
             &Registry::new(),
             None,
+            None,
         );
     }
 
@@ -200,6 +201,7 @@ async fn test_partial_committee_change() {
             /* tx_committed_certificates */ tx_feedback,
             &Registry::new(),
             None,
+            None,
         );
     }
 
@@ -289,6 +291,7 @@ async fn test_partial_committee_change() {
             /* tx_committed_certificates */ tx_feedback,
             &Registry::new(),
             None,
+            None,
         );
     }
 
@@ -369,6 +372,7 @@ async fn test_restart_with_new_committee_change() {
             /* tx_committed_certificates */ tx_feedback,
             &Registry::new(),
             None,
+            None,
         );
         handles.extend(primary_handles);
     }
@@ -458,6 +462,7 @@ async fn test_restart_with_new_committee_change() {
                 /* tx_committed_certificates */ tx_feedback,
                 &Registry::new(),
                 None,
+                None,
             );
             handles.extend(primary_handles);
         }
@@ -561,6 +566,7 @@ async fn test_simple_committee_update() {
             /* tx_committed_certificates */ tx_feedback,
             &Registry::new(),
             None,
+            None,
         );
     }
 
@@ -120,6 +120,7 @@ async fn test_rounds_errors() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     // AND Wait for tasks to start
@@ -203,6 +204,7 @@ async fn test_rounds_return_successful_response() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     // AND Wait for tasks to start
@@ -355,6 +357,7 @@ async fn test_node_read_causal_signed_certificates() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     let (tx_new_certificates_2, rx_new_certificates_2) =
@@ -397,6 +400,7 @@ async fn test_node_read_causal_signed_certificates() {
         tx_feedback_2,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
@@ -29,7 +29,7 @@ use types::{
     ReadCausalRequest, ReconfigureNotification, RemoveCollectionsRequest, RetrievalResult,
     Transaction, ValidatorClient,
 };
-use worker::{metrics::initialise_metrics, Worker};
+use worker::{metrics::initialise_metrics, TrivialTransactionValidator, Worker};
 
 #[tokio::test]
 async fn test_get_collections() {
@@ -128,6 +128,7 @@ async fn test_get_collections() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     let registry = Registry::new();
@@ -142,6 +143,7 @@ async fn test_get_collections() {
         worker_cache.clone(),
         parameters.clone(),
         store.batch_store.clone(),
+        TrivialTransactionValidator::default(),
         metrics,
     );
 
@@ -317,6 +319,7 @@ async fn test_remove_collections() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
@@ -358,6 +361,7 @@ async fn test_remove_collections() {
         worker_cache.clone(),
         parameters.clone(),
         store.batch_store.clone(),
+        TrivialTransactionValidator::default(),
         metrics,
     );
 
@@ -529,6 +533,7 @@ async fn test_read_causal_signed_certificates() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     let (tx_new_certificates_2, rx_new_certificates_2) =
@@ -572,6 +577,7 @@ async fn test_read_causal_signed_certificates() {
         tx_feedback_2,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
@@ -740,6 +746,7 @@ async fn test_read_causal_unsigned_certificates() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     let (tx_new_certificates_2, rx_new_certificates_2) =
@@ -776,6 +783,7 @@ async fn test_read_causal_unsigned_certificates() {
         tx_feedback_2,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
@@ -938,6 +946,7 @@ async fn test_get_collections_with_missing_certificates() {
         tx_feedback_1,
         &Registry::new(),
         None,
+        None,
     );
 
     let registry_1 = Registry::new();
@@ -952,6 +961,7 @@ async fn test_get_collections_with_missing_certificates() {
         worker_cache.clone(),
         parameters_1.clone(),
         store_primary_1.batch_store,
+        TrivialTransactionValidator::default(),
         metrics_1,
     );
 
@@ -997,6 +1007,7 @@ async fn test_get_collections_with_missing_certificates() {
         tx_feedback_2,
         &Registry::new(),
         None,
+        None,
     );
 
     let registry_2 = Registry::new();
@@ -1011,6 +1022,7 @@ async fn test_get_collections_with_missing_certificates() {
         worker_cache.clone(),
         parameters_2.clone(),
         store_primary_2.batch_store,
+        TrivialTransactionValidator::default(),
         metrics_2,
     );
 
@@ -203,6 +203,11 @@ impl CertificateStore {
 
         batch = batch.delete_batch(&self.certificate_id_by_round, iter::once(key))?;
 
+        // write the certificate index by its origin
+        let key = (cert.origin(), cert.round());
+
+        batch = batch.delete_batch(&self.certificate_id_by_origin, iter::once(key))?;
+
         // execute the batch (atomically) and return the result
         batch.write()
     }
@@ -220,11 +225,16 @@ impl CertificateStore {
         if keys_by_round.is_empty() {
             return Ok(());
         }
+        let keys_by_origin = keys_by_round
+            .iter()
+            .map(|(round, origin)| (origin.clone(), *round))
+            .collect::<Vec<_>>();
 
         let mut batch = self.certificates_by_id.batch();
 
-        // delete the certificates from the secondary index
+        // delete the certificates from the secondary indexes
         batch = batch.delete_batch(&self.certificate_id_by_round, keys_by_round)?;
+        batch = batch.delete_batch(&self.certificate_id_by_origin, keys_by_origin)?;
 
         // delete the certificates by its ids
         batch = batch.delete_batch(&self.certificates_by_id, ids)?;
@@ -270,6 +280,31 @@ impl CertificateStore {
             .collect()
     }
 
+    /// Retrieves all the certificates with round < the provided round.
+    /// The result is returned with certificates sorted in round asc order
+    pub fn before_round(&self, round: Round) -> StoreResult<Vec<Certificate>> {
+        let digests: Vec<_> = self
+            .certificate_id_by_round
+            .iter()
+            .take_while(|((r, _), _)| *r < round)
+            .map(|(_, d)| d)
+            .collect();
+
+        // Fetch all those certificates from main storage, return an error if any one is missing.
+        self.certificates_by_id
+            .multi_get(digests.clone())?
+            .into_iter()
+            .map(|opt_cert| {
+                opt_cert.ok_or_else(|| {
+                    RocksDBError(format!(
+                        "Certificate with some digests not found, CertificateStore invariant violation: {:?}",
+                        digests
+                    ))
+                })
+            })
+            .collect()
+    }
+
     /// Retrieves the certificates of the last round
     pub fn last_round(&self) -> StoreResult<Vec<Certificate>> {
         // starting from the last element - hence the last round - move backwards until
@@ -613,6 +648,39 @@ mod test {
         assert!(certs_ids_over_cutoff_round.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_before_round() {
+        // GIVEN
+        let store = new_store(temp_dir());
+        let total_rounds = 10;
+
+        // create certificates for 10 rounds
+        let certs = certificates(total_rounds);
+
+        // store them in both main and secondary index
+        store.write_all(certs.clone()).unwrap();
+
+        let round_cutoff = 4;
+
+        // WHEN
+        let result = store
+            .before_round(round_cutoff)
+            .expect("Error returned while reading before_round");
+
+        // THEN
+        let certs_per_round = 4;
+        assert_eq!(result.len() as u64, (round_cutoff - 1) * certs_per_round);
+
+        // AND result certificates should be returned in increasing order, and be the ones
+        // below the cut-off round
+        let mut last_round = 0;
+        for certificate in result {
+            assert!(certificate.round() >= last_round);
+            assert!(certificate.round() < round_cutoff);
+            last_round = certificate.round();
+        }
+    }
+
     #[tokio::test]
     async fn test_notify_read() {
         let store = new_store(temp_dir());
@@ -733,4 +801,31 @@ mod test {
         assert!(store.read(to_delete[0]).unwrap().is_none());
         assert!(store.read(to_delete[1]).unwrap().is_none());
     }
+
+    #[tokio::test]
+    async fn test_delete_all_clears_indexes() {
+        // GIVEN
+        let store = new_store(temp_dir());
+
+        // create certificates for 10 rounds
+        let certs = certificates(10);
+
+        // store them in both main and secondary index
+        store.write_all(certs.clone()).unwrap();
+
+        // WHEN delete all the certificates of the first 5 rounds
+        let to_delete = store
+            .before_round(6)
+            .unwrap()
+            .iter()
+            .map(|c| c.digest())
+            .collect::<Vec<_>>();
+        store.delete_all(to_delete).unwrap();
+
+        // THEN the certificates are also gone from the secondary indexes
+        assert!(store.before_round(6).unwrap().is_empty());
+        assert_eq!(store.after_round(0).unwrap().len(), 5 * 4);
+        let origin = certs[0].origin();
+        assert_eq!(store.next_round_number(&origin, 0).unwrap(), Some(6));
+    }
 }
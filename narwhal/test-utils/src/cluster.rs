@@ -23,6 +23,7 @@ use tokio::{
 use tonic::transport::Channel;
 use tracing::info;
 use types::{ConfigurationClient, ProposerClient, TransactionsClient};
+use worker::TrivialTransactionValidator;
 
 #[cfg(test)]
 #[path = "tests/cluster_tests.rs"]
@@ -460,6 +461,7 @@ impl WorkerNodeDetails {
             self.worker_cache.clone(),
             &worker_store,
             self.parameters.clone(),
+            TrivialTransactionValidator::default(),
             &registry,
         );
 
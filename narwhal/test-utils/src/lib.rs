@@ -26,8 +26,10 @@ use tokio::sync::mpsc::{channel, Receiver, Sender};
 use tracing::info;
 use types::{
     Batch, BatchDigest, Certificate, CertificateDigest, ConsensusStore, FetchCertificatesRequest,
-    FetchCertificatesResponse, Header, HeaderBuilder, PrimaryMessage, PrimaryToPrimary,
-    PrimaryToPrimaryServer, PrimaryToWorker, PrimaryToWorkerServer, RequestBatchRequest,
+    FetchCertificatesResponse, Header, HeaderBuilder, LeaderSchedule, LegacyRequestBatchRequest,
+    LegacyRequestBatchResponse, LegacyWorkerBatchMessage, LegacyWorkerBatchRequest,
+    LegacyWorkerBatchResponse, PrimaryMessage, PrimaryToPrimary, PrimaryToPrimaryServer,
+    PrimaryToWorker, PrimaryToWorkerServer, ReputationScores, RequestBatchRequest,
     RequestBatchResponse, Round, SequenceNumber, Transaction, Vote, WorkerBatchMessage,
     WorkerBatchRequest, WorkerBatchResponse, WorkerDeleteBatchesMessage, WorkerReconfigureMessage,
     WorkerSynchronizeMessage, WorkerToWorker, WorkerToWorkerServer,
@@ -119,16 +121,34 @@ pub fn random_key() -> KeyPair {
 pub fn make_consensus_store(store_path: &std::path::Path) -> Arc<ConsensusStore> {
     const LAST_COMMITTED_CF: &str = "last_committed";
     const SEQUENCE_CF: &str = "sequence";
+    const REPUTATION_SCORES_CF: &str = "reputation_scores";
+    const LEADER_SCHEDULES_CF: &str = "leader_schedules";
+
+    let rocksdb = rocks::open_cf(
+        store_path,
+        None,
+        &[
+            LAST_COMMITTED_CF,
+            SEQUENCE_CF,
+            REPUTATION_SCORES_CF,
+            LEADER_SCHEDULES_CF,
+        ],
+    )
+    .expect("Failed creating database");
 
-    let rocksdb = rocks::open_cf(store_path, None, &[LAST_COMMITTED_CF, SEQUENCE_CF])
-        .expect("Failed creating database");
-
-    let (last_committed_map, sequence_map) = reopen!(&rocksdb,
+    let (last_committed_map, sequence_map, reputation_scores_map, leader_schedules_map) = reopen!(&rocksdb,
         LAST_COMMITTED_CF;<PublicKey, Round>,
-        SEQUENCE_CF;<SequenceNumber, CertificateDigest>
+        SEQUENCE_CF;<SequenceNumber, CertificateDigest>,
+        REPUTATION_SCORES_CF;<u64, ReputationScores>,
+        LEADER_SCHEDULES_CF;<Round, LeaderSchedule>
     );
 
-    Arc::new(ConsensusStore::new(last_committed_map, sequence_map))
+    Arc::new(ConsensusStore::new(
+        last_committed_map,
+        sequence_map,
+        reputation_scores_map,
+        leader_schedules_map,
+    ))
 }
 
 pub fn fixture_payload(number_of_batches: u8) -> IndexMap<BatchDigest, WorkerId> {
@@ -333,6 +353,30 @@ impl WorkerToWorker for WorkerToWorkerMockServer {
         tracing::error!("Not implemented WorkerToWorkerMockServer::request_batch");
         Err(anemo::rpc::Status::internal("Unimplemented"))
     }
+
+    async fn legacy_report_batch(
+        &self,
+        _request: anemo::Request<LegacyWorkerBatchMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        tracing::error!("Not implemented WorkerToWorkerMockServer::legacy_report_batch");
+        Err(anemo::rpc::Status::internal("Unimplemented"))
+    }
+
+    async fn legacy_request_batches(
+        &self,
+        _request: anemo::Request<LegacyWorkerBatchRequest>,
+    ) -> Result<anemo::Response<LegacyWorkerBatchResponse>, anemo::rpc::Status> {
+        tracing::error!("Not implemented WorkerToWorkerMockServer::legacy_request_batches");
+        Err(anemo::rpc::Status::internal("Unimplemented"))
+    }
+
+    async fn legacy_request_batch(
+        &self,
+        _request: anemo::Request<LegacyRequestBatchRequest>,
+    ) -> Result<anemo::Response<LegacyRequestBatchResponse>, anemo::rpc::Status> {
+        tracing::error!("Not implemented WorkerToWorkerMockServer::legacy_request_batch");
+        Err(anemo::rpc::Status::internal("Unimplemented"))
+    }
 }
 
 ////////////////////////////////////////////////////////////////
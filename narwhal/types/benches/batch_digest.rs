@@ -6,7 +6,7 @@ use criterion::{
 use fastcrypto::hash::Hash;
 use narwhal_types as types;
 use rand::Rng;
-use types::{serialized_batch_digest, Batch, WorkerBatchMessage};
+use types::{serialized_batch_digest, Batch};
 
 pub fn batch_digest(c: &mut Criterion) {
     let mut digest_group = c.benchmark_group("Batch digests");
@@ -21,10 +21,7 @@ pub fn batch_digest(c: &mut Criterion) {
                 .collect::<Vec<u8>>()
         };
         let batch = Batch((0..size).map(|_| tx_gen()).collect::<Vec<_>>());
-        let message = WorkerBatchMessage {
-            batch: batch.clone(),
-        };
-        let serialized_batch = bincode::serialize(&message).unwrap();
+        let serialized_batch = bincode::serialize(&batch).unwrap();
 
         digest_group.throughput(Throughput::Bytes(512 * size as u64));
 
@@ -143,7 +143,7 @@ fn build_anemo_services(out_dir: &Path) {
         .method(
             anemo_build::manual::Method::builder()
                 .name("report_batch")
-                .route_name("ReportBatch")
+                .route_name("ReportCompressedBatch")
                 .request_type("crate::WorkerBatchMessage")
                 .response_type("()")
                 .codec_path("anemo::rpc::codec::BincodeCodec")
@@ -152,7 +152,7 @@ fn build_anemo_services(out_dir: &Path) {
         .method(
             anemo_build::manual::Method::builder()
                 .name("request_batches")
-                .route_name("RequestBatches")
+                .route_name("RequestCompressedBatches")
                 .request_type("crate::WorkerBatchRequest")
                 .response_type("crate::WorkerBatchResponse")
                 .codec_path("anemo::rpc::codec::BincodeCodec")
@@ -161,12 +161,40 @@ fn build_anemo_services(out_dir: &Path) {
         .method(
             anemo_build::manual::Method::builder()
                 .name("request_batch")
-                .route_name("RequestBatch")
+                .route_name("RequestCompressedBatch")
                 .request_type("crate::RequestBatchRequest")
                 .response_type("crate::RequestBatchResponse")
                 .codec_path("anemo::rpc::codec::BincodeCodec")
                 .build(),
         )
+        // The routes of the uncompressed batches, kept for the nodes that predate compression.
+        .method(
+            anemo_build::manual::Method::builder()
+                .name("legacy_report_batch")
+                .route_name("ReportBatch")
+                .request_type("crate::LegacyWorkerBatchMessage")
+                .response_type("()")
+                .codec_path("anemo::rpc::codec::BincodeCodec")
+                .build(),
+        )
+        .method(
+            anemo_build::manual::Method::builder()
+                .name("legacy_request_batches")
+                .route_name("RequestBatches")
+                .request_type("crate::LegacyWorkerBatchRequest")
+                .response_type("crate::LegacyWorkerBatchResponse")
+                .codec_path("anemo::rpc::codec::BincodeCodec")
+                .build(),
+        )
+        .method(
+            anemo_build::manual::Method::builder()
+                .name("legacy_request_batch")
+                .route_name("RequestBatch")
+                .request_type("crate::LegacyRequestBatchRequest")
+                .response_type("crate::LegacyRequestBatchResponse")
+                .codec_path("anemo::rpc::codec::BincodeCodec")
+                .build(),
+        )
         .build();
 
     anemo_build::manual::Builder::new()
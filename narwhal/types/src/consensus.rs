@@ -3,10 +3,15 @@
 #![allow(clippy::mutable_key_type)]
 
 use crate::{CertificateDigest, Round};
+use config::{Committee, Stake};
 use crypto::PublicKey;
-use std::{collections::HashMap, ops::RangeInclusive};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ops::RangeInclusive,
+};
 use store::{
-    rocks::{DBMap, TypedStoreError},
+    rocks::{DBBatch, DBMap, TypedStoreError},
     traits::Map,
 };
 use tokio::sync::mpsc;
@@ -20,23 +25,126 @@ pub type ShutdownToken = mpsc::Sender<()>;
 /// Convenience type to propagate store errors.
 pub type StoreResult<T> = Result<T, TypedStoreError>;
 
+/// The reputation of the authorities over the current scoring window of the leader schedule.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReputationScores {
+    /// The number of certificates of every authority that were part of a committed sub-dag.
+    /// Authorities missing from the map have a score of zero.
+    pub scores_per_authority: BTreeMap<PublicKey, u64>,
+    /// The number of sub-dags committed in the current scoring window.
+    pub num_sub_dags: u64,
+}
+
+impl ReputationScores {
+    /// Add `score` to the reputation of `authority`.
+    pub fn add_score(&mut self, authority: &PublicKey, score: u64) {
+        *self
+            .scores_per_authority
+            .entry(authority.clone())
+            .or_default() += score;
+    }
+
+    /// Return the score of `authority`.
+    pub fn score(&self, authority: &PublicKey) -> u64 {
+        self.scores_per_authority
+            .get(authority)
+            .copied()
+            .unwrap_or_default()
+    }
+}
+
+/// The leader schedule derived from the reputation scores of a scoring window. Leaders elected by
+/// the stake-weighted choice of the committee that have a bad reputation are swapped for
+/// authorities with a good one.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LeaderSchedule {
+    /// The authorities with the lowest scores. Their total stake is below the validity threshold.
+    pub bad_nodes: BTreeSet<PublicKey>,
+    /// The authorities with the highest scores, in decreasing score order, that lead in place of
+    /// the bad nodes.
+    pub good_nodes: Vec<PublicKey>,
+}
+
+impl LeaderSchedule {
+    /// Derive the leader schedule from the scores of a completed scoring window. The schedule only
+    /// depends on the committee and the scores, so that all nodes derive the same one.
+    pub fn new(committee: &Committee, scores: &ReputationScores) -> Self {
+        // Order the authorities by increasing score, breaking ties with their public key.
+        let mut authorities: Vec<(u64, &PublicKey, Stake)> = committee
+            .authorities
+            .iter()
+            .map(|(name, authority)| (scores.score(name), name, authority.stake))
+            .collect();
+        authorities.sort();
+
+        let max_score = authorities.last().map_or(0, |(score, _, _)| *score);
+        let mut bad_nodes = BTreeSet::new();
+        let mut bad_nodes_stake = 0;
+        for (score, name, stake) in &authorities {
+            // An authority has a bad reputation if it scored less than half of the best one. We
+            // never swap out f+1 or more of the stake, so that at least one honest authority keeps
+            // leading its rounds.
+            if score * 2 >= max_score || bad_nodes_stake + stake >= committee.validity_threshold() {
+                break;
+            }
+            bad_nodes_stake += stake;
+            bad_nodes.insert((*name).clone());
+        }
+
+        let good_nodes = authorities
+            .iter()
+            .rev()
+            .filter(|(_, name, _)| !bad_nodes.contains(*name))
+            .take(bad_nodes.len())
+            .map(|(_, name, _)| (*name).clone())
+            .collect();
+
+        Self {
+            bad_nodes,
+            good_nodes,
+        }
+    }
+
+    /// Return the leader of `round`, given the authority elected by the committee.
+    pub fn leader(&self, elected: PublicKey, round: Round) -> PublicKey {
+        if !self.bad_nodes.contains(&elected) {
+            return elected;
+        }
+        // Leaders are only elected on even rounds, spread them over all the good nodes.
+        let index = (round / 2) as usize % self.good_nodes.len();
+        self.good_nodes[index].clone()
+    }
+}
+
 /// The persistent storage of the sequencer.
 pub struct ConsensusStore {
     /// The latest committed round of each validator.
     last_committed: DBMap<PublicKey, Round>,
     /// The global consensus sequence.
     sequence: DBMap<SequenceNumber, CertificateDigest>,
+    /// The reputation scores of the current scoring window, under `REPUTATION_SCORES_KEY`.
+    reputation_scores: DBMap<u64, ReputationScores>,
+    /// The leader schedules, keyed by the round of the leader whose commit completed the scoring
+    /// window they are derived from.
+    leader_schedules: DBMap<Round, LeaderSchedule>,
 }
 
 impl ConsensusStore {
+    /// The key of the single entry of the reputation scores map.
+    const REPUTATION_SCORES_KEY: u64 = 0;
+
     /// Create a new consensus store structure by using already loaded maps.
     pub fn new(
         last_committed: DBMap<PublicKey, Round>,
         sequence: DBMap<SequenceNumber, CertificateDigest>,
+        reputation_scores: DBMap<u64, ReputationScores>,
+        leader_schedules: DBMap<Round, LeaderSchedule>,
     ) -> Self {
         Self {
             last_committed,
             sequence,
+            reputation_scores,
+            leader_schedules,
         }
     }
 
@@ -44,25 +152,79 @@ impl ConsensusStore {
     pub fn clear(&self) -> StoreResult<()> {
         self.last_committed.clear()?;
         self.sequence.clear()?;
+        self.reputation_scores.clear()?;
+        self.leader_schedules.clear()?;
         Ok(())
     }
 
-    /// Persist the consensus state.
-    pub fn write_consensus_state(
+    fn consensus_state_batch(
         &self,
         last_committed: &HashMap<PublicKey, Round>,
         consensus_index: &SequenceNumber,
         certificate_id: &CertificateDigest,
-    ) -> Result<(), TypedStoreError> {
+    ) -> Result<DBBatch, TypedStoreError> {
         let mut write_batch = self.last_committed.batch();
         write_batch = write_batch.insert_batch(&self.last_committed, last_committed.iter())?;
-        write_batch = write_batch.insert_batch(
+        write_batch.insert_batch(
             &self.sequence,
             std::iter::once((consensus_index, certificate_id)),
+        )
+    }
+
+    /// Persist the consensus state.
+    pub fn write_consensus_state(
+        &self,
+        last_committed: &HashMap<PublicKey, Round>,
+        consensus_index: &SequenceNumber,
+        certificate_id: &CertificateDigest,
+    ) -> Result<(), TypedStoreError> {
+        self.consensus_state_batch(last_committed, consensus_index, certificate_id)?
+            .write()
+    }
+
+    /// Persist the consensus state along with the reputation scores, and the new leader schedule
+    /// (with the round of the leader that completed its scoring window) if there is one.
+    pub fn write_consensus_state_with_reputation(
+        &self,
+        last_committed: &HashMap<PublicKey, Round>,
+        consensus_index: &SequenceNumber,
+        certificate_id: &CertificateDigest,
+        reputation_scores: &ReputationScores,
+        leader_schedule: Option<(Round, &LeaderSchedule)>,
+    ) -> Result<(), TypedStoreError> {
+        let mut write_batch =
+            self.consensus_state_batch(last_committed, consensus_index, certificate_id)?;
+        write_batch = write_batch.insert_batch(
+            &self.reputation_scores,
+            std::iter::once((Self::REPUTATION_SCORES_KEY, reputation_scores)),
         )?;
+        if let Some((round, leader_schedule)) = leader_schedule {
+            write_batch = write_batch.insert_batch(
+                &self.leader_schedules,
+                std::iter::once((round, leader_schedule)),
+            )?;
+        }
         write_batch.write()
     }
 
+    /// Load the reputation scores of the current scoring window.
+    pub fn read_reputation_scores(&self) -> StoreResult<ReputationScores> {
+        Ok(self
+            .reputation_scores
+            .get(&Self::REPUTATION_SCORES_KEY)?
+            .unwrap_or_default())
+    }
+
+    /// Load the latest leader schedule, along with the round of the leader that completed its
+    /// scoring window.
+    pub fn read_latest_leader_schedule(&self) -> StoreResult<Option<(Round, LeaderSchedule)>> {
+        Ok(self
+            .leader_schedules
+            .iter()
+            .skip_prior_to(&Round::MAX)?
+            .next())
+    }
+
     /// Load the last committed round of each validator.
     pub fn read_last_committed(&self) -> HashMap<PublicKey, Round> {
         self.last_committed.iter().collect()
@@ -90,6 +252,22 @@ impl ConsensusStore {
             .collect())
     }
 
+    /// Iterate over the digests of the certificates sequenced at the consensus indices of `range`,
+    /// along with their consensus index.
+    pub fn read_sequence<'a>(
+        &'a self,
+        range: &RangeInclusive<SequenceNumber>,
+    ) -> StoreResult<impl Iterator<Item = (SequenceNumber, CertificateDigest)> + 'a> {
+        // Every certificate is persisted under the consensus index that follows its own.
+        let end = range.end().saturating_add(1);
+        Ok(self
+            .sequence
+            .iter()
+            .skip_to(&range.start().saturating_add(1))?
+            .take_while(move |(index, _)| *index <= end)
+            .map(|(index, digest)| (index - 1, digest)))
+    }
+
     /// Load the last (ie. the highest) consensus index associated to a certificate.
     pub fn read_last_consensus_index(&self) -> StoreResult<SequenceNumber> {
         Ok(self
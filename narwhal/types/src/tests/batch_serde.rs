@@ -1,7 +1,10 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{serialized_batch_digest, Batch, WorkerBatchMessage};
+use crate::{
+    serialized_batch_digest, Batch, CompressedBatch, CompressionError, WorkerBatchMessage,
+};
+use config::BatchCompression;
 use fastcrypto::hash::Hash;
 use proptest::arbitrary::Arbitrary;
 use serde_test::{assert_tokens, Token};
@@ -66,16 +69,22 @@ fn test_bincode_serde_batch_message() {
     let tx = || vec![1; 5];
 
     let txes = WorkerBatchMessage {
-        batch: Batch((0..2).map(|_| tx()).collect()),
+        batch: CompressedBatch::new(
+            &Batch((0..2).map(|_| tx()).collect()),
+            BatchCompression::None,
+        )
+        .unwrap(),
     };
 
     let txes_bytes = bincode::serialize(&txes).unwrap();
 
-    // We expect this will be the same as the above.
-    // Length-prefix 2, length-prefix 5, 11111, length-prefix 5, 11111
-    let expected_bytes =
-        hex::decode("02000000000000000500000000000000010101010105000000000000000101010101")
-            .unwrap();
+    // We expect this will be the serialized batch above, preceded by the compression variant,
+    // the uncompressed size (34) and the length-prefix of the bytes (34).
+    let expected_bytes = hex::decode(
+        "0000000022000000000000002200000000000000\
+         02000000000000000500000000000000010101010105000000000000000101010101",
+    )
+    .unwrap();
 
     assert_eq!(
         txes_bytes.clone(),
@@ -92,9 +101,48 @@ proptest::proptest! {
         batch in Batch::arbitrary()
     ) {
         let digest = batch.digest();
-        let message = WorkerBatchMessage{batch};
-        let serialized = bincode::serialize(&message).expect("Failed to serialize our own batch");
-        let digest_from_serialized = serialized_batch_digest(&serialized).expect("Failed to hash serialized batch");
+        let message = WorkerBatchMessage{batch: CompressedBatch::new(&batch, BatchCompression::None).unwrap()};
+        let digest_from_serialized = serialized_batch_digest(&message.batch.bytes).expect("Failed to hash serialized batch");
         assert_eq!(digest, digest_from_serialized);
     }
+
+    #[test]
+    fn test_batch_compression_round_trip(
+        batch in Batch::arbitrary()
+    ) {
+        for compression in [BatchCompression::None, BatchCompression::Zstd] {
+            let compressed = CompressedBatch::new(&batch, compression).unwrap();
+            assert_eq!(compressed.decompress().unwrap(), batch.clone());
+        }
+    }
+}
+
+#[test]
+fn test_batch_compression() {
+    // Transactions repeat a lot of content, which compresses well.
+    let batch = Batch((0..100).map(|i| vec![i % 4; 100]).collect());
+
+    let compressed = CompressedBatch::new(&batch, BatchCompression::Zstd).unwrap();
+    assert_eq!(
+        compressed.uncompressed_size,
+        bincode::serialized_size(&batch).unwrap()
+    );
+    assert!(compressed.compression_ratio() < 0.1);
+    assert_eq!(compressed.decompress().unwrap().digest(), batch.digest());
+
+    // Batches that do not decompress to the announced size are rejected.
+    let mut truncated = compressed.clone();
+    truncated.uncompressed_size -= 1;
+    assert!(matches!(
+        truncated.decompress(),
+        Err(CompressionError::SizeMismatch { .. })
+    ));
+
+    // Batches announcing an oversized content are rejected before decompression.
+    let mut oversized = compressed;
+    oversized.uncompressed_size = CompressedBatch::MAX_UNCOMPRESSED_SIZE + 1;
+    assert!(matches!(
+        oversized.decompress(),
+        Err(CompressionError::TooLarge(_))
+    ));
 }
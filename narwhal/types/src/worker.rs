@@ -1,45 +1,249 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{Batch, BatchDigest};
+use crate::{Batch, BatchDigest, WorkerToWorkerClient};
 
+use anemo::types::response::StatusCode;
+use config::BatchCompression;
 use fastcrypto::hash::HashFunction;
 use serde::{Deserialize, Serialize};
+use std::{io::Read, time::Duration};
 use thiserror::Error;
 
 #[cfg(test)]
 #[path = "tests/batch_serde.rs"]
 mod batch_serde;
 
+/// A serialized batch, compressed for transmission over the network.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CompressedBatch {
+    /// The compression applied to the serialized batch.
+    pub compression: BatchCompression,
+    /// The size of the serialized batch before compression.
+    pub uncompressed_size: u64,
+    /// The compressed serialized batch.
+    pub bytes: Vec<u8>,
+}
+
+impl CompressedBatch {
+    /// The largest serialized batch accepted when decompressing, to bound the memory spent on
+    /// batches received from the network.
+    pub const MAX_UNCOMPRESSED_SIZE: u64 = 64 * 1024 * 1024;
+
+    /// Serialize and compress a batch.
+    pub fn new(batch: &Batch, compression: BatchCompression) -> Result<Self, CompressionError> {
+        let serialized = bincode::serialize(batch)?;
+        let uncompressed_size = serialized.len() as u64;
+        let bytes = match compression {
+            BatchCompression::None => serialized,
+            BatchCompression::Zstd => zstd::bulk::compress(&serialized, 0)?,
+        };
+        Ok(Self {
+            compression,
+            uncompressed_size,
+            bytes,
+        })
+    }
+
+    /// Decompress and deserialize the batch.
+    pub fn decompress(&self) -> Result<Batch, CompressionError> {
+        if self.uncompressed_size > Self::MAX_UNCOMPRESSED_SIZE {
+            return Err(CompressionError::TooLarge(self.uncompressed_size));
+        }
+        let serialized = match self.compression {
+            BatchCompression::None => self.bytes.clone(),
+            BatchCompression::Zstd => {
+                // Never decompress more than announced.
+                let mut serialized = Vec::with_capacity(self.uncompressed_size as usize);
+                zstd::stream::read::Decoder::new(self.bytes.as_slice())?
+                    .take(self.uncompressed_size + 1)
+                    .read_to_end(&mut serialized)?;
+                serialized
+            }
+        };
+        if serialized.len() as u64 != self.uncompressed_size {
+            return Err(CompressionError::SizeMismatch {
+                expected: self.uncompressed_size,
+                actual: serialized.len() as u64,
+            });
+        }
+        Ok(bincode::deserialize(&serialized)?)
+    }
+
+    /// The ratio of the compressed size to the uncompressed size of the batch.
+    pub fn compression_ratio(&self) -> f64 {
+        if self.uncompressed_size == 0 {
+            return 1.0;
+        }
+        self.bytes.len() as f64 / self.uncompressed_size as f64
+    }
+}
+
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CompressionError {
+    #[error("Failed to serialize or deserialize the batch: {0}")]
+    SerializationError(#[from] bincode::Error),
+    #[error("Failed to compress or decompress the batch: {0}")]
+    IoError(#[from] std::io::Error),
+    #[error("Batch of {0} B is too large")]
+    TooLarge(u64),
+    #[error("Batch of {actual} B does not have the announced size of {expected} B")]
+    SizeMismatch { expected: u64, actual: u64 },
+}
+
 /// Used by workers to send a new batch.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct WorkerBatchMessage {
-    pub batch: Batch,
+    pub batch: CompressedBatch,
 }
 
 /// Used by workers to request batches from other workers.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct WorkerBatchRequest {
     pub digests: Vec<BatchDigest>,
+    /// The compression with which the batches should be sent back.
+    pub compression: BatchCompression,
 }
 
 /// Used by workers to provide batches to other workers.
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct WorkerBatchResponse {
-    pub batches: Vec<Batch>,
+    pub batches: Vec<CompressedBatch>,
 }
 
 /// Used by primary to ask worker for the request.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RequestBatchRequest {
     pub batch: BatchDigest,
+    /// The compression with which the batch should be sent back.
+    pub compression: BatchCompression,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct RequestBatchResponse {
+    pub batch: Option<CompressedBatch>,
+}
+
+/// Used by workers to send a new batch to workers that predate batch compression.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LegacyWorkerBatchMessage {
+    pub batch: Batch,
+}
+
+/// Used by workers that predate batch compression to request batches from other workers.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LegacyWorkerBatchRequest {
+    pub digests: Vec<BatchDigest>,
+}
+
+/// Used by workers to provide uncompressed batches to workers that predate batch compression.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LegacyWorkerBatchResponse {
+    pub batches: Vec<Batch>,
+}
+
+/// Used by primaries that predate batch compression to ask worker for the request.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LegacyRequestBatchRequest {
+    pub batch: BatchDigest,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LegacyRequestBatchResponse {
     pub batch: Option<Batch>,
 }
 
+/// Nodes that predate batch compression do not serve the compressed routes, and reject them as
+/// not found. The requests below fall back to the uncompressed routes for such peers.
+impl WorkerToWorkerClient<anemo::Peer> {
+    /// Send a batch to another worker.
+    pub async fn report_batch_or_legacy(
+        &mut self,
+        message: WorkerBatchMessage,
+        timeout: Option<Duration>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        let result = self
+            .report_batch(with_timeout(message.clone(), timeout))
+            .await;
+        if !is_unknown_route(&result) {
+            return result;
+        }
+        let batch = message.batch.decompress().map_err(invalid_batch)?;
+        self.legacy_report_batch(with_timeout(LegacyWorkerBatchMessage { batch }, timeout))
+            .await
+    }
+
+    /// Request batches from another worker.
+    pub async fn request_batches_or_legacy(
+        &mut self,
+        message: WorkerBatchRequest,
+        timeout: Option<Duration>,
+    ) -> Result<anemo::Response<WorkerBatchResponse>, anemo::rpc::Status> {
+        let result = self
+            .request_batches(with_timeout(message.clone(), timeout))
+            .await;
+        if !is_unknown_route(&result) {
+            return result;
+        }
+        let request = LegacyWorkerBatchRequest {
+            digests: message.digests,
+        };
+        let batches = self
+            .legacy_request_batches(with_timeout(request, timeout))
+            .await?
+            .into_body()
+            .batches
+            .iter()
+            .map(|batch| CompressedBatch::new(batch, BatchCompression::None))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(invalid_batch)?;
+        Ok(anemo::Response::new(WorkerBatchResponse { batches }))
+    }
+
+    /// Request a batch from a worker.
+    pub async fn request_batch_or_legacy(
+        &mut self,
+        message: RequestBatchRequest,
+        timeout: Option<Duration>,
+    ) -> Result<anemo::Response<RequestBatchResponse>, anemo::rpc::Status> {
+        let result = self
+            .request_batch(with_timeout(message.clone(), timeout))
+            .await;
+        if !is_unknown_route(&result) {
+            return result;
+        }
+        let request = LegacyRequestBatchRequest {
+            batch: message.batch,
+        };
+        let batch = self
+            .legacy_request_batch(with_timeout(request, timeout))
+            .await?
+            .into_body()
+            .batch
+            .map(|batch| CompressedBatch::new(&batch, BatchCompression::None))
+            .transpose()
+            .map_err(invalid_batch)?;
+        Ok(anemo::Response::new(RequestBatchResponse { batch }))
+    }
+}
+
+fn with_timeout<T>(message: T, timeout: Option<Duration>) -> anemo::Request<T> {
+    let request = anemo::Request::new(message);
+    match timeout {
+        Some(timeout) => request.with_timeout(timeout),
+        None => request,
+    }
+}
+
+fn is_unknown_route<T>(result: &Result<T, anemo::rpc::Status>) -> bool {
+    matches!(result, Err(status) if status.status() == StatusCode::NotFound)
+}
+
+fn invalid_batch(e: CompressionError) -> anemo::rpc::Status {
+    anemo::rpc::Status::new_with_message(StatusCode::BadRequest, format!("Invalid batch: {e}"))
+}
+
 /// Hashes a serialized batch message without deserializing it into a batch.
 ///
 /// See the test `test_batch_and_serialized`, which guarantees that the output of this
@@ -1,9 +1,14 @@
 // Copyright (c) 2021, Facebook, Inc. and its affiliates
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
+use crate::{metrics::WorkerMetrics, TransactionValidator};
+use anemo::types::response::StatusCode;
 use anyhow::Result;
 use async_trait::async_trait;
-use config::{Committee, SharedCommittee, SharedWorkerCache, WorkerCache, WorkerId, WorkerIndex};
+use config::{
+    BatchCompression, Committee, SharedCommittee, SharedWorkerCache, WorkerCache, WorkerId,
+    WorkerIndex,
+};
 use crypto::PublicKey;
 use fastcrypto::hash::Hash;
 use futures::{stream::FuturesUnordered, StreamExt};
@@ -19,10 +24,12 @@ use tap::TapOptional;
 use tokio::sync::watch;
 use tracing::{debug, error, info, trace, warn};
 use types::{
-    metered_channel::Sender, Batch, BatchDigest, PrimaryToWorker, ReconfigureNotification,
-    RequestBatchRequest, RequestBatchResponse, WorkerBatchMessage, WorkerBatchRequest,
-    WorkerBatchResponse, WorkerDeleteBatchesMessage, WorkerOthersBatchMessage,
-    WorkerReconfigureMessage, WorkerSynchronizeMessage, WorkerToWorker, WorkerToWorkerClient,
+    metered_channel::Sender, Batch, BatchDigest, CompressedBatch, LegacyRequestBatchRequest,
+    LegacyRequestBatchResponse, LegacyWorkerBatchMessage, LegacyWorkerBatchRequest,
+    LegacyWorkerBatchResponse, PrimaryToWorker, ReconfigureNotification, RequestBatchRequest,
+    RequestBatchResponse, WorkerBatchMessage, WorkerBatchRequest, WorkerBatchResponse,
+    WorkerDeleteBatchesMessage, WorkerOthersBatchMessage, WorkerReconfigureMessage,
+    WorkerSynchronizeMessage, WorkerToWorker, WorkerToWorkerClient,
 };
 
 #[cfg(test)]
@@ -31,21 +38,48 @@ pub mod handlers_tests;
 
 /// Defines how the network receiver handles incoming workers messages.
 #[derive(Clone)]
-pub struct WorkerReceiverHandler {
+pub struct WorkerReceiverHandler<V> {
     pub id: WorkerId,
     pub tx_others_batch: Sender<WorkerOthersBatchMessage>,
     pub store: Store<BatchDigest, Batch>,
+    pub validator: V,
+    pub node_metrics: Arc<WorkerMetrics>,
 }
 
-#[async_trait]
-impl WorkerToWorker for WorkerReceiverHandler {
-    async fn report_batch(
+impl<V> WorkerReceiverHandler<V> {
+    /// Compress a batch sent to another worker, with the compression it requested.
+    fn compress(
         &self,
-        request: anemo::Request<WorkerBatchMessage>,
-    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
-        let message = request.into_body();
-        let digest = message.batch.digest();
-        self.store.write(digest, message.batch).await;
+        batch: &Batch,
+        compression: BatchCompression,
+    ) -> Result<CompressedBatch, anemo::rpc::Status> {
+        let compressed = CompressedBatch::new(batch, compression)
+            .map_err(|e| anemo::rpc::Status::from_error(Box::new(e)))?;
+        self.node_metrics.observe_compression(&compressed);
+        Ok(compressed)
+    }
+}
+
+impl<V: TransactionValidator> WorkerReceiverHandler<V> {
+    /// Validate, store and vote on a batch received from another worker.
+    async fn process_batch(&self, batch: Batch) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        // Do not store nor vote on batches containing invalid transactions. Validation may
+        // verify signatures, so it runs off the async runtime.
+        let validator = self.validator.clone();
+        let (batch, result) = tokio::task::spawn_blocking(move || {
+            let result = validator.validate_batch(&batch);
+            (batch, result)
+        })
+        .await
+        .map_err(|e| anemo::rpc::Status::internal(e.to_string()))?;
+        if let Err(e) = result {
+            return Err(anemo::rpc::Status::new_with_message(
+                StatusCode::BadRequest,
+                format!("Invalid batch: {e}"),
+            ));
+        }
+        let digest = batch.digest();
+        self.store.write(digest, batch).await;
         self.tx_others_batch
             .send(WorkerOthersBatchMessage {
                 digest,
@@ -56,21 +90,56 @@ impl WorkerToWorker for WorkerReceiverHandler {
             .map_err(|e| anemo::rpc::Status::internal(e.to_string()))
     }
 
-    async fn request_batches(
+    async fn read_batches(
         &self,
-        request: anemo::Request<WorkerBatchRequest>,
-    ) -> Result<anemo::Response<WorkerBatchResponse>, anemo::rpc::Status> {
-        let message = request.into_body();
+        digests: Vec<BatchDigest>,
+    ) -> Result<Vec<Batch>, anemo::rpc::Status> {
         // TODO [issue #7]: Do some accounting to prevent bad actors from monopolizing our resources
         // TODO: Add a limit on number of requested batches
-        let batches: Vec<Batch> = self
+        Ok(self
             .store
-            .read_all(message.digests)
+            .read_all(digests)
             .await
             .map_err(|e| anemo::rpc::Status::from_error(Box::new(e)))?
             .into_iter()
             .flatten()
-            .collect();
+            .collect())
+    }
+
+    async fn read_batch(&self, digest: BatchDigest) -> Result<Option<Batch>, anemo::rpc::Status> {
+        self.store
+            .read(digest)
+            .await
+            .map_err(|e| anemo::rpc::Status::from_error(Box::new(e)))
+    }
+}
+
+#[async_trait]
+impl<V: TransactionValidator> WorkerToWorker for WorkerReceiverHandler<V> {
+    async fn report_batch(
+        &self,
+        request: anemo::Request<WorkerBatchMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        let batch = request.into_body().batch.decompress().map_err(|e| {
+            anemo::rpc::Status::new_with_message(
+                StatusCode::BadRequest,
+                format!("Invalid batch: {e}"),
+            )
+        })?;
+        self.process_batch(batch).await
+    }
+
+    async fn request_batches(
+        &self,
+        request: anemo::Request<WorkerBatchRequest>,
+    ) -> Result<anemo::Response<WorkerBatchResponse>, anemo::rpc::Status> {
+        let message = request.into_body();
+        let batches = self
+            .read_batches(message.digests)
+            .await?
+            .iter()
+            .map(|batch| self.compress(batch, message.compression))
+            .collect::<Result<Vec<_>, _>>()?;
         Ok(anemo::Response::new(WorkerBatchResponse { batches }))
     }
 
@@ -78,15 +147,38 @@ impl WorkerToWorker for WorkerReceiverHandler {
         &self,
         request: anemo::Request<RequestBatchRequest>,
     ) -> Result<anemo::Response<RequestBatchResponse>, anemo::rpc::Status> {
-        let batch = request.into_body().batch;
+        let message = request.into_body();
         let batch = self
-            .store
-            .read(batch)
-            .await
-            .map_err(|e| anemo::rpc::Status::from_error(Box::new(e)))?;
+            .read_batch(message.batch)
+            .await?
+            .map(|batch| self.compress(&batch, message.compression))
+            .transpose()?;
 
         Ok(anemo::Response::new(RequestBatchResponse { batch }))
     }
+
+    async fn legacy_report_batch(
+        &self,
+        request: anemo::Request<LegacyWorkerBatchMessage>,
+    ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+        self.process_batch(request.into_body().batch).await
+    }
+
+    async fn legacy_request_batches(
+        &self,
+        request: anemo::Request<LegacyWorkerBatchRequest>,
+    ) -> Result<anemo::Response<LegacyWorkerBatchResponse>, anemo::rpc::Status> {
+        let batches = self.read_batches(request.into_body().digests).await?;
+        Ok(anemo::Response::new(LegacyWorkerBatchResponse { batches }))
+    }
+
+    async fn legacy_request_batch(
+        &self,
+        request: anemo::Request<LegacyRequestBatchRequest>,
+    ) -> Result<anemo::Response<LegacyRequestBatchResponse>, anemo::rpc::Status> {
+        let batch = self.read_batch(request.into_body().batch).await?;
+        Ok(anemo::Response::new(LegacyRequestBatchResponse { batch }))
+    }
 }
 
 /// Defines how the network receiver handles incoming primary messages.
@@ -104,6 +196,8 @@ pub struct PrimaryReceiverHandler {
     pub request_batches_timeout: Duration,
     // Number of random nodes to query when retrying batch requests.
     pub request_batches_retry_nodes: usize,
+    // The compression requested for the batches fetched from other workers.
+    pub batch_compression: BatchCompression,
     /// Send reconfiguration update to other tasks.
     pub tx_reconfigure: watch::Sender<ReconfigureNotification>,
 }
@@ -173,6 +267,7 @@ impl PrimaryToWorker for PrimaryReceiverHandler {
 
             let batch_request = WorkerBatchRequest {
                 digests: missing.iter().cloned().collect(),
+                compression: self.batch_compression,
             };
             let network = request
                 .extensions()
@@ -183,16 +278,15 @@ impl PrimaryToWorker for PrimaryReceiverHandler {
                 })?;
 
             let mut handles = FuturesUnordered::new();
-            let request_batches_fn = |mut client: WorkerToWorkerClient<anemo::Peer>,
-                                      batch_request,
-                                      timeout| {
-                // Wrapper function enables us to move `client` into the future.
-                async move {
-                    client
-                        .request_batches(anemo::Request::new(batch_request).with_timeout(timeout))
-                        .await
-                }
-            };
+            let request_batches_fn =
+                |mut client: WorkerToWorkerClient<anemo::Peer>, batch_request, timeout| {
+                    // Wrapper function enables us to move `client` into the future.
+                    async move {
+                        client
+                            .request_batches_or_legacy(batch_request, Some(timeout))
+                            .await
+                    }
+                };
             if first_attempt {
                 // Send first sync request to a single node.
                 let worker_name = match self.worker_cache.load().worker(&message.target, &self.id) {
@@ -250,6 +344,13 @@ impl PrimaryToWorker for PrimaryReceiverHandler {
                 match result {
                     Ok(response) => {
                         for batch in response.into_body().batches {
+                            let batch = match batch.decompress() {
+                                Ok(batch) => batch,
+                                Err(e) => {
+                                    warn!("Invalid batch in WorkerBatchResponse: {e}");
+                                    continue;
+                                }
+                            };
                             let digest = batch.digest();
                             if missing.remove(&digest) {
                                 self.store.write(digest, batch).await;
@@ -13,6 +13,10 @@ mod handlers;
 pub mod metrics;
 mod primary_connector;
 mod quorum_waiter;
+mod tx_validator;
 mod worker;
 
-pub use crate::worker::Worker;
+pub use crate::{
+    tx_validator::{TransactionValidator, TrivialTransactionValidator},
+    worker::Worker,
+};
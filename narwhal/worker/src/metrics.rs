@@ -9,6 +9,7 @@ use prometheus::{
 };
 use std::time::Duration;
 use tonic::Code;
+use types::CompressedBatch;
 
 #[derive(Clone)]
 pub struct Metrics {
@@ -52,6 +53,8 @@ pub fn initialise_metrics(metrics_registry: &Registry) -> Metrics {
 pub struct WorkerMetrics {
     /// Number of created batches from the batch_maker
     pub created_batch_size: HistogramVec,
+    /// Ratio of the compressed size to the uncompressed size of the batches sent to other workers
+    pub batch_compression_ratio: HistogramVec,
 }
 
 impl WorkerMetrics {
@@ -78,8 +81,23 @@ impl WorkerMetrics {
                 registry
             )
             .unwrap(),
+            batch_compression_ratio: register_histogram_vec_with_registry!(
+                "batch_compression_ratio",
+                "Ratio of the compressed size to the uncompressed size of the batches sent to other workers",
+                &["compression"],
+                vec![0.05, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1],
+                registry
+            )
+            .unwrap(),
         }
     }
+
+    /// Record the compression ratio of a batch sent to other workers.
+    pub fn observe_compression(&self, batch: &CompressedBatch) {
+        self.batch_compression_ratio
+            .with_label_values(&[&batch.compression.to_string()])
+            .observe(batch.compression_ratio());
+    }
 }
 
 impl Default for WorkerMetrics {
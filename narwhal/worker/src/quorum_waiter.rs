@@ -1,17 +1,20 @@
 // Copyright (c) 2021, Facebook, Inc. and its affiliates
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
-use config::{Committee, SharedWorkerCache, Stake, WorkerId};
+use crate::metrics::WorkerMetrics;
+use config::{BatchCompression, Committee, SharedWorkerCache, Stake, WorkerId};
 use crypto::PublicKey;
 use fastcrypto::hash::Hash;
 use futures::stream::{futures_unordered::FuturesUnordered, StreamExt as _};
 use network::{CancelOnDropHandler, P2pNetwork, ReliableNetwork};
+use std::sync::Arc;
 use store::Store;
 use tokio::{sync::watch, task::JoinHandle};
 use types::{
     error::DagError,
     metered_channel::{Receiver, Sender},
-    Batch, BatchDigest, ReconfigureNotification, WorkerBatchMessage, WorkerOurBatchMessage,
+    Batch, BatchDigest, CompressedBatch, ReconfigureNotification, WorkerBatchMessage,
+    WorkerOurBatchMessage,
 };
 
 #[cfg(test)]
@@ -38,6 +41,10 @@ pub struct QuorumWaiter {
     tx_our_batch: Sender<WorkerOurBatchMessage>,
     /// A network sender to broadcast the batches to the other workers.
     network: P2pNetwork,
+    /// The compression of the batches broadcast to the other workers.
+    batch_compression: BatchCompression,
+    /// Metrics handler
+    node_metrics: Arc<WorkerMetrics>,
 }
 
 impl QuorumWaiter {
@@ -53,6 +60,8 @@ impl QuorumWaiter {
         rx_quorum_waiter: Receiver<Batch>,
         tx_our_batch: Sender<WorkerOurBatchMessage>,
         network: P2pNetwork,
+        batch_compression: BatchCompression,
+        node_metrics: Arc<WorkerMetrics>,
     ) -> JoinHandle<()> {
         tokio::spawn(async move {
             Self {
@@ -65,6 +74,8 @@ impl QuorumWaiter {
                 rx_quorum_waiter,
                 tx_our_batch,
                 network,
+                batch_compression,
+                node_metrics,
             }
             .run()
             .await;
@@ -94,7 +105,10 @@ impl QuorumWaiter {
                         .map(|(name, info)| (name, info.name))
                         .collect();
                     let (primary_names, worker_names): (Vec<_>, _) = workers.into_iter().unzip();
-                    let message = WorkerBatchMessage{batch: batch.clone()};
+                    let compressed = CompressedBatch::new(&batch, self.batch_compression)
+                        .expect("Failed to compress our own batch");
+                    self.node_metrics.observe_compression(&compressed);
+                    let message = WorkerBatchMessage{batch: compressed};
                     let handlers = self.network.broadcast(worker_names, &message).await;
 
                     // Collect all the handlers to receive acknowledgements.
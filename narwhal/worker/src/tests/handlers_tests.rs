@@ -2,7 +2,9 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 use super::*;
+use crate::{worker::worker_tests::NilTxValidator, TrivialTransactionValidator};
 use fastcrypto::hash::Hash;
+use prometheus::Registry;
 use test_utils::CommitteeFixture;
 use types::WorkerToWorkerServer;
 
@@ -29,6 +31,7 @@ async fn synchronize() {
         store: store.clone(),
         request_batches_timeout: Duration::from_secs(999),
         request_batches_retry_nodes: 3, // Not used in this test.
+        batch_compression: BatchCompression::Zstd,
         tx_reconfigure,
     };
 
@@ -67,13 +70,34 @@ async fn synchronize() {
         ) -> Result<anemo::Response<RequestBatchResponse>, anemo::rpc::Status> {
             unimplemented!();
         }
+        async fn legacy_report_batch(
+            &self,
+            _request: anemo::Request<LegacyWorkerBatchMessage>,
+        ) -> Result<anemo::Response<()>, anemo::rpc::Status> {
+            unimplemented!();
+        }
+        async fn legacy_request_batches(
+            &self,
+            _request: anemo::Request<LegacyWorkerBatchRequest>,
+        ) -> Result<anemo::Response<LegacyWorkerBatchResponse>, anemo::rpc::Status> {
+            unimplemented!();
+        }
+        async fn legacy_request_batch(
+            &self,
+            _request: anemo::Request<LegacyRequestBatchRequest>,
+        ) -> Result<anemo::Response<LegacyRequestBatchResponse>, anemo::rpc::Status> {
+            unimplemented!();
+        }
     }
 
     let routes =
         anemo::Router::new().add_rpc_service(WorkerToWorkerServer::new(MockWorkerToWorker {
-            expected_request: WorkerBatchRequest { digests: missing },
+            expected_request: WorkerBatchRequest {
+                digests: missing,
+                compression: BatchCompression::Zstd,
+            },
             response: WorkerBatchResponse {
-                batches: vec![batch.clone()],
+                batches: vec![CompressedBatch::new(&batch, BatchCompression::Zstd).unwrap()],
             },
         }));
     let target_worker = target_primary.worker(id);
@@ -120,6 +144,7 @@ async fn synchronize_when_batch_exists() {
         store: store.clone(),
         request_batches_timeout: Duration::from_secs(999),
         request_batches_retry_nodes: 3, // Not used in this test.
+        batch_compression: BatchCompression::Zstd,
         tx_reconfigure,
     };
 
@@ -172,6 +197,7 @@ async fn delete_batches() {
         store: store.clone(),
         request_batches_timeout: Duration::from_secs(999),
         request_batches_retry_nodes: 3, // Not used in this test.
+        batch_compression: BatchCompression::Zstd,
         tx_reconfigure,
     };
     let message = WorkerDeleteBatchesMessage {
@@ -184,3 +210,160 @@ async fn delete_batches() {
 
     assert!(store.read(digest).await.unwrap().is_none());
 }
+
+#[tokio::test]
+async fn reject_invalid_batch() {
+    // Create a new test store.
+    let store = test_utils::open_batch_store();
+    let (tx_others_batch, _rx_others_batch) = test_utils::test_channel!(1);
+
+    // Report a batch to a handler rejecting all transactions.
+    let handler = WorkerReceiverHandler {
+        id: 0,
+        tx_others_batch,
+        store: store.clone(),
+        validator: NilTxValidator,
+        node_metrics: Arc::new(WorkerMetrics::new(&Registry::new())),
+    };
+    let batch = test_utils::batch();
+    let digest = batch.digest();
+    let message = WorkerBatchMessage {
+        batch: CompressedBatch::new(&batch, BatchCompression::Zstd).unwrap(),
+    };
+    let result = handler.report_batch(anemo::Request::new(message)).await;
+
+    // Ensure the batch is rejected and not stored.
+    assert!(result.is_err());
+    assert!(store.read(digest).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn reject_corrupted_batch() {
+    // Create a new test store.
+    let store = test_utils::open_batch_store();
+    let (tx_others_batch, _rx_others_batch) = test_utils::test_channel!(1);
+
+    let handler = WorkerReceiverHandler {
+        id: 0,
+        tx_others_batch,
+        store: store.clone(),
+        validator: TrivialTransactionValidator::default(),
+        node_metrics: Arc::new(WorkerMetrics::new(&Registry::new())),
+    };
+
+    // Report a batch that does not decompress to its announced size.
+    let batch = test_utils::batch();
+    let digest = batch.digest();
+    let mut compressed = CompressedBatch::new(&batch, BatchCompression::Zstd).unwrap();
+    compressed.uncompressed_size += 1;
+    let message = WorkerBatchMessage { batch: compressed };
+    let result = handler.report_batch(anemo::Request::new(message)).await;
+
+    // Ensure the batch is rejected and not stored.
+    assert!(result.is_err());
+    assert!(store.read(digest).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn request_batches_with_compression() {
+    // Create a new test store holding a batch.
+    let store = test_utils::open_batch_store();
+    let batch = test_utils::batch();
+    let digest = batch.digest();
+    store.write(digest, batch.clone()).await;
+    let (tx_others_batch, _rx_others_batch) = test_utils::test_channel!(1);
+
+    let handler = WorkerReceiverHandler {
+        id: 0,
+        tx_others_batch,
+        store,
+        validator: TrivialTransactionValidator::default(),
+        node_metrics: Arc::new(WorkerMetrics::new(&Registry::new())),
+    };
+
+    // The batches are sent back with the compression requested.
+    for compression in [BatchCompression::None, BatchCompression::Zstd] {
+        let request = WorkerBatchRequest {
+            digests: vec![digest],
+            compression,
+        };
+        let response = handler
+            .request_batches(anemo::Request::new(request))
+            .await
+            .unwrap()
+            .into_body();
+        assert_eq!(response.batches.len(), 1);
+        assert_eq!(response.batches[0].compression, compression);
+        assert_eq!(response.batches[0].decompress().unwrap(), batch);
+
+        let request = RequestBatchRequest {
+            batch: digest,
+            compression,
+        };
+        let response = handler
+            .request_batch(anemo::Request::new(request))
+            .await
+            .unwrap()
+            .into_body();
+        let compressed = response.batch.unwrap();
+        assert_eq!(compressed.compression, compression);
+        assert_eq!(compressed.decompress().unwrap(), batch);
+    }
+
+    // The compression ratio of the batches sent is recorded.
+    assert_eq!(
+        handler
+            .node_metrics
+            .batch_compression_ratio
+            .with_label_values(&["zstd"])
+            .get_sample_count(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn serve_legacy_routes() {
+    // Create a new test store.
+    let store = test_utils::open_batch_store();
+    let (tx_others_batch, mut rx_others_batch) = test_utils::test_channel!(1);
+
+    let handler = WorkerReceiverHandler {
+        id: 0,
+        tx_others_batch,
+        store: store.clone(),
+        validator: TrivialTransactionValidator::default(),
+        node_metrics: Arc::new(WorkerMetrics::new(&Registry::new())),
+    };
+
+    // An uncompressed batch reported by a worker that predates compression is stored.
+    let batch = test_utils::batch();
+    let digest = batch.digest();
+    let message = LegacyWorkerBatchMessage {
+        batch: batch.clone(),
+    };
+    handler
+        .legacy_report_batch(anemo::Request::new(message))
+        .await
+        .unwrap();
+    assert_eq!(rx_others_batch.recv().await.unwrap().digest, digest);
+    assert_eq!(store.read(digest).await.unwrap(), Some(batch.clone()));
+
+    // And it is sent back uncompressed.
+    let request = LegacyWorkerBatchRequest {
+        digests: vec![digest],
+    };
+    let response = handler
+        .legacy_request_batches(anemo::Request::new(request))
+        .await
+        .unwrap()
+        .into_body();
+    assert_eq!(response.batches, vec![batch.clone()]);
+
+    let request = LegacyRequestBatchRequest { batch: digest };
+    let response = handler
+        .legacy_request_batch(anemo::Request::new(request))
+        .await
+        .unwrap()
+        .into_body();
+    assert_eq!(response.batch, Some(batch));
+}
@@ -2,6 +2,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 use super::*;
+use prometheus::Registry;
 use test_utils::{batch, test_network, CommitteeFixture, WorkerToWorkerMockServer};
 
 #[tokio::test]
@@ -31,12 +32,14 @@ async fn wait_for_quorum() {
         rx_message,
         tx_batch,
         P2pNetwork::new(network.clone()),
+        BatchCompression::Zstd,
+        Arc::new(WorkerMetrics::new(&Registry::new())),
     );
 
     // Make a batch.
     let batch = batch();
     let message = WorkerBatchMessage {
-        batch: batch.clone(),
+        batch: CompressedBatch::new(&batch, BatchCompression::Zstd).unwrap(),
     };
 
     // Spawn enough listeners to acknowledge our batches.
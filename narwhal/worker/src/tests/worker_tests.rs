@@ -2,7 +2,7 @@
 // Copyright (c) Mysten Labs, Inc.
 // SPDX-License-Identifier: Apache-2.0
 use super::*;
-use crate::metrics::initialise_metrics;
+use crate::{metrics::initialise_metrics, TrivialTransactionValidator};
 use arc_swap::ArcSwap;
 use bytes::Bytes;
 use consensus::{dag::Dag, metrics::ConsensusMetrics};
@@ -47,6 +47,7 @@ async fn handle_clients_transactions() {
         worker_cache.clone(),
         parameters,
         store,
+        TrivialTransactionValidator::default(),
         metrics,
     );
 
@@ -107,6 +108,69 @@ async fn handle_clients_transactions() {
     rx_await_batch.recv().await.unwrap();
 }
 
+/// A validator rejecting all transactions.
+#[derive(Clone)]
+pub struct NilTxValidator;
+
+impl TransactionValidator for NilTxValidator {
+    type Error = anyhow::Error;
+
+    fn validate(&self, _transaction: &[u8]) -> Result<(), Self::Error> {
+        anyhow::bail!("Invalid transaction");
+    }
+}
+
+#[tokio::test]
+async fn reject_invalid_clients_transactions() {
+    let fixture = CommitteeFixture::builder().randomize_ports(true).build();
+    let committee = fixture.committee();
+    let worker_cache = fixture.shared_worker_cache();
+
+    let worker_id = 0;
+    let my_primary = fixture.authorities().next().unwrap();
+    let myself = my_primary.worker(worker_id);
+    let name = my_primary.public_key();
+
+    // Create a new test store.
+    let db = rocks::DBMap::<BatchDigest, Batch>::open(temp_dir(), None, Some("batches")).unwrap();
+    let store = Store::new(db);
+
+    let registry = Registry::new();
+    let metrics = initialise_metrics(&registry);
+
+    // Spawn a `Worker` instance rejecting all transactions.
+    Worker::spawn(
+        name.clone(),
+        myself.keypair(),
+        worker_id,
+        Arc::new(ArcSwap::from_pointee(committee.clone())),
+        worker_cache.clone(),
+        Parameters::default(),
+        store,
+        NilTxValidator,
+        metrics,
+    );
+
+    // Wait till other services have been able to start up
+    tokio::task::yield_now().await;
+    // Send an invalid transaction.
+    let address = worker_cache
+        .load()
+        .worker(&name, &worker_id)
+        .unwrap()
+        .transactions;
+    let config = mysten_network::config::Config::new();
+    let channel = config.connect_lazy(&address).unwrap();
+    let mut client = TransactionsClient::new(channel);
+    let txn = TransactionProto {
+        transaction: Bytes::from(test_utils::transaction()),
+    };
+
+    // Check invalid transactions are rejected.
+    let res = client.submit_transaction(txn).await;
+    assert_eq!(res.unwrap_err().code(), tonic::Code::InvalidArgument);
+}
+
 #[tokio::test]
 async fn get_network_peers_from_admin_server() {
     // telemetry_subscribers::init_for_testing();
@@ -159,6 +223,7 @@ async fn get_network_peers_from_admin_server() {
         tx_feedback,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
@@ -181,6 +246,7 @@ async fn get_network_peers_from_admin_server() {
         worker_cache.clone(),
         worker_1_parameters.clone(),
         store.batch_store.clone(),
+        TrivialTransactionValidator::default(),
         metrics_1.clone(),
     );
 
@@ -271,6 +337,7 @@ async fn get_network_peers_from_admin_server() {
         tx_feedback_2,
         &Registry::new(),
         None,
+        None,
     );
 
     // Wait for tasks to start
@@ -293,6 +360,7 @@ async fn get_network_peers_from_admin_server() {
         worker_cache.clone(),
         worker_2_parameters.clone(),
         store.batch_store,
+        TrivialTransactionValidator::default(),
         metrics_2.clone(),
     );
 
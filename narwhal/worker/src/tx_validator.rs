@@ -0,0 +1,39 @@
+// Copyright (c) Mysten Labs, Inc.
+// SPDX-License-Identifier: Apache-2.0
+use std::fmt::{Debug, Display};
+use types::Batch;
+
+/// Defines the validation procedure for receiving either a new single transaction (from a client)
+/// or a batch of transactions (from another worker). Invalid transactions will not receive further
+/// processing.
+pub trait TransactionValidator: Clone + Send + Sync + 'static {
+    type Error: Display + Debug + Send + Sync + 'static;
+
+    /// Determines if a transaction is valid for the worker to consider putting in a batch.
+    fn validate(&self, transaction: &[u8]) -> Result<(), Self::Error>;
+
+    /// Determines if this batch can be voted on. By default, a batch is valid if all of its
+    /// transactions are.
+    fn validate_batch(&self, batch: &Batch) -> Result<(), Self::Error> {
+        batch
+            .0
+            .iter()
+            .try_for_each(|transaction| self.validate(transaction))
+    }
+}
+
+/// Simple validator that accepts all transactions and batches.
+#[derive(Debug, Clone, Default)]
+pub struct TrivialTransactionValidator;
+
+impl TransactionValidator for TrivialTransactionValidator {
+    type Error = anyhow::Error;
+
+    fn validate(&self, _transaction: &[u8]) -> Result<(), Self::Error> {
+        Ok(())
+    }
+
+    fn validate_batch(&self, _batch: &Batch) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ use crate::{
     metrics::WorkerChannelMetrics,
     primary_connector::PrimaryConnector,
     quorum_waiter::QuorumWaiter,
+    TransactionValidator,
 };
 use anemo::{types::PeerInfo, PeerId};
 use anemo_tower::{
@@ -15,6 +16,7 @@ use anemo_tower::{
     trace::TraceLayer,
 };
 use async_trait::async_trait;
+use bytes::Bytes;
 use config::{Parameters, SharedCommittee, SharedWorkerCache, WorkerId};
 use crypto::{traits::KeyPair as _, NetworkKeyPair, PublicKey};
 use futures::StreamExt;
@@ -70,6 +72,7 @@ impl Worker {
         worker_cache: SharedWorkerCache,
         parameters: Parameters,
         store: Store<BatchDigest, Batch>,
+        validator: impl TransactionValidator,
         metrics: Metrics,
     ) -> Vec<JoinHandle<()>> {
         // Define a worker instance.
@@ -110,6 +113,8 @@ impl Worker {
             id: worker.id,
             tx_others_batch,
             store: worker.store.clone(),
+            validator: validator.clone(),
+            node_metrics: node_metrics.clone(),
         });
         let primary_service = PrimaryToWorkerServer::new(PrimaryReceiverHandler {
             name: worker.primary_name.clone(),
@@ -119,6 +124,7 @@ impl Worker {
             store: worker.store.clone(),
             request_batches_timeout: worker.parameters.sync_retry_delay,
             request_batches_retry_nodes: worker.parameters.sync_retry_nodes,
+            batch_compression: worker.parameters.batch_compression,
             tx_reconfigure,
         });
 
@@ -260,6 +266,7 @@ impl Worker {
             node_metrics,
             channel_metrics,
             endpoint_metrics,
+            validator,
             network,
         );
 
@@ -288,6 +295,7 @@ impl Worker {
         node_metrics: Arc<WorkerMetrics>,
         channel_metrics: Arc<WorkerChannelMetrics>,
         endpoint_metrics: WorkerEndpointMetrics,
+        validator: impl TransactionValidator,
         network: anemo::Network,
     ) -> Vec<JoinHandle<()>> {
         let (tx_batch_maker, rx_batch_maker) = channel_with_total(
@@ -311,11 +319,11 @@ impl Worker {
         let address = address
             .replace(0, |_protocol| Some(Protocol::Ip4(Ipv4Addr::UNSPECIFIED)))
             .unwrap();
-        let tx_receiver_handle = TxReceiverHandler { tx_batch_maker }.spawn(
-            address.clone(),
-            rx_reconfigure.clone(),
-            endpoint_metrics,
-        );
+        let tx_receiver_handle = TxReceiverHandler {
+            tx_batch_maker,
+            validator,
+        }
+        .spawn(address.clone(), rx_reconfigure.clone(), endpoint_metrics);
 
         // The transactions are sent to the `BatchMaker` that assembles them into batches. It then broadcasts
         // (in a reliable manner) the batches to all other workers that share the same `id` as us. Finally, it
@@ -327,7 +335,7 @@ impl Worker {
             rx_reconfigure.clone(),
             rx_batch_maker,
             tx_quorum_waiter,
-            node_metrics,
+            node_metrics.clone(),
         );
 
         // The `QuorumWaiter` waits for 2f authorities to acknowledge reception of the batch. It then forwards
@@ -342,6 +350,8 @@ impl Worker {
             rx_quorum_waiter,
             tx_our_batch,
             P2pNetwork::new(network),
+            self.parameters.batch_compression,
+            node_metrics,
         );
 
         info!(
@@ -355,11 +365,26 @@ impl Worker {
 
 /// Defines how the network receiver handles incoming transactions.
 #[derive(Clone)]
-struct TxReceiverHandler {
+struct TxReceiverHandler<V> {
     tx_batch_maker: Sender<Transaction>,
+    validator: V,
 }
 
-impl TxReceiverHandler {
+impl<V: TransactionValidator> TxReceiverHandler<V> {
+    /// Validate a transaction off the async runtime, as validation may verify signatures.
+    async fn validate(&self, transaction: Bytes) -> Result<Bytes, Status> {
+        let validator = self.validator.clone();
+        let (transaction, result) = tokio::task::spawn_blocking(move || {
+            let result = validator.validate(transaction.as_ref());
+            (transaction, result)
+        })
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?;
+        result
+            .map(|_| transaction)
+            .map_err(|e| Status::invalid_argument(format!("Invalid transaction: {e}")))
+    }
+
     async fn wait_for_shutdown(mut rx_reconfigure: watch::Receiver<ReconfigureNotification>) {
         loop {
             let result = rx_reconfigure.changed().await;
@@ -395,12 +420,12 @@ impl TxReceiverHandler {
 }
 
 #[async_trait]
-impl Transactions for TxReceiverHandler {
+impl<V: TransactionValidator> Transactions for TxReceiverHandler<V> {
     async fn submit_transaction(
         &self,
         request: Request<TransactionProto>,
     ) -> Result<Response<Empty>, Status> {
-        let message = request.into_inner().transaction;
+        let message = self.validate(request.into_inner().transaction).await?;
         // Send the transaction to the batch maker.
         self.tx_batch_maker
             .send(message.to_vec())
@@ -418,9 +443,10 @@ impl Transactions for TxReceiverHandler {
         let mut transactions = request.into_inner();
 
         while let Some(Ok(txn)) = transactions.next().await {
+            let transaction = self.validate(txn.transaction).await?;
             // Send the transaction to the batch maker.
             self.tx_batch_maker
-                .send(txn.transaction.to_vec())
+                .send(transaction.to_vec())
                 .await
                 .expect("Failed to send transaction");
         }
@@ -8,8 +8,9 @@ import {
   isGetOwnedObjectsResponse,
   isGetTxnDigestsResponse,
   isGetTxnDigestsResponse__DEPRECATED,
+  isObjectsPage,
+  isPaginatedEvents,
   isPaginatedTransactionDigests,
-  isSuiEvents,
   isSuiExecuteTransactionResponse,
   isSuiMoveFunctionArgTypes,
   isSuiMoveNormalizedFunction,
@@ -20,9 +21,7 @@ import {
 } from '../types/index.guard';
 import {
   Coin,
-  DEFAULT_END_TIME,
-  DEFAULT_START_TIME,
-  EVENT_QUERY_MAX_LIMIT,
+  EventId,
   ExecuteTransactionRequestType,
   CoinDenominationInfoResponse,
   GatewayTxSeqNumber,
@@ -30,14 +29,13 @@ import {
   getObjectReference,
   GetTxnDigestsResponse,
   ObjectId,
-  ObjectOwner,
+  ObjectsPage,
   Ordering,
+  PaginatedEvents,
   PaginatedTransactionDigests,
   SubscriptionId,
-  SuiAddress,
   SuiEventEnvelope,
   SuiEventFilter,
-  SuiEvents,
   SuiExecuteTransactionResponse,
   SuiMoveFunctionArgTypes,
   SuiMoveNormalizedFunction,
@@ -205,11 +203,18 @@ export class JsonRpcProvider extends Provider {
   // Objects
   async getObjectsOwnedByAddress(address: string): Promise<SuiObjectInfo[]> {
     try {
-      return await this.client.requestWithType(
+      // TODO: remove after we deploy 0.12.0 DevNet
+      if ((await this.getRpcApiVersion()) === PRE_PAGINATION_API_VERSION) {
+        return await this.client.requestWithType(
+          'sui_getObjectsOwnedByAddress',
+          [address],
+          isGetOwnedObjectsResponse,
+          this.skipDataValidation
+        );
+      }
+      return await this.getAllOwnedObjects(
         'sui_getObjectsOwnedByAddress',
-        [address],
-        isGetOwnedObjectsResponse,
-        this.skipDataValidation
+        address
       );
     } catch (err) {
       throw new Error(
@@ -286,11 +291,18 @@ export class JsonRpcProvider extends Provider {
 
   async getObjectsOwnedByObject(objectId: string): Promise<SuiObjectInfo[]> {
     try {
-      return await this.client.requestWithType(
+      // TODO: remove after we deploy 0.12.0 DevNet
+      if ((await this.getRpcApiVersion()) === PRE_PAGINATION_API_VERSION) {
+        return await this.client.requestWithType(
+          'sui_getObjectsOwnedByObject',
+          [objectId],
+          isGetOwnedObjectsResponse,
+          this.skipDataValidation
+        );
+      }
+      return await this.getAllOwnedObjects(
         'sui_getObjectsOwnedByObject',
-        [objectId],
-        isGetOwnedObjectsResponse,
-        this.skipDataValidation
+        objectId
       );
     } catch (err) {
       throw new Error(
@@ -299,6 +311,28 @@ export class JsonRpcProvider extends Provider {
     }
   }
 
+  /**
+   * Fetches every page of a paginated owned objects method
+   */
+  private async getAllOwnedObjects(
+    method: string,
+    owner: string
+  ): Promise<SuiObjectInfo[]> {
+    const objects: SuiObjectInfo[] = [];
+    let cursor: ObjectId | null = null;
+    do {
+      const page: ObjectsPage = await this.client.requestWithType(
+        method,
+        [owner, cursor, null],
+        isObjectsPage,
+        this.skipDataValidation
+      );
+      objects.push(...page.data);
+      cursor = page.hasNextPage ? page.nextCursor : null;
+    } while (cursor !== null);
+    return objects;
+  }
+
   async getObject(objectId: string): Promise<GetObjectDataResponse> {
     try {
       return await this.client.requestWithType(
@@ -602,140 +636,22 @@ export class JsonRpcProvider extends Provider {
 
   // Events
 
-  async getEventsByTransaction(
-    digest: TransactionDigest,
-    count: number = EVENT_QUERY_MAX_LIMIT
-  ): Promise<SuiEvents> {
-    try {
-      return await this.client.requestWithType(
-        'sui_getEventsByTransaction',
-        [digest, count],
-        isSuiEvents,
-        this.skipDataValidation
-      );
-    } catch (err) {
-      throw new Error(
-        `Error getting events by transaction: ${digest}, with error: ${err}`
-      );
-    }
-  }
-
-  async getEventsByModule(
-    package_: string,
-    module: string,
-    count: number = EVENT_QUERY_MAX_LIMIT,
-    startTime: number = DEFAULT_START_TIME,
-    endTime: number = DEFAULT_END_TIME
-  ): Promise<SuiEvents> {
-    try {
-      return await this.client.requestWithType(
-        'sui_getEventsByModule',
-        [package_, module, count, startTime, endTime],
-        isSuiEvents,
-        this.skipDataValidation
-      );
-    } catch (err) {
-      throw new Error(
-        `Error getting events by transaction module: ${package_}::${module}, with error: ${err}`
-      );
-    }
-  }
-
-  async getEventsByMoveEventStructName(
-    moveEventStructName: string,
-    count: number = EVENT_QUERY_MAX_LIMIT,
-    startTime: number = DEFAULT_START_TIME,
-    endTime: number = DEFAULT_END_TIME
-  ): Promise<SuiEvents> {
-    try {
-      return await this.client.requestWithType(
-        'sui_getEventsByMoveEventStructName',
-        [moveEventStructName, count, startTime, endTime],
-        isSuiEvents,
-        this.skipDataValidation
-      );
-    } catch (err) {
-      throw new Error(
-        `Error getting events by move event struct name: ${moveEventStructName}, with error: ${err}`
-      );
-    }
-  }
-
-  async getEventsBySender(
-    sender: SuiAddress,
-    count: number = EVENT_QUERY_MAX_LIMIT,
-    startTime: number = DEFAULT_START_TIME,
-    endTime: number = DEFAULT_END_TIME
-  ): Promise<SuiEvents> {
-    try {
-      return await this.client.requestWithType(
-        'sui_getEventsBySender',
-        [sender, count, startTime, endTime],
-        isSuiEvents,
-        this.skipDataValidation
-      );
-    } catch (err) {
-      throw new Error(
-        `Error getting events by sender: ${sender}, with error: ${err}`
-      );
-    }
-  }
-
-  async getEventsByRecipient(
-    recipient: ObjectOwner,
-    count: number = EVENT_QUERY_MAX_LIMIT,
-    startTime: number = DEFAULT_START_TIME,
-    endTime: number = DEFAULT_END_TIME
-  ): Promise<SuiEvents> {
-    try {
-      return await this.client.requestWithType(
-        'sui_getEventsByRecipient',
-        [recipient, count, startTime, endTime],
-        isSuiEvents,
-        this.skipDataValidation
-      );
-    } catch (err) {
-      throw new Error(
-        `Error getting events by receipient: ${recipient}, with error: ${err}`
-      );
-    }
-  }
-
-  async getEventsByObject(
-    object: ObjectId,
-    count: number = EVENT_QUERY_MAX_LIMIT,
-    startTime: number = DEFAULT_START_TIME,
-    endTime: number = DEFAULT_END_TIME
-  ): Promise<SuiEvents> {
-    try {
-      return await this.client.requestWithType(
-        'sui_getEventsByObject',
-        [object, count, startTime, endTime],
-        isSuiEvents,
-        this.skipDataValidation
-      );
-    } catch (err) {
-      throw new Error(
-        `Error getting events by object: ${object}, with error: ${err}`
-      );
-    }
-  }
-
-  async getEventsByTimeRange(
-    count: number = EVENT_QUERY_MAX_LIMIT,
-    startTime: number = DEFAULT_START_TIME,
-    endTime: number = DEFAULT_END_TIME
-  ): Promise<SuiEvents> {
+  async getEvents(
+    query: SuiEventFilter,
+    cursor: EventId | null,
+    limit: number | null,
+    order: Ordering = 'Ascending'
+  ): Promise<PaginatedEvents> {
     try {
       return await this.client.requestWithType(
-        'sui_getEventsByTimeRange',
-        [count, startTime, endTime],
-        isSuiEvents,
+        'sui_getEvents',
+        [query, cursor, limit, order === 'Descending'],
+        isPaginatedEvents,
         this.skipDataValidation
       );
     } catch (err) {
       throw new Error(
-        `Error getting events by time range: ${startTime} thru ${endTime}, with error: ${err}`
+        `Error getting events for query: ${err} for query ${JSON.stringify(query)}`
       );
     }
   }
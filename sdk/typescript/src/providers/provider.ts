@@ -22,9 +22,8 @@ import {
   SuiExecuteTransactionResponse,
   TransactionDigest,
   ObjectId,
-  SuiAddress,
-  ObjectOwner,
-  SuiEvents,
+  EventId,
+  PaginatedEvents,
   PaginatedTransactionDigests,
   TransactionQuery,
   Ordering,
@@ -220,98 +219,18 @@ export abstract class Provider {
   abstract syncAccountState(address: string): Promise<any>;
 
   /**
-   * Get events for one transaction
-   * @param digest transaction digest to search by
-   * @param count max result count
+   * Get events for a given query criteria
+   * @param query - filter the events are matched against
+   * @param cursor - optional paging cursor, the `nextCursor` of the previous page
+   * @param limit - maximum number of events per page, capped to EVENT_QUERY_MAX_LIMIT
+   * @param order - event query ordering
    */
-  abstract getEventsByTransaction(
-    digest: TransactionDigest,
-    count: number
-  ): Promise<SuiEvents>;
-
-  /**
-   * Get events emitted from within the specified Move module
-   * @param package_ Move package object ID
-   * @param module Move module name
-   * @param count max result count
-   * @param startTime start of time range
-   * @param endTime end of time range, exclusive
-   */
-  abstract getEventsByModule(
-    packageId: ObjectId,
-    module: string,
-    count: number,
-    startTime: number,
-    endTime: number
-  ): Promise<SuiEvents>;
-
-  /**
-   * Get events with a matching Move type name
-   * @param moveEventStructName Move struct type name
-   * @param count max result count
-   * @param startTime start of time range to search
-   * @param endTime end of time range
-   */
-  abstract getEventsByMoveEventStructName(
-    moveEventStructName: string,
-    count: number,
-    startTime: number,
-    endTime: number
-  ): Promise<SuiEvents>;
-
-  /**
-   * Get events from transactions sent by a specific address
-   * @param sender Sui address of the sender of the transaction that generated the event
-   * @param count max result count
-   * @param startTime start of time range to search
-   * @param endTime end of time range
-   */
-  abstract getEventsBySender(
-    sender: SuiAddress,
-    count: number,
-    startTime: number,
-    endTime: number
-  ): Promise<SuiEvents>;
-
-  /**
-   * Get events with a matching recipient
-   * @param recipient object owner that received the transaction that generated the event
-   * @param count max result count
-   * @param startTime start of time range to search
-   * @param endTime end of time range
-   */
-  abstract getEventsByRecipient(
-    recipient: ObjectOwner,
-    count: number,
-    startTime: number,
-    endTime: number
-  ): Promise<SuiEvents>;
-
-  /**
-   * Get events involving the given object
-   * @param object object id created, mutated, or deleted in events
-   * @param count max result count
-   * @param startTime start of time range to search
-   * @param endTime end of time range
-   */
-  abstract getEventsByObject(
-    object: ObjectId,
-    count: number,
-    startTime: number,
-    endTime: number
-  ): Promise<SuiEvents>;
-
-  /**
-   * Get all events within the given time span
-   * @param count max result count
-   * @param startTime start of time range to search
-   * @param endTime end of time range
-   */
-  abstract getEventsByTimeRange(
-    count: number,
-    startTime: number,
-    endTime: number
-  ): Promise<SuiEvents>;
+  abstract getEvents(
+    query: SuiEventFilter,
+    cursor: EventId | null,
+    limit: number | null,
+    order: Ordering
+  ): Promise<PaginatedEvents>;
 
   /**
    * Subscribe to get notifications whenever an event matching the filter occurs
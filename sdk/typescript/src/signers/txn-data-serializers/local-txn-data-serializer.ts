@@ -346,7 +346,13 @@ export class LocalTxnDataSerializer implements TxnDataSerializer {
       ? 'TransactionData_Deprecated'
       : 'TransactionData';
 
-    const dataBytes = bcs.ser(format, tx, size).toBytes();
+    const dataBytes = bcs
+      .ser(
+        format,
+        { sponsor: { None: null }, expiration: { None: null }, ...tx },
+        size
+      )
+      .toBytes();
     const serialized = new Uint8Array(TYPE_TAG.length + dataBytes.length);
     serialized.set(TYPE_TAG);
     serialized.set(dataBytes, TYPE_TAG.length);
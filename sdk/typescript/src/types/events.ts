@@ -76,20 +76,36 @@ export type SuiEventFilter =
   | { MoveEventType: string }
   | { MoveEventField: MoveEventField }
   | { SenderAddress: SuiAddress }
+  | { Recipient: ObjectOwner }
   | { EventType: EventType }
+  | { ObjectId: ObjectId }
+  | { Transaction: TransactionDigest }
+  | { TimeRange: { start_time: number; end_time: number } }
   | { All: SuiEventFilter[] }
   | { Any: SuiEventFilter[] }
   | { And: [SuiEventFilter, SuiEventFilter] }
   | { Or: [SuiEventFilter, SuiEventFilter] };
 
+export type EventId = {
+  txSeq: number;
+  eventSeq: number;
+};
+
 export type SuiEventEnvelope = {
   timestamp: number;
   txDigest: TransactionDigest;
+  id: EventId;
   event: SuiEvent;
 };
 
 export type SuiEvents = SuiEventEnvelope[];
 
+export type PaginatedEvents = {
+  data: SuiEvents;
+  nextCursor: EventId | null;
+  hasNextPage: boolean;
+};
+
 export type SubscriptionId = number;
 
 export type SubscriptionEvent = {
@@ -99,5 +115,3 @@ export type SubscriptionEvent = {
 
 // mirrors the value defined in https://github.com/MystenLabs/sui/blob/e12f8c58ef7ba17205c4caf5ad2c350cbb01656c/crates/sui-json-rpc/src/api.rs#L27
 export const EVENT_QUERY_MAX_LIMIT = 100;
-export const DEFAULT_START_TIME = 0;
-export const DEFAULT_END_TIME = Number.MAX_SAFE_INTEGER;
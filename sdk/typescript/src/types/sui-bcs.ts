@@ -303,14 +303,30 @@ export type TransactionData = {
   gasPrice: number;
   kind: TransactionKind;
   gasPayment: SuiObjectRef;
+  /** The address paying for gas, if it is not the sender. */
+  sponsor?: { None: null } | { Some: string };
+  /** The last epoch in which the transaction can be executed, if any. */
+  expiration?: { None: null } | { Epoch: number };
 };
 
+bcs.registerEnumType('Option<SuiAddress>', {
+  None: null,
+  Some: 'SuiAddress',
+});
+
+bcs.registerEnumType('TransactionExpiration', {
+  None: null,
+  Epoch: 'u64',
+});
+
 bcs.registerStructType('TransactionData', {
   kind: 'TransactionKind',
   sender: 'SuiAddress',
   gasPayment: 'SuiObjectRef',
   gasPrice: 'u64',
   gasBudget: 'u64',
+  sponsor: 'Option<SuiAddress>',
+  expiration: 'TransactionExpiration',
 });
 
 // ========== Deprecated ===========